@@ -131,6 +131,133 @@ export interface NativeFileInput {
   repoId: string
   /** Language identifier (e.g., "ts", "tsx", "js", "py", "go"). */
   language: string
+  /**
+   * Module path of the enclosing Go module (the `module` line of
+   * `go.mod`, e.g. "github.com/acme/app"). Used to tell module-internal
+   * imports apart from third-party ones; ignored for other languages.
+   */
+  modulePath?: string
+}
+/** Options for `scan_directory`. */
+export interface NativeScanOptions {
+  /**
+   * Gitignore-style patterns to exclude, on top of `.gitignore` and
+   * `.sdlmcpignore` files.
+   */
+  ignorePatterns?: Array<string>
+  /**
+   * Glob patterns relative to the root; when set, only matching files
+   * are returned.
+   */
+  includeGlobs?: Array<string>
+  /**
+   * Language identifiers to keep (e.g. "ts", "py"); all supported
+   * languages when unset or empty.
+   */
+  languages?: Array<string>
+  /** Files larger than this many bytes are skipped. No limit when unset. */
+  maxFileBytes?: number
+  /**
+   * Walk into symlinked directories. Off by default, in which case they
+   * are skipped and listed in `NativeScanResult::skipped_symlinks`.
+   * Symlinked files are scanned either way.
+   */
+  followSymlinks?: boolean
+}
+/** Result of `scan_directory_with_report`. */
+export interface NativeScanResult {
+  /** Files ready for `parse_files`. */
+  files: Array<NativeFileInput>
+  /** Symlinks that were not scanned through. */
+  skippedSymlinks: Array<NativeSkippedSymlink>
+}
+/** A symlink `scan_directory` did not follow. */
+export interface NativeSkippedSymlink {
+  /** Path of the link relative to the scan root (forward slashes). */
+  path: string
+  /**
+   * Where the link points: its raw target, or for a cycle the ancestor
+   * directory it leads back to.
+   */
+  target: string
+  /**
+   * "not_followed" (following is off), "cycle" (the target contains
+   * the link) or "broken" (the target does not exist).
+   */
+  reason: string
+}
+/** Per-repo options for a `parse_files` batch. */
+export interface NativeParseOptions {
+  /**
+   * tsconfig `compilerOptions.paths` entries with targets already made
+   * repo-relative (`baseUrl` applied), e.g. `@app/*` -> `["src/*"]`.
+   */
+  pathAliases?: Array<NativePathAlias>
+  /**
+   * Names of the packages in this monorepo (pnpm / yarn / npm workspaces,
+   * Cargo workspace members, Go modules, Python distributions). Imports of
+   * them get `origin` "workspace" instead of "external".
+   */
+  workspacePackages?: Array<string>
+  /**
+   * Add Halstead volume and a maintainability-index estimate to each
+   * callable's `metricsJson`. Off by default.
+   */
+  halsteadMetrics?: boolean
+  /**
+   * Collect string literals into `NativeParsedFile::string_literals`.
+   * Off by default.
+   */
+  stringLiterals?: boolean
+  /**
+   * Scan for likely hardcoded secrets into
+   * `NativeParsedFile::secrets`. Off by default.
+   */
+  secretScan?: boolean
+  /**
+   * Add a MinHash signature to each callable for similarity search.
+   * Off by default.
+   */
+  minhashSignatures?: boolean
+  /**
+   * File-system routing convention of the project ("nextjs" or
+   * "sveltekit"); TS/JS files under its route directories are added to
+   * `NativeParsedFile::routes`. Unset by default.
+   */
+  routeFramework?: string
+  /**
+   * Collect every comment into `NativeParsedFile::comments`. Off by
+   * default.
+   */
+  comments?: boolean
+  /**
+   * Compute `NativeParsedFile::trigrams` for code search. Off by
+   * default.
+   */
+  trigrams?: boolean
+  /**
+   * Algorithm for `NativeParsedFile::content_hash`: "sha256" (default)
+   * or "xxh3", a much faster non-cryptographic hash for change
+   * detection. Symbol IDs and fingerprints always use SHA-256.
+   */
+  contentHash?: string
+  /**
+   * How `NativeParsedSymbol::symbol_id` is derived: "path" (default)
+   * hashes the file path in, "qualified" uses the enclosing symbols'
+   * names instead so moving a file keeps its symbol IDs. Qualified IDs
+   * that collide across files of one batch get a module-path prefix.
+   */
+  symbolIdScheme?: string
+}
+/** One tsconfig `paths` entry. */
+export interface NativePathAlias {
+  /** Specifier pattern with at most one `*` (e.g. `"@app/*"`). */
+  pattern: string
+  /**
+   * Repo-relative targets tried in order; `*` is replaced by the text the
+   * pattern's `*` matched (e.g. `["src/*"]`).
+   */
+  targets: Array<string>
 }
 /** Range within a source file (1-indexed lines, 0-indexed columns). */
 export interface NativeRange {
@@ -152,13 +279,29 @@ export interface NativeSymbolSignatureParam {
   /** Declared type annotation, if present (e.g. `"string"`, `"Request | null"`). */
   typeName?: string
 }
+/**
+ * A generic type parameter: `T extends Foo = Bar` (TS), `T: Clone = Self`
+ * (Rust), `K comparable` (Go), `T extends Comparable<T>` (Java),
+ * `T : class` (C#).
+ */
+export interface NativeTypeParameter {
+  /** Parameter name (e.g. `"T"`, or `"'a"` for a Rust lifetime). */
+  name: string
+  /**
+   * Constraint as written, without the `extends` / `:` keyword (e.g.
+   * `"Foo"`, `"Clone + Send"`, `"class, new()"`).
+   */
+  constraint?: string
+  /** Default type as written, without the `=` (e.g. `"Bar"`). */
+  default?: string
+}
 /**
  * Structured representation of a function or method signature.
  *
  * `None` fields are omitted rather than serialised as empty arrays/strings,
  * keeping the napi payload compact. The struct is `None` on the parent symbol
- * when there are no params, no return type, and no generics (e.g. plain
- * variables or class declarations).
+ * when there are no params, no return type, no generics, and no modifiers
+ * (e.g. plain variables or class declarations).
  */
 export interface NativeSymbolSignature {
   /** Parameter list. `None` when the symbol has no parameters. */
@@ -167,6 +310,67 @@ export interface NativeSymbolSignature {
   returns?: string
   /** Generic type parameters (e.g. `["T", "U extends Serializable"]`). */
   generics?: Array<string>
+  /** Receiver type of a Go method as written (e.g. `"*Server"`). */
+  receiver?: string
+  /**
+   * Value of an enum member: the initializer as written, or the implicit
+   * auto-incremented number (e.g. `"\"active\""`, `"2"`).
+   */
+  value?: string
+  /**
+   * Generic type parameters with their constraint and default, in
+   * declaration order (e.g. `<T extends Foo = Bar>`).
+   */
+  typeParameters?: Array<NativeTypeParameter>
+  /**
+   * Props type of a React component: a referenced type name
+   * (`"UserCardProps"`) or an inline object type (`"{ label: string }"`).
+   */
+  propsType?: string
+  /**
+   * Declaration modifiers, in the fixed order `abstract`, `static`,
+   * `override`, `readonly`, `async`, `generator`. `None` when there are none.
+   */
+  modifiers?: Array<string>
+  /**
+   * Parameters the body assigns a new value to (`opts = {...}`), in
+   * parameter order. `None` when there are none.
+   */
+  reassignedParams?: Array<string>
+  /**
+   * Parameters whose properties or elements the body writes, or that it
+   * calls a mutating method on (`opts.retries = 3`, `items.push(x)`), in
+   * parameter order. `None` when there are none.
+   */
+  mutatedParams?: Array<string>
+}
+/**
+ * A format-specific key/value fact attached to a symbol, for extractors
+ * whose nodes carry data that has no dedicated field (e.g. the `namespace`
+ * and container `image` of a Kubernetes resource).
+ */
+export interface NativeSymbolAttribute {
+  key: string
+  value: string
+}
+/** A decorator / annotation / attribute parsed from its source text. */
+export interface NativeDecorator {
+  /**
+   * Name without sigil or arguments (e.g. `"Controller"`, `"app.route"`,
+   * `"derive"`).
+   */
+  name: string
+  /**
+   * Positional arguments. String literals are unquoted; anything else is
+   * kept as written (e.g. `@Get(':id')` -> `[":id"]`).
+   */
+  arguments: Array<string>
+  /**
+   * Object-literal properties and named arguments (e.g.
+   * `@Component({ selector: 'app-root' })` -> `selector=app-root`,
+   * `@RequestMapping(value = "/users")` -> `value=/users`).
+   */
+  options: Array<NativeSymbolAttribute>
 }
 /** Extracted symbol from AST analysis. */
 export interface NativeParsedSymbol {
@@ -211,10 +415,121 @@ export interface NativeParsedSymbol {
    * `#[derive(Debug)]`). Empty for languages without a decorator concept.
    */
   decorators: Array<string>
+  /**
+   * Structured form of `decorators`: each decorator's name with its literal
+   * arguments (e.g. `@Get(':id')`, `@Component({ selector: 'app-root' })`).
+   */
+  decoratorMetadata: Array<NativeDecorator>
   /** Search-oriented text including identifier splits, summary, tags, and path hints. */
   searchText: string
   /** Summary quality score: 1.0 = doc comment, 0.4 = typed function, 0.3 = heuristic, 0.0 = none. */
   summaryQuality?: number
+  /**
+   * Language- or format-specific key/value facts (e.g. `owner`, `async`,
+   * a Kubernetes `namespace`).
+   */
+  attributes: Array<NativeSymbolAttribute>
+  /**
+   * `symbol_id` of the innermost symbol containing this one: the class of
+   * a method, the function around a nested function, the namespace around
+   * its members. `None` for top-level symbols.
+   */
+  parentSymbolId?: string
+  /**
+   * JSON object of code metrics for functions, methods and constructors
+   * (`{"cyclomatic": 4}`). `None` for other kinds and for files without a
+   * syntax tree.
+   */
+  metricsJson?: string
+  /**
+   * Likely dead code: unexported (or `private`) and never mentioned
+   * elsewhere in its file. Decorated symbols and entry points such as
+   * `main` are never flagged.
+   */
+  unreferenced: boolean
+  /**
+   * Marked deprecated by an attribute (`#[deprecated]`, `[Obsolete]`,
+   * `@Deprecated`), its doc comment (`@deprecated`, `Deprecated:`), or a
+   * Python `DeprecationWarning` in its body.
+   */
+  deprecated: boolean
+  /** Deprecation note or warning text, when `deprecated` and one is given. */
+  deprecationMessage?: string
+  /**
+   * Winnowed k-gram fingerprints of the body's normalized tokens, for
+   * near-duplicate detection: functions sharing many of them share code.
+   * `None` for other kinds and for bodies shorter than one k-gram.
+   */
+  winnowFingerprints?: Array<number>
+  /**
+   * MinHash signature of the body's k-gram shingles: the share of
+   * positions two signatures agree on estimates their similarity. Set
+   * only with `NativeParseOptions::minhash_signatures`, and only for
+   * functions, methods and constructors.
+   */
+  minhashSignature?: Array<number>
+  /**
+   * Functions and methods only: "pure", "read_only" (reads environment
+   * or instance state) or "effectful" (I/O, shared-state or parameter
+   * mutation), including what same-file callees do.
+   */
+  purity?: string
+  /**
+   * `invariants` with their category, evidence and confidence, in the
+   * same order.
+   */
+  invariantRecords: Array<NativeBehaviorRecord>
+  /**
+   * `side_effects` with their category, evidence and confidence, in the
+   * same order.
+   */
+  sideEffectRecords: Array<NativeBehaviorRecord>
+  /**
+   * Hash of the body alone, without the name or location, so a symbol
+   * renamed or moved to another file keeps it. Unset for symbols of
+   * text formats.
+   */
+  bodyFingerprint?: string
+  /**
+   * `get_fingerprint_version()` of the parser that computed
+   * `ast_fingerprint` and `body_fingerprint`.
+   */
+  fingerprintVersion: number
+  /**
+   * Hash of the declaration up to the body (the whole symbol if it has
+   * none), whitespace-insensitive. A change means the symbol's
+   * interface changed. Unset for symbols of text formats.
+   */
+  signatureHash?: string
+  /**
+   * Hash of the body text, whitespace-insensitive. A change with an
+   * unchanged `signature_hash` means only the implementation changed.
+   */
+  bodyHash?: string
+}
+/** An invariant or side effect of a symbol and where it was detected. */
+export interface NativeBehaviorRecord {
+  /** The entry as listed in `invariants` / `side_effects`. */
+  text: string
+  /**
+   * Invariants: "requires", "asserts", "validates", "ensures",
+   * "expects", "panics", "raises", "propagates" or "throws". Side
+   * effects: "network", "filesystem", "database", "global_state",
+   * "dom", "environment" or "process".
+   */
+  category: string
+  /**
+   * The source text the entry was read from, whitespace-collapsed and
+   * cut to 160 characters.
+   */
+  evidence: string
+  range: NativeRange
+  /**
+   * 0-1: how reliably the detector identifies this kind of entry. Line
+   * patterns that also match unrelated code (`open(`, `.query(`) score
+   * low; syntax-tree matches and specific APIs score high.
+   */
+  confidence: number
 }
 /** Extracted import statement. */
 export interface NativeParsedImport {
@@ -237,6 +552,78 @@ export interface NativeParsedImport {
   isReExport: boolean
   /** Source range. */
   range: NativeRange
+  /**
+   * Number of leading dots on a Python relative import (`from . import x`
+   * is 1, `from ..pkg import y` is 2), or of leading `super::` segments on
+   * a Rust path. `None` for absolute imports and for languages without
+   * package-relative imports.
+   */
+  relativeLevel?: number
+  /**
+   * Where the imported module lives: "stdlib", "internal" (same module or
+   * crate as the importing file), "workspace" (another package of the same
+   * monorepo, see `NativeParseOptions::workspace_packages`), or
+   * "external". `None` when the extractor does not classify imports
+   * beyond `is_external`.
+   */
+  origin?: string
+  /**
+   * Renamed specifiers, original name first (e.g. `export { a as b } from
+   * './x'` -> `a` / `b`), so re-export chains through barrel files can be
+   * followed back to the declaring module. `named_imports` keeps only the
+   * local / exported name.
+   */
+  aliases: Array<NativeImportAlias>
+  /**
+   * Whether the module is loaded lazily through `import('./x')` rather
+   * than a static import statement. Such imports carry no bindings.
+   */
+  isDynamic: boolean
+  /**
+   * Import attributes (`with { type: 'json' }`, legacy `assert { ... }`,
+   * or the `with` option of `import('./x.json', { with: { ... } })`).
+   */
+  attributes: Array<NativeSymbolAttribute>
+  /**
+   * Repo-relative path of the file the specifier resolves to (relative
+   * specifiers and `NativeParseOptions::path_aliases`, TS/JS family
+   * only). `None` for bare package specifiers and unresolvable targets.
+   */
+  resolvedRelPath?: string
+  /**
+   * How often each imported binding is referenced in the rest of the file
+   * (TS/JS family and Python). Empty for re-exports and other languages.
+   */
+  bindingUsages: Array<NativeBindingUsage>
+  /**
+   * Whether this re-exports everything the target exports without naming
+   * it (`export * from './mod'`, Rust `pub use mod::*`), so barrel files
+   * can be flattened. `export * as ns` binds a namespace instead and is
+   * not flagged.
+   */
+  isStarReExport: boolean
+  /**
+   * URL-like scheme of the specifier, lowercased (`https`, `npm`, `jsr`,
+   * `node`), TS/JS family only. `None` for bare and relative specifiers.
+   */
+  scheme?: string
+}
+/** References to one imported binding. */
+export interface NativeBindingUsage {
+  /** Local name of the binding. */
+  name: string
+  /**
+   * Identifier references outside the import itself, matched by name
+   * (shadowing locals are not told apart). 0 means the import is unused.
+   */
+  references: number
+}
+/** A renamed import or re-export specifier. */
+export interface NativeImportAlias {
+  /** Name as declared by the source module (`a` in `a as b`). */
+  imported: string
+  /** Name bound or re-exported by this file (`b` in `a as b`). */
+  local: string
 }
 /** Extracted call site. */
 export interface NativeParsedCall {
@@ -256,13 +643,487 @@ export interface NativeParsedCall {
   callType: string
   /** Source range. */
   range: NativeRange
+  /**
+   * Specifier of the import that binds the callee's base identifier
+   * (`api` in `api.save()`), if any.
+   */
+  importSpecifier?: string
+  /**
+   * Name the callee refers to in that module: the original name of a
+   * named import, `"default"` for a default import, or the member called
+   * on a namespace import (`save` in `api.save()`).
+   */
+  importedName?: string
+  /**
+   * Type the callee is a member of, when known: the enclosing class for
+   * `this.save()` / `self.save()`.
+   */
+  receiverType?: string
+  /**
+   * Position of this call in a fluent chain (`a.b().c().d()`: `b` is 0,
+   * `c` is 1, `d` is 2). Later links carry only their own method name as
+   * `callee_identifier`. `None` when the call is not part of a chain.
+   */
+  chainIndex?: number
+  /**
+   * Whether the call's result is awaited directly (`await f()`,
+   * `f().await`); `false` for fire-and-forget or stored promises.
+   */
+  isAwaited: boolean
+  /**
+   * Inside a branch of an `if` / `switch` / `match` / ternary (not its
+   * condition).
+   */
+  inConditional: boolean
+  /** Inside a loop body or comprehension. */
+  inLoop: boolean
+  /** Inside the body of a `try` block. */
+  inTry: boolean
+  /**
+   * Inside error handling: a `catch` / `except` clause, Go's
+   * `if err != nil`, a Rust `Err(..)` match arm, or a promise `.catch`
+   * callback.
+   */
+  inErrorHandler: boolean
+  /**
+   * Statement type ("select", "insert", "update", "delete", ...) of the
+   * SQL literal passed to a query function (`db.query("SELECT ...")`).
+   */
+  sqlStatement?: string
+  /**
+   * Tables that SQL references (schema-qualified as written); empty
+   * unless `sql_statement` is set.
+   */
+  sqlTables: Array<string>
+}
+/**
+ * React hook call (`useState`, `useEffect`, custom `useX`) attributed to
+ * the component or hook that makes it.
+ */
+export interface NativeHookUsage {
+  /** Hook name without any `React.` prefix (e.g., "useState", "useAuth"). */
+  hook: string
+  /**
+   * nodeId of the enclosing component or custom hook (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Whether the hook is one of React's built-in hooks. */
+  isBuiltin: boolean
+  /**
+   * Dependency array entries (`useEffect(fn, [a, b.c])` -> `["a", "b.c"]`).
+   * `None` when no dependency array is passed.
+   */
+  dependencies?: Array<string>
+  /** Source range of the hook call. */
+  range: NativeRange
+}
+/**
+ * One side of an event-emitter edge: a listener registration
+ * (`emitter.on('ready', onReady)`) or an emission (`emitter.emit('ready')`).
+ * Listeners and emissions of the same `event` pair up into a flow.
+ */
+export interface NativeEventUsage {
+  /** Event name string literal. */
+  event: string
+  /** "listen" or "emit". */
+  kind: string
+  /** Method called ("on", "once", "addListener", "emit", ...). */
+  method: string
+  /** Receiver expression as written (e.g., "emitter", "this.bus"). */
+  emitter: string
+  /**
+   * Listener passed by name (`onReady`, `this.handle`); `None` for inline
+   * functions and emissions.
+   */
+  handler?: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the call. */
+  range: NativeRange
+}
+/**
+ * Where a symbol raises or handles an error (`throw new NotFound()`,
+ * `raise ValueError`, `Err(..)`, `panic!`, `catch (IOException e)`).
+ */
+export interface NativeExceptionSite {
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** "throw", "panic", "error_return" or "catch". */
+  kind: string
+  /** Exception / error type as written, when the source names one. */
+  exceptionType?: string
+  /** Source range of the throw, macro, call or clause. */
+  range: NativeRange
+}
+/**
+ * A named type used by a parameter, return or field annotation: the source
+ * of a USES_TYPE edge from the enclosing symbol to the type.
+ */
+export interface NativeTypeReference {
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Type name as written, qualified if it was (`User`, `models.User`). */
+  typeName: string
+  /** "parameter", "return" or "field". */
+  role: string
+  /** Source range of the first reference. */
+  range: NativeRange
+}
+/** An ORM model class and the table it maps to. */
+export interface NativeOrmEntity {
+  /** "typeorm", "sequelize", "sqlalchemy" or "entity_framework". */
+  framework: string
+  /** Class name as declared. */
+  className: string
+  /** nodeId of the class symbol. */
+  classNodeId?: string
+  /**
+   * Table name when declared (`@Entity("users")`, `__tablename__`,
+   * `[Table("users")]`); `None` when the ORM derives it.
+   */
+  table?: string
+  /** Mapped columns, in declaration order. */
+  columns: Array<NativeOrmColumn>
+  /** Relations to other entities, in declaration order. */
+  relations: Array<NativeOrmRelation>
+  /** Source range of the class. */
+  range: NativeRange
+}
+/** A mapped column of an ORM entity. */
+export interface NativeOrmColumn {
+  /** Field or property name. */
+  name: string
+  /**
+   * Declared column type (`"varchar"`, `String(50)`, `DataType.STRING`),
+   * else the field's type annotation.
+   */
+  columnType?: string
+  /** Part of the primary key. */
+  primaryKey: boolean
+}
+/** A relation declared on an ORM entity. */
+export interface NativeOrmRelation {
+  /**
+   * "one_to_one", "one_to_many", "many_to_one", "many_to_many",
+   * "relationship" (SQLAlchemy, cardinality not declared) or
+   * "foreign_key".
+   */
+  kind: string
+  /** Field or property holding the relation. */
+  field: string
+  /**
+   * Related entity class (`Photo`), or the referenced column for
+   * SQLAlchemy foreign keys (`users.id`).
+   */
+  target?: string
+}
+/**
+ * A GraphQL resolver: the function implementing one field of a schema
+ * type (`Query.user`, `User.posts`).
+ */
+export interface NativeGraphqlResolver {
+  /**
+   * Schema type the field belongs to ("Query", "Mutation",
+   * "Subscription" or an object type).
+   */
+  parentType: string
+  /** Schema field name. */
+  field: string
+  /** Handler as written; `None` for inline functions. */
+  handler?: string
+  /** nodeId of the handler when it is declared in this file. */
+  handlerNodeId?: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /**
+   * Source range of the map entry, decorated method or registration
+   * call.
+   */
+  range: NativeRange
+}
+/**
+ * A gRPC service registration (`rpc` unset) or the method implementing
+ * one of its RPCs.
+ */
+export interface NativeGrpcBinding {
+  /**
+   * Service name from the generated stub (`Greeter` for
+   * `Greeter.GreeterBase`, `RegisterGreeterServer`, `GreeterServicer`).
+   */
+  service: string
+  /**
+   * RPC name as implemented (Java methods are lowerCamelCase); `None`
+   * for a service registration.
+   */
+  rpc?: string
+  /** Implementing class or type as written, when known. */
+  implementation?: string
+  /** Handler as written; `None` for registrations and inline functions. */
+  handler?: string
+  /** nodeId of the handler when it is declared in this file. */
+  handlerNodeId?: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the registration call or implementing method. */
+  range: NativeRange
+}
+/**
+ * An HTTP route registration (`app.get("/users/:id", show)`,
+ * `@app.post("/items")`, `@GetMapping("/{id}")`).
+ */
+export interface NativeHttpRoute {
+  /** Upper-case HTTP method; "ALL" when the registration matches any. */
+  method: string
+  /**
+   * Path pattern as declared (`/users/:id`, `/items/{id}`), joined with
+   * any class-level prefix.
+   */
+  path: string
+  /** Handler as written; `None` for inline functions. */
+  handler?: string
+  /** nodeId of the handler when it is declared in this file. */
+  handlerNodeId?: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the registration call or annotation. */
+  range: NativeRange
+}
+/**
+ * A read of a named environment variable (`process.env.PORT`,
+ * `os.getenv("HOME")`, `std::env::var("PATH")`).
+ */
+export interface NativeEnvVarUsage {
+  /** Variable name. */
+  key: string
+  /**
+   * Environment object or reader function as written, whitespace removed
+   * (e.g., "process.env", "os.getenv", "env!").
+   */
+  accessor: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the access. */
+  range: NativeRange
+}
+/** A logging call (`logger.info("...")`, `log.Printf(...)`, `warn!(...)`). */
+export interface NativeLogCall {
+  /** "trace", "debug", "info", "warn", "error" or "fatal". */
+  level: string
+  /**
+   * Logger as written, whitespace removed (e.g., "console", "this.logger",
+   * "logging", "tracing").
+   */
+  logger: string
+  /**
+   * First string argument as written, placeholders included; `None` when
+   * no argument is a string literal.
+   */
+  message?: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the call. */
+  range: NativeRange
+}
+/**
+ * A string literal, tagged with a guessed role so translation keys,
+ * endpoints and queries can be cross-referenced between files.
+ */
+export interface NativeStringLiteral {
+  /** Contents without quotes or prefix; escapes are left as written. */
+  value: string
+  /** "i18n", "sql", "url" or "path"; `None` when no heuristic matched. */
+  role?: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the literal, quotes included. */
+  range: NativeRange
+}
+/**
+ * A tagged template literal (`` gql`...` ``, `` sql`...` ``,
+ * `` styled.div`...` ``).
+ */
+export interface NativeTaggedTemplate {
+  /**
+   * Tag expression as written, whitespace removed (`gql`, `styled.div`,
+   * `styled(Button)`).
+   */
+  tag: string
+  /**
+   * Embedded language for known tags: "graphql", "sql", "css" or
+   * "html".
+   */
+  language?: string
+  /** Raw text between the backticks, `${...}` substitutions included. */
+  content: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the tagged template. */
+  range: NativeRange
+}
+/** An identifier used in a file and how often it occurs. */
+export interface NativeIdentifierCount {
+  /** Identifier text. */
+  name: string
+  /** Occurrences in the file, declarations included. */
+  count: number
+}
+/** A source comment and the symbol it is attached to. */
+export interface NativeComment {
+  /** Comment text as written, delimiters included. */
+  text: string
+  /** "line", "block" or "doc" (`/**`, `///`, `//!` and `/*!` comments). */
+  kind: string
+  /**
+   * nodeId of the symbol declared on the line after the comment (or
+   * after the run of comments it belongs to), or on the same line for
+   * a trailing comment.
+   */
+  attachedNodeId?: string
+  /** Source range of the comment. */
+  range: NativeRange
+}
+/** A likely hardcoded secret. The value itself is never included. */
+export interface NativeSecretFinding {
+  /**
+   * "aws_access_key", "private_key", "github_token", "slack_token",
+   * "stripe_key", "google_api_key", or "high_entropy_assignment" for a
+   * random-looking literal assigned to a credential-like name.
+   */
+  kind: string
+  /**
+   * Name the literal is assigned to (`apiToken`); `None` for format
+   * matches.
+   */
+  name?: string
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Source range of the match or the assigned literal. */
+  range: NativeRange
+}
+/**
+ * A function passed by name as an argument (`arr.map(transform)`,
+ * `router.get(path, handler)`): an indirect call edge from the enclosing
+ * symbol to the referenced function.
+ */
+export interface NativeCallbackReference {
+  /**
+   * nodeId of the enclosing symbol (same format as
+   * `NativeParsedCall::caller_node_id`).
+   */
+  callerNodeId: string
+  /** Name of the referenced function as written at the call site. */
+  calleeIdentifier: string
+  /**
+   * Callee of the call the function is passed to (e.g., "arr.map",
+   * "router.get").
+   */
+  passedTo: string
+  /** Zero-based position of the argument. */
+  argumentIndex: number
+  /**
+   * For promise handlers (`.then` / `.catch` / `.finally`), the call or
+   * value the chain starts from (`load` in `load(id).then(a).catch(b)`).
+   */
+  chainRoot?: string
+  /** Source range of the argument. */
+  range: NativeRange
+}
+/**
+ * Line counts of a file. Every line is exactly one of code, comment or
+ * blank.
+ */
+export interface NativeLineCounts {
+  /** Number of lines. */
+  total: number
+  /** Lines with any non-comment, non-whitespace text. */
+  code: number
+  /** Lines holding only comment text. */
+  comment: number
+  /** Empty or whitespace-only lines. */
+  blank: number
+}
+/** License and copyright notices from the top of a file. */
+export interface NativeLicenseHeader {
+  /**
+   * SPDX license expression (`MIT`, `Apache-2.0 OR MIT`, `GPL-3.0`).
+   * `None` when the header only carries copyright notices.
+   */
+  license?: string
+  /**
+   * How `license` was found: `spdx` for an `SPDX-License-Identifier:`
+   * tag, `header` for recognised license prose.
+   */
+  source?: string
+  /**
+   * Copyright lines without comment markers
+   * (`Copyright (c) 2024 Acme Inc.`), in file order.
+   */
+  copyrights: Array<string>
+}
+/** What a module does when it is first evaluated. */
+export interface NativeFileFlags {
+  /** `await` (or `for await`) outside any function body. */
+  hasTopLevelAwait: boolean
+  /**
+   * A top-level statement that runs code on import: calls, assignments,
+   * control flow, side-effect-only imports (`import "./polyfill"`), or
+   * declarations initialized by a call. Pure declarations do not count.
+   */
+  hasTopLevelSideEffects: boolean
+  /**
+   * Directive prologue entries (e.g. `"use client"`, `"use server"`,
+   * `"use strict"`), without quotes.
+   */
+  directives: Array<string>
 }
 /** Complete parse result for a single file. */
 export interface NativeParsedFile {
   /** Relative path (matches input). */
   relPath: string
-  /** SHA-256 hex digest of file content. */
+  /**
+   * Hex digest of file content; SHA-256 unless
+   * `NativeParseOptions::content_hash` selects another algorithm.
+   */
   contentHash: string
+  /**
+   * Algorithm that produced `content_hash` ("sha256" or "xxh3"). `None`
+   * when the file could not be read.
+   */
+  contentHashKind?: string
   /**
    * Raw file content (passed through to avoid double-read on JS side).
    * `None` for error paths where content was unavailable or irrelevant.
@@ -274,6 +1135,80 @@ export interface NativeParsedFile {
   imports: Array<NativeParsedImport>
   /** Extracted calls. */
   calls: Array<NativeParsedCall>
+  /** React hook usages (TS/JS family only). */
+  hooks: Array<NativeHookUsage>
+  /** Functions passed by name as call arguments. */
+  callbackReferences: Array<NativeCallbackReference>
+  /**
+   * Event-emitter listener registrations and emissions (TS/JS family
+   * only).
+   */
+  events: Array<NativeEventUsage>
+  /** Throw, panic and error-return sites and caught exception types. */
+  exceptions: Array<NativeExceptionSite>
+  /**
+   * Named types used by parameter, return and field annotations, one per
+   * enclosing symbol, type and role.
+   */
+  typeReferences: Array<NativeTypeReference>
+  /**
+   * HTTP route registrations, plus file-system routes when
+   * `NativeParseOptions::route_framework` is set.
+   */
+  routes: Array<NativeHttpRoute>
+  /**
+   * ORM entity classes (TypeORM, sequelize-typescript, SQLAlchemy,
+   * Entity Framework).
+   */
+  ormEntities: Array<NativeOrmEntity>
+  /** GraphQL resolvers bound to schema fields. */
+  graphqlResolvers: Array<NativeGraphqlResolver>
+  /** gRPC service registrations and RPC implementations. */
+  grpcBindings: Array<NativeGrpcBinding>
+  /** Environment variables read by name. */
+  envVars: Array<NativeEnvVarUsage>
+  /** Logging calls with their level and message. */
+  logCalls: Array<NativeLogCall>
+  /** Tagged template literals (TS/JS family only). */
+  taggedTemplates: Array<NativeTaggedTemplate>
+  /**
+   * Every identifier in the file with its occurrence count, sorted by
+   * name.
+   */
+  identifiers: Array<NativeIdentifierCount>
+  /**
+   * String literals; empty unless `NativeParseOptions::string_literals`
+   * is set.
+   */
+  stringLiterals: Array<NativeStringLiteral>
+  /**
+   * Likely hardcoded secrets; empty unless
+   * `NativeParseOptions::secret_scan` is set.
+   */
+  secrets: Array<NativeSecretFinding>
+  /**
+   * Comments in source order; empty unless
+   * `NativeParseOptions::comments` is set.
+   */
+  comments: Array<NativeComment>
+  /**
+   * Distinct byte trigrams of the content, ASCII case folded, packed
+   * into the low 24 bits of each value and sorted; `None` unless
+   * `NativeParseOptions::trigrams` is set.
+   */
+  trigrams?: Array<number>
+  /** Module-level flags (TS/JS family only). */
+  flags?: NativeFileFlags
+  /**
+   * Code / comment / blank line counts. `None` for files handled by the
+   * line-based extractors, which have no syntax tree to find comments in.
+   */
+  lineCounts?: NativeLineCounts
+  /**
+   * SPDX tag or recognised license header and copyright notices at the
+   * top of the file. `None` when there are neither.
+   */
+  license?: NativeLicenseHeader
   /** Parse error message, if any. */
   parseError?: string
 }
@@ -281,12 +1216,59 @@ export interface PreloadedWindowsLibrary {
   token: number
   loadedPath: string
 }
+/** One file fed into `build_directory_manifest`. */
+export interface NativeManifestFile {
+  /** Relative path from repo root (forward slashes). */
+  relPath: string
+  /** Content hash of the file, e.g. `NativeParsedFile::content_hash`. */
+  contentHash: string
+}
+/** Merkle hash of one directory in a `build_directory_manifest` result. */
+export interface NativeDirectoryHash {
+  /** Directory path relative to repo root; empty for the root itself. */
+  path: string
+  /**
+   * SHA-256 over the sorted names and hashes of the directory's files
+   * and subdirectories.
+   */
+  hash: string
+  /** Number of files anywhere beneath the directory. */
+  fileCount: number
+}
 export declare function preloadWindowsLibrary(absolutePath: string): PreloadedWindowsLibrary
 export declare function releaseWindowsLibrary(token: number): void
-export declare function parseFiles(files: Array<NativeFileInput>, threadCount: number): Array<NativeParsedFile>
-export declare function parseFilesAsync(files: Array<NativeFileInput>, threadCount: number): Promise<unknown>
-export declare function hashContentNative(content: string): string
+export declare function parseFiles(files: Array<NativeFileInput>, threadCount: number, options?: NativeParseOptions | undefined | null): Array<NativeParsedFile>
+export declare function parseFilesAsync(files: Array<NativeFileInput>, threadCount: number, options?: NativeParseOptions | undefined | null): Promise<unknown>
+/**
+ * Walk `root_path` for source files (respecting `.gitignore`) and return
+ * them ready for `parse_files`.
+ */
+export declare function scanDirectory(rootPath: string, repoId: string, options?: NativeScanOptions | undefined | null): Array<NativeFileInput>
+/** `scan_directory`, also reporting the symlinks that were skipped. */
+export declare function scanDirectoryWithReport(rootPath: string, repoId: string, options?: NativeScanOptions | undefined | null): NativeScanResult
+/**
+ * Hash content with the algorithm named by `kind` ("sha256", the default,
+ * or "xxh3"), matching `NativeParsedFile::content_hash`.
+ */
+export declare function hashContentNative(content: string, kind?: string | undefined | null): string
 export declare function generateSymbolIdNative(repoId: string, relPath: string, kind: string, name: string, fingerprint: string): string
+/**
+ * Path-independent symbol ID, as produced by the "qualified"
+ * `symbol_id_scheme`.
+ */
+export declare function generateQualifiedSymbolIdNative(repoId: string, kind: string, qualifiedName: string, fingerprint: string): string
+/**
+ * Version of the symbol fingerprint algorithms. Symbols stored with an
+ * older `fingerprint_version` need re-fingerprinting.
+ */
+export declare function getFingerprintVersion(): number
+/** Merkle manifest of directory hashes built from per-file content hashes. */
+export declare function buildDirectoryManifest(files: Array<NativeManifestFile>): Array<NativeDirectoryHash>
+/**
+ * Top-most directories whose manifest hash did not change; no file in
+ * their subtree needs re-processing by an incremental run.
+ */
+export declare function findUnchangedDirectories(previous: Array<NativeDirectoryHash>, current: Array<NativeDirectoryHash>): Array<string>
 export declare function computeClusters(symbols: Array<NativeClusterSymbol>, edges: Array<NativeClusterEdge>, minClusterSize: number): Array<NativeClusterAssignment>
 export declare function computeLayout(inputJson: string, seed: number, iterations: number): string
 export declare function computePersonalizedPagerank(adjacency: Array<Array<NativePprAdjEntry>>, seeds: Array<NativePprSeed>, alpha: number, epsilon: number, maxNodesTouched: number): Array<NativePprScore>
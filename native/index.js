@@ -146,7 +146,13 @@ if (!nativeBinding) {
 }
 
 module.exports.parseFiles = nativeBinding.parseFiles;
+module.exports.scanDirectory = nativeBinding.scanDirectory;
+module.exports.scanDirectoryWithReport = nativeBinding.scanDirectoryWithReport;
 module.exports.hashContentNative = nativeBinding.hashContentNative;
 module.exports.generateSymbolIdNative = nativeBinding.generateSymbolIdNative;
+module.exports.generateQualifiedSymbolIdNative = nativeBinding.generateQualifiedSymbolIdNative;
+module.exports.getFingerprintVersion = nativeBinding.getFingerprintVersion;
+module.exports.buildDirectoryManifest = nativeBinding.buildDirectoryManifest;
+module.exports.findUnchangedDirectories = nativeBinding.findUnchangedDirectories;
 module.exports.preloadWindowsLibrary = nativeBinding.preloadWindowsLibrary;
 module.exports.releaseWindowsLibrary = nativeBinding.releaseWindowsLibrary;
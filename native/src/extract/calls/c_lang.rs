@@ -83,5 +83,6 @@ fn process_call_expression(
         callee_identifier,
         call_type,
         range: extract_range(node),
+        ..Default::default()
     })
 }
@@ -0,0 +1,303 @@
+//! Functions passed by name as call arguments.
+//!
+//! `arr.map(transform)` or `router.get(path, handler)` never call
+//! `transform` / `handler` syntactically, but the callee will, so the
+//! reference is as much a call edge as `transform(x)` is. The pass is
+//! grammar-agnostic: it looks at the argument lists every supported grammar
+//! shares and keeps only arguments that name a function or method declared
+//! in the file, or a binding imported into it.
+//!
+//! Promise handlers (`.then(onLoad)`, `.catch(onError)`) are always
+//! functions, so any identifier passed to them is kept, and the reference
+//! records the call the chain started from so async flows read like
+//! `await` code.
+
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::types::{NativeCallbackReference, NativeParsedImport, NativeParsedSymbol};
+
+use super::common::{extract_range, find_enclosing_symbol, node_text};
+
+/// Call node kinds across the supported grammars. Argument lists also
+/// appear elsewhere (Python class bases), which are not calls.
+const CALL_KINDS: &[&str] = &[
+    "call_expression",
+    "call",
+    "new_expression",
+    "method_invocation",
+    "object_creation_expression",
+    "invocation_expression",
+    "function_call_expression",
+    "member_call_expression",
+    "scoped_call_expression",
+];
+
+/// Argument list node kinds across the supported grammars.
+const ARGUMENT_LISTS: &[&str] = &["arguments", "argument_list"];
+
+/// Wrappers around a single argument (C# / PHP `argument`, Python
+/// `target=worker`).
+const ARGUMENT_WRAPPERS: &[&str] = &["argument", "keyword_argument"];
+
+const FUNCTION_KINDS: &[&str] = &["function", "method"];
+
+const PROMISE_METHODS: &[&str] = &["then", "catch", "finally"];
+
+pub fn extract_callback_references(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    imports: &[NativeParsedImport],
+) -> Vec<NativeCallbackReference> {
+    let functions = callable_names(symbols, imports);
+    let mut references = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if ARGUMENT_LISTS.contains(&node.kind()) {
+            collect_from_arguments(node, source, symbols, &functions, &mut references);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    references
+}
+
+/// Names of functions and methods declared in the file plus every local
+/// name an import binds.
+fn callable_names<'a>(
+    symbols: &'a [NativeParsedSymbol],
+    imports: &'a [NativeParsedImport],
+) -> HashSet<&'a str> {
+    let declared = symbols
+        .iter()
+        .filter(|symbol| FUNCTION_KINDS.contains(&symbol.kind.as_str()))
+        .map(|symbol| symbol.name.as_str());
+    let imported = imports
+        .iter()
+        .filter(|import| !import.is_re_export)
+        .flat_map(|import| {
+            import
+                .default_import
+                .iter()
+                .chain(import.named_imports.iter())
+        })
+        .map(String::as_str)
+        .filter(|name| *name != "*");
+    declared.chain(imported).collect()
+}
+
+fn collect_from_arguments(
+    arguments: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    functions: &HashSet<&str>,
+    references: &mut Vec<NativeCallbackReference>,
+) {
+    let Some(call) = arguments
+        .parent()
+        .filter(|parent| CALL_KINDS.contains(&parent.kind()))
+    else {
+        return;
+    };
+    let passed_to = callee_text(call, arguments, source);
+    if passed_to.is_empty() {
+        return;
+    }
+    let chain_root = promise_chain_root(call, source);
+
+    let mut cursor = arguments.walk();
+    let named: Vec<Node<'_>> = arguments
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() != "comment")
+        .collect();
+    for (index, argument) in named.into_iter().enumerate() {
+        let Some(identifier) = unwrap_argument(argument) else {
+            continue;
+        };
+        let name = node_text(identifier, source);
+        if chain_root.is_none() && !functions.contains(name) {
+            continue;
+        }
+        references.push(NativeCallbackReference {
+            caller_node_id: find_enclosing_symbol(argument, symbols),
+            callee_identifier: name.to_string(),
+            passed_to: passed_to.to_string(),
+            argument_index: index as u32,
+            chain_root: chain_root.clone(),
+            range: extract_range(identifier),
+        });
+    }
+}
+
+/// For a `.then` / `.catch` / `.finally` call, the expression the promise
+/// chain starts from: the callee of the first call that is not itself a
+/// promise method (`load(id).then(a).catch(b)` -> `load`), or the receiver
+/// when the chain starts from a value (`pending.then(a)` -> `pending`).
+fn promise_chain_root(call: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut receiver = promise_receiver(call, source)?;
+    loop {
+        if receiver.kind() != "call_expression" {
+            return Some(node_text(receiver, source).to_string());
+        }
+        match promise_receiver(receiver, source) {
+            Some(inner) => receiver = inner,
+            None => {
+                let callee = receiver.child_by_field_name("function")?;
+                return Some(node_text(callee, source).to_string());
+            }
+        }
+    }
+}
+
+/// Receiver of `receiver.then(...)` (or `.catch` / `.finally`, including
+/// `?.then`).
+fn promise_receiver<'a>(call: Node<'a>, source: &[u8]) -> Option<Node<'a>> {
+    let callee = call.child_by_field_name("function")?;
+    if callee.kind() != "member_expression" {
+        return None;
+    }
+    let property = node_text(callee.child_by_field_name("property")?, source);
+    if !PROMISE_METHODS.contains(&property) {
+        return None;
+    }
+    callee.child_by_field_name("object")
+}
+
+/// The bare identifier an argument consists of, looking through argument
+/// wrappers.
+fn unwrap_argument(argument: Node<'_>) -> Option<Node<'_>> {
+    let argument = if ARGUMENT_WRAPPERS.contains(&argument.kind()) {
+        argument
+            .child_by_field_name("value")
+            .or_else(|| argument.named_child(argument.named_child_count().checked_sub(1)?))?
+    } else {
+        argument
+    };
+    (argument.kind() == "identifier").then_some(argument)
+}
+
+/// Source text of the call up to its argument list, without `new` or
+/// generic arguments (`new Worker<T>(run)` -> `Worker`).
+fn callee_text<'a>(call: Node<'_>, arguments: Node<'_>, source: &'a [u8]) -> &'a str {
+    let text = std::str::from_utf8(&source[call.start_byte()..arguments.start_byte()])
+        .unwrap_or_default()
+        .trim();
+    let text = text
+        .strip_prefix("new")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .map_or(text, str::trim_start);
+    let text = text.split('<').next().unwrap_or(text).trim_end();
+    text.trim_end_matches("?.")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn functions_passed_as_arguments_are_recorded_as_callback_references() {
+        let cases = [
+            (
+                "ts",
+                "routes.ts",
+                r#"import { handler } from "./handlers";
+
+function transform(x: number) {
+  return x * 2;
+}
+
+export function setup(router: Router, items: number[]) {
+  router.get("/users", handler);
+  items.map(transform).filter(Boolean);
+  log(items);
+}
+"#,
+                vec![("handler", "router.get", 1), ("transform", "items.map", 0)],
+            ),
+            (
+                "py",
+                "jobs.py",
+                r#"from threading import Thread
+
+class Job(Thread):
+    pass
+
+def worker():
+    pass
+
+def start(items):
+    Thread(target=worker).start()
+    sorted(items, key=len)
+"#,
+                vec![("worker", "Thread", 0)],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let references: Vec<(&str, &str, u32)> = parsed
+                .callback_references
+                .iter()
+                .map(|reference| {
+                    (
+                        reference.callee_identifier.as_str(),
+                        reference.passed_to.as_str(),
+                        reference.argument_index,
+                    )
+                })
+                .collect();
+            assert_eq!(references, expected, "{language}");
+            assert!(
+                parsed
+                    .callback_references
+                    .iter()
+                    .all(|reference| reference.caller_node_id.starts_with("setup:")
+                        || reference.caller_node_id.starts_with("start:")),
+                "{language}"
+            );
+        }
+    }
+
+    #[test]
+    fn promise_chain_handlers_are_callback_references_with_their_root() {
+        let source = r#"export function load(id: string, onError: (e: Error) => void) {
+  fetchUser(id).then(render).catch(onError).finally(cleanup);
+  pending.then(render);
+}
+
+function render() {}
+function cleanup() {}
+"#;
+
+        let parsed = parse_source("ts", "src/load.ts", source);
+
+        let references: Vec<(&str, Option<&str>)> = parsed
+            .callback_references
+            .iter()
+            .map(|reference| {
+                (
+                    reference.callee_identifier.as_str(),
+                    reference.chain_root.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            references,
+            vec![
+                ("render", Some("fetchUser")),
+                ("onError", Some("fetchUser")),
+                ("cleanup", Some("fetchUser")),
+                ("render", Some("pending")),
+            ]
+        );
+    }
+}
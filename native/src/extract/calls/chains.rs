@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::types::{NativeParsedCall, NativeRange};
+
+use super::common::line_starts;
+
+/// Number each link of a fluent chain (`db.select().from(t).where(c)`) and
+/// reduce every link after the first to its own method name.
+///
+/// Every link of a chain starts where the chain starts and each link's
+/// range contains the previous one, so chains are found from the call
+/// ranges alone, whatever the grammar. Extractors that build the callee
+/// from the receiver text otherwise report the last link as
+/// `db.select().from(t).where`.
+pub fn decompose_call_chains(calls: &mut [NativeParsedCall], source: &str) {
+    let mut by_start: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for (index, call) in calls.iter().enumerate() {
+        by_start
+            .entry((call.range.start_line, call.range.start_col))
+            .or_default()
+            .push(index);
+    }
+
+    let line_starts = line_starts(source);
+    for mut links in by_start.into_values().filter(|links| links.len() > 1) {
+        links.sort_by_key(|&index| end(&calls[index].range));
+        // Duplicate records for one call node are not a chain.
+        if links
+            .windows(2)
+            .any(|pair| end(&calls[pair[0]].range) == end(&calls[pair[1]].range))
+        {
+            continue;
+        }
+
+        for (position, pair) in links.windows(2).enumerate() {
+            let inner_end = end(&calls[pair[0]].range);
+            if let Some(name) = link_name(source, &line_starts, inner_end) {
+                calls[pair[1]].callee_identifier = name.to_string();
+            }
+            calls[pair[0]].chain_index = Some(position as u32);
+        }
+        if let Some(&last) = links.last() {
+            calls[last].chain_index = Some(links.len() as u32 - 1);
+        }
+    }
+}
+
+/// Method name that follows the previous link's closing parenthesis,
+/// skipping the member operator and anything between (`?`, `!`, line
+/// breaks). `None` for a call of a call's result (`make()()`).
+fn link_name<'a>(
+    source: &'a str,
+    line_starts: &[usize],
+    (line, col): (u32, u32),
+) -> Option<&'a str> {
+    let offset = line_starts.get(line.checked_sub(1)? as usize)? + col as usize;
+    let rest = source.get(offset..)?.trim_start_matches(|c: char| {
+        c.is_whitespace() || matches!(c, '?' | '!' | '.' | ':' | '-' | '>')
+    });
+    let len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(rest.len());
+    (len > 0).then(|| &rest[..len])
+}
+
+fn end(range: &NativeRange) -> (u32, u32) {
+    (range.end_line, range.end_col)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn fluent_call_chains_are_decomposed_into_links() {
+        let cases = [
+            (
+                "ts",
+                "query.ts",
+                r#"export function find(db: Db) {
+  return db
+    .select("id")
+    .from(users)!
+    .where(active());
+}
+"#,
+            ),
+            (
+                "py",
+                "query.py",
+                r#"def find(session):
+    return session.query(User).filter(active()).all()
+"#,
+            ),
+        ];
+
+        for (language, rel_path, source) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let mut links: Vec<(u32, &str)> = parsed
+                .calls
+                .iter()
+                .filter_map(|call| Some((call.chain_index?, call.callee_identifier.as_str())))
+                .collect();
+            links.sort();
+            let expected: &[(u32, &str)] = match language {
+                "ts" => &[(0, "select"), (1, "from"), (2, "where")],
+                _ => &[(0, "session.query"), (1, "filter"), (2, "all")],
+            };
+            assert_eq!(links, expected, "{language}");
+
+            let active = parsed
+                .calls
+                .iter()
+                .find(|call| call.callee_identifier == "active")
+                .expect("missing active() call");
+            assert_eq!(active.chain_index, None, "{language}");
+        }
+    }
+}
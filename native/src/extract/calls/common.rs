@@ -1,4 +1,4 @@
-use tree_sitter::Node;
+use tree_sitter::{Node, Point};
 
 use crate::types::{NativeParsedSymbol, NativeRange};
 
@@ -13,7 +13,8 @@ use crate::types::{NativeParsedSymbol, NativeRange};
 ///
 /// Must match the TypeScript source-of-truth set in
 /// `src/indexer/treesitter/extractCalls.ts` (function, method, constructor,
-/// dynamic, computed, tagged-template). Rust extractors must emit only these
+/// dynamic, computed, tagged-template), plus `iife` for immediately-invoked
+/// function expressions. Rust extractors must emit only these
 /// kebab-case/lowercase strings — `"direct"` and `"tagged_template"` are
 /// legacy aliases that were removed in Task 1.5.
 pub const CALL_TYPES: &[&str] = &[
@@ -23,6 +24,23 @@ pub const CALL_TYPES: &[&str] = &[
     "dynamic",
     "computed",
     "tagged-template",
+    "iife",
+];
+
+/// Function-expression node kinds, across grammars, that can be invoked
+/// where they are written.
+const FUNCTION_EXPRESSIONS: &[&str] = &[
+    "function_expression",
+    "function",
+    "generator_function",
+    "arrow_function",
+    "lambda",
+    "func_literal",
+    "closure_expression",
+    "lambda_expression",
+    "anonymous_method_expression",
+    "anonymous_function",
+    "anonymous_function_creation_expression",
 ];
 
 pub fn make_node_id(name: &str, range: &NativeRange) -> String {
@@ -104,6 +122,107 @@ pub fn node_text<'a>(node: Node<'a>, source: &'a [u8]) -> &'a str {
     node.utf8_text(source).unwrap_or("")
 }
 
+/// Byte offset of the start of each line, for mapping a [`NativeRange`] back
+/// into the source text.
+pub fn line_starts(source: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+        .collect()
+}
+
+/// Leading dotted / `::` / `->` path of the call whose range is `range`,
+/// after any `new` keyword (`new api.Client()` -> `api.Client`,
+/// `this->save(` -> `this->save`). Read from the source because several
+/// extractors shorten `callee_identifier` to the bare method name.
+pub fn callee_path<'a>(
+    source: &'a str,
+    line_starts: &[usize],
+    range: &NativeRange,
+) -> Option<&'a str> {
+    let offset =
+        line_starts.get(range.start_line.checked_sub(1)? as usize)? + range.start_col as usize;
+    let text = source.get(offset..)?;
+    let text = text
+        .strip_prefix("new")
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .map_or(text, str::trim_start);
+    let mut end = 0;
+    while end < text.len() {
+        if text[end..].starts_with("->") {
+            end += 2;
+            continue;
+        }
+        let c = text[end..].chars().next()?;
+        if !(c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | ':' | '?')) {
+            break;
+        }
+        end += c.len_utf8();
+    }
+    Some(&text[..end])
+}
+
+/// Whether the value of `call` is awaited directly (`await f()`,
+/// `f().await`, `await (f())`), as opposed to fired and forgotten or stored.
+pub fn is_awaited(call: Node<'_>) -> bool {
+    let mut current = call;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "parenthesized_expression" | "non_null_expression" => current = parent,
+            // Python spells the node `await`.
+            "await_expression" | "await" => return true,
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Whether a call's `function` node is a function expression, possibly
+/// parenthesized: an immediately-invoked function expression
+/// (`(function () {})()`, `(() => {})()`, `func() {}()`, `(|| {})()`).
+pub fn is_iife(function: Node<'_>) -> bool {
+    let mut current = function;
+    while current.kind() == "parenthesized_expression" {
+        match current.named_child(0) {
+            Some(inner) => current = inner,
+            None => return false,
+        }
+    }
+    FUNCTION_EXPRESSIONS.contains(&current.kind())
+}
+
+/// Callee identifier for an IIFE: the function expression's own name when
+/// it has one (`(function boot() {})()` -> `boot`), else `<iife>`. The body's
+/// calls belong to the symbol enclosing the IIFE, which is what
+/// [`find_enclosing_symbol`] reports for them.
+pub fn iife_callee(function: Node<'_>, source: &[u8]) -> String {
+    let mut current = function;
+    while current.kind() == "parenthesized_expression" {
+        match current.named_child(0) {
+            Some(inner) => current = inner,
+            None => break,
+        }
+    }
+    current
+        .child_by_field_name("name")
+        .map(|name| node_text(name, source).to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "<iife>".to_string())
+}
+
+/// The smallest node spanning `range`, i.e. the node a record produced by
+/// [`extract_range`] came from (or a same-sized descendant of it).
+pub fn node_at_range<'a>(root: Node<'a>, range: &NativeRange) -> Option<Node<'a>> {
+    let start = Point::new(
+        range.start_line.checked_sub(1)? as usize,
+        range.start_col as usize,
+    );
+    let end = Point::new(
+        range.end_line.checked_sub(1)? as usize,
+        range.end_col as usize,
+    );
+    root.descendant_for_point_range(start, end)
+}
+
 pub fn extract_range(node: Node<'_>) -> NativeRange {
     let start = node.start_position();
     let end = node.end_position();
@@ -154,6 +273,7 @@ mod tests {
             decorators: vec![],
             search_text: String::new(),
             summary_quality: None,
+            ..Default::default()
         }
     }
 
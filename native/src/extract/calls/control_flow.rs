@@ -0,0 +1,231 @@
+use tree_sitter::Node;
+
+use crate::types::NativeParsedCall;
+
+use super::common::{node_at_range, node_text};
+
+/// Branching constructs. A call in the construct's `condition` / `value` /
+/// `initializer` (the test, the scrutinee, Go's `if x := f(); ...`) always
+/// runs, so only the branches count.
+const CONDITIONALS: &[&str] = &[
+    "if_statement",
+    "if_expression",
+    "else_clause",
+    "elif_clause",
+    "ternary_expression",
+    "conditional_expression",
+    "switch_statement",
+    "switch_expression",
+    "switch_case",
+    "switch_default",
+    "switch_section",
+    "expression_switch_statement",
+    "type_switch_statement",
+    "select_statement",
+    "match_statement",
+    "match_expression",
+    "match_arm",
+    "case_clause",
+];
+
+/// Loops and comprehensions. The iterable of a `for ... in` loop is
+/// evaluated once, so calls in it do not count.
+const LOOPS: &[&str] = &[
+    "for_statement",
+    "for_in_statement",
+    "enhanced_for_statement",
+    "foreach_statement",
+    "for_range_loop",
+    "for_expression",
+    "while_statement",
+    "while_expression",
+    "do_statement",
+    "loop_expression",
+    "list_comprehension",
+    "set_comprehension",
+    "dictionary_comprehension",
+    "generator_expression",
+];
+
+const TRY_BLOCKS: &[&str] = &["try_statement", "try_with_resources_statement"];
+
+const ERROR_HANDLERS: &[&str] = &["catch_clause", "except_clause", "except_group_clause"];
+
+/// Function bodies end the search: a callback defined in a loop does not run
+/// in the loop.
+const FUNCTION_BOUNDARIES: &[&str] = &[
+    "function_declaration",
+    "generator_function_declaration",
+    "function_expression",
+    "function",
+    "generator_function",
+    "arrow_function",
+    "method_definition",
+    "method_declaration",
+    "constructor_declaration",
+    "function_definition",
+    "lambda",
+    "lambda_expression",
+    "function_item",
+    "closure_expression",
+    "func_literal",
+    "local_function_statement",
+    "anonymous_function",
+];
+
+/// Set the `in_conditional` / `in_loop` / `in_try` / `in_error_handler`
+/// flags from the constructs between each call and its enclosing function.
+///
+/// Besides `catch` / `except` clauses, error-handling branches include Go's
+/// `if err != nil { ... }`, Rust's `Err(..) =>` match arms and callbacks
+/// passed to a promise's `.catch(...)`.
+pub fn annotate_control_flow(calls: &mut [NativeParsedCall], root: Node<'_>, source: &[u8]) {
+    for call in calls.iter_mut() {
+        let Some(node) = node_at_range(root, &call.range) else {
+            continue;
+        };
+
+        let mut child = node;
+        while let Some(parent) = child.parent() {
+            let kind = parent.kind();
+            if FUNCTION_BOUNDARIES.contains(&kind) {
+                if is_promise_catch_handler(parent, source) {
+                    call.in_error_handler = true;
+                }
+                break;
+            }
+
+            let from_field = |field: &str| parent.child_by_field_name(field) == Some(child);
+            if CONDITIONALS.contains(&kind)
+                && !["condition", "value", "initializer"]
+                    .into_iter()
+                    .any(from_field)
+            {
+                call.in_conditional = true;
+                if is_error_branch(parent, child, source) {
+                    call.in_error_handler = true;
+                }
+            }
+            if LOOPS.contains(&kind) && !from_field("right") && !from_field("value") {
+                call.in_loop = true;
+            }
+            if TRY_BLOCKS.contains(&kind) && from_field("body") {
+                call.in_try = true;
+            }
+            if ERROR_HANDLERS.contains(&kind) {
+                call.in_error_handler = true;
+            }
+            child = parent;
+        }
+    }
+}
+
+/// Whether `branch` is the error branch of `node`: the body of a Go
+/// `if err != nil`, or a Rust `Err(..) =>` arm.
+fn is_error_branch(node: Node<'_>, branch: Node<'_>, source: &[u8]) -> bool {
+    match node.kind() {
+        "if_statement" => {
+            node.child_by_field_name("consequence") == Some(branch)
+                && node
+                    .child_by_field_name("condition")
+                    .is_some_and(|condition| node_text(condition, source).contains("err != nil"))
+        }
+        "match_arm" => node
+            .child_by_field_name("pattern")
+            .is_some_and(|pattern| node_text(pattern, source).starts_with("Err")),
+        _ => false,
+    }
+}
+
+/// `promise.catch((error) => ...)`.
+fn is_promise_catch_handler(function: Node<'_>, source: &[u8]) -> bool {
+    let Some(call) = function
+        .parent()
+        .filter(|arguments| arguments.kind() == "arguments")
+        .and_then(|arguments| arguments.parent())
+    else {
+        return false;
+    };
+    call.child_by_field_name("function")
+        .filter(|callee| callee.kind() == "member_expression")
+        .and_then(|callee| callee.child_by_field_name("property"))
+        .is_some_and(|property| node_text(property, source) == "catch")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn calls_record_their_control_flow_context() {
+        let cases = [
+            (
+                "ts",
+                "sync.ts",
+                r#"export function sync(items: Item[]) {
+  if (ready()) {
+    notify();
+  }
+  for (const item of pending()) {
+    upload(item);
+  }
+  try {
+    commit();
+  } catch (error) {
+    rollback();
+  }
+  load().catch((error) => report(error));
+}
+"#,
+            ),
+            (
+                "go",
+                "sync.go",
+                r#"package main
+
+func sync() {
+	if err := ready(); err != nil {
+		notify()
+	}
+	for _, item := range items {
+		upload(item)
+	}
+}
+"#,
+            ),
+        ];
+
+        for (language, rel_path, source) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            // (conditional, loop, try, error handler)
+            let context = |callee: &str| {
+                let call = parsed
+                    .calls
+                    .iter()
+                    .find(|call| call.callee_identifier == callee)
+                    .unwrap_or_else(|| panic!("{language}: missing call {callee}"));
+                (
+                    call.in_conditional,
+                    call.in_loop,
+                    call.in_try,
+                    call.in_error_handler,
+                )
+            };
+
+            assert_eq!(context("ready"), (false, false, false, false), "{language}");
+            assert_eq!(
+                context("notify"),
+                (true, false, false, language == "go"),
+                "{language}"
+            );
+            assert_eq!(context("upload"), (false, true, false, false), "{language}");
+            if language == "ts" {
+                assert_eq!(context("pending"), (false, false, false, false));
+                assert_eq!(context("commit"), (false, false, true, false));
+                assert_eq!(context("rollback"), (false, false, false, true));
+                assert_eq!(context("report"), (false, false, false, true));
+            }
+        }
+    }
+}
@@ -4,7 +4,7 @@ use tree_sitter::Node;
 
 use crate::types::{NativeParsedCall, NativeParsedSymbol};
 
-use super::common::{extract_range, find_enclosing_symbol, node_text};
+use super::common::{extract_range, find_enclosing_symbol, iife_callee, is_iife, node_text};
 
 pub fn extract_calls_cpp(
     root: Node<'_>,
@@ -53,6 +53,7 @@ fn process_call_expression(
     let function = node.child_by_field_name("function")?;
 
     let (callee_identifier, call_type) = match function.kind() {
+        _ if is_iife(function) => (iife_callee(function, source), "iife".to_string()),
         "identifier" => {
             let name = node_text(function, source).to_string();
             if name.is_empty() {
@@ -100,6 +101,7 @@ fn process_call_expression(
         callee_identifier,
         call_type,
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
@@ -125,5 +127,6 @@ fn process_new_expression(
         callee_identifier: format!("new {type_text}"),
         call_type: "constructor".to_string(),
         range: extract_range(node),
+        ..Default::default()
     })
 }
@@ -4,7 +4,9 @@ use tree_sitter::Node;
 
 use crate::types::{NativeParsedCall, NativeParsedSymbol};
 
-use super::common::{extract_range, find_enclosing_symbol, node_text};
+use super::common::{
+    extract_range, find_enclosing_symbol, iife_callee, is_awaited, is_iife, node_text,
+};
 
 pub fn extract_calls_csharp(
     root: Node<'_>,
@@ -31,6 +33,16 @@ pub fn extract_calls_csharp(
                     }
                 }
             }
+            "implicit_object_creation_expression" if seen_nodes.insert(node.id()) => {
+                if let Some(call) = process_implicit_object_creation(node, source, symbols) {
+                    calls.push(call);
+                }
+            }
+            "constructor_initializer" if seen_nodes.insert(node.id()) => {
+                if let Some(call) = process_constructor_initializer(node, source, symbols) {
+                    calls.push(call);
+                }
+            }
             "await_expression" => {
                 process_await_expression(node, source, symbols, &mut seen_nodes, &mut calls);
             }
@@ -56,6 +68,7 @@ fn process_invocation_expression(
     let func_node = node.child_by_field_name("function")?;
 
     let (callee_identifier, call_type) = match func_node.kind() {
+        _ if is_iife(func_node) => (iife_callee(func_node, source), "iife".to_string()),
         "identifier" => {
             let name = node_text(func_node, source).to_string();
             if name.is_empty() {
@@ -67,6 +80,20 @@ fn process_invocation_expression(
             let name = member_access_identifier(func_node, source)?;
             (name, "method".to_string())
         }
+        // `client?.Send()` is a method call on `client`, not a dynamic target.
+        "conditional_access_expression" => {
+            let name = conditional_access_identifier(func_node, source)?;
+            (name, "method".to_string())
+        }
+        // Same call when the grammar nests the invocation inside the access.
+        "member_binding_expression" => {
+            let access = func_node
+                .parent()
+                .and_then(|parent| parent.parent())
+                .filter(|node| node.kind() == "conditional_access_expression")?;
+            let name = conditional_access_identifier(access, source)?;
+            (name, "method".to_string())
+        }
         "generic_name" => {
             let name_node = func_node
                 .child_by_field_name("name")
@@ -94,6 +121,8 @@ fn process_invocation_expression(
         callee_identifier,
         call_type,
         range: extract_range(node),
+        is_awaited: is_awaited(node),
+        ..Default::default()
     })
 }
 
@@ -112,17 +141,7 @@ fn process_object_creation_expression(
         return None;
     };
 
-    let type_text = if type_node.kind() == "generic_name" {
-        let name_node = type_node
-            .child_by_field_name("name")
-            .or_else(|| find_first_descendant(type_node, "identifier"));
-        let Some(name_node) = name_node else {
-            return None;
-        };
-        node_text(name_node, source).to_string()
-    } else {
-        node_text(type_node, source).to_string()
-    };
+    let type_text = simple_type_name(type_node, source);
 
     if type_text.is_empty() {
         return None;
@@ -133,6 +152,61 @@ fn process_object_creation_expression(
         callee_identifier: format!("new {type_text}"),
         call_type: "constructor".to_string(),
         range: extract_range(node),
+        is_awaited: is_awaited(node),
+        ..Default::default()
+    })
+}
+
+/// Target-typed `new(...)` (C# 9). The constructed type comes from the
+/// enclosing variable declaration (`Foo foo = new(...)`); when it cannot be
+/// recovered (e.g. an argument position) no call is emitted.
+fn process_implicit_object_creation(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeParsedCall> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        match parent.kind() {
+            "variable_declaration" => break,
+            "variable_declarator" | "equals_value_clause" => current = parent.parent(),
+            _ => return None,
+        }
+    }
+    let declaration = current?;
+    let type_node = declaration.child_by_field_name("type")?;
+    let type_text = simple_type_name(type_node, source);
+    if type_text.is_empty() || type_text == "var" {
+        return None;
+    }
+
+    Some(NativeParsedCall {
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        callee_identifier: format!("new {type_text}"),
+        call_type: "constructor".to_string(),
+        range: extract_range(node),
+        ..Default::default()
+    })
+}
+
+/// `: base(...)` / `: this(...)` on a constructor declaration.
+fn process_constructor_initializer(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeParsedCall> {
+    let mut cursor = node.walk();
+    let target = node
+        .children(&mut cursor)
+        .find(|child| matches!(child.kind(), "base" | "this"))?;
+    let callee_identifier = node_text(target, source).to_string();
+
+    Some(NativeParsedCall {
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        callee_identifier,
+        call_type: "constructor".to_string(),
+        range: extract_range(node),
+        ..Default::default()
     })
 }
 
@@ -180,7 +254,7 @@ fn member_access_identifier(node: Node<'_>, source: &[u8]) -> Option<String> {
     match (expression, name) {
         (Some(lhs), Some(rhs)) => {
             let lhs_text = node_text(lhs, source);
-            let rhs_text = node_text(rhs, source);
+            let rhs_text = simple_type_name(rhs, source);
             if lhs_text.is_empty() || rhs_text.is_empty() {
                 return None;
             }
@@ -197,6 +271,34 @@ fn member_access_identifier(node: Node<'_>, source: &[u8]) -> Option<String> {
     }
 }
 
+/// `obj?.Method` -> `obj.Method`.
+fn conditional_access_identifier(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let condition = node.child_by_field_name("condition")?;
+    let binding = find_first_descendant(node, "member_binding_expression")?;
+    let name = binding.child_by_field_name("name")?;
+
+    let lhs_text = node_text(condition, source);
+    let rhs_text = simple_type_name(name, source);
+    if lhs_text.is_empty() || rhs_text.is_empty() {
+        return None;
+    }
+    Some(format!("{lhs_text}.{rhs_text}"))
+}
+
+/// Text of a type or member name with any type argument list dropped
+/// (`List<int>` -> `List`, `Cast<T>` -> `Cast`).
+fn simple_type_name(node: Node<'_>, source: &[u8]) -> String {
+    if node.kind() == "generic_name" {
+        let name_node = node
+            .child_by_field_name("name")
+            .or_else(|| find_first_descendant(node, "identifier"));
+        return name_node
+            .map(|name| node_text(name, source).to_string())
+            .unwrap_or_default();
+    }
+    node_text(node, source).to_string()
+}
+
 fn find_type_like_child(node: Node<'_>) -> Option<Node<'_>> {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -4,7 +4,7 @@ use tree_sitter::Node;
 
 use crate::types::{NativeParsedCall, NativeParsedSymbol};
 
-use super::common::{extract_range, find_enclosing_symbol, node_text};
+use super::common::{extract_range, find_enclosing_symbol, iife_callee, is_iife, node_text};
 
 pub fn extract_calls_go(
     root: Node<'_>,
@@ -64,6 +64,13 @@ fn parse_call_node(
     let function_node = call_node.child_by_field_name("function")?;
 
     let (callee_identifier, call_type) = match function_node.kind() {
+        _ if is_iife(function_node) => (iife_callee(function_node, source), "iife".to_string()),
+        // `new(T)` allocates a zero value of `T`; record it as a constructor
+        // edge so the type gets a reference like a composite literal would.
+        "identifier" if node_text(function_node, source) == "new" => {
+            let type_text = new_call_type(call_node, source)?;
+            (format!("new {type_text}"), "constructor".to_string())
+        }
         "identifier" => (
             node_text(function_node, source).to_string(),
             "function".to_string(),
@@ -80,6 +87,11 @@ fn parse_call_node(
 
             (format!("{operand_text}.{field_text}"), "method".to_string())
         }
+        // `handlers[name](w, r)`, `(fn)(x)`: the target is only known at run time.
+        "index_expression" | "parenthesized_expression" => (
+            node_text(function_node, source).to_string(),
+            "dynamic".to_string(),
+        ),
         _ => return None,
     };
 
@@ -92,9 +104,18 @@ fn parse_call_node(
         callee_identifier,
         call_type,
         range: extract_range(call_node),
+        ..Default::default()
     })
 }
 
+fn new_call_type(call_node: Node<'_>, source: &[u8]) -> Option<String> {
+    let arguments = call_node.child_by_field_name("arguments")?;
+    let mut cursor = arguments.walk();
+    let first = arguments.named_children(&mut cursor).next()?;
+    let text = node_text(first, source).to_string();
+    (!text.is_empty()).then_some(text)
+}
+
 fn find_call_expression_child(node: Node<'_>) -> Option<Node<'_>> {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -0,0 +1,181 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use tree_sitter::Node;
+
+use crate::types::{NativeParsedCall, NativeParsedSymbol};
+
+use super::common::{extract_range, find_enclosing_symbol, node_text};
+
+static HANDLER_CALL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([A-Za-z_$][\w$]*(?:\.[A-Za-z_$][\w$]*)*)\s*\(").unwrap());
+
+/// JS keywords that look like calls inside handler bodies (`if (...)`).
+const HANDLER_KEYWORDS: &[&str] = &[
+    "if", "for", "while", "switch", "return", "typeof", "function",
+];
+
+/// Extract template-to-script edges from HTML:
+///
+/// - inline event handlers (`onclick="save(event)"`) become calls to the
+///   referenced functions (`"function"`) or members (`"method"`);
+/// - custom elements (`<user-card>`) become `"constructor"` calls to the
+///   PascalCase class name (`UserCard`) that conventionally implements them
+///   via `customElements.define`.
+pub fn extract_calls_html(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeParsedCall> {
+    let mut calls = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "start_tag" | "self_closing_tag" => {
+                process_tag(node, source, symbols, &mut calls);
+            }
+            "script_element" | "style_element" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    if child.kind() == "start_tag" {
+                        process_tag(child, source, symbols, &mut calls);
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    calls
+}
+
+fn process_tag(
+    tag: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    calls: &mut Vec<NativeParsedCall>,
+) {
+    let mut cursor = tag.walk();
+    for child in tag.named_children(&mut cursor) {
+        match child.kind() {
+            "tag_name" => {
+                let tag_name = node_text(child, source);
+                // Custom element names must contain a hyphen per the HTML spec.
+                if tag_name.contains('-') {
+                    calls.push(NativeParsedCall {
+                        caller_node_id: find_enclosing_symbol(child, symbols),
+                        callee_identifier: custom_element_class_name(tag_name),
+                        call_type: "constructor".to_string(),
+                        range: extract_range(tag),
+                        ..Default::default()
+                    });
+                }
+            }
+            "attribute" => process_handler_attribute(child, source, symbols, calls),
+            _ => {}
+        }
+    }
+}
+
+fn process_handler_attribute(
+    attribute: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    calls: &mut Vec<NativeParsedCall>,
+) {
+    let mut cursor = attribute.walk();
+    let mut children = attribute.named_children(&mut cursor);
+    let Some(name) = children.next() else {
+        return;
+    };
+    let attr_name = node_text(name, source).to_ascii_lowercase();
+    if !attr_name.starts_with("on") || attr_name.len() <= 2 {
+        return;
+    }
+    let Some(value) = children.next() else {
+        return;
+    };
+
+    // The handler code sits inside the attribute's own quotes.
+    let body = if value.kind() == "quoted_attribute_value" {
+        let mut cursor = value.walk();
+        let inner = value.named_children(&mut cursor).next();
+        inner.map_or("", |inner| node_text(inner, source))
+    } else {
+        node_text(value, source)
+    };
+    let body = blank_string_literals(body);
+    for caps in HANDLER_CALL.captures_iter(&body) {
+        let callee = &caps[1];
+        if HANDLER_KEYWORDS.contains(&callee) {
+            continue;
+        }
+        let call_type = if callee.contains('.') {
+            "method"
+        } else {
+            "function"
+        };
+        calls.push(NativeParsedCall {
+            caller_node_id: find_enclosing_symbol(attribute, symbols),
+            callee_identifier: callee.to_string(),
+            call_type: call_type.to_string(),
+            range: extract_range(attribute),
+            ..Default::default()
+        });
+    }
+}
+
+/// `code` with the contents of its JS string literals replaced by spaces, so
+/// call-like text in them (`alert('save()')`) is not taken for a call.
+fn blank_string_literals(code: &str) -> String {
+    let mut out = String::with_capacity(code.len());
+    let mut quote = None;
+    let mut escaped = false;
+    for c in code.chars() {
+        match quote {
+            None => {
+                if matches!(c, '\'' | '"' | '`') {
+                    quote = Some(c);
+                }
+                out.push(c);
+            }
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                    out.push(c);
+                    continue;
+                }
+                out.push(' ');
+            }
+        }
+    }
+    out
+}
+
+/// `user-card` -> `UserCard`.
+fn custom_element_class_name(tag_name: &str) -> String {
+    tag_name
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use crate::types::{NativeParsedCall, NativeParsedImport};
+
+use super::common::{callee_path, line_starts};
+
+/// Record, on each call whose callee starts with an imported binding, the
+/// import's specifier and the name the callee refers to in that module:
+///
+/// - `format()` with `import { format } from "./util"` -> `./util`, `format`
+/// - `fmt()` with `import { format as fmt }` -> the original `format`
+/// - `api.save()` with `import * as api` -> `save`
+/// - `Client()` / `Client.create()` with a default import -> `default`
+///
+/// The callee path is read from the source at the call's start rather than
+/// from `callee_identifier`, which some extractors shorten to the bare
+/// method name. Re-exports bind nothing locally and are ignored; when two
+/// imports bind the same name the first one wins.
+pub fn annotate_import_hints(
+    calls: &mut [NativeParsedCall],
+    imports: &[NativeParsedImport],
+    source: &str,
+) {
+    let bindings = local_bindings(imports);
+    if bindings.is_empty() {
+        return;
+    }
+
+    let line_starts = line_starts(source);
+    for call in calls.iter_mut() {
+        let Some(path) = callee_path(source, &line_starts, &call.range) else {
+            continue;
+        };
+        let mut segments = path
+            .split(['.', ':', '?'])
+            .filter(|segment| !segment.is_empty());
+        let Some(base) = segments.next() else {
+            continue;
+        };
+        let Some(binding) = bindings.get(base) else {
+            continue;
+        };
+        let imported_name = match binding.kind {
+            BindingKind::Named(imported) => Some(imported.to_string()),
+            BindingKind::Default => Some("default".to_string()),
+            BindingKind::Namespace => segments.next().map(str::to_string),
+        };
+        call.import_specifier = Some(binding.specifier.to_string());
+        call.imported_name = imported_name;
+    }
+}
+
+enum BindingKind<'a> {
+    /// Named import; holds the name exported by the source module.
+    Named(&'a str),
+    Default,
+    Namespace,
+}
+
+struct Binding<'a> {
+    specifier: &'a str,
+    kind: BindingKind<'a>,
+}
+
+fn local_bindings(imports: &[NativeParsedImport]) -> HashMap<&str, Binding<'_>> {
+    let mut bindings = HashMap::new();
+    for import in imports.iter().filter(|import| !import.is_re_export) {
+        let specifier = import.specifier.as_str();
+        if let Some(name) = &import.default_import {
+            bindings.entry(name.as_str()).or_insert(Binding {
+                specifier,
+                kind: BindingKind::Default,
+            });
+        }
+        if let Some(name) = &import.namespace_import {
+            bindings.entry(name.as_str()).or_insert(Binding {
+                specifier,
+                kind: BindingKind::Namespace,
+            });
+        }
+        for name in import.named_imports.iter().filter(|name| *name != "*") {
+            let imported = import
+                .aliases
+                .iter()
+                .find(|alias| alias.local == *name)
+                .map_or(name.as_str(), |alias| alias.imported.as_str());
+            bindings.entry(name.as_str()).or_insert(Binding {
+                specifier,
+                kind: BindingKind::Named(imported),
+            });
+        }
+    }
+    bindings
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn calls_through_imported_bindings_carry_import_hints() {
+        let source = r#"import Client from "./client";
+import * as api from "./api";
+import { format as fmt, parse } from "./util";
+
+export function run() {
+  const client = new Client();
+  api.save(fmt(parse("x")));
+  local();
+}
+
+function local() {}
+"#;
+
+        let parsed = parse_source("ts", "src/run.ts", source);
+
+        let hint = |callee: &str| {
+            let call = parsed
+                .calls
+                .iter()
+                .find(|call| call.callee_identifier == callee)
+                .unwrap_or_else(|| panic!("missing call {callee}"));
+            (
+                call.import_specifier.as_deref(),
+                call.imported_name.as_deref(),
+            )
+        };
+
+        assert_eq!(hint("new Client"), (Some("./client"), Some("default")));
+        assert_eq!(hint("save"), (Some("./api"), Some("save")));
+        assert_eq!(hint("fmt"), (Some("./util"), Some("format")));
+        assert_eq!(hint("parse"), (Some("./util"), Some("parse")));
+        assert_eq!(hint("local"), (None, None));
+    }
+}
@@ -37,6 +37,11 @@ pub fn extract_calls_java(
                     }
                 }
             }
+            "explicit_constructor_invocation" if seen_nodes.insert(node.id()) => {
+                if let Some(call) = parse_explicit_constructor_invocation(node, source, symbols) {
+                    calls.push(call);
+                }
+            }
             "call_expression" => {
                 if seen_nodes.insert(node.id()) {
                     if let Some(call) = parse_call_expression(node, source, symbols) {
@@ -101,6 +106,7 @@ fn parse_method_invocation(
         callee_identifier,
         call_type,
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
@@ -116,7 +122,7 @@ fn parse_object_creation(
         .or_else(|| node.child_by_field_name("name"));
 
     let type_text = type_node
-        .map(|n| node_text(n, source).to_string())
+        .map(|n| strip_type_arguments(n, source))
         .or_else(|| find_type_like_child(node, source))?;
 
     if type_text.is_empty() {
@@ -130,9 +136,46 @@ fn parse_object_creation(
         callee_identifier: format!("new {type_text}"),
         call_type: "constructor".to_string(),
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
+/// `this(...)` / `super(...)` as the first statement of a constructor.
+fn parse_explicit_constructor_invocation(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeParsedCall> {
+    let constructor = node.child_by_field_name("constructor")?;
+    let callee_identifier = node_text(constructor, source).to_string();
+    if callee_identifier.is_empty() {
+        return None;
+    }
+
+    Some(NativeParsedCall {
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        callee_identifier,
+        call_type: "constructor".to_string(),
+        range: extract_range(node),
+        ..Default::default()
+    })
+}
+
+/// `new ArrayList<>()` constructs `ArrayList`; the diamond or explicit type
+/// arguments are not part of the callee name.
+fn strip_type_arguments(type_node: Node<'_>, source: &[u8]) -> String {
+    if type_node.kind() == "generic_type" {
+        let mut cursor = type_node.walk();
+        let base = type_node
+            .named_children(&mut cursor)
+            .find(|child| matches!(child.kind(), "type_identifier" | "scoped_type_identifier"));
+        if let Some(base) = base {
+            return node_text(base, source).to_string();
+        }
+    }
+    node_text(type_node, source).to_string()
+}
+
 fn parse_call_expression(
     node: Node<'_>,
     source: &[u8],
@@ -151,6 +194,7 @@ fn parse_call_expression(
             callee_identifier,
             call_type: "function".to_string(),
             range: extract_range(node),
+            ..Default::default()
         });
     }
 
@@ -164,7 +208,7 @@ fn find_type_like_child(node: Node<'_>, source: &[u8]) -> Option<String> {
             child.kind(),
             "type_identifier" | "generic_type" | "scoped_type_identifier"
         ) {
-            let text = node_text(child, source).to_string();
+            let text = strip_type_arguments(child, source);
             if !text.is_empty() {
                 return Some(text);
             }
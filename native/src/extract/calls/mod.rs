@@ -1,36 +1,286 @@
-use tree_sitter::Node;
+pub(crate) mod c_lang;
+pub mod callbacks;
+pub mod chains;
+pub mod common;
+pub mod control_flow;
+pub(crate) mod cpp;
+pub(crate) mod csharp;
+pub(crate) mod go;
+pub(crate) mod html;
+pub mod import_hints;
+pub(crate) mod java;
+pub(crate) mod php;
+pub(crate) mod python;
+pub mod receivers;
+pub(crate) mod rust_lang;
+pub(crate) mod shell;
+pub mod sql;
+pub mod type_positions;
+pub(crate) mod typescript;
 
-use crate::types::{NativeParsedCall, NativeParsedSymbol};
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
 
-mod c_lang;
-pub mod common;
-mod cpp;
-mod csharp;
-mod go;
-mod java;
-mod php;
-mod python;
-mod rust_lang;
-mod shell;
-mod typescript;
-
-pub fn extract_calls(
-    root: Node<'_>,
-    source: &[u8],
-    symbols: &[NativeParsedSymbol],
-    language: &str,
-) -> Vec<NativeParsedCall> {
-    match language {
-        "c" => c_lang::extract_calls_c(root, source, symbols),
-        "cpp" => cpp::extract_calls_cpp(root, source, symbols),
-        "go" => go::extract_calls_go(root, source, symbols),
-        "java" => java::extract_calls_java(root, source, symbols),
-        "php" => php::extract_calls_php(root, source, symbols),
-        "py" => python::extract_calls_python(root, source, symbols),
-        "rs" => rust_lang::extract_calls_rust(root, source, symbols),
-        "sh" => shell::extract_calls_shell(root, source, symbols),
-        "cs" => csharp::extract_calls_csharp(root, source, symbols),
-        "ts" | "tsx" | "js" | "jsx" => typescript::extract_calls_ts(root, source, symbols),
-        _ => vec![],
+    #[test]
+    fn compiled_language_calls_classify_constructors_and_methods() {
+        let cases = [
+            (
+                "go",
+                "main.go",
+                r#"package main
+
+func run() {
+	s := new(Server)
+	s.Start()
+	handlers["x"]()
+}
+"#,
+                vec![
+                    ("new Server", "constructor"),
+                    ("s.Start", "method"),
+                    ("handlers[\"x\"]", "dynamic"),
+                ],
+            ),
+            (
+                "rs",
+                "src/lib.rs",
+                r#"fn run() {
+    let v = Vec::<u8>::new();
+    let n = "1".parse::<u32>();
+    let c = Config::from_env();
+    std::mem::drop(v);
+    println!("{n:?}");
+}
+"#,
+                vec![
+                    ("Vec::new", "constructor"),
+                    ("\"1\".parse", "method"),
+                    ("Config::from_env", "associated"),
+                    ("std::mem::drop", "function"),
+                    ("println!", "dynamic"),
+                ],
+            ),
+            (
+                "java",
+                "src/Repo.java",
+                r#"class Repo extends Base {
+    Repo() {
+        super(1);
+        java.util.List<String> items = new ArrayList<>();
+        items.add("x");
+    }
+}
+"#,
+                vec![
+                    ("super", "constructor"),
+                    ("new ArrayList", "constructor"),
+                    ("items.add", "method"),
+                ],
+            ),
+            (
+                "cs",
+                "src/Repo.cs",
+                r#"class Repo : Base {
+    public Repo() : base(1) {
+        List<int> items = new();
+        client?.Send();
+        items.Cast<long>();
+    }
+}
+"#,
+                vec![
+                    ("base", "constructor"),
+                    ("new List", "constructor"),
+                    ("client.Send", "method"),
+                    ("items.Cast", "method"),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let callees: Vec<(&str, &str)> = parsed
+                .calls
+                .iter()
+                .map(|call| (call.callee_identifier.as_str(), call.call_type.as_str()))
+                .collect();
+            for call in expected {
+                assert!(
+                    callees.contains(&call),
+                    "{language}: missing {call:?} in {callees:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn self_receiver_calls_record_the_enclosing_class() {
+        let cases = [
+            (
+                "ts",
+                "repo.ts",
+                r#"export class Repo {
+  save() {}
+
+  sync() {
+    const flush = () => this.save();
+    this.save();
+    flush();
+  }
+}
+
+const store = {
+  save() {},
+  sync() { this.save(); },
+};
+"#,
+            ),
+            (
+                "py",
+                "repo.py",
+                r#"class Repo:
+    def save(self):
+        pass
+
+    def sync(self):
+        self.save()
+        flush()
+"#,
+            ),
+        ];
+
+        for (language, rel_path, source) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let receivers: Vec<(u32, Option<&str>)> = parsed
+                .calls
+                .iter()
+                .filter(|call| call.callee_identifier.ends_with("save"))
+                .map(|call| (call.range.start_line, call.receiver_type.as_deref()))
+                .collect();
+            let expected: &[(u32, Option<&str>)] = match language {
+                "ts" => &[(5, Some("Repo")), (6, Some("Repo")), (13, None)],
+                _ => &[(6, Some("Repo"))],
+            };
+            assert_eq!(receivers, expected, "{language}");
+            assert!(parsed
+                .calls
+                .iter()
+                .filter(|call| call.callee_identifier == "flush")
+                .all(|call| call.receiver_type.is_none()));
+        }
+    }
+
+    #[test]
+    fn awaited_calls_are_flagged() {
+        let cases = [
+            (
+                "ts",
+                "sync.ts",
+                r#"export async function sync() {
+  const user = await (loadUser());
+  saveLater(user);
+  return await api.push(user);
+}
+"#,
+            ),
+            (
+                "py",
+                "sync.py",
+                r#"async def sync():
+    user = await load_user()
+    save_later(user)
+    return await api.push(user)
+"#,
+            ),
+            (
+                "rs",
+                "sync.rs",
+                r#"async fn sync() {
+    let user = load_user().await;
+    save_later(&user);
+    api::push(user).await;
+}
+"#,
+            ),
+        ];
+
+        for (language, rel_path, source) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let mut awaited: Vec<(u32, bool)> = parsed
+                .calls
+                .iter()
+                .map(|call| (call.range.start_line, call.is_awaited))
+                .collect();
+            awaited.sort();
+            awaited.dedup();
+            assert_eq!(
+                awaited,
+                vec![(2, true), (3, false), (4, true)],
+                "{language}"
+            );
+        }
+    }
+
+    #[test]
+    fn immediately_invoked_function_expressions_are_classified() {
+        let cases = [
+            (
+                "ts",
+                "boot.ts",
+                r#"export function boot() {
+  (function start() {
+    init();
+  })();
+  (() => init())();
+}
+"#,
+                vec!["start", "<iife>"],
+            ),
+            (
+                "go",
+                "boot.go",
+                r#"package main
+
+func boot() {
+	func() {
+		init()
+	}()
+}
+"#,
+                vec!["<iife>"],
+            ),
+            (
+                "py",
+                "boot.py",
+                r#"def boot():
+    (lambda: init())()
+"#,
+                vec!["<iife>"],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let iifes: Vec<&str> = parsed
+                .calls
+                .iter()
+                .filter(|call| call.call_type == "iife")
+                .map(|call| call.callee_identifier.as_str())
+                .collect();
+            assert_eq!(iifes, expected, "{language}");
+            assert!(
+                parsed
+                    .calls
+                    .iter()
+                    .filter(|call| call.callee_identifier == "init")
+                    .all(|call| call.caller_node_id.starts_with("boot:")),
+                "{language}"
+            );
+        }
     }
 }
@@ -4,7 +4,7 @@ use tree_sitter::Node;
 
 use crate::types::{NativeParsedCall, NativeParsedSymbol};
 
-use super::common::{extract_range, find_enclosing_symbol, node_text};
+use super::common::{extract_range, find_enclosing_symbol, iife_callee, is_iife, node_text};
 
 pub fn extract_calls_php(
     root: Node<'_>,
@@ -60,6 +60,7 @@ fn parse_call_expression(
         callee_identifier,
         call_type,
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
@@ -75,6 +76,9 @@ fn parse_function_call(node: Node<'_>, source: &[u8]) -> Option<(String, String)
     let function_node = function_node?;
 
     match function_node.kind() {
+        _ if is_iife(function_node) => {
+            Some((iife_callee(function_node, source), "iife".to_string()))
+        }
         "name" => {
             let callee = node_text(function_node, source).to_string();
             if callee.is_empty() {
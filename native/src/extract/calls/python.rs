@@ -4,7 +4,9 @@ use tree_sitter::Node;
 
 use crate::types::{NativeParsedCall, NativeParsedSymbol};
 
-use super::common::{extract_range, find_enclosing_symbol, node_text};
+use super::common::{
+    extract_range, find_enclosing_symbol, iife_callee, is_awaited, is_iife, node_text,
+};
 
 pub fn extract_calls_python(
     root: Node<'_>,
@@ -86,6 +88,7 @@ fn parse_call_node(
     let function_node = call_node.child_by_field_name("function")?;
 
     let (callee_identifier, call_type) = match function_node.kind() {
+        _ if is_iife(function_node) => (iife_callee(function_node, source), "iife".to_string()),
         "identifier" => (
             node_text(function_node, source).to_string(),
             "function".to_string(),
@@ -120,6 +123,8 @@ fn parse_call_node(
         callee_identifier,
         call_type,
         range: extract_range(call_node),
+        is_awaited: is_awaited(call_node),
+        ..Default::default()
     })
 }
 
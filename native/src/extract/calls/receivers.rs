@@ -0,0 +1,511 @@
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::types::{NativeParsedCall, NativeParsedSymbol};
+
+use super::common::{callee_path, line_starts, node_text};
+
+/// Receivers that refer to the instance or type the calling code belongs to.
+const SELF_RECEIVERS: &[&str] = &["this.", "this->", "$this->", "self.", "self::", "Self::"];
+
+/// Set `receiver_type` to the enclosing type's name on calls made through
+/// `this` / `self` (`this.save()`, `self.save()`, `$this->save()`,
+/// `Self::new()`).
+///
+/// The type is found by walking the caller's `parent_symbol_id` chain to the
+/// nearest class, interface or type symbol, so `this` inside an arrow function
+/// nested in a method still resolves to the method's class. Calls whose
+/// caller has no enclosing type (object-literal methods, free functions) are
+/// left alone.
+pub fn annotate_self_receivers(
+    calls: &mut [NativeParsedCall],
+    symbols: &[NativeParsedSymbol],
+    source: &str,
+) {
+    let by_node_id: HashMap<&str, &NativeParsedSymbol> = symbols
+        .iter()
+        .map(|symbol| (symbol.node_id.as_str(), symbol))
+        .collect();
+    let by_symbol_id: HashMap<&str, &NativeParsedSymbol> = symbols
+        .iter()
+        .map(|symbol| (symbol.symbol_id.as_str(), symbol))
+        .collect();
+
+    let line_starts = line_starts(source);
+    for call in calls.iter_mut() {
+        let Some(path) = callee_path(source, &line_starts, &call.range) else {
+            continue;
+        };
+        // `this.save()`, not `this.repo.find()`, whose receiver is a field.
+        let is_self_call = SELF_RECEIVERS.iter().any(|prefix| {
+            path.strip_prefix(prefix)
+                .is_some_and(|member| !member.is_empty() && !member.contains(['.', ':', '?', '-']))
+        });
+        if !is_self_call {
+            continue;
+        }
+        let Some(caller) = by_node_id.get(call.caller_node_id.as_str()) else {
+            continue;
+        };
+        call.receiver_type = enclosing_type(caller, &by_symbol_id).map(str::to_string);
+    }
+}
+
+fn enclosing_type<'a>(
+    symbol: &'a NativeParsedSymbol,
+    by_symbol_id: &HashMap<&str, &'a NativeParsedSymbol>,
+) -> Option<&'a str> {
+    let mut current = symbol;
+    // Bounded by the symbol count in case of a malformed parent cycle.
+    for _ in 0..=by_symbol_id.len() {
+        if matches!(current.kind.as_str(), "class" | "interface" | "type") {
+            return Some(current.name.as_str());
+        }
+        current = by_symbol_id.get(current.parent_symbol_id.as_deref()?)?;
+    }
+    None
+}
+
+/// Declarations whose type names the value a receiver holds.
+const TYPED_DECLARATIONS: &[&str] = &[
+    // TS/JS
+    "variable_declarator",
+    "required_parameter",
+    "optional_parameter",
+    "public_field_definition",
+    // Java / C#
+    "local_variable_declaration",
+    "field_declaration",
+    "formal_parameter",
+    "variable_declaration",
+    "parameter",
+    // Go
+    "var_spec",
+    "parameter_declaration",
+    // Rust
+    "let_declaration",
+    // Python
+    "typed_parameter",
+    "typed_default_parameter",
+    "assignment",
+];
+
+/// Nodes that bound the scope of the locals and parameters they declare.
+const FUNCTION_SCOPES: &[&str] = &[
+    "function_declaration",
+    "generator_function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "method_declaration",
+    "constructor_declaration",
+    "function_definition",
+    "function_item",
+    "closure_expression",
+    "func_literal",
+    "lambda_expression",
+    "local_function_statement",
+];
+
+/// Nodes that bound the scope of the fields they declare.
+const CLASS_SCOPES: &[&str] = &[
+    "class_body",
+    "class_declaration",
+    "class_definition",
+    "declaration_list",
+];
+
+/// Type names that say nothing about the value (`var x = ...`).
+const UNTYPED: &[&str] = &[
+    "var", "let", "auto", "any", "unknown", "dynamic", "object", "Object",
+];
+
+/// A name with a declared type, visible from `position` to the end of
+/// `scope` (byte offsets).
+struct TypedName<'a> {
+    /// `repo` for locals and parameters, `this.repo` for fields.
+    key: String,
+    type_name: &'a str,
+    scope: (usize, usize),
+    position: usize,
+}
+
+/// Set `receiver_type` on method calls whose receiver is a local, parameter
+/// or field with a declared type in the same file:
+///
+/// - `const repo: UserRepo = make(); repo.find()` -> `UserRepo`
+/// - `constructor(private repo: UserRepo)` ... `this.repo.find()` -> `UserRepo`
+/// - `func (s *Store) Save() { s.flush() }` -> `Store`
+/// - `const repo = new UserRepo()` -> `UserRepo`
+///
+/// Type names are reduced to their last path segment without references,
+/// pointers, nullability or type arguments (`&mut Vec<T>` -> `Vec`). The
+/// innermost, latest declaration in scope wins. Field chains deeper than
+/// `this.x` are not followed, and calls that already have a receiver type
+/// (`this.save()`) are left alone.
+pub fn annotate_declared_receivers(calls: &mut [NativeParsedCall], root: Node<'_>, source: &str) {
+    let declarations = typed_names(root, source.as_bytes());
+    if declarations.is_empty() {
+        return;
+    }
+
+    let line_starts = line_starts(source);
+    for call in calls.iter_mut().filter(|call| call.receiver_type.is_none()) {
+        let Some(path) = callee_path(source, &line_starts, &call.range) else {
+            continue;
+        };
+        let Some(key) = receiver_key(path) else {
+            continue;
+        };
+        let Some(offset) = line_starts
+            .get(call.range.start_line.saturating_sub(1) as usize)
+            .map(|start| start + call.range.start_col as usize)
+        else {
+            continue;
+        };
+
+        let declared = declarations
+            .iter()
+            .filter(|declaration| declaration.key == key)
+            .filter(|declaration| {
+                declaration.scope.0 <= offset
+                    && offset < declaration.scope.1
+                    && declaration.position <= offset
+            })
+            .min_by_key(|declaration| {
+                (
+                    declaration.scope.1 - declaration.scope.0,
+                    usize::MAX - declaration.position,
+                )
+            });
+        if let Some(declaration) = declared {
+            call.receiver_type = Some(declaration.type_name.to_string());
+        }
+    }
+}
+
+/// Lookup key for the receiver of a one-member call: `repo.find` -> `repo`,
+/// `this.repo.find` / `self.repo.find` / `$this->repo->find` -> `this.repo`.
+fn receiver_key(path: &str) -> Option<String> {
+    let normalized = path
+        .trim_start_matches('$')
+        .replace("?.", ".")
+        .replace("->", ".")
+        .replace("::", ".");
+    let segments: Vec<&str> = normalized.split('.').collect();
+    match segments.as_slice() {
+        [receiver, _] if !receiver.is_empty() => Some((*receiver).to_string()),
+        ["this" | "self", field, _] if !field.is_empty() => Some(format!("this.{field}")),
+        _ => None,
+    }
+}
+
+fn typed_names<'a>(root: Node<'a>, source: &'a [u8]) -> Vec<TypedName<'a>> {
+    let mut names = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if TYPED_DECLARATIONS.contains(&node.kind()) {
+            collect_typed_names(node, source, &mut names);
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    names
+}
+
+fn collect_typed_names<'a>(node: Node<'a>, source: &'a [u8], names: &mut Vec<TypedName<'a>>) {
+    let Some(type_name) = declared_type(node, source) else {
+        return;
+    };
+
+    for name in declared_names(node, source) {
+        let (key, bare) = match name.strip_prefix("self.") {
+            Some(field) => (format!("this.{field}"), None),
+            None if is_field_declaration(node) => (format!("this.{name}"), Some(name)),
+            None => (name.to_string(), None),
+        };
+        if key.starts_with("this.") {
+            let scope = class_scope(node, source.len());
+            names.push(TypedName {
+                key,
+                type_name,
+                scope,
+                position: scope.0,
+            });
+            // Java / C# / Go fields are also reachable without `this.`.
+            if let Some(bare) = bare {
+                names.push(TypedName {
+                    key: bare.to_string(),
+                    type_name,
+                    scope,
+                    position: scope.0,
+                });
+            }
+            // TS parameter properties are parameters too.
+            if !is_parameter(node) {
+                continue;
+            }
+        }
+        let scope = function_scope(node, source.len());
+        // Parameters are in scope throughout their function.
+        let position = if is_parameter(node) {
+            scope.0
+        } else {
+            node.start_byte()
+        };
+        names.push(TypedName {
+            key: name.to_string(),
+            type_name,
+            scope,
+            position,
+        });
+    }
+}
+
+/// The declared type, or the constructed type of a `new X(...)`
+/// initializer.
+fn declared_type<'a>(node: Node<'a>, source: &'a [u8]) -> Option<&'a str> {
+    let text = match node.child_by_field_name("type") {
+        Some(type_node) => node_text(type_node, source),
+        None => {
+            let value = node.child_by_field_name("value")?;
+            if value.kind() != "new_expression" {
+                return None;
+            }
+            node_text(value.child_by_field_name("constructor")?, source)
+        }
+    };
+    simple_type_name(text)
+}
+
+/// `: &mut pkg::Repo<T>` -> `Repo`, `*Store` -> `Store`, `Repo | null` ->
+/// `Repo`, `Optional[Repo]` stays `Optional`.
+fn simple_type_name(text: &str) -> Option<&str> {
+    let text = text
+        .trim_start_matches(|c: char| c == ':' || c == '&' || c == '*' || c.is_whitespace())
+        .trim_start_matches("mut ")
+        .trim_start_matches("readonly ")
+        .trim_start();
+    let end = text
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | ':')))
+        .unwrap_or(text.len());
+    let path = text[..end].trim_end_matches([':', '.']);
+    let name = path.rsplit(['.', ':']).next()?;
+    let starts_like_name = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$');
+    (starts_like_name && !UNTYPED.contains(&name)).then_some(name)
+}
+
+/// Identifiers bound by the declaration: the `name`, `pattern` or
+/// `declarator` field(s), or the assignment target. Destructuring patterns
+/// bind nothing here.
+fn declared_names<'a>(node: Node<'a>, source: &'a [u8]) -> Vec<&'a str> {
+    let mut names = Vec::new();
+    let mut cursor = node.walk();
+    for field in ["name", "pattern", "left"] {
+        for child in node.children_by_field_name(field, &mut cursor) {
+            push_name(child, source, &mut names);
+        }
+    }
+    for declarator in node.children_by_field_name("declarator", &mut cursor) {
+        if let Some(name) = declarator.child_by_field_name("name") {
+            push_name(name, source, &mut names);
+        }
+    }
+    // C# `variable_declaration` and Python `typed_parameter` leave the
+    // names unlabelled.
+    let mut children = node.walk();
+    for child in node.named_children(&mut children) {
+        match child.kind() {
+            "variable_declarator" => {
+                if let Some(name) = child
+                    .child_by_field_name("name")
+                    .or_else(|| child.named_child(0))
+                {
+                    push_name(name, source, &mut names);
+                }
+            }
+            "identifier" if node.kind() == "typed_parameter" => {
+                push_name(child, source, &mut names)
+            }
+            _ => {}
+        }
+    }
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+fn push_name<'a>(node: Node<'a>, source: &'a [u8], names: &mut Vec<&'a str>) {
+    match node.kind() {
+        "identifier" | "property_identifier" | "field_identifier" => {
+            names.push(node_text(node, source))
+        }
+        // Python `self.repo: Repo = ...`.
+        "attribute" => {
+            let text = node_text(node, source);
+            if text.starts_with("self.") && !text[5..].contains('.') {
+                names.push(text);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_parameter(node: Node<'_>) -> bool {
+    matches!(
+        node.kind(),
+        "required_parameter"
+            | "optional_parameter"
+            | "formal_parameter"
+            | "parameter"
+            | "parameter_declaration"
+            | "typed_parameter"
+            | "typed_default_parameter"
+    )
+}
+
+/// Class members (including C# `field_declaration > variable_declaration`
+/// and Python class-level annotations), and TS constructor parameters with
+/// an accessibility modifier (`constructor(private repo: Repo)`).
+fn is_field_declaration(node: Node<'_>) -> bool {
+    match node.kind() {
+        "public_field_definition" | "field_declaration" => true,
+        "variable_declaration" => node
+            .parent()
+            .is_some_and(|parent| parent.kind() == "field_declaration"),
+        "assignment" => {
+            let mut current = node.parent();
+            while let Some(parent) = current {
+                if parent.kind() == "class_definition" {
+                    return true;
+                }
+                if FUNCTION_SCOPES.contains(&parent.kind()) {
+                    return false;
+                }
+                current = parent.parent();
+            }
+            false
+        }
+        "required_parameter" | "optional_parameter" => {
+            let mut cursor = node.walk();
+            let has_modifier = node
+                .children(&mut cursor)
+                .any(|child| matches!(child.kind(), "accessibility_modifier" | "readonly"));
+            has_modifier
+        }
+        _ => false,
+    }
+}
+
+fn function_scope(node: Node<'_>, source_len: usize) -> (usize, usize) {
+    enclosing_scope(node, FUNCTION_SCOPES, source_len)
+}
+
+/// The class a field belongs to; the whole file for Rust / Go struct
+/// fields, whose methods are declared outside the struct.
+fn class_scope(node: Node<'_>, source_len: usize) -> (usize, usize) {
+    enclosing_scope(node, CLASS_SCOPES, source_len)
+}
+
+fn enclosing_scope(node: Node<'_>, kinds: &[&str], source_len: usize) -> (usize, usize) {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if kinds.contains(&parent.kind()) {
+            return (parent.start_byte(), parent.end_byte());
+        }
+        current = parent.parent();
+    }
+    (0, source_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn method_calls_record_the_declared_receiver_type() {
+        let cases = [
+            (
+                "ts",
+                "service.ts",
+                r#"export class Service {
+  constructor(private repo: UserRepo) {}
+
+  run(cache: Map<string, User>) {
+    const audit = new AuditLog();
+    this.repo.find();
+    cache.get("id");
+    audit.write();
+    unknown.call();
+  }
+}
+"#,
+                vec![
+                    ("find", Some("UserRepo")),
+                    ("get", Some("Map")),
+                    ("write", Some("AuditLog")),
+                    ("call", None),
+                ],
+            ),
+            (
+                "java",
+                "Service.java",
+                r#"class Service {
+    private UserRepo repo;
+
+    void run(Cache cache) {
+        repo.find();
+        cache.get("id");
+        unknown.call();
+    }
+}
+"#,
+                vec![
+                    ("repo.find", Some("UserRepo")),
+                    ("cache.get", Some("Cache")),
+                    ("unknown.call", None),
+                ],
+            ),
+            (
+                "rs",
+                "service.rs",
+                r#"fn run(repo: &mut UserRepo) {
+    let cache: cache::Cache<String> = load();
+    repo.find();
+    cache.get("id");
+}
+"#,
+                vec![
+                    ("load", None),
+                    ("repo.find", Some("UserRepo")),
+                    ("cache.get", Some("Cache")),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let mut calls: Vec<_> = parsed
+                .calls
+                .iter()
+                .filter(|call| call.call_type != "constructor")
+                .collect();
+            calls.sort_by_key(|call| (call.range.start_line, call.range.start_col));
+            let receivers: Vec<(&str, Option<&str>)> = calls
+                .iter()
+                .map(|call| {
+                    (
+                        call.callee_identifier.as_str(),
+                        call.receiver_type.as_deref(),
+                    )
+                })
+                .collect();
+            assert_eq!(receivers, expected, "{language}");
+        }
+    }
+}
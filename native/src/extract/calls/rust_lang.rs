@@ -4,7 +4,9 @@ use tree_sitter::Node;
 
 use crate::types::{NativeParsedCall, NativeParsedSymbol};
 
-use super::common::{extract_range, find_enclosing_symbol, node_text};
+use super::common::{
+    extract_range, find_enclosing_symbol, iife_callee, is_awaited, is_iife, node_text,
+};
 
 pub fn extract_calls_rust(
     root: Node<'_>,
@@ -50,9 +52,16 @@ fn parse_call_expression(
     source: &[u8],
     symbols: &[NativeParsedSymbol],
 ) -> Option<NativeParsedCall> {
-    let function_node = node.child_by_field_name("function")?;
+    let mut function_node = node.child_by_field_name("function")?;
+    // `parse::<u32>()`: drop the trailing turbofish and classify the
+    // underlying path. Turbofish inside a path (`Vec::<u8>::new`) is
+    // stripped from the path text below.
+    if function_node.kind() == "generic_function" {
+        function_node = function_node.child_by_field_name("function")?;
+    }
 
     let (callee_identifier, call_type) = match function_node.kind() {
+        _ if is_iife(function_node) => (iife_callee(function_node, source), "iife".to_string()),
         "identifier" => {
             let callee = node_text(function_node, source).to_string();
             if callee.is_empty() {
@@ -64,24 +73,27 @@ fn parse_call_expression(
             let path = function_node.child_by_field_name("path");
             let name = function_node.child_by_field_name("name");
 
-            let callee = match (path, name) {
+            let (callee, call_type) = match (path, name) {
                 (Some(path), Some(name)) => {
-                    let path_text = node_text(path, source);
+                    let path_text = strip_type_arguments(node_text(path, source));
                     let name_text = node_text(name, source);
                     if path_text.is_empty() || name_text.is_empty() {
-                        node_text(function_node, source).to_string()
+                        (node_text(function_node, source).to_string(), "function")
                     } else {
-                        format!("{path_text}::{name_text}")
+                        (
+                            format!("{path_text}::{name_text}"),
+                            associated_call_type(&path_text, name_text),
+                        )
                     }
                 }
-                _ => node_text(function_node, source).to_string(),
+                _ => (node_text(function_node, source).to_string(), "function"),
             };
 
             if callee.is_empty() {
                 return None;
             }
 
-            (callee, "function".to_string())
+            (callee, call_type.to_string())
         }
         "field_expression" => {
             let value = function_node.child_by_field_name("value")?;
@@ -109,9 +121,62 @@ fn parse_call_expression(
         callee_identifier,
         call_type,
         range: extract_range(node),
+        is_awaited: is_awaited(node),
+        ..Default::default()
     })
 }
 
+/// Classify a `path::name(...)` call. A type-like last path segment
+/// (`Self`, `Vec`, `Config`) makes it an associated function call: `new` and
+/// `default` are the conventional constructors, anything else is
+/// "associated". Module paths (`std::mem::swap`) stay plain function calls.
+fn associated_call_type(path: &str, name: &str) -> &'static str {
+    let is_type = path
+        .rsplit("::")
+        .find(|segment| !segment.starts_with('<'))
+        .and_then(|segment| segment.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase());
+    match (is_type, name) {
+        (true, "new" | "default") => "constructor",
+        (true, _) => "associated",
+        (false, _) => "function",
+    }
+}
+
+/// `path` without generic arguments on its segments: `Vec::<u8>` and
+/// `HashMap<K, V>` become `Vec` and `HashMap`. A qualified-self prefix such
+/// as `<T as Trait>` is kept as written.
+fn strip_type_arguments(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        let follows_segment = out.ends_with("::")
+            || out
+                .chars()
+                .last()
+                .is_some_and(|last| last.is_alphanumeric() || last == '_');
+        if c != '<' || !follows_segment {
+            out.push(c);
+            continue;
+        }
+        let mut depth = 1;
+        for c in chars.by_ref() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+        }
+        if out.ends_with("::") {
+            out.truncate(out.len() - 2);
+        }
+    }
+    out
+}
+
 fn parse_macro_invocation(
     node: Node<'_>,
     source: &[u8],
@@ -128,5 +193,6 @@ fn parse_macro_invocation(
         callee_identifier: format!("{macro_name}!"),
         call_type: "dynamic".to_string(),
         range: extract_range(node),
+        ..Default::default()
     })
 }
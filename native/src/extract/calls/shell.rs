@@ -33,6 +33,21 @@ pub fn extract_calls_shell(
     calls
 }
 
+/// Shell builtins and keywords-as-commands. These never resolve to another
+/// script or program, so they are not recorded as calls.
+const SHELL_BUILTINS: &[&str] = &[
+    ":", "[", "[[", "alias", "bg", "bind", "break", "builtin", "caller", "cd", "continue",
+    "declare", "dirs", "disown", "echo", "enable", "eval", "exit", "export", "false", "fg",
+    "getopts", "hash", "help", "history", "jobs", "let", "local", "logout", "popd", "printf",
+    "pushd", "pwd", "read", "readonly", "return", "set", "shift", "shopt", "test", "times", "trap",
+    "true", "type", "typeset", "ulimit", "umask", "unalias", "unset", "wait",
+];
+
+/// Commands that run their first non-option argument as another command
+/// (`exec node server.js`, `nohup ./worker.sh`); the wrapped command is
+/// recorded as the callee instead.
+const COMMAND_WRAPPERS: &[&str] = &["command", "exec", "nohup", "time"];
+
 fn process_command(
     node: Node<'_>,
     source: &[u8],
@@ -43,11 +58,19 @@ fn process_command(
         return None;
     }
 
-    let callee_identifier = node_text(command_name_node, source).to_string();
+    let mut callee_identifier = node_text(command_name_node, source).to_string();
     if callee_identifier.is_empty() {
         return None;
     }
 
+    if COMMAND_WRAPPERS.contains(&callee_identifier.as_str()) {
+        callee_identifier = wrapped_command(node, source)?;
+    }
+
+    if SHELL_BUILTINS.contains(&callee_identifier.as_str()) {
+        return None;
+    }
+
     if callee_identifier == "source" || callee_identifier == "." {
         return None;
     }
@@ -74,5 +97,17 @@ fn process_command(
         callee_identifier,
         call_type,
         range: extract_range(node),
+        ..Default::default()
     })
 }
+
+fn wrapped_command(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut cursor = node.walk();
+    let found = node
+        .children_by_field_name("argument", &mut cursor)
+        .filter(|arg| arg.kind() == "word")
+        .map(|arg| node_text(arg, source))
+        .find(|text| !text.is_empty() && !text.starts_with('-'))
+        .map(str::to_string);
+    found
+}
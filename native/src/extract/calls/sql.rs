@@ -0,0 +1,482 @@
+//! Embedded SQL on query calls.
+//!
+//! `db.query("SELECT * FROM users WHERE id = $1")` reads `users`, but only
+//! the string says so. For calls to a query function whose argument is a
+//! SQL literal (plain, template, concatenated or a tagged template such as
+//! `sql\`...\``), the statement type and the tables it references are
+//! copied onto the call record. Interpolated parts become placeholders, so
+//! a table name that is itself interpolated is not reported.
+
+use tree_sitter::Node;
+
+use crate::extract::string_literals::{is_sql, literal_value};
+use crate::types::NativeParsedCall;
+
+use super::common::{node_at_range, node_text};
+
+/// Method / function names (last callee segment) that run SQL.
+const QUERY_FUNCTIONS: &[&str] = &[
+    "query",
+    "queryRaw",
+    "$queryRaw",
+    "$queryRawUnsafe",
+    "$executeRaw",
+    "$executeRawUnsafe",
+    "execute",
+    "executemany",
+    "executescript",
+    "exec",
+    "raw",
+    "prepare",
+    "sql",
+    "text",
+    "all",
+    "get",
+    "run",
+    "one",
+    "many",
+    "none",
+    "any",
+    "fetch",
+    "fetchrow",
+    "fetchval",
+    "fetch_one",
+    "fetch_all",
+    "fetch_optional",
+    "query_as",
+    "query_scalar",
+    "Query",
+    "QueryRow",
+    "QueryContext",
+    "QueryRowContext",
+    "Exec",
+    "ExecContext",
+    "Prepare",
+    "PrepareContext",
+    "Raw",
+    "prepareStatement",
+    "executeQuery",
+    "executeUpdate",
+    "createQuery",
+    "createNativeQuery",
+    "FromSqlRaw",
+    "ExecuteSqlRaw",
+    "SqlQuery",
+];
+
+/// Literal kinds whose contents are the query text.
+const STRING_KINDS: &[&str] = &[
+    "string",
+    "template_string",
+    "string_literal",
+    "raw_string_literal",
+    "interpreted_string_literal",
+    "verbatim_string_literal",
+    "encapsed_string",
+];
+
+/// Expressions joining literals into one query (`"SELECT " + "..."`,
+/// Python's implicit concatenation).
+const CONCATENATIONS: &[&str] = &[
+    "binary_expression",
+    "concatenated_string",
+    "parenthesized_expression",
+];
+
+/// Leading statement keywords reported as `sql_statement`.
+const STATEMENTS: &[&str] = &[
+    "select", "insert", "update", "delete", "create", "alter", "drop", "merge", "replace",
+    "truncate",
+];
+
+/// Keywords followed by a table name.
+const TABLE_INTRODUCERS: &[&str] = &["from", "join", "into", "update", "table"];
+
+/// Words skipped between an introducer and the table name.
+const TABLE_MODIFIERS: &[&str] = &["if", "not", "exists", "only", "lateral", "ignore"];
+
+/// Reserved words that can follow a table name, so are never its alias or
+/// the name itself.
+const RESERVED: &[&str] = &[
+    "select",
+    "where",
+    "join",
+    "inner",
+    "left",
+    "right",
+    "full",
+    "outer",
+    "cross",
+    "natural",
+    "on",
+    "using",
+    "group",
+    "order",
+    "having",
+    "limit",
+    "offset",
+    "union",
+    "intersect",
+    "except",
+    "set",
+    "values",
+    "returning",
+    "default",
+    "window",
+    "for",
+    "as",
+    "with",
+];
+
+/// Set `sql_statement` / `sql_tables` on query calls with a SQL literal.
+pub fn annotate_sql_queries(calls: &mut [NativeParsedCall], root: Node<'_>, source: &[u8]) {
+    for call in calls.iter_mut() {
+        let name = call
+            .callee_identifier
+            .rsplit(['.', ':', '>'])
+            .next()
+            .unwrap_or(&call.callee_identifier);
+        if !QUERY_FUNCTIONS.contains(&name) {
+            continue;
+        }
+        let Some(query) = node_at_range(root, &call.range)
+            .and_then(call_arguments)
+            .and_then(|arguments| query_text(arguments, source))
+        else {
+            continue;
+        };
+        if !is_sql(&query) {
+            continue;
+        }
+        let tokens = tokenize(&query);
+        call.sql_statement = statement_type(&tokens);
+        call.sql_tables = referenced_tables(&tokens);
+    }
+}
+
+/// The argument list of the call at (or wrapping) `node`; for a tagged
+/// template, the template itself.
+fn call_arguments(node: Node<'_>) -> Option<Node<'_>> {
+    let mut current = Some(node);
+    while let Some(candidate) = current {
+        if let Some(arguments) = candidate.child_by_field_name("arguments") {
+            return Some(arguments);
+        }
+        current = candidate
+            .parent()
+            .filter(|parent| parent.byte_range() == node.byte_range());
+    }
+    None
+}
+
+/// Text of the first argument that is a literal or a concatenation of them.
+fn query_text(arguments: Node<'_>, source: &[u8]) -> Option<String> {
+    if arguments.kind() == "template_string" {
+        return literal_value(node_text(arguments, source)).map(str::to_string);
+    }
+    let mut cursor = arguments.walk();
+    let found = arguments.named_children(&mut cursor).find_map(|argument| {
+        // C# / PHP `argument`, Python `keyword_argument` wrappers.
+        let value = match argument.kind() {
+            "argument" | "keyword_argument" => {
+                argument.named_child(argument.named_child_count().checked_sub(1)?)?
+            }
+            _ => argument,
+        };
+        let mut parts = Vec::new();
+        collect_literals(value, source, &mut parts).then(|| parts.concat())
+    });
+    found
+}
+
+/// Push the contents of every literal in `node`; `false` if anything other
+/// than literals and `+` is involved.
+fn collect_literals(node: Node<'_>, source: &[u8], parts: &mut Vec<String>) -> bool {
+    if STRING_KINDS.contains(&node.kind()) {
+        return match literal_value(node_text(node, source)) {
+            Some(value) => {
+                parts.push(value.to_string());
+                true
+            }
+            None => false,
+        };
+    }
+    if !CONCATENATIONS.contains(&node.kind()) {
+        return false;
+    }
+    if node
+        .child_by_field_name("operator")
+        .is_some_and(|operator| node_text(operator, source) != "+")
+    {
+        return false;
+    }
+    let mut cursor = node.walk();
+    let children: Vec<Node<'_>> = node.named_children(&mut cursor).collect();
+    !children.is_empty()
+        && children
+            .into_iter()
+            .all(|child| collect_literals(child, source, parts))
+}
+
+/// Words (identifiers, keywords, possibly quoted and dotted) and single
+/// punctuation characters. Interpolations (`${x}`, `{x}`) become `?`;
+/// string literals and `--` comments inside the query are dropped.
+fn tokenize(query: &str) -> Vec<String> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != '\'' {
+                i += 1;
+            }
+            i += 1;
+        } else if c == '-' && chars.get(i + 1) == Some(&'-') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '{' || (c == '$' && chars.get(i + 1) == Some(&'{')) {
+            while i < chars.len() && chars[i] != '}' {
+                i += 1;
+            }
+            i += 1;
+            tokens.push("?".to_string());
+        } else if is_word_char(c) {
+            let start = i;
+            while i < chars.len() && is_word_char(chars[i]) {
+                i += 1;
+            }
+            let word: String = chars[start..i]
+                .iter()
+                .filter(|c| !matches!(c, '"' | '`' | '[' | ']'))
+                .collect();
+            tokens.push(word);
+        } else {
+            tokens.push(c.to_string());
+            i += 1;
+        }
+    }
+    tokens
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '.' | '$' | '"' | '`' | '[' | ']' | ':' | '@')
+}
+
+fn is_identifier(token: &str) -> bool {
+    token
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && !RESERVED.contains(&token.to_ascii_lowercase().as_str())
+}
+
+/// First statement keyword outside parentheses, so `WITH ... AS (SELECT
+/// ...) DELETE FROM ...` is a delete.
+fn statement_type(tokens: &[String]) -> Option<String> {
+    let mut depth = 0usize;
+    for token in tokens {
+        match token.as_str() {
+            "(" => depth += 1,
+            ")" => depth = depth.saturating_sub(1),
+            word if depth == 0 => {
+                let word = word.to_ascii_lowercase();
+                if STATEMENTS.contains(&word.as_str()) {
+                    return Some(word);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Tables after `FROM` / `JOIN` / `INTO` / `UPDATE` / `TABLE`, including
+/// comma-separated `FROM a, b` lists, minus CTE names. In order of first
+/// appearance.
+fn referenced_tables(tokens: &[String]) -> Vec<String> {
+    let ctes = cte_names(tokens);
+    let mut tables: Vec<String> = Vec::new();
+    let mut push = |table: &str| {
+        if !ctes.iter().any(|cte| cte.eq_ignore_ascii_case(table))
+            && !tables.iter().any(|known| known == table)
+        {
+            tables.push(table.to_string());
+        }
+    };
+
+    for (i, token) in tokens.iter().enumerate() {
+        let keyword = token.to_ascii_lowercase();
+        if !TABLE_INTRODUCERS.contains(&keyword.as_str()) {
+            continue;
+        }
+        let mut j = i + 1;
+        while tokens
+            .get(j)
+            .is_some_and(|word| TABLE_MODIFIERS.contains(&word.to_ascii_lowercase().as_str()))
+        {
+            j += 1;
+        }
+        let Some(table) = tokens.get(j).filter(|word| is_identifier(word)) else {
+            continue;
+        };
+        push(table);
+        if keyword != "from" {
+            continue;
+        }
+        // `FROM a x, b AS y`
+        let mut k = skip_alias(tokens, j + 1);
+        while tokens.get(k).is_some_and(|word| word == ",") {
+            let Some(table) = tokens.get(k + 1).filter(|word| is_identifier(word)) else {
+                break;
+            };
+            push(table);
+            k = skip_alias(tokens, k + 2);
+        }
+    }
+    tables
+}
+
+fn skip_alias(tokens: &[String], at: usize) -> usize {
+    match tokens.get(at) {
+        Some(word) if word.eq_ignore_ascii_case("as") => at + 2,
+        Some(word) if is_identifier(word) => at + 1,
+        _ => at,
+    }
+}
+
+/// Names bound by `WITH name AS (...)`, `WITH RECURSIVE name(...) AS (...)`.
+/// The `(` after `AS` tells a CTE from a `FROM a, b AS x` alias.
+fn cte_names(tokens: &[String]) -> Vec<String> {
+    let mut names = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let keyword = token.to_ascii_lowercase();
+        if keyword != "with" && token != "," {
+            continue;
+        }
+        let mut j = i + 1;
+        if tokens
+            .get(j)
+            .is_some_and(|word| word.eq_ignore_ascii_case("recursive"))
+        {
+            j += 1;
+        }
+        let Some(name) = tokens.get(j).filter(|word| is_identifier(word)) else {
+            continue;
+        };
+        let mut k = j + 1;
+        if tokens.get(k).is_some_and(|word| word == "(") {
+            while tokens.get(k).is_some_and(|word| word != ")") {
+                k += 1;
+            }
+            k += 1;
+        }
+        if tokens
+            .get(k)
+            .is_some_and(|word| word.eq_ignore_ascii_case("as"))
+            && tokens.get(k + 1).is_some_and(|word| word == "(")
+        {
+            names.push(name.clone());
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::test_support::parse_source;
+
+    fn analyze(query: &str) -> (Option<String>, Vec<String>) {
+        let tokens = tokenize(query);
+        (statement_type(&tokens), referenced_tables(&tokens))
+    }
+
+    #[test]
+    fn statements_and_tables_are_read_from_the_query() {
+        assert_eq!(
+            analyze("SELECT u.id FROM users u JOIN orders o ON o.user_id = u.id WHERE u.id = $1"),
+            (Some("select".into()), vec!["users".into(), "orders".into()])
+        );
+        assert_eq!(
+            analyze("insert into public.audit_log (id, note) values (?, 'from x')"),
+            (Some("insert".into()), vec!["public.audit_log".into()])
+        );
+        assert_eq!(
+            analyze("UPDATE \"accounts\" SET balance = balance - ${amount} WHERE id = ${id}"),
+            (Some("update".into()), vec!["accounts".into()])
+        );
+        assert_eq!(
+            analyze("WITH recent AS (SELECT * FROM events) DELETE FROM archive USING recent, users AS u"),
+            (Some("delete".into()), vec!["events".into(), "archive".into()])
+        );
+        assert_eq!(
+            analyze("SELECT * FROM a, b AS y, c WHERE a.id = b.id"),
+            (
+                Some("select".into()),
+                vec!["a".into(), "b".into(), "c".into()]
+            )
+        );
+    }
+
+    #[test]
+    fn query_calls_record_sql_statement_and_tables() {
+        let cases = [
+            (
+                "ts",
+                "repo.ts",
+                r#"export async function load(db, id: string) {
+  await db.query("SELECT u.name FROM users u " + "JOIN teams t ON t.id = u.team_id WHERE u.id = $1", [id]);
+  await prisma.$executeRaw`UPDATE accounts SET seen = now() WHERE id = ${id}`;
+  await db.query(buildQuery(id));
+  cache.get("users");
+}
+"#,
+                vec![
+                    ("query", Some("select"), vec!["users", "teams"]),
+                    ("$executeRaw", Some("update"), vec!["accounts"]),
+                    ("query", None, vec![]),
+                    ("get", None, vec![]),
+                ],
+            ),
+            (
+                "py",
+                "repo.py",
+                r#"def archive(cursor, day):
+    cursor.execute(
+        "INSERT INTO archive.events (id) "
+        "SELECT id FROM events WHERE day = %s",
+        (day,),
+    )
+"#,
+                vec![(
+                    "cursor.execute",
+                    Some("insert"),
+                    vec!["archive.events", "events"],
+                )],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let queries: Vec<(&str, Option<&str>, Vec<&str>)> = parsed
+                .calls
+                .iter()
+                .filter(|call| call.callee_identifier != "buildQuery")
+                .map(|call| {
+                    (
+                        call.callee_identifier.as_str(),
+                        call.sql_statement.as_deref(),
+                        call.sql_tables.iter().map(String::as_str).collect(),
+                    )
+                })
+                .collect();
+            assert_eq!(queries, expected, "{language}");
+        }
+    }
+}
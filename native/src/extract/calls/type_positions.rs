@@ -0,0 +1,98 @@
+use tree_sitter::Node;
+
+use crate::types::NativeParsedCall;
+
+use super::common::node_at_range;
+
+/// Node kinds whose subtree is a type, or an unevaluated operand of one:
+/// annotations, generic arguments, `typeof` type queries, array lengths and
+/// `decltype`.
+const TYPE_CONTEXTS: &[&str] = &[
+    // TS/JS
+    "type_annotation",
+    "opting_type_annotation",
+    "omitting_type_annotation",
+    "type_arguments",
+    "type_query",
+    "type_alias_declaration",
+    // Python annotations (`x: Annotated[int, Field(gt=0)]`)
+    "type",
+    // Rust / Go
+    "array_type",
+    "type_item",
+    // C++
+    "decltype",
+    "template_argument_list",
+    // C#
+    "type_argument_list",
+];
+
+/// Drop calls that sit inside a type position (`typeof import("./x")`,
+/// `decltype(make())`, `[u8; size_of::<T>()]`). They are not run by the
+/// enclosing symbol and would add false edges to the call graph.
+///
+/// Extractors walk the whole tree without tracking type context, so the
+/// call's node is looked up again from its range and its ancestors checked.
+pub fn drop_type_position_calls(calls: &mut Vec<NativeParsedCall>, root: Node<'_>) {
+    calls.retain(|call| node_at_range(root, &call.range).is_none_or(|node| !in_type_context(node)));
+}
+
+fn in_type_context(node: Node<'_>) -> bool {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if TYPE_CONTEXTS.contains(&parent.kind()) {
+            return true;
+        }
+        current = parent.parent();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn calls_in_type_positions_are_not_recorded() {
+        let cases = [
+            (
+                "ts",
+                "types.ts",
+                r#"type Mod = typeof import("./mod");
+
+export function run(): ReturnType<typeof build> {
+  return build();
+}
+"#,
+            ),
+            (
+                "cpp",
+                "types.cpp",
+                r#"int build();
+
+decltype(build()) run() {
+  return build();
+}
+"#,
+            ),
+            (
+                "py",
+                "types.py",
+                r#"def run(limit: Annotated[int, Field(gt=0)]) -> int:
+    return build()
+"#,
+            ),
+        ];
+
+        for (language, rel_path, source) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let callees: Vec<&str> = parsed
+                .calls
+                .iter()
+                .map(|call| call.callee_identifier.as_str())
+                .collect();
+            assert_eq!(callees, vec!["build"], "{language}");
+        }
+    }
+}
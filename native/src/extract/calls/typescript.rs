@@ -35,7 +35,9 @@ use tree_sitter::Node;
 
 use crate::types::{NativeParsedCall, NativeParsedSymbol};
 
-use super::common::{extract_range, find_enclosing_symbol, node_text};
+use super::common::{
+    extract_range, find_enclosing_symbol, iife_callee, is_awaited, is_iife, node_text,
+};
 
 pub fn extract_calls_ts(
     root: Node<'_>,
@@ -130,6 +132,10 @@ fn process_standard_call(
     let mut call_type: &'static str;
 
     match func_node.kind() {
+        _ if is_iife(func_node) => {
+            callee_identifier = iife_callee(func_node, source);
+            call_type = "iife";
+        }
         "identifier" => {
             let name = node_text(func_node, source);
             callee_identifier = name.to_string();
@@ -219,6 +225,8 @@ fn process_standard_call(
         callee_identifier,
         call_type: call_type.to_string(),
         range: extract_range(call_node),
+        is_awaited: is_awaited(call_node),
+        ..Default::default()
     });
 
     // Tagged-template chained-call recursion: the TS
@@ -283,6 +291,7 @@ fn process_new_expression(
         callee_identifier,
         call_type: "constructor".to_string(),
         range: extract_range(new_node),
+        ..Default::default()
     });
 
     // Silence unused-parameter lint for symbol_map — the optional-chain
@@ -468,5 +477,7 @@ fn extract_single_call(
         callee_identifier,
         call_type: call_type.to_string(),
         range: extract_range(call_node),
+        is_awaited: is_awaited(call_node),
+        ..Default::default()
     })
 }
@@ -0,0 +1,172 @@
+//! Comment extraction.
+//!
+//! Every comment node in the file, in source order, with its text as
+//! written and the symbol it documents when it is adjacent to one: a
+//! comment (or run of comments on consecutive lines) ending on the line
+//! before a symbol's declaration, or a trailing comment on the line where
+//! a symbol starts.
+
+use tree_sitter::Node;
+
+use crate::types::{NativeComment, NativeParsedSymbol};
+
+use super::calls::common::{extract_range, node_text};
+
+pub fn extract_comments(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeComment> {
+    let mut nodes = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        // Rust doc comments have marker / content children; the comment
+        // itself is the unit.
+        if node.kind().ends_with("comment") {
+            nodes.push(node);
+            continue;
+        }
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    let mut comments: Vec<NativeComment> = nodes
+        .into_iter()
+        .map(|node| {
+            let text = node_text(node, source).trim_end();
+            NativeComment {
+                text: text.to_string(),
+                kind: comment_kind(text).to_string(),
+                attached_node_id: None,
+                range: extract_range(node),
+            }
+        })
+        .collect();
+
+    // Walk backwards so a comment followed by another on the next line
+    // inherits that comment's symbol.
+    let mut following: Option<(u32, Option<String>)> = None;
+    for comment in comments.iter_mut().rev() {
+        let attached = trailing_symbol(comment, symbols)
+            .or_else(|| symbol_starting_on(comment.range.end_line + 1, symbols))
+            .or_else(|| match &following {
+                Some((start_line, node_id)) if *start_line == comment.range.end_line + 1 => {
+                    node_id.clone()
+                }
+                _ => None,
+            });
+        comment.attached_node_id = attached.clone();
+        following = Some((comment.range.start_line, attached));
+    }
+    comments
+}
+
+/// "doc" for `/** */`, `///`, `//!` and `/*!` comments, "block" for other
+/// delimited comments, "line" otherwise.
+fn comment_kind(text: &str) -> &'static str {
+    if text.starts_with("/**") && text != "/**/"
+        || text.starts_with("///")
+        || text.starts_with("//!")
+        || text.starts_with("/*!")
+    {
+        "doc"
+    } else if text.starts_with("/*") || text.starts_with("<!--") || text.starts_with("--[[") {
+        "block"
+    } else {
+        "line"
+    }
+}
+
+/// `int count; // number of items` -> the symbol declared before the
+/// comment on its first line.
+fn trailing_symbol(comment: &NativeComment, symbols: &[NativeParsedSymbol]) -> Option<String> {
+    symbols
+        .iter()
+        .filter(|symbol| {
+            symbol.range.start_line == comment.range.start_line
+                && symbol.range.start_col < comment.range.start_col
+        })
+        .min_by_key(|symbol| symbol.range.start_col)
+        .map(|symbol| symbol.node_id.clone())
+}
+
+/// The outermost symbol declared on `line`.
+fn symbol_starting_on(line: u32, symbols: &[NativeParsedSymbol]) -> Option<String> {
+    symbols
+        .iter()
+        .filter(|symbol| symbol.range.start_line == line)
+        .min_by_key(|symbol| symbol.range.start_col)
+        .map(|symbol| symbol.node_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::{parse_source, parse_source_with_options};
+    use crate::types::NativeParseOptions;
+
+    #[test]
+    fn comments_are_collected_with_their_attached_symbol() {
+        let source = r#"// License header
+
+/**
+ * Adds two numbers.
+ */
+export function add(a: number, b: number) {
+  return a + b;
+}
+
+// Multiplies.
+// Second line.
+export function mul(a: number, b: number) {
+  /* inline */
+  return a * b;
+}
+
+const limit = 10; // upper bound
+"#;
+
+        let options = NativeParseOptions {
+            comments: Some(true),
+            ..Default::default()
+        };
+
+        let parsed = parse_source_with_options("ts", "comments.ts", source, &options);
+        let plain = parse_source("ts", "comments.ts", source);
+
+        let node_id = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .map(|symbol| symbol.node_id.clone())
+        };
+        let comments: Vec<(&str, &str, Option<String>)> = parsed
+            .comments
+            .iter()
+            .map(|comment| {
+                (
+                    comment.text.as_str(),
+                    comment.kind.as_str(),
+                    comment.attached_node_id.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            comments,
+            vec![
+                ("// License header", "line", None),
+                ("/**\n * Adds two numbers.\n */", "doc", node_id("add")),
+                ("// Multiplies.", "line", node_id("mul")),
+                ("// Second line.", "line", node_id("mul")),
+                ("/* inline */", "block", None),
+                ("// upper bound", "line", node_id("limit")),
+            ]
+        );
+        assert!(node_id("limit").is_some());
+        assert!(plain.comments.is_empty());
+    }
+}
@@ -0,0 +1,161 @@
+//! Intra-file dead-code hints.
+//!
+//! A symbol nothing outside its own file can reach (unexported, or a
+//! `private` member) and whose name appears nowhere else in the file is
+//! likely dead. Any identifier with the same text counts as a use (a call,
+//! a type annotation, an `export { x }` clause, a property access), so the
+//! check errs towards "used"; mentions inside the symbol itself, such as
+//! recursion, do not count. Decorated symbols and conventional entry points
+//! are never flagged since a framework or runtime calls them by name.
+
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::types::NativeParsedSymbol;
+
+use super::calls::common::{node_at_range, node_text};
+
+/// Kinds flagged when unexported.
+const MODULE_KINDS: &[&str] = &[
+    "function",
+    "class",
+    "interface",
+    "type_alias",
+    "enum",
+    "variable",
+];
+
+/// Kinds flagged when `private`.
+const MEMBER_KINDS: &[&str] = &["method", "property"];
+
+/// Leaf kinds holding a name, besides the `*identifier` family.
+const NAME_KINDS: &[&str] = &["name", "constant", "word"];
+
+/// Names a runtime or test runner calls without a reference.
+const ENTRY_POINTS: &[&str] = &["main", "init", "setup", "teardown"];
+
+const ENTRY_POINT_PREFIXES: &[&str] = &["test", "Test", "Benchmark", "Example", "Fuzz"];
+
+/// Set `unreferenced` on every symbol that is a dead-code candidate.
+pub fn flag_unreferenced_symbols(
+    symbols: &mut [NativeParsedSymbol],
+    root: Node<'_>,
+    source: &[u8],
+) {
+    let mut mentions: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.child_count() == 0 {
+            if is_name(node) {
+                mentions
+                    .entry(node_text(node, source))
+                    .or_default()
+                    .push(node.start_byte());
+            }
+            continue;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    for symbol in symbols.iter_mut().filter(|symbol| is_candidate(symbol)) {
+        let Some(node) = node_at_range(root, &symbol.range) else {
+            continue;
+        };
+        let own = node.start_byte()..node.end_byte();
+        symbol.unreferenced = mentions
+            .get(short_name(&symbol.name))
+            .is_none_or(|starts| starts.iter().all(|start| own.contains(start)));
+    }
+}
+
+/// Identifier-like leaf (`identifier`, `field_identifier`, PHP `name`, ...).
+pub fn is_name(node: Node<'_>) -> bool {
+    node.is_named() && (node.kind().ends_with("identifier") || NAME_KINDS.contains(&node.kind()))
+}
+
+fn is_candidate(symbol: &NativeParsedSymbol) -> bool {
+    let kind = symbol.kind.as_str();
+    let reachable_only_here = (MODULE_KINDS.contains(&kind) && !symbol.exported)
+        || (MEMBER_KINDS.contains(&kind) && symbol.visibility == "private");
+    let name = short_name(&symbol.name);
+    reachable_only_here
+        && symbol.decorators.is_empty()
+        && !name.is_empty()
+        && (!name.starts_with("__") || !name.ends_with("__"))
+        && !ENTRY_POINTS.contains(&name)
+        && !ENTRY_POINT_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+}
+
+/// The name as it appears at use sites: `Worker._step` -> `_step`.
+fn short_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn unexported_symbols_never_mentioned_in_their_file_are_flagged() {
+        let cases = [
+            (
+                "ts",
+                "service.ts",
+                r#"function used() { return 1; }
+function unused(n: number): number { return n > 0 ? unused(n - 1) : 0; }
+interface Shape { x: number }
+const config = { retries: 3 };
+export class Service {
+  private stale() { return 0; }
+  run(shape: Shape) { return used() + config.retries + shape.x; }
+}
+"#,
+                vec!["unused", "stale"],
+            ),
+            (
+                "py",
+                "service.py",
+                r#"def _helper():
+    return 1
+
+def _unused():
+    return 2
+
+@app.get("/")
+def _index():
+    return _helper()
+
+def test_service():
+    assert _helper() == 1
+
+class Worker:
+    def _step(self):
+        return 0
+
+    def _stale(self):
+        return 1
+
+    def run(self):
+        return self._step()
+"#,
+                vec!["_unused", "Worker._stale"],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let unreferenced: Vec<&str> = parsed
+                .symbols
+                .iter()
+                .filter(|symbol| symbol.unreferenced)
+                .map(|symbol| symbol.name.as_str())
+                .collect();
+            assert_eq!(unreferenced, expected, "{language}");
+        }
+    }
+}
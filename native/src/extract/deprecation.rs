@@ -0,0 +1,183 @@
+//! Deprecation markers.
+//!
+//! A symbol is deprecated when it carries a deprecation attribute
+//! (`#[deprecated]`, `[Obsolete]`, `@Deprecated`, Python's `@deprecated`),
+//! its doc comment says so (`@deprecated`, `Deprecated:`,
+//! `.. deprecated::`), or, in Python, its body issues a
+//! `DeprecationWarning`. The message is the attribute's note, the doc text
+//! or the warning text, in that order of preference.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::types::NativeParsedSymbol;
+
+use super::summary::doc_deprecation;
+
+/// Attribute names (last path segment) that mark a deprecation.
+const DEPRECATION_ATTRIBUTES: &[&str] =
+    &["deprecated", "Deprecated", "Obsolete", "ObsoleteAttribute"];
+
+/// Named arguments holding the message.
+const MESSAGE_OPTIONS: &[&str] = &["note", "message", "reason"];
+
+const CALLABLE_KINDS: &[&str] = &["function", "method", "constructor"];
+
+/// `warnings.warn("msg", DeprecationWarning)`, also `PendingDeprecationWarning`
+/// and `category=DeprecationWarning`.
+static DEPRECATION_WARNING: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"warn\(\s*(?:f?(?:"([^"]*)"|'([^']*)'))?[^)]*DeprecationWarning"#).unwrap()
+});
+
+/// `Some(message)` when `symbol` is deprecated; the message is empty when
+/// none is given.
+pub fn detect_deprecation(
+    symbol: &NativeParsedSymbol,
+    file_content: &str,
+    language: &str,
+) -> Option<String> {
+    attribute_deprecation(symbol)
+        .or_else(|| doc_deprecation(symbol, file_content, language))
+        .or_else(|| {
+            (language == "py" && CALLABLE_KINDS.contains(&symbol.kind.as_str()))
+                .then(|| warning_deprecation(symbol, file_content))
+                .flatten()
+        })
+}
+
+fn attribute_deprecation(symbol: &NativeParsedSymbol) -> Option<String> {
+    symbol.decorator_metadata.iter().find_map(|decorator| {
+        // `#[deprecated = "use x"]` has no parentheses, so the parser leaves
+        // the message in the name.
+        let (name, assigned) = match decorator.name.split_once('=') {
+            Some((name, message)) => (name.trim(), Some(message.trim().trim_matches('"'))),
+            None => (decorator.name.as_str(), None),
+        };
+        let name = name.rsplit(['.', ':']).next().unwrap_or(name);
+        if !DEPRECATION_ATTRIBUTES.contains(&name) {
+            return None;
+        }
+        let message = assigned
+            .map(str::to_string)
+            .or_else(|| {
+                decorator
+                    .options
+                    .iter()
+                    .find(|option| MESSAGE_OPTIONS.contains(&option.key.as_str()))
+                    .map(|option| option.value.clone())
+            })
+            .or_else(|| decorator.arguments.first().cloned())
+            .unwrap_or_default();
+        Some(message)
+    })
+}
+
+fn warning_deprecation(symbol: &NativeParsedSymbol, file_content: &str) -> Option<String> {
+    let start = (symbol.range.start_line as usize).saturating_sub(1);
+    let end = symbol.range.end_line as usize;
+    let body = file_content
+        .lines()
+        .skip(start)
+        .take(end.saturating_sub(start))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let captures = DEPRECATION_WARNING.captures(&body)?;
+    Some(
+        captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .map(|message| message.as_str().to_string())
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn deprecated_symbols_are_flagged_with_their_message() {
+        let cases = [
+            (
+                "ts",
+                "users.ts",
+                r#"/**
+ * Loads a user.
+ * @deprecated Use fetchUser instead.
+ */
+export function getUser() {}
+
+export function fetchUser() {}
+"#,
+                vec![("getUser", Some("Use fetchUser instead."))],
+            ),
+            (
+                "rs",
+                "users.rs",
+                r#"#[deprecated(since = "1.2", note = "use fetch_user")]
+pub fn get_user() {}
+
+pub fn fetch_user() {}
+
+/// Old settings.
+#[deprecated]
+#[derive(Debug)]
+pub struct Settings;
+"#,
+                vec![("get_user", Some("use fetch_user")), ("Settings", None)],
+            ),
+            (
+                "py",
+                "users.py",
+                r#"import warnings
+
+def get_user():
+    warnings.warn("get_user is going away", DeprecationWarning, stacklevel=2)
+
+def fetch_user():
+    pass
+"#,
+                vec![("get_user", Some("get_user is going away"))],
+            ),
+            (
+                "java",
+                "Users.java",
+                r#"class Users {
+    @Deprecated
+    void getUser() {}
+
+    void fetchUser() {}
+}
+"#,
+                vec![("getUser", None)],
+            ),
+            (
+                "go",
+                "users.go",
+                r#"package users
+
+// GetUser loads a user.
+//
+// Deprecated: use FetchUser.
+func GetUser() {}
+
+func FetchUser() {}
+"#,
+                vec![("GetUser", Some("use FetchUser."))],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let deprecated: Vec<(&str, Option<&str>)> = parsed
+                .symbols
+                .iter()
+                .filter(|symbol| symbol.deprecated)
+                .map(|symbol| (symbol.name.as_str(), symbol.deprecation_message.as_deref()))
+                .collect();
+            assert_eq!(deprecated, expected, "{language}");
+        }
+    }
+}
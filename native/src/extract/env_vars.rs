@@ -0,0 +1,349 @@
+//! Environment variable reads with their key names.
+//!
+//! `extract_side_effects` only says a symbol touches the environment; this
+//! pass records which variables, so configuration dependencies can be
+//! queried. Only literal keys are recorded: `process.env[name]` is skipped.
+//!
+//! Recognised forms:
+//!
+//! - property and index access on an environment object
+//!   (`process.env.PORT`, `import.meta.env["VITE_URL"]`,
+//!   `os.environ["HOME"]`, `$_ENV["APP_KEY"]`)
+//! - destructuring one (`const { PORT, HOST = "0.0.0.0" } = process.env`)
+//! - reader calls (`os.getenv("X")`, `os.Getenv("X")`,
+//!   `std::env::var("X")`, `System.getenv("X")`, `getenv("X")`, ...)
+//! - Rust `env!("X")` / `option_env!("X")`
+
+use tree_sitter::Node;
+
+use crate::types::{NativeEnvVarUsage, NativeParsedSymbol};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use super::string_literals::literal_value;
+
+/// Objects whose properties are environment variables.
+const ENV_OBJECTS: &[&str] = &[
+    "process.env",
+    "import.meta.env",
+    "os.environ",
+    "environ",
+    "$_ENV",
+    "$_SERVER",
+];
+
+/// Functions whose first argument is a variable name.
+const ENV_READERS: &[&str] = &[
+    "os.getenv",
+    "os.environ.get",
+    "environ.get",
+    "os.environ.setdefault",
+    "os.Getenv",
+    "os.LookupEnv",
+    "std::env::var",
+    "std::env::var_os",
+    "env::var",
+    "env::var_os",
+    "System.getenv",
+    "Environment.GetEnvironmentVariable",
+    "Deno.env.get",
+    "getenv",
+    "std::getenv",
+];
+
+/// Rust macros reading a variable at compile time.
+const ENV_MACROS: &[&str] = &["env", "option_env"];
+
+/// Call node kinds across the supported grammars.
+const CALL_KINDS: &[&str] = &[
+    "call_expression",
+    "call",
+    "method_invocation",
+    "invocation_expression",
+    "function_call_expression",
+];
+
+/// Index access node kinds; the object is the first named child and the
+/// key the second.
+const SUBSCRIPT_KINDS: &[&str] = &["subscript_expression", "subscript"];
+
+pub fn extract_env_vars(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeEnvVarUsage> {
+    let mut usages = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        let kind = node.kind();
+        if kind == "member_expression" {
+            process_member(node, source, symbols, &mut usages);
+        } else if SUBSCRIPT_KINDS.contains(&kind) {
+            process_subscript(node, source, symbols, &mut usages);
+        } else if kind == "variable_declarator" {
+            process_destructuring(node, source, symbols, &mut usages);
+        } else if CALL_KINDS.contains(&kind) {
+            process_call(node, source, symbols, &mut usages);
+        } else if kind == "macro_invocation" {
+            process_macro(node, source, symbols, &mut usages);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    usages
+}
+
+/// `process.env.PORT`
+fn process_member(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    usages: &mut Vec<NativeEnvVarUsage>,
+) {
+    let (Some(object), Some(property)) = (
+        node.child_by_field_name("object"),
+        node.child_by_field_name("property"),
+    ) else {
+        return;
+    };
+    let accessor = compact(node_text(object, source));
+    if ENV_OBJECTS.contains(&accessor.as_str()) {
+        push(usages, node_text(property, source), accessor, node, symbols);
+    }
+}
+
+/// `process.env["PORT"]`, `os.environ["HOME"]`
+fn process_subscript(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    usages: &mut Vec<NativeEnvVarUsage>,
+) {
+    let mut cursor = node.walk();
+    let named: Vec<Node<'_>> = node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() != "comment")
+        .collect();
+    let &[object, index, ..] = &named[..] else {
+        return;
+    };
+    let accessor = compact(node_text(object, source));
+    if !ENV_OBJECTS.contains(&accessor.as_str()) {
+        return;
+    }
+    if let Some(key) = string_key(index, source) {
+        push(usages, key, accessor, node, symbols);
+    }
+}
+
+/// `const { PORT, HOST = "0.0.0.0", API_KEY: key } = process.env`
+fn process_destructuring(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    usages: &mut Vec<NativeEnvVarUsage>,
+) {
+    let (Some(pattern), Some(value)) = (
+        node.child_by_field_name("name"),
+        node.child_by_field_name("value"),
+    ) else {
+        return;
+    };
+    let accessor = compact(node_text(value, source));
+    if pattern.kind() != "object_pattern" || !ENV_OBJECTS.contains(&accessor.as_str()) {
+        return;
+    }
+    let mut cursor = pattern.walk();
+    for entry in pattern.named_children(&mut cursor) {
+        let key = match entry.kind() {
+            "shorthand_property_identifier_pattern" => Some(entry),
+            "object_assignment_pattern" => entry.child_by_field_name("left"),
+            "pair_pattern" => entry.child_by_field_name("key"),
+            _ => None,
+        };
+        if let Some(key) = key {
+            push(
+                usages,
+                node_text(key, source),
+                accessor.clone(),
+                entry,
+                symbols,
+            );
+        }
+    }
+}
+
+/// `os.getenv("HOME")`, `std::env::var("PATH")`
+fn process_call(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    usages: &mut Vec<NativeEnvVarUsage>,
+) {
+    let Some(accessor) = callee(node, source) else {
+        return;
+    };
+    if !ENV_READERS.contains(&accessor.as_str()) {
+        return;
+    }
+    let Some(arguments) = node.child_by_field_name("arguments") else {
+        return;
+    };
+    let Some(mut first) = arguments.named_child(0) else {
+        return;
+    };
+    // C# / PHP wrap each argument.
+    if first.kind() == "argument" {
+        match first.named_child(0) {
+            Some(inner) => first = inner,
+            None => return,
+        }
+    }
+    if let Some(key) = string_key(first, source) {
+        push(usages, key, accessor, node, symbols);
+    }
+}
+
+/// `env!("CARGO_PKG_VERSION")`
+fn process_macro(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    usages: &mut Vec<NativeEnvVarUsage>,
+) {
+    let Some(name) = node.child_by_field_name("macro") else {
+        return;
+    };
+    let name = node_text(name, source);
+    if !ENV_MACROS.contains(&name) {
+        return;
+    }
+    let key = node
+        .named_children(&mut node.walk())
+        .find(|child| child.kind() == "token_tree")
+        .and_then(|tree| tree.named_child(0))
+        .and_then(|first| string_key(first, source));
+    if let Some(key) = key {
+        push(usages, key, format!("{name}!"), node, symbols);
+    }
+}
+
+/// Callee as written, without whitespace. Java's `method_invocation` splits
+/// it into `object` and `name`.
+fn callee(node: Node<'_>, source: &[u8]) -> Option<String> {
+    if let Some(function) = node.child_by_field_name("function") {
+        return Some(compact(node_text(function, source)));
+    }
+    let object = node.child_by_field_name("object")?;
+    let name = node.child_by_field_name("name")?;
+    Some(compact(&format!(
+        "{}.{}",
+        node_text(object, source),
+        node_text(name, source)
+    )))
+}
+
+fn string_key<'a>(node: Node<'a>, source: &'a [u8]) -> Option<&'a str> {
+    if !matches!(
+        node.kind(),
+        "string"
+            | "string_literal"
+            | "interpreted_string_literal"
+            | "raw_string_literal"
+            | "encapsed_string"
+    ) {
+        return None;
+    }
+    literal_value(node_text(node, source)).filter(|key| !key.is_empty())
+}
+
+fn compact(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+fn push(
+    usages: &mut Vec<NativeEnvVarUsage>,
+    key: &str,
+    accessor: String,
+    node: Node<'_>,
+    symbols: &[NativeParsedSymbol],
+) {
+    usages.push(NativeEnvVarUsage {
+        key: key.to_string(),
+        accessor,
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        range: extract_range(node),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn environment_variable_reads_record_their_keys() {
+        let cases = [
+            (
+                "ts",
+                "config.ts",
+                r#"const { PORT, HOST = "0.0.0.0" } = process.env;
+
+export function databaseUrl(name: string) {
+  return process.env.DATABASE_URL ?? process.env["FALLBACK_URL"] ?? process.env[name];
+}
+"#,
+                vec!["PORT", "HOST", "DATABASE_URL", "FALLBACK_URL"],
+            ),
+            (
+                "py",
+                "config.py",
+                r#"import os
+
+def settings():
+    return os.environ["HOME"], os.getenv("DEBUG", "0"), os.environ.get("TOKEN")
+"#,
+                vec!["HOME", "DEBUG", "TOKEN"],
+            ),
+            (
+                "rs",
+                "config.rs",
+                r#"pub fn settings() -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    std::env::var("RUST_LOG").unwrap_or_else(|_| version.to_string())
+}
+"#,
+                vec!["CARGO_PKG_VERSION", "RUST_LOG"],
+            ),
+            (
+                "go",
+                "config.go",
+                r#"package config
+
+import "os"
+
+func Settings() string {
+	return os.Getenv("GO_ENV")
+}
+"#,
+                vec!["GO_ENV"],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let keys: Vec<&str> = parsed
+                .env_vars
+                .iter()
+                .map(|usage| usage.key.as_str())
+                .collect();
+            assert_eq!(keys, expected, "{language}");
+        }
+    }
+}
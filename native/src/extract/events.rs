@@ -0,0 +1,165 @@
+//! Event-emitter edge extraction for the TS/JS family.
+//!
+//! `emitter.on('saved', onSaved)` and `emitter.emit('saved')` are never
+//! linked by a call edge, so each becomes a [`NativeEventUsage`] keyed by the
+//! event name and the graph pairs listeners with emitters by that key.
+
+use tree_sitter::Node;
+
+use crate::types::{NativeEventUsage, NativeParsedSymbol};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+
+/// Listener registration methods of Node's `EventEmitter` and the DOM
+/// `EventTarget`.
+const LISTEN_METHODS: &[&str] = &[
+    "on",
+    "once",
+    "addListener",
+    "prependListener",
+    "prependOnceListener",
+    "addEventListener",
+];
+
+const EMIT_METHODS: &[&str] = &["emit"];
+
+pub fn extract_events_ts(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeEventUsage> {
+    let mut events = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression" {
+            if let Some(usage) = process_event_call(node, source, symbols) {
+                events.push(usage);
+            }
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    events
+}
+
+fn process_event_call(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeEventUsage> {
+    let callee = node.child_by_field_name("function")?;
+    if callee.kind() != "member_expression" {
+        return None;
+    }
+    let method = node_text(callee.child_by_field_name("property")?, source);
+    let kind = if LISTEN_METHODS.contains(&method) {
+        "listen"
+    } else if EMIT_METHODS.contains(&method) {
+        "emit"
+    } else {
+        return None;
+    };
+    let emitter = node_text(callee.child_by_field_name("object")?, source);
+
+    let args = node.child_by_field_name("arguments")?;
+    let mut cursor = args.walk();
+    let named: Vec<Node<'_>> = args
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() != "comment")
+        .collect();
+    // Only literal event names can be paired; `emit(eventName)` is skipped.
+    let event = string_value(*named.first()?, source)?;
+    let handler = if kind == "listen" {
+        named
+            .get(1)
+            .filter(|arg| matches!(arg.kind(), "identifier" | "member_expression"))
+            .map(|arg| node_text(*arg, source).to_string())
+    } else {
+        None
+    };
+
+    Some(NativeEventUsage {
+        event,
+        kind: kind.to_string(),
+        method: method.to_string(),
+        emitter: emitter.to_string(),
+        handler,
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        range: extract_range(node),
+    })
+}
+
+/// Contents of a string literal or a template literal without
+/// substitutions.
+fn string_value(node: Node<'_>, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "string" => {}
+        "template_string" if !has_substitution(node) => {}
+        _ => return None,
+    }
+    let text = node_text(node, source);
+    Some(text[1..text.len() - 1].to_string())
+}
+
+fn has_substitution(node: Node<'_>) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .any(|child| child.kind() == "template_substitution");
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn event_emitter_listeners_and_emissions_are_paired_by_event_name() {
+        let source = r#"export class Store {
+  constructor(private bus: EventEmitter) {
+    this.bus.on("saved", this.refresh);
+    bus.once(`closed`, () => {});
+  }
+
+  save(name: string) {
+    this.bus.emit("saved", { id: 1 });
+    this.bus.emit(name);
+  }
+
+  refresh() {}
+}
+"#;
+
+        let parsed = parse_source("ts", "src/store.ts", source);
+
+        let events: Vec<(&str, &str, &str, &str, Option<&str>)> = parsed
+            .events
+            .iter()
+            .map(|usage| {
+                (
+                    usage.event.as_str(),
+                    usage.kind.as_str(),
+                    usage.method.as_str(),
+                    usage.emitter.as_str(),
+                    usage.handler.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                ("saved", "listen", "on", "this.bus", Some("this.refresh")),
+                ("closed", "listen", "once", "bus", None),
+                ("saved", "emit", "emit", "this.bus", None),
+            ]
+        );
+        assert!(parsed.events[2].caller_node_id.starts_with("save:"));
+    }
+}
@@ -0,0 +1,303 @@
+//! Throw sites and caught exception types.
+//!
+//! Each record says where a symbol raises or handles an error, and with
+//! which type when the source names one, so "who can throw `NotFound`" and
+//! "who catches it" become graph lookups. Kinds:
+//!
+//! - `throw`: `throw new XError()`, Python `raise X`
+//! - `panic`: Rust `panic!` / `unreachable!` / `todo!` / `unimplemented!`,
+//!   Go `panic(..)`
+//! - `error_return`: Rust `Err(..)` construction
+//! - `catch`: one per type of a `catch` / `except` clause (untyped clauses
+//!   get one record without a type)
+//!
+//! Rethrowing a variable (`throw err`, `raise`) records no type.
+
+use tree_sitter::Node;
+
+use crate::types::{NativeExceptionSite, NativeParsedSymbol};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+
+const THROW_KINDS: &[&str] = &["throw_statement", "throw_expression", "raise_statement"];
+
+const CATCH_KINDS: &[&str] = &["catch_clause", "except_clause"];
+
+const PANIC_MACROS: &[&str] = &["panic", "unreachable", "todo", "unimplemented"];
+
+/// Constructor expressions; the type is the `constructor` / `type` field or
+/// the first named child.
+const NEW_KINDS: &[&str] = &["new_expression", "object_creation_expression"];
+
+/// Nodes of a caught type, taken whole.
+const TYPE_NAMES: &[&str] = &[
+    "type_identifier",
+    "scoped_type_identifier",
+    "qualified_name",
+    "identifier",
+    "attribute",
+    "name",
+];
+
+pub fn extract_exception_sites(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeExceptionSite> {
+    let mut sites = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        let kind = node.kind();
+        if THROW_KINDS.contains(&kind) {
+            let exception_type = first_named(node).and_then(|thrown| thrown_type(thrown, source));
+            sites.push(site(node, "throw", exception_type, symbols));
+        } else if CATCH_KINDS.contains(&kind) {
+            let types = caught_types(node, source);
+            if types.is_empty() {
+                sites.push(site(node, "catch", None, symbols));
+            }
+            for exception_type in types {
+                sites.push(site(node, "catch", Some(exception_type), symbols));
+            }
+        } else if kind == "macro_invocation" {
+            let is_panic = node
+                .child_by_field_name("macro")
+                .is_some_and(|name| PANIC_MACROS.contains(&node_text(name, source)));
+            if is_panic {
+                sites.push(site(node, "panic", None, symbols));
+            }
+        } else if kind == "call_expression" {
+            match node
+                .child_by_field_name("function")
+                .map(|function| (function.kind(), node_text(function, source)))
+            {
+                // Go's builtin; Rust's `Err` is a tuple-struct constructor.
+                Some(("identifier", "panic")) => sites.push(site(node, "panic", None, symbols)),
+                Some(("identifier", "Err")) => {
+                    let exception_type = node
+                        .child_by_field_name("arguments")
+                        .and_then(first_named)
+                        .and_then(|error| error_type(error, source));
+                    sites.push(site(node, "error_return", exception_type, symbols));
+                }
+                _ => {}
+            }
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    sites
+}
+
+fn site(
+    node: Node<'_>,
+    kind: &str,
+    exception_type: Option<String>,
+    symbols: &[NativeParsedSymbol],
+) -> NativeExceptionSite {
+    NativeExceptionSite {
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        kind: kind.to_string(),
+        exception_type,
+        range: extract_range(node),
+    }
+}
+
+fn first_named(node: Node<'_>) -> Option<Node<'_>> {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() != "comment");
+    found
+}
+
+/// `new NotFound(..)`, `NotFound(..)`, `NotFound`; `None` for `err` or
+/// `makeError(..)`.
+fn thrown_type(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let name = if NEW_KINDS.contains(&node.kind()) {
+        node.child_by_field_name("constructor")
+            .or_else(|| node.child_by_field_name("type"))
+            .or_else(|| first_named(node))?
+    } else if matches!(node.kind(), "call" | "call_expression") {
+        node.child_by_field_name("function")?
+    } else {
+        node
+    };
+    type_name(node_text(name, source))
+}
+
+/// `Err(MyError::NotFound)` / `Err(Error::new(..))` -> the type before the
+/// last `::`; `Err(NotFound { .. })` -> `NotFound`; `Err(e)` -> `None`.
+fn error_type(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let path = match node.kind() {
+        "call_expression" => node.child_by_field_name("function")?,
+        "struct_expression" => node.child_by_field_name("name")?,
+        _ => node,
+    };
+    let text = node_text(path, source);
+    match text.rsplit_once("::") {
+        Some((owner, _)) => type_name(owner),
+        None => type_name(text),
+    }
+}
+
+/// `text` without generic arguments, if its last segment is capitalized
+/// like a type.
+fn type_name(text: &str) -> Option<String> {
+    let text = text.split(['<', '(']).next()?.trim();
+    let last = text.rsplit(['.', ':', '\\']).next()?;
+    last.starts_with(|c: char| c.is_uppercase())
+        .then(|| text.to_string())
+}
+
+/// Types named by a `catch` / `except` clause: Java multi-catch
+/// (`A | B`), C# / PHP / C++ declarations, Python names, tuples and
+/// `X as e`.
+fn caught_types(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    let Some(declaration) = caught_declaration(node) else {
+        return Vec::new();
+    };
+    let mut types = Vec::new();
+    let mut stack = vec![declaration];
+    while let Some(current) = stack.pop() {
+        if TYPE_NAMES.contains(&current.kind()) {
+            types.extend(type_name(node_text(current, source)));
+            continue;
+        }
+        let child_count = current.named_child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = current.named_child(i) {
+                stack.push(child);
+            }
+        }
+    }
+    types
+}
+
+/// The type part of a clause, without the bound variable.
+fn caught_declaration(node: Node<'_>) -> Option<Node<'_>> {
+    if node.kind() == "except_clause" {
+        let value = first_named(node).filter(|value| value.kind() != "block")?;
+        return Some(if value.kind() == "as_pattern" {
+            first_named(value).unwrap_or(value)
+        } else {
+            value
+        });
+    }
+    // TS annotation, PHP type list.
+    if let Some(annotation) = node.child_by_field_name("type") {
+        return Some(annotation);
+    }
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .find_map(|child| match child.kind() {
+            "catch_formal_parameter" => {
+                let mut inner = child.walk();
+                let catch_type = child
+                    .named_children(&mut inner)
+                    .find(|part| part.kind() == "catch_type");
+                catch_type
+            }
+            "catch_declaration" => child.child_by_field_name("type"),
+            "parameter_list" => Some(child),
+            _ => None,
+        });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn throw_and_catch_sites_record_exception_types() {
+        let cases = [
+            (
+                "ts",
+                "load.ts",
+                r#"export function load(id: string) {
+  try {
+    if (!id) throw new NotFoundError(id);
+  } catch (err) {
+    throw err;
+  }
+}
+"#,
+                vec![
+                    ("throw", Some("NotFoundError")),
+                    ("catch", None),
+                    ("throw", None),
+                ],
+            ),
+            (
+                "py",
+                "load.py",
+                r#"def load(id):
+    try:
+        raise ValueError(id)
+    except (KeyError, errors.Missing) as e:
+        raise
+"#,
+                vec![
+                    ("throw", Some("ValueError")),
+                    ("catch", Some("KeyError")),
+                    ("catch", Some("errors.Missing")),
+                    ("throw", None),
+                ],
+            ),
+            (
+                "rs",
+                "load.rs",
+                r#"pub fn load(id: u32) -> Result<(), LoadError> {
+    if id == 0 {
+        return Err(LoadError::NotFound);
+    }
+    panic!("unreachable id {id}");
+}
+"#,
+                vec![("error_return", Some("LoadError")), ("panic", None)],
+            ),
+            (
+                "java",
+                "Loader.java",
+                r#"class Loader {
+    void load() {
+        try {
+            throw new IllegalStateException("x");
+        } catch (IOException | SQLException e) {
+        }
+    }
+}
+"#,
+                vec![
+                    ("throw", Some("IllegalStateException")),
+                    ("catch", Some("IOException")),
+                    ("catch", Some("SQLException")),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let sites: Vec<(&str, Option<&str>)> = parsed
+                .exceptions
+                .iter()
+                .map(|site| (site.kind.as_str(), site.exception_type.as_deref()))
+                .collect();
+            assert_eq!(sites, expected, "{language}");
+            assert!(parsed
+                .exceptions
+                .iter()
+                .all(|site| site.caller_node_id.starts_with("load:")));
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Module-level flags for the TS/JS family.
+//!
+//! Bundling and runtime questions ("can this module be tree-shaken?", "is it
+//! a client component?") depend on what runs when the module is first
+//! evaluated, not on any single symbol, so they are recorded per file.
+
+use tree_sitter::Node;
+
+use crate::types::NativeFileFlags;
+
+use super::calls::common::node_text;
+
+/// Nodes whose bodies do not run at module evaluation time.
+const DEFERRED_SCOPES: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "function",
+    "generator_function_declaration",
+    "generator_function",
+    "arrow_function",
+    "method_definition",
+    "class_body",
+];
+
+/// Top-level statements that only declare names.
+const DECLARATIONS: &[&str] = &[
+    "function_declaration",
+    "generator_function_declaration",
+    "class_declaration",
+    "abstract_class_declaration",
+    "interface_declaration",
+    "type_alias_declaration",
+    "enum_declaration",
+    "ambient_declaration",
+    "module",
+    "internal_module",
+    "import_alias",
+    "empty_statement",
+    "comment",
+    "hash_bang_line",
+];
+
+/// Expression kinds that may observably run code or mutate state.
+const EFFECTFUL_EXPRESSIONS: &[&str] = &[
+    "call_expression",
+    "new_expression",
+    "await_expression",
+    "assignment_expression",
+    "augmented_assignment_expression",
+    "update_expression",
+    "yield_expression",
+];
+
+pub fn extract_file_flags_ts(root: Node<'_>, source: &[u8]) -> NativeFileFlags {
+    let mut flags = NativeFileFlags {
+        directives: directive_prologue(root, source),
+        ..Default::default()
+    };
+
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        if !flags.has_top_level_side_effects && is_side_effectful(statement) {
+            flags.has_top_level_side_effects = true;
+        }
+        if !flags.has_top_level_await && contains_top_level_await(statement) {
+            flags.has_top_level_await = true;
+        }
+    }
+
+    flags
+}
+
+/// Leading string-literal statements (`"use client"`, `'use strict'`).
+fn directive_prologue(root: Node<'_>, source: &[u8]) -> Vec<String> {
+    let mut directives = Vec::new();
+
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        match statement.kind() {
+            "comment" | "hash_bang_line" => continue,
+            "expression_statement" => {}
+            _ => break,
+        }
+        let Some(expression) = statement.named_child(0) else {
+            break;
+        };
+        if expression.kind() != "string" {
+            break;
+        }
+        let text = node_text(expression, source);
+        directives.push(text.trim_matches(|c| c == '"' || c == '\'').to_string());
+    }
+
+    directives
+}
+
+fn is_side_effectful(statement: Node<'_>) -> bool {
+    let kind = statement.kind();
+    if DECLARATIONS.contains(&kind) {
+        return false;
+    }
+    match kind {
+        // `import "./polyfill"` runs the module for its effects alone.
+        "import_statement" => !has_child_kind(statement, "import_clause"),
+        "export_statement" => {
+            if let Some(declaration) = statement.child_by_field_name("declaration") {
+                return is_side_effectful(declaration);
+            }
+            statement
+                .child_by_field_name("value")
+                .is_some_and(has_effectful_expression)
+        }
+        "lexical_declaration" | "variable_declaration" => has_effectful_expression(statement),
+        // Directives are strings, not code; `namespace X {}` parses as an
+        // expression statement.
+        "expression_statement" => statement
+            .named_child(0)
+            .is_none_or(|expression| !matches!(expression.kind(), "string" | "internal_module")),
+        _ => true,
+    }
+}
+
+fn has_effectful_expression(node: Node<'_>) -> bool {
+    let mut stack = vec![node];
+    while let Some(current) = stack.pop() {
+        let kind = current.kind();
+        if EFFECTFUL_EXPRESSIONS.contains(&kind) {
+            return true;
+        }
+        if kind == "unary_expression" && has_child_kind(current, "delete") {
+            return true;
+        }
+        if DEFERRED_SCOPES.contains(&kind) {
+            continue;
+        }
+        let mut cursor = current.walk();
+        stack.extend(current.children(&mut cursor));
+    }
+    false
+}
+
+/// `await x` or `for await (...)` outside any function body.
+fn contains_top_level_await(node: Node<'_>) -> bool {
+    let mut stack = vec![node];
+    while let Some(current) = stack.pop() {
+        let kind = current.kind();
+        if kind == "await_expression" {
+            return true;
+        }
+        if kind == "for_in_statement" && has_child_kind(current, "await") {
+            return true;
+        }
+        if DEFERRED_SCOPES.contains(&kind) {
+            continue;
+        }
+        let mut cursor = current.walk();
+        stack.extend(current.children(&mut cursor));
+    }
+    false
+}
+
+fn has_child_kind(node: Node<'_>, kind: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).any(|child| child.kind() == kind);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn module_flags_capture_directives_await_and_side_effects() {
+        let cases = [
+            (
+                "client.tsx",
+                "\"use client\";\nimport { useState } from \"react\";\nexport function Button() {\n  return null;\n}\n",
+                vec!["use client"],
+                false,
+                false,
+            ),
+            (
+                "setup.ts",
+                "import \"./polyfill\";\nconst config = await loadConfig();\nexport async function run() {\n  await config.ready;\n}\n",
+                vec![],
+                true,
+                true,
+            ),
+            (
+                "pure.ts",
+                "export const LIMIT = 10;\nexport async function fetchAll() {\n  await Promise.all([]);\n  register();\n}\n",
+                vec![],
+                false,
+                false,
+            ),
+            (
+                "server.ts",
+                "'use server';\nregisterAction(save);\nexport async function save() {}\n",
+                vec!["use server"],
+                false,
+                true,
+            ),
+        ];
+
+        for (name, source, directives, top_level_await, side_effects) in cases {
+            let parsed = parse_source(
+                name.rsplit('.').next().unwrap(),
+                &format!("src/{name}"),
+                source,
+            );
+
+            let flags = parsed
+                .flags
+                .unwrap_or_else(|| panic!("missing flags for {name}"));
+            assert_eq!(flags.directives, directives, "{name}");
+            assert_eq!(flags.has_top_level_await, top_level_await, "{name}");
+            assert_eq!(flags.has_top_level_side_effects, side_effects, "{name}");
+        }
+    }
+}
@@ -1,6 +1,13 @@
 use tree_sitter::Node;
 
-use crate::parse::content_hash::hash_content;
+use crate::parse::content_hash::{hash_content, to_nfc};
+
+/// Version of the fingerprint algorithms (`generate_ast_fingerprint`,
+/// `generate_body_fingerprint`). Bump it whenever either produces
+/// different output for the same source: the AST fingerprint feeds the
+/// symbol ID, so stored symbols fingerprinted under another version must
+/// be re-fingerprinted rather than matched.
+pub const FINGERPRINT_VERSION: u32 = 1;
 
 /// Generate a stable AST fingerprint for a symbol node.
 ///
@@ -139,6 +146,94 @@ fn collect_normalized_parts(root: Node<'_>, parts: &mut Vec<String>) {
     }
 }
 
+/// Generate a fingerprint of a symbol's body that survives renames and
+/// moves.
+///
+/// `generate_ast_fingerprint` includes the declared name, so a renamed
+/// symbol gets a new fingerprint (and symbol ID). This one hashes only the
+/// body: the node types of its subtree, skipping comments and literals as
+/// the subtree hash does, plus the text of identifiers so bodies of the
+/// same shape that use different names stay apart. The symbol's own name
+/// (a recursive call) is replaced by `$self`. A symbol without a body
+/// field (a variable, a type alias) is hashed whole.
+pub fn generate_body_fingerprint(node: Node<'_>, source: &[u8], name: &str) -> String {
+    let body = body_node(node).unwrap_or(node);
+
+    let mut parts: Vec<String> = Vec::new();
+    let mut stack = vec![body];
+    while let Some(current) = stack.pop() {
+        let kind = current.kind();
+        if is_literal_kind(kind) {
+            continue;
+        }
+        if current.child_count() == 0 && kind.ends_with("identifier") {
+            let text = current.utf8_text(source).unwrap_or("");
+            parts.push(if text == name {
+                "$self".to_string()
+            } else {
+                format!("{kind}:{text}")
+            });
+            continue;
+        }
+        parts.push(kind.to_string());
+
+        let child_count = current.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = current.child(i) {
+                if child.kind() != "comment" {
+                    stack.push(child);
+                }
+            }
+        }
+    }
+    hash_content(&parts.join(","))
+}
+
+/// Hash of a symbol's declaration up to its body: name, parameters, return
+/// type, modifiers. The whole symbol when it has no body (a variable, a
+/// type alias), since all of it is interface. Whitespace is collapsed so
+/// reformatting does not count as a change, and the text is normalized to
+/// NFC.
+///
+/// Paired with [`generate_body_hash`]: a changed signature hash means
+/// callers may need re-resolving, a changed body hash alone only that the
+/// implementation changed.
+pub fn generate_signature_hash(node: Node<'_>, source: &[u8]) -> String {
+    let end = body_node(node).map_or(node.end_byte(), |body| body.start_byte());
+    let signature = String::from_utf8_lossy(source.get(node.start_byte()..end).unwrap_or_default());
+    hash_content(&collapse_whitespace(&to_nfc(&signature)))
+}
+
+/// Hash of a symbol's body text with whitespace collapsed and normalized
+/// to NFC, or `None` when the symbol has no body.
+pub fn generate_body_hash(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let body = body_node(node)?;
+    let text = body.utf8_text(source).unwrap_or("");
+    Some(hash_content(&collapse_whitespace(&to_nfc(text))))
+}
+
+/// The `body` of a declaration, or of the function assigned by a
+/// declarator (`const f = () => { ... }`).
+fn body_node(node: Node<'_>) -> Option<Node<'_>> {
+    node.child_by_field_name("body").or_else(|| {
+        node.child_by_field_name("value")
+            .and_then(|value| value.child_by_field_name("body"))
+    })
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn is_literal_kind(kind: &str) -> bool {
+    kind.contains("string")
+        || kind.contains("number")
+        || kind == "true"
+        || kind == "false"
+        || kind == "null"
+        || kind == "undefined"
+}
+
 /// Backwards-compatible wrapper for callers that pass source explicitly.
 pub fn generate_ast_fingerprint_with_source(node: Node<'_>, source: &[u8]) -> String {
     generate_ast_fingerprint(node, source)
@@ -146,6 +241,9 @@ pub fn generate_ast_fingerprint_with_source(node: Node<'_>, source: &[u8]) -> St
 
 #[cfg(test)]
 mod tests {
+    use crate::parse::test_support::parse_source;
+    
+
     #[test]
     fn test_is_literal_detection() {
         // Verify the literal detection logic matches TypeScript
@@ -179,4 +277,134 @@ mod tests {
             assert!(!is_lit, "{nl} should not be detected as literal");
         }
     }
+
+    #[test]
+    fn body_fingerprint_survives_rename_and_move() {
+        let cases = [
+            (
+                "src/cart.ts",
+                r#"export function total(items: Item[]) {
+  let sum = 0;
+  for (const item of items) {
+    sum += item.price;
+  }
+  return sum;
+}
+
+export function fact(n: number): number {
+  return n <= 1 ? 1 : n * fact(n - 1);
+}
+"#,
+            ),
+            (
+                "lib/pricing.ts",
+                r#"// Moved from src/cart.ts.
+export function sumPrices(items: Item[]) {
+  let sum = 0;
+  for (const item of items) {
+    // Running total.
+    sum += item.price;
+  }
+  return sum;
+}
+
+export function factorial(n: number): number {
+  return n <= 1 ? 1 : n * factorial(n - 1);
+}
+
+export function count(items: Item[]) {
+  return items.length;
+}
+"#,
+            ),
+        ];
+
+        let mut parsed_files = Vec::new();
+        for (rel_path, source) in cases {
+            parsed_files.push(parse_source("ts", rel_path, source));
+        }
+        let symbol = |file: usize, name: &str| {
+            parsed_files[file]
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .expect("symbol")
+                .clone()
+        };
+
+        let (total, sum_prices) = (symbol(0, "total"), symbol(1, "sumPrices"));
+        assert!(total.body_fingerprint.is_some());
+        assert_eq!(total.body_fingerprint, sum_prices.body_fingerprint);
+        assert_ne!(total.ast_fingerprint, sum_prices.ast_fingerprint);
+
+        // A recursive call names the symbol itself; it is not a body change.
+        assert_eq!(
+            symbol(0, "fact").body_fingerprint,
+            symbol(1, "factorial").body_fingerprint
+        );
+        assert_ne!(total.body_fingerprint, symbol(1, "count").body_fingerprint);
+        assert!(parsed_files
+            .iter()
+            .flat_map(|file| &file.symbols)
+            .all(|symbol| symbol.fingerprint_version == crate::get_fingerprint_version()));
+    }
+
+    #[test]
+    fn signature_and_body_hashes_change_independently() {
+        let versions = [
+            "export function price(item: Item): number {\n  return item.cost * 2;\n}\n",
+            // Reformatted only.
+            "export function price(item: Item): number {\n    return item.cost  *  2;\n}\n",
+            // Body changed.
+            "export function price(item: Item): number {\n  return item.cost * 3;\n}\n",
+            // Signature changed.
+            "export function price(item: Item, rate: number): number {\n  return item.cost * 3;\n}\n",
+        ];
+
+        let mut hashes = Vec::new();
+        for source in versions.iter() {
+            let parsed = parse_source("ts", "price.ts", source);
+            let symbol = &parsed.symbols[0];
+            assert!(symbol.signature_hash.is_some() && symbol.body_hash.is_some());
+            hashes.push((symbol.signature_hash.clone(), symbol.body_hash.clone()));
+        }
+
+        assert_eq!(hashes[0], hashes[1]);
+        assert_eq!(hashes[1].0, hashes[2].0);
+        assert_ne!(hashes[1].1, hashes[2].1);
+        assert_ne!(hashes[2].0, hashes[3].0);
+        assert_eq!(hashes[2].1, hashes[3].1);
+    }
+
+    #[test]
+    fn every_symbol_is_stamped_with_the_fingerprint_version() {
+        assert_eq!(
+            crate::get_fingerprint_version(),
+            crate::extract::fingerprint::FINGERPRINT_VERSION
+        );
+        assert!(crate::get_fingerprint_version() >= 1);
+
+        let cases = [
+            (
+                "ts",
+                "service.ts",
+                "export class Service {\n  run() { return 1; }\n}\nexport const LIMIT = 3;\n",
+            ),
+            ("markdown", "README.md", "# Service\n\n## Usage\n"),
+        ];
+
+        for (language, rel_path, source) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            assert!(!parsed.symbols.is_empty(), "{language}");
+            assert!(
+                parsed
+                    .symbols
+                    .iter()
+                    .all(|symbol| symbol.fingerprint_version
+                        == crate::extract::fingerprint::FINGERPRINT_VERSION),
+                "{language}"
+            );
+        }
+    }
 }
@@ -0,0 +1,109 @@
+pub(crate) mod python;
+pub(crate) mod typescript;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn graphql_resolvers_link_schema_fields_to_handlers() {
+        let cases = [
+            (
+                "ts",
+                "resolvers.ts",
+                r#"function getUser(_: unknown, args: { id: string }) {
+  return args;
+}
+
+export const resolvers = {
+  Query: {
+    user: getUser,
+    users: () => [],
+  },
+  User: {
+    posts(parent) {
+      return [];
+    },
+  },
+};
+
+@Resolver(() => Recipe)
+export class RecipeResolver {
+  @Query(() => [Recipe], { name: "recipes" })
+  async all() {
+    return [];
+  }
+
+  @Mutation(() => Recipe)
+  addRecipe() {}
+
+  @FieldResolver()
+  ratings() {}
+
+  helper() {}
+}
+"#,
+                vec![
+                    ("Query", "user", Some("getUser"), true),
+                    ("Query", "users", None, false),
+                    ("User", "posts", None, false),
+                    ("Query", "recipes", Some("all"), true),
+                    ("Mutation", "addRecipe", Some("addRecipe"), true),
+                    ("Recipe", "ratings", Some("ratings"), true),
+                ],
+            ),
+            (
+                "py",
+                "schema.py",
+                r#"query = QueryType()
+user = ObjectType("User")
+
+@query.field("hello")
+def resolve_hello(_, info):
+    return "hi"
+
+def resolve_email(obj, info):
+    return obj.email
+
+user.set_field("email", resolve_email)
+
+@strawberry.type
+class Book:
+    @strawberry.field(name="pageCount")
+    def pages(self) -> int:
+        return 1
+
+class Query(graphene.ObjectType):
+    name = graphene.String()
+
+    def resolve_name(root, info):
+        return "x"
+"#,
+                vec![
+                    ("Query", "hello", Some("resolve_hello"), true),
+                    ("User", "email", Some("resolve_email"), true),
+                    ("Book", "pageCount", Some("pages"), true),
+                    ("Query", "name", Some("resolve_name"), true),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let resolvers: Vec<(&str, &str, Option<&str>, bool)> = parsed
+                .graphql_resolvers
+                .iter()
+                .map(|resolver| {
+                    (
+                        resolver.parent_type.as_str(),
+                        resolver.field.as_str(),
+                        resolver.handler.as_deref(),
+                        resolver.handler_node_id.is_some(),
+                    )
+                })
+                .collect();
+            assert_eq!(resolvers, expected, "{language}");
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! Ariadne, Strawberry and Graphene resolvers.
+//!
+//! - Ariadne binds resolvers to `QueryType()` / `MutationType()` /
+//!   `ObjectType("User")` instances: `@query.field("user")` on a function,
+//!   or `query.set_field("user", resolve_user)`.
+//! - Strawberry: methods decorated `@strawberry.field` / `.mutation` /
+//!   `.subscription` in a `@strawberry.type` class resolve the field of
+//!   that type (the class name, or the decorator's `name=`).
+//! - Graphene: `resolve_<field>` methods of an `ObjectType` subclass.
+
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::extract::routes::common::{callable_node_id, declared_node_id, string_value};
+use crate::types::{NativeGraphqlResolver, NativeParsedSymbol};
+
+/// Ariadne bindable constructors and the type each binds; `None` takes the
+/// type name from the first argument.
+const BINDABLES: &[(&str, Option<&str>)] = &[
+    ("QueryType", Some("Query")),
+    ("MutationType", Some("Mutation")),
+    ("SubscriptionType", Some("Subscription")),
+    ("ObjectType", None),
+    ("InterfaceType", None),
+];
+
+/// Strawberry decorators that make a class a schema type.
+const STRAWBERRY_TYPES: &[&str] = &["strawberry.type", "strawberry.interface"];
+
+/// Strawberry decorators that make a method a resolver.
+const STRAWBERRY_FIELDS: &[&str] = &[
+    "strawberry.field",
+    "strawberry.mutation",
+    "strawberry.subscription",
+];
+
+pub fn extract_graphql_resolvers_python(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeGraphqlResolver> {
+    let bindables = ariadne_bindables(root, source);
+    let mut resolvers = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "decorated_definition" => {
+                process_decorated(node, &bindables, source, symbols, &mut resolvers);
+            }
+            "class_definition" if is_graphene_type(node, source) => {
+                process_graphene_type(node, source, symbols, &mut resolvers);
+            }
+            "call" => process_set_field(node, &bindables, source, symbols, &mut resolvers),
+            _ => {}
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    resolvers
+}
+
+/// `query = QueryType()` / `user = ObjectType("User")` -> variable -> type.
+fn ariadne_bindables(root: Node<'_>, source: &[u8]) -> HashMap<String, String> {
+    let mut bindables = HashMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "assignment" {
+            if let Some((variable, parent_type)) = bindable(node, source) {
+                bindables.insert(variable, parent_type);
+            }
+        }
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+    bindables
+}
+
+fn bindable(assignment: Node<'_>, source: &[u8]) -> Option<(String, String)> {
+    let left = assignment
+        .child_by_field_name("left")
+        .filter(|left| left.kind() == "identifier")?;
+    let right = assignment
+        .child_by_field_name("right")
+        .filter(|right| right.kind() == "call")?;
+    let constructor = node_text(right.child_by_field_name("function")?, source);
+    let constructor = constructor.rsplit('.').next().unwrap_or(constructor);
+    let &(_, parent_type) = BINDABLES.iter().find(|(name, _)| *name == constructor)?;
+    let parent_type = match parent_type {
+        Some(parent_type) => parent_type.to_string(),
+        None => string_value(first_argument(right)?, source)?,
+    };
+    Some((node_text(left, source).to_string(), parent_type))
+}
+
+fn process_decorated(
+    node: Node<'_>,
+    bindables: &HashMap<String, String>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    resolvers: &mut Vec<NativeGraphqlResolver>,
+) {
+    let Some(definition) = node.child_by_field_name("definition") else {
+        return;
+    };
+    match definition.kind() {
+        "function_definition" => {
+            // `@query.field("user")` on a module-level function.
+            let Some(name_node) = definition.child_by_field_name("name") else {
+                return;
+            };
+            for decorator in decorators(node) {
+                let Some((callee, Some(call))) = decorator_call(decorator, source) else {
+                    continue;
+                };
+                let Some((variable, "field")) = callee.rsplit_once('.') else {
+                    continue;
+                };
+                let (Some(parent_type), Some(field)) = (
+                    bindables.get(variable),
+                    first_argument(call).and_then(|first| string_value(first, source)),
+                ) else {
+                    continue;
+                };
+                resolvers.push(NativeGraphqlResolver {
+                    parent_type: parent_type.clone(),
+                    field,
+                    handler: Some(node_text(name_node, source).to_string()),
+                    handler_node_id: declared_node_id(name_node, symbols),
+                    caller_node_id: find_enclosing_symbol(node, symbols),
+                    range: extract_range(node),
+                });
+            }
+        }
+        "class_definition" => {
+            process_strawberry_type(node, definition, source, symbols, resolvers);
+        }
+        _ => {}
+    }
+}
+
+/// `query.set_field("user", resolve_user)`.
+fn process_set_field(
+    node: Node<'_>,
+    bindables: &HashMap<String, String>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    resolvers: &mut Vec<NativeGraphqlResolver>,
+) {
+    let Some(function) = node
+        .child_by_field_name("function")
+        .filter(|function| function.kind() == "attribute")
+    else {
+        return;
+    };
+    let is_set_field = function
+        .child_by_field_name("attribute")
+        .is_some_and(|attribute| node_text(attribute, source) == "set_field");
+    let parent_type = function
+        .child_by_field_name("object")
+        .and_then(|object| bindables.get(node_text(object, source)));
+    let (true, Some(parent_type), Some(arguments)) = (
+        is_set_field,
+        parent_type,
+        node.child_by_field_name("arguments"),
+    ) else {
+        return;
+    };
+    let (Some(field), Some(handler)) = (
+        arguments
+            .named_child(0)
+            .and_then(|first| string_value(first, source)),
+        arguments.named_child(1),
+    ) else {
+        return;
+    };
+    let handler = matches!(handler.kind(), "identifier" | "attribute")
+        .then(|| node_text(handler, source).to_string());
+    resolvers.push(NativeGraphqlResolver {
+        parent_type: parent_type.clone(),
+        field,
+        handler_node_id: handler
+            .as_deref()
+            .and_then(|handler| callable_node_id(handler, symbols)),
+        handler,
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        range: extract_range(node),
+    });
+}
+
+fn process_strawberry_type(
+    decorated: Node<'_>,
+    class: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    resolvers: &mut Vec<NativeGraphqlResolver>,
+) {
+    let Some(type_name) = decorators(decorated).into_iter().find_map(|decorator| {
+        let (callee, call) = decorator_call(decorator, source)?;
+        STRAWBERRY_TYPES
+            .contains(&callee)
+            .then(|| call.and_then(|call| keyword_argument(call, "name", source)))
+    }) else {
+        return;
+    };
+    let (Some(name_node), Some(body)) = (
+        class.child_by_field_name("name"),
+        class.child_by_field_name("body"),
+    ) else {
+        return;
+    };
+    let parent_type = type_name.unwrap_or_else(|| node_text(name_node, source).to_string());
+
+    let mut cursor = body.walk();
+    for member in body
+        .named_children(&mut cursor)
+        .filter(|member| member.kind() == "decorated_definition")
+    {
+        let Some(method_name) = member
+            .child_by_field_name("definition")
+            .filter(|definition| definition.kind() == "function_definition")
+            .and_then(|definition| definition.child_by_field_name("name"))
+        else {
+            continue;
+        };
+        let Some(field) = decorators(member).into_iter().find_map(|decorator| {
+            let (callee, call) = decorator_call(decorator, source)?;
+            STRAWBERRY_FIELDS
+                .contains(&callee)
+                .then(|| call.and_then(|call| keyword_argument(call, "name", source)))
+        }) else {
+            continue;
+        };
+        let method = node_text(method_name, source);
+        resolvers.push(NativeGraphqlResolver {
+            parent_type: parent_type.clone(),
+            field: field.unwrap_or_else(|| method.to_string()),
+            handler: Some(method.to_string()),
+            handler_node_id: declared_node_id(method_name, symbols),
+            caller_node_id: find_enclosing_symbol(class, symbols),
+            range: extract_range(member),
+        });
+    }
+}
+
+/// `class Query(graphene.ObjectType)`.
+fn is_graphene_type(node: Node<'_>, source: &[u8]) -> bool {
+    let Some(bases) = node.child_by_field_name("superclasses") else {
+        return false;
+    };
+    let mut cursor = bases.walk();
+    let found = bases
+        .named_children(&mut cursor)
+        .any(|base| node_text(base, source).rsplit('.').next() == Some("ObjectType"));
+    found
+}
+
+fn process_graphene_type(
+    class: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    resolvers: &mut Vec<NativeGraphqlResolver>,
+) {
+    let (Some(name_node), Some(body)) = (
+        class.child_by_field_name("name"),
+        class.child_by_field_name("body"),
+    ) else {
+        return;
+    };
+    let parent_type = node_text(name_node, source);
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        // `@staticmethod` resolvers are wrapped in a decorated_definition.
+        let definition = if member.kind() == "decorated_definition" {
+            member.child_by_field_name("definition")
+        } else {
+            Some(member)
+        };
+        let Some(method_name) = definition
+            .filter(|definition| definition.kind() == "function_definition")
+            .and_then(|definition| definition.child_by_field_name("name"))
+        else {
+            continue;
+        };
+        let method = node_text(method_name, source);
+        let Some(field) = method.strip_prefix("resolve_").filter(|f| !f.is_empty()) else {
+            continue;
+        };
+        resolvers.push(NativeGraphqlResolver {
+            parent_type: parent_type.to_string(),
+            field: field.to_string(),
+            handler: Some(method.to_string()),
+            handler_node_id: declared_node_id(method_name, symbols),
+            caller_node_id: find_enclosing_symbol(class, symbols),
+            range: extract_range(member),
+        });
+    }
+}
+
+/// The `decorator` children of a decorated definition.
+fn decorators(node: Node<'_>) -> Vec<Node<'_>> {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "decorator")
+        .collect();
+    found
+}
+
+/// `@a.b(args)` -> `("a.b", Some(call))`; `@a.b` -> `("a.b", None)`.
+fn decorator_call<'a>(
+    decorator: Node<'a>,
+    source: &'a [u8],
+) -> Option<(&'a str, Option<Node<'a>>)> {
+    let expression = decorator.named_child(0)?;
+    match expression.kind() {
+        "identifier" | "attribute" => Some((node_text(expression, source), None)),
+        "call" => Some((
+            node_text(expression.child_by_field_name("function")?, source),
+            Some(expression),
+        )),
+        _ => None,
+    }
+}
+
+fn keyword_argument(call: Node<'_>, keyword: &str, source: &[u8]) -> Option<String> {
+    let arguments = call.child_by_field_name("arguments")?;
+    let mut cursor = arguments.walk();
+    let found = arguments.named_children(&mut cursor).find_map(|argument| {
+        if argument.kind() != "keyword_argument"
+            || node_text(argument.child_by_field_name("name")?, source) != keyword
+        {
+            return None;
+        }
+        string_value(argument.child_by_field_name("value")?, source)
+    });
+    found
+}
+
+fn first_argument(call: Node<'_>) -> Option<Node<'_>> {
+    call.child_by_field_name("arguments")?.named_child(0)
+}
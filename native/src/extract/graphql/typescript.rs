@@ -0,0 +1,282 @@
+//! Apollo-style resolver maps and NestJS / TypeGraphQL resolver classes.
+//!
+//! A resolver map is an object literal keyed by type name whose values map
+//! field names to functions: `const resolvers = { Query: { user: getUser },
+//! User: { posts(parent) { ... } } }`. An object counts as a map when it is
+//! bound to a `*resolvers` variable or property, or has a `Query` /
+//! `Mutation` / `Subscription` key holding an object.
+//!
+//! A resolver class carries `@Resolver(() => User)`; its `@Query()`,
+//! `@Mutation()` and `@Subscription()` methods are root fields and its
+//! `@ResolveField()` / `@FieldResolver()` methods are fields of the
+//! resolved type. `{ name: "..." }` in the decorator overrides the method
+//! name.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::extract::routes::common::{callable_node_id, declared_node_id, string_value};
+use crate::types::{NativeGraphqlResolver, NativeParsedSymbol};
+
+/// Schema root operation types.
+const ROOT_TYPES: &[&str] = &["Query", "Mutation", "Subscription"];
+
+const CLASS_KINDS: &[&str] = &["class_declaration", "abstract_class_declaration"];
+
+/// Decorators on a method that resolve a field of the class's type.
+const FIELD_DECORATORS: &[&str] = &["ResolveField", "FieldResolver", "ResolveProperty"];
+
+pub fn extract_graphql_resolvers_ts(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeGraphqlResolver> {
+    let mut resolvers = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "object" && is_resolver_map(node, source) {
+            process_resolver_map(node, source, symbols, &mut resolvers);
+        } else if CLASS_KINDS.contains(&node.kind()) {
+            process_resolver_class(node, source, symbols, &mut resolvers);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    resolvers
+}
+
+fn is_resolver_map(node: Node<'_>, source: &[u8]) -> bool {
+    let binding = node.parent().and_then(|parent| match parent.kind() {
+        "variable_declarator" => parent.child_by_field_name("name"),
+        "pair" => parent.child_by_field_name("key"),
+        _ => None,
+    });
+    if binding.is_some_and(|binding| {
+        node_text(binding, source)
+            .to_ascii_lowercase()
+            .ends_with("resolvers")
+    }) {
+        return true;
+    }
+    type_maps(node, source)
+        .iter()
+        .any(|(type_name, _)| ROOT_TYPES.contains(&type_name.as_str()))
+}
+
+/// `Type: { ... }` entries of a resolver map.
+fn type_maps<'a>(node: Node<'a>, source: &[u8]) -> Vec<(String, Node<'a>)> {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .filter(|pair| pair.kind() == "pair")
+        .filter_map(|pair| {
+            let value = pair
+                .child_by_field_name("value")
+                .filter(|value| value.kind() == "object")?;
+            Some((key_text(pair.child_by_field_name("key")?, source), value))
+        })
+        .collect();
+    found
+}
+
+fn process_resolver_map(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    resolvers: &mut Vec<NativeGraphqlResolver>,
+) {
+    for (parent_type, fields) in type_maps(node, source) {
+        let mut cursor = fields.walk();
+        for entry in fields.named_children(&mut cursor) {
+            let (field, handler) = match entry.kind() {
+                "pair" => {
+                    let (Some(key), Some(value)) = (
+                        entry.child_by_field_name("key"),
+                        entry.child_by_field_name("value"),
+                    ) else {
+                        continue;
+                    };
+                    let handler = matches!(value.kind(), "identifier" | "member_expression")
+                        .then(|| node_text(value, source).to_string());
+                    (key_text(key, source), handler)
+                }
+                "method_definition" => {
+                    let Some(name) = entry.child_by_field_name("name") else {
+                        continue;
+                    };
+                    (key_text(name, source), None)
+                }
+                "shorthand_property_identifier" => {
+                    let name = node_text(entry, source).to_string();
+                    (name.clone(), Some(name))
+                }
+                _ => continue,
+            };
+            let handler_node_id = handler
+                .as_deref()
+                .and_then(|handler| callable_node_id(handler, symbols));
+            resolvers.push(NativeGraphqlResolver {
+                parent_type: parent_type.clone(),
+                field,
+                handler,
+                handler_node_id,
+                caller_node_id: find_enclosing_symbol(entry, symbols),
+                range: extract_range(entry),
+            });
+        }
+    }
+}
+
+fn process_resolver_class(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    resolvers: &mut Vec<NativeGraphqlResolver>,
+) {
+    let export = node
+        .parent()
+        .filter(|parent| parent.kind() == "export_statement");
+    let Some(target) = export
+        .into_iter()
+        .chain(std::iter::once(node))
+        .flat_map(decorators)
+        .find_map(|decorator| {
+            let (name, arguments) = decorator_call(decorator, source)?;
+            (name == "Resolver").then(|| {
+                arguments
+                    .and_then(|arguments| arguments.named_child(0))
+                    .and_then(|first| resolved_type(first, source))
+            })
+        })
+    else {
+        return;
+    };
+    // `@Resolver()` without a type: `UserResolver` resolves `User`.
+    let target = target.or_else(|| {
+        let name = node_text(node.child_by_field_name("name")?, source);
+        Some(name.strip_suffix("Resolver").unwrap_or(name).to_string())
+    });
+    let Some(body) = node.child_by_field_name("body") else {
+        return;
+    };
+
+    // Method decorators are siblings of the method in the class body.
+    let mut pending: Vec<Node<'_>> = Vec::new();
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        match member.kind() {
+            "decorator" => pending.push(member),
+            "method_definition" => {
+                let decorators = std::mem::take(&mut pending);
+                let Some(name_node) = member.child_by_field_name("name") else {
+                    continue;
+                };
+                let method = node_text(name_node, source);
+                for decorator in decorators {
+                    let Some((name, arguments)) = decorator_call(decorator, source) else {
+                        continue;
+                    };
+                    let parent_type = if ROOT_TYPES.contains(&name) {
+                        Some(name.to_string())
+                    } else if FIELD_DECORATORS.contains(&name) {
+                        target.clone()
+                    } else {
+                        continue;
+                    };
+                    let Some(parent_type) = parent_type else {
+                        continue;
+                    };
+                    let field = arguments
+                        .and_then(|arguments| field_name(arguments, source))
+                        .unwrap_or_else(|| method.to_string());
+                    resolvers.push(NativeGraphqlResolver {
+                        parent_type,
+                        field,
+                        handler: Some(method.to_string()),
+                        handler_node_id: declared_node_id(name_node, symbols),
+                        caller_node_id: find_enclosing_symbol(node, symbols),
+                        range: extract_range(member),
+                    });
+                }
+            }
+            _ => pending.clear(),
+        }
+    }
+}
+
+/// `() => User` / `(of) => User` / `"User"` -> `User`.
+fn resolved_type(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let target = if node.kind() == "arrow_function" {
+        node.child_by_field_name("body")?
+    } else {
+        node
+    };
+    match target.kind() {
+        "identifier" => Some(node_text(target, source).to_string()),
+        _ => string_value(target, source),
+    }
+}
+
+/// `@ResolveField("posts")` or `@Query(() => User, { name: "user" })`.
+fn field_name(arguments: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut cursor = arguments.walk();
+    let found = arguments
+        .named_children(&mut cursor)
+        .find_map(|argument| match argument.kind() {
+            "object" => {
+                let mut inner = argument.walk();
+                let name = argument.named_children(&mut inner).find_map(|pair| {
+                    if pair.kind() != "pair"
+                        || key_text(pair.child_by_field_name("key")?, source) != "name"
+                    {
+                        return None;
+                    }
+                    string_value(pair.child_by_field_name("value")?, source)
+                });
+                name
+            }
+            _ => string_value(argument, source),
+        });
+    found
+}
+
+/// Object key without quotes.
+fn key_text(key: Node<'_>, source: &[u8]) -> String {
+    node_text(key, source).trim_matches(['"', '\'']).to_string()
+}
+
+/// The `decorator` children of a class or export statement.
+fn decorators(node: Node<'_>) -> Vec<Node<'_>> {
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "decorator")
+        .collect();
+    found
+}
+
+/// `@Name(args)` -> `("Name", Some(args))`; `@Name` -> `("Name", None)`.
+fn decorator_call<'a>(
+    decorator: Node<'a>,
+    source: &'a [u8],
+) -> Option<(&'a str, Option<Node<'a>>)> {
+    let expression = decorator.named_child(0)?;
+    match expression.kind() {
+        "identifier" => Some((node_text(expression, source), None)),
+        "call_expression" => {
+            let function = expression.child_by_field_name("function")?;
+            Some((
+                node_text(function, source),
+                expression.child_by_field_name("arguments"),
+            ))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,95 @@
+//! Grpc.Core / grpc-dotnet service implementations.
+//!
+//! The generated base class is nested in the service's static class:
+//! `class GreeterService : Greeter.GreeterBase`. Its `override` methods
+//! implement the RPCs.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::extract::routes::common::declared_node_id;
+use crate::types::{NativeGrpcBinding, NativeParsedSymbol};
+
+pub fn extract_grpc_bindings_csharp(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeGrpcBinding> {
+    let mut bindings = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "class_declaration" {
+            process_service_class(node, source, symbols, &mut bindings);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    bindings
+}
+
+fn process_service_class(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    bindings: &mut Vec<NativeGrpcBinding>,
+) {
+    let (Some(name_node), Some(body)) = (
+        node.child_by_field_name("name"),
+        node.child_by_field_name("body"),
+    ) else {
+        return;
+    };
+    let mut cursor = node.walk();
+    let Some(service) = node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "base_list")
+        .find_map(|bases| {
+            let mut inner = bases.walk();
+            let found = bases
+                .named_children(&mut inner)
+                .find_map(|base| service_name(node_text(base, source)));
+            found
+        })
+    else {
+        return;
+    };
+    let class_name = node_text(name_node, source);
+
+    let mut cursor = body.walk();
+    for method in body
+        .named_children(&mut cursor)
+        .filter(|member| member.kind() == "method_declaration")
+    {
+        let mut modifiers = method.walk();
+        let is_override = method
+            .children(&mut modifiers)
+            .any(|child| child.kind() == "modifier" && node_text(child, source) == "override");
+        let Some(method_name) = method.child_by_field_name("name").filter(|_| is_override) else {
+            continue;
+        };
+        let rpc = node_text(method_name, source);
+        bindings.push(NativeGrpcBinding {
+            service: service.to_string(),
+            rpc: Some(rpc.to_string()),
+            implementation: Some(class_name.to_string()),
+            handler: Some(rpc.to_string()),
+            handler_node_id: declared_node_id(method_name, symbols),
+            caller_node_id: find_enclosing_symbol(node, symbols),
+            range: extract_range(method),
+        });
+    }
+}
+
+/// `Greeter.GreeterBase` -> `Greeter`.
+fn service_name(base: &str) -> Option<&str> {
+    let (qualifier, last) = base.rsplit_once('.')?;
+    let outer = qualifier.rsplit('.').next().unwrap_or(qualifier);
+    (last.strip_suffix("Base") == Some(outer) && !outer.is_empty()).then_some(outer)
+}
@@ -0,0 +1,150 @@
+//! grpc-go service registrations and implementations.
+//!
+//! `pb.RegisterGreeterServer(s, &server{})` registers `server` as the
+//! `Greeter` implementation; a struct embedding
+//! `pb.UnimplementedGreeterServer` declares the same. Exported methods of
+//! either type are bound to the service's RPCs.
+
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::extract::routes::common::declared_node_id;
+use crate::types::{NativeGrpcBinding, NativeParsedSymbol};
+
+pub fn extract_grpc_bindings_go(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeGrpcBinding> {
+    let mut bindings = Vec::new();
+    // Implementation type -> service.
+    let mut services: HashMap<String, String> = HashMap::new();
+    let mut methods = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "call_expression" => {
+                if let Some(binding) = registration(node, source, symbols) {
+                    if let Some(implementation) = &binding.implementation {
+                        services
+                            .entry(implementation.clone())
+                            .or_insert_with(|| binding.service.clone());
+                    }
+                    bindings.push(binding);
+                }
+            }
+            "type_spec" => {
+                if let Some((implementation, service)) = embedded_service(node, source) {
+                    services.entry(implementation).or_insert(service);
+                }
+            }
+            "method_declaration" => methods.push(node),
+            _ => {}
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    for method in methods {
+        let (Some(receiver), Some(name_node)) = (
+            receiver_type(method, source),
+            method.child_by_field_name("name"),
+        ) else {
+            continue;
+        };
+        let Some(service) = services.get(receiver) else {
+            continue;
+        };
+        let name = node_text(name_node, source);
+        if !name.starts_with(char::is_uppercase) || name.starts_with("mustEmbed") {
+            continue;
+        }
+        bindings.push(NativeGrpcBinding {
+            service: service.clone(),
+            rpc: Some(name.to_string()),
+            implementation: Some(receiver.to_string()),
+            handler: Some(name.to_string()),
+            handler_node_id: declared_node_id(name_node, symbols),
+            caller_node_id: find_enclosing_symbol(method, symbols),
+            range: extract_range(method),
+        });
+    }
+
+    bindings
+}
+
+/// `pb.RegisterGreeterServer(s, &server{})`.
+fn registration(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeGrpcBinding> {
+    let function = node_text(node.child_by_field_name("function")?, source);
+    let name = function.rsplit('.').next().unwrap_or(function);
+    let service = name.strip_prefix("Register")?.strip_suffix("Server")?;
+    if service.is_empty() {
+        return None;
+    }
+    let implementation = node
+        .child_by_field_name("arguments")
+        .and_then(|arguments| arguments.named_child(1))
+        .and_then(|argument| {
+            let literal = if argument.kind() == "unary_expression" {
+                argument.child_by_field_name("operand")?
+            } else {
+                argument
+            };
+            Some(literal)
+                .filter(|literal| literal.kind() == "composite_literal")
+                .and_then(|literal| literal.child_by_field_name("type"))
+                .map(|type_node| node_text(type_node, source).to_string())
+        });
+    Some(NativeGrpcBinding {
+        service: service.to_string(),
+        rpc: None,
+        implementation,
+        handler: None,
+        handler_node_id: None,
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        range: extract_range(node),
+    })
+}
+
+/// `type server struct { pb.UnimplementedGreeterServer }` ->
+/// `("server", "Greeter")`.
+fn embedded_service(node: Node<'_>, source: &[u8]) -> Option<(String, String)> {
+    let name = node.child_by_field_name("name")?;
+    let fields = node
+        .child_by_field_name("type")
+        .filter(|struct_type| struct_type.kind() == "struct_type")?
+        .named_child(0)?;
+    let mut cursor = fields.walk();
+    let service = fields.named_children(&mut cursor).find_map(|field| {
+        if field.kind() != "field_declaration" || field.child_by_field_name("name").is_some() {
+            return None;
+        }
+        let embedded = node_text(field.child_by_field_name("type")?, source);
+        let embedded = embedded.rsplit('.').next().unwrap_or(embedded);
+        let service = embedded
+            .strip_prefix("Unimplemented")
+            .or_else(|| embedded.strip_prefix("Unsafe"))?
+            .strip_suffix("Server")?;
+        (!service.is_empty()).then(|| service.to_string())
+    });
+    Some((node_text(name, source).to_string(), service?))
+}
+
+/// `func (s *server) SayHello(...)` -> `server`.
+fn receiver_type<'a>(method: Node<'a>, source: &'a [u8]) -> Option<&'a str> {
+    let receiver = method.child_by_field_name("receiver")?.named_child(0)?;
+    let type_text = node_text(receiver.child_by_field_name("type")?, source);
+    Some(type_text.trim_start_matches('*'))
+}
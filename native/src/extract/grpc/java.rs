@@ -0,0 +1,86 @@
+//! grpc-java service implementations.
+//!
+//! `class GreeterImpl extends GreeterGrpc.GreeterImplBase` implements the
+//! `Greeter` service; its public instance methods are the RPCs.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::extract::routes::common::declared_node_id;
+use crate::types::{NativeGrpcBinding, NativeParsedSymbol};
+
+pub fn extract_grpc_bindings_java(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeGrpcBinding> {
+    let mut bindings = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "class_declaration" {
+            process_service_class(node, source, symbols, &mut bindings);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    bindings
+}
+
+fn process_service_class(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    bindings: &mut Vec<NativeGrpcBinding>,
+) {
+    let (Some(name_node), Some(superclass), Some(body)) = (
+        node.child_by_field_name("name"),
+        node.child_by_field_name("superclass")
+            .and_then(|superclass| superclass.named_child(0)),
+        node.child_by_field_name("body"),
+    ) else {
+        return;
+    };
+    // `GreeterGrpc.GreeterImplBase` or a statically imported
+    // `GreeterImplBase`.
+    let base = node_text(superclass, source);
+    let base = base.rsplit('.').next().unwrap_or(base);
+    let Some(service) = base.strip_suffix("ImplBase").filter(|s| !s.is_empty()) else {
+        return;
+    };
+    let class_name = node_text(name_node, source);
+
+    let mut cursor = body.walk();
+    for method in body
+        .named_children(&mut cursor)
+        .filter(|member| member.kind() == "method_declaration")
+    {
+        let mut modifiers = method.walk();
+        let modifiers = method
+            .children(&mut modifiers)
+            .find(|child| child.kind() == "modifiers")
+            .map(|modifiers| node_text(modifiers, source))
+            .unwrap_or("");
+        let is_rpc = modifiers.split_whitespace().any(|word| word == "public")
+            && !modifiers.split_whitespace().any(|word| word == "static");
+        let Some(method_name) = method.child_by_field_name("name").filter(|_| is_rpc) else {
+            continue;
+        };
+        let rpc = node_text(method_name, source);
+        bindings.push(NativeGrpcBinding {
+            service: service.to_string(),
+            rpc: Some(rpc.to_string()),
+            implementation: Some(class_name.to_string()),
+            handler: Some(rpc.to_string()),
+            handler_node_id: declared_node_id(method_name, symbols),
+            caller_node_id: find_enclosing_symbol(node, symbols),
+            range: extract_range(method),
+        });
+    }
+}
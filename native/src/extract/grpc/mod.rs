@@ -0,0 +1,136 @@
+pub(crate) mod csharp;
+pub(crate) mod go;
+pub(crate) mod java;
+pub(crate) mod python;
+pub(crate) mod typescript;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn grpc_bindings_link_services_to_implementations() {
+        let cases = [
+            (
+                "go",
+                "server.go",
+                r#"package main
+
+type server struct {
+	pb.UnimplementedGreeterServer
+}
+
+func (s *server) SayHello(ctx context.Context, in *pb.HelloRequest) (*pb.HelloReply, error) {
+	return nil, nil
+}
+
+func (s *server) helper() {}
+
+func main() {
+	s := grpc.NewServer()
+	pb.RegisterGreeterServer(s, &server{})
+}
+"#,
+                vec![
+                    ("Greeter", None, Some("server"), false),
+                    ("Greeter", Some("SayHello"), Some("server"), true),
+                ],
+            ),
+            (
+                "cs",
+                "GreeterService.cs",
+                r#"public class GreeterService : Greeter.GreeterBase {
+    public override Task<HelloReply> SayHello(HelloRequest request, ServerCallContext context) {
+        return Task.FromResult(new HelloReply());
+    }
+
+    private void Helper() {}
+}
+"#,
+                vec![("Greeter", Some("SayHello"), Some("GreeterService"), true)],
+            ),
+            (
+                "java",
+                "GreeterImpl.java",
+                r#"class GreeterImpl extends GreeterGrpc.GreeterImplBase {
+    @Override
+    public void sayHello(HelloRequest req, StreamObserver<HelloReply> observer) {}
+
+    private void helper() {}
+}
+"#,
+                vec![("Greeter", Some("sayHello"), Some("GreeterImpl"), true)],
+            ),
+            (
+                "py",
+                "server.py",
+                r#"class Greeter(helloworld_pb2_grpc.GreeterServicer):
+    def SayHello(self, request, context):
+        return None
+
+    def _helper(self):
+        pass
+
+def serve(server):
+    helloworld_pb2_grpc.add_GreeterServicer_to_server(Greeter(), server)
+"#,
+                vec![
+                    ("Greeter", Some("SayHello"), Some("Greeter"), true),
+                    ("Greeter", None, Some("Greeter"), false),
+                ],
+            ),
+            (
+                "ts",
+                "server.ts",
+                r#"function sayHello(call, callback) {}
+
+server.addService(proto.Greeter.service, { sayHello, sayGoodbye: (call, cb) => cb() });
+
+@Controller()
+export class HeroesController {
+  @GrpcMethod("HeroesService", "FindOne")
+  findOne(data) {}
+
+  @GrpcMethod()
+  list() {}
+}
+"#,
+                vec![
+                    ("Greeter", None, None, false),
+                    ("Greeter", Some("sayHello"), None, true),
+                    ("Greeter", Some("sayGoodbye"), None, false),
+                    (
+                        "HeroesService",
+                        Some("FindOne"),
+                        Some("HeroesController"),
+                        true,
+                    ),
+                    (
+                        "HeroesController",
+                        Some("List"),
+                        Some("HeroesController"),
+                        true,
+                    ),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let bindings: Vec<(&str, Option<&str>, Option<&str>, bool)> = parsed
+                .grpc_bindings
+                .iter()
+                .map(|binding| {
+                    (
+                        binding.service.as_str(),
+                        binding.rpc.as_deref(),
+                        binding.implementation.as_deref(),
+                        binding.handler_node_id.is_some(),
+                    )
+                })
+                .collect();
+            assert_eq!(bindings, expected, "{language}");
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! grpcio servicer classes and registrations.
+//!
+//! `class Greeter(helloworld_pb2_grpc.GreeterServicer)` implements the
+//! `Greeter` service; its public methods are the RPCs.
+//! `add_GreeterServicer_to_server(Greeter(), server)` registers it.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::extract::routes::common::declared_node_id;
+use crate::types::{NativeGrpcBinding, NativeParsedSymbol};
+
+pub fn extract_grpc_bindings_python(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeGrpcBinding> {
+    let mut bindings = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "class_definition" => process_servicer(node, source, symbols, &mut bindings),
+            "call" => bindings.extend(registration(node, source, symbols)),
+            _ => {}
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    bindings
+}
+
+fn process_servicer(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    bindings: &mut Vec<NativeGrpcBinding>,
+) {
+    let (Some(name_node), Some(bases), Some(body)) = (
+        node.child_by_field_name("name"),
+        node.child_by_field_name("superclasses"),
+        node.child_by_field_name("body"),
+    ) else {
+        return;
+    };
+    let mut cursor = bases.walk();
+    let service = bases.named_children(&mut cursor).find_map(|base| {
+        let text = node_text(base, source);
+        let name = text.rsplit('.').next().unwrap_or(text);
+        name.strip_suffix("Servicer")
+            .filter(|service| !service.is_empty())
+    });
+    let Some(service) = service else {
+        return;
+    };
+    let class_name = node_text(name_node, source);
+
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        let definition = if member.kind() == "decorated_definition" {
+            member.child_by_field_name("definition")
+        } else {
+            Some(member)
+        };
+        let Some(method_name) = definition
+            .filter(|definition| definition.kind() == "function_definition")
+            .and_then(|definition| definition.child_by_field_name("name"))
+        else {
+            continue;
+        };
+        let rpc = node_text(method_name, source);
+        if rpc.starts_with('_') {
+            continue;
+        }
+        bindings.push(NativeGrpcBinding {
+            service: service.to_string(),
+            rpc: Some(rpc.to_string()),
+            implementation: Some(class_name.to_string()),
+            handler: Some(rpc.to_string()),
+            handler_node_id: declared_node_id(method_name, symbols),
+            caller_node_id: find_enclosing_symbol(node, symbols),
+            range: extract_range(member),
+        });
+    }
+}
+
+/// `pb2_grpc.add_GreeterServicer_to_server(Greeter(), server)`.
+fn registration(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeGrpcBinding> {
+    let function = node_text(node.child_by_field_name("function")?, source);
+    let name = function.rsplit('.').next().unwrap_or(function);
+    let service = name
+        .strip_prefix("add_")?
+        .strip_suffix("Servicer_to_server")?;
+    if service.is_empty() {
+        return None;
+    }
+    // `Greeter()` or an instance variable.
+    let implementation = node
+        .child_by_field_name("arguments")
+        .and_then(|arguments| arguments.named_child(0))
+        .map(|argument| match argument.kind() {
+            "call" => argument.child_by_field_name("function").unwrap_or(argument),
+            _ => argument,
+        })
+        .map(|implementation| node_text(implementation, source).to_string());
+    Some(NativeGrpcBinding {
+        service: service.to_string(),
+        rpc: None,
+        implementation,
+        handler: None,
+        handler_node_id: None,
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        range: extract_range(node),
+    })
+}
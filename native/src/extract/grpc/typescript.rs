@@ -0,0 +1,204 @@
+//! @grpc/grpc-js registrations and NestJS gRPC controllers.
+//!
+//! `server.addService(proto.Greeter.service, { sayHello, sayGoodbye:
+//! bye })` registers the service and binds each key of the implementation
+//! object to its handler. In NestJS, `@GrpcMethod("HeroesService",
+//! "FindOne")` binds a controller method; the service defaults to the
+//! class name and the RPC to the capitalized method name.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::extract::routes::common::{callable_node_id, declared_node_id, string_value};
+use crate::types::{NativeGrpcBinding, NativeParsedSymbol};
+
+const CLASS_KINDS: &[&str] = &["class_declaration", "abstract_class_declaration"];
+
+const REGISTRATION_METHODS: &[&str] = &["addService", "addProtoService"];
+
+const METHOD_DECORATORS: &[&str] = &["GrpcMethod", "GrpcStreamMethod", "GrpcStreamCall"];
+
+pub fn extract_grpc_bindings_ts(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeGrpcBinding> {
+    let mut bindings = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression" {
+            process_registration(node, source, symbols, &mut bindings);
+        } else if CLASS_KINDS.contains(&node.kind()) {
+            process_controller(node, source, symbols, &mut bindings);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    bindings
+}
+
+fn process_registration(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    bindings: &mut Vec<NativeGrpcBinding>,
+) {
+    let is_registration = node
+        .child_by_field_name("function")
+        .filter(|callee| callee.kind() == "member_expression")
+        .and_then(|callee| callee.child_by_field_name("property"))
+        .is_some_and(|property| REGISTRATION_METHODS.contains(&node_text(property, source)));
+    let Some(arguments) = node
+        .child_by_field_name("arguments")
+        .filter(|_| is_registration)
+    else {
+        return;
+    };
+    let (Some(definition), implementation) = (arguments.named_child(0), arguments.named_child(1))
+    else {
+        return;
+    };
+    // `proto.Greeter.service` -> `Greeter`; `GreeterService` as is.
+    let definition = node_text(definition, source);
+    let definition = definition.strip_suffix(".service").unwrap_or(definition);
+    let service = definition.rsplit('.').next().unwrap_or(definition);
+    let caller_node_id = find_enclosing_symbol(node, symbols);
+
+    bindings.push(NativeGrpcBinding {
+        service: service.to_string(),
+        rpc: None,
+        implementation: implementation
+            .filter(|implementation| implementation.kind() != "object")
+            .map(|implementation| node_text(implementation, source).to_string()),
+        handler: None,
+        handler_node_id: None,
+        caller_node_id: caller_node_id.clone(),
+        range: extract_range(node),
+    });
+
+    let Some(object) = implementation.filter(|implementation| implementation.kind() == "object")
+    else {
+        return;
+    };
+    let mut cursor = object.walk();
+    for entry in object.named_children(&mut cursor) {
+        let (rpc, handler) = match entry.kind() {
+            "pair" => {
+                let (Some(key), Some(value)) = (
+                    entry.child_by_field_name("key"),
+                    entry.child_by_field_name("value"),
+                ) else {
+                    continue;
+                };
+                let handler = matches!(value.kind(), "identifier" | "member_expression")
+                    .then(|| node_text(value, source).to_string());
+                (node_text(key, source).trim_matches(['"', '\'']), handler)
+            }
+            "method_definition" => {
+                let Some(name) = entry.child_by_field_name("name") else {
+                    continue;
+                };
+                (node_text(name, source), None)
+            }
+            "shorthand_property_identifier" => {
+                let name = node_text(entry, source);
+                (name, Some(name.to_string()))
+            }
+            _ => continue,
+        };
+        bindings.push(NativeGrpcBinding {
+            service: service.to_string(),
+            rpc: Some(rpc.to_string()),
+            implementation: None,
+            handler_node_id: handler
+                .as_deref()
+                .and_then(|handler| callable_node_id(handler, symbols)),
+            handler,
+            caller_node_id: caller_node_id.clone(),
+            range: extract_range(entry),
+        });
+    }
+}
+
+fn process_controller(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    bindings: &mut Vec<NativeGrpcBinding>,
+) {
+    let (Some(name_node), Some(body)) = (
+        node.child_by_field_name("name"),
+        node.child_by_field_name("body"),
+    ) else {
+        return;
+    };
+    let class_name = node_text(name_node, source);
+
+    // Method decorators are siblings of the method in the class body.
+    let mut pending: Vec<Node<'_>> = Vec::new();
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        match member.kind() {
+            "decorator" => pending.push(member),
+            "method_definition" => {
+                let decorators = std::mem::take(&mut pending);
+                let Some(method_name) = member.child_by_field_name("name") else {
+                    continue;
+                };
+                let method = node_text(method_name, source);
+                for decorator in decorators {
+                    let Some(arguments) = grpc_decorator_arguments(decorator, source) else {
+                        continue;
+                    };
+                    let argument = |index| {
+                        arguments
+                            .and_then(|arguments| arguments.named_child(index))
+                            .and_then(|argument| string_value(argument, source))
+                    };
+                    bindings.push(NativeGrpcBinding {
+                        service: argument(0).unwrap_or_else(|| class_name.to_string()),
+                        rpc: Some(argument(1).unwrap_or_else(|| capitalize(method))),
+                        implementation: Some(class_name.to_string()),
+                        handler: Some(method.to_string()),
+                        handler_node_id: declared_node_id(method_name, symbols),
+                        caller_node_id: find_enclosing_symbol(node, symbols),
+                        range: extract_range(member),
+                    });
+                }
+            }
+            _ => pending.clear(),
+        }
+    }
+}
+
+/// Arguments of a `@GrpcMethod(...)` decorator: `Some(None)` when it has
+/// none, `None` for any other decorator.
+fn grpc_decorator_arguments<'a>(decorator: Node<'a>, source: &[u8]) -> Option<Option<Node<'a>>> {
+    let expression = decorator.named_child(0)?;
+    let (name, arguments) = match expression.kind() {
+        "identifier" => (expression, None),
+        "call_expression" => (
+            expression.child_by_field_name("function")?,
+            expression.child_by_field_name("arguments"),
+        ),
+        _ => return None,
+    };
+    METHOD_DECORATORS
+        .contains(&node_text(name, source))
+        .then_some(arguments)
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
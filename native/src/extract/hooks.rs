@@ -0,0 +1,171 @@
+//! React hook usage extraction for the TS/JS family.
+//!
+//! Every `useX(...)` call becomes a [`NativeHookUsage`] attributed to the
+//! enclosing component or custom hook, so "who uses `useAuth`?" can be
+//! answered without re-walking call edges.
+
+use tree_sitter::Node;
+
+use crate::types::{NativeHookUsage, NativeParsedSymbol};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use super::symbols::react::is_hook_name;
+
+const BUILTIN_HOOKS: &[&str] = &[
+    "useActionState",
+    "useCallback",
+    "useContext",
+    "useDebugValue",
+    "useDeferredValue",
+    "useEffect",
+    "useFormStatus",
+    "useId",
+    "useImperativeHandle",
+    "useInsertionEffect",
+    "useLayoutEffect",
+    "useMemo",
+    "useOptimistic",
+    "useReducer",
+    "useRef",
+    "useState",
+    "useSyncExternalStore",
+    "useTransition",
+];
+
+pub fn extract_hooks_ts(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeHookUsage> {
+    let mut hooks = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression" {
+            if let Some(usage) = process_hook_call(node, source, symbols) {
+                hooks.push(usage);
+            }
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    hooks
+}
+
+fn process_hook_call(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeHookUsage> {
+    let callee = node.child_by_field_name("function")?;
+    if !matches!(callee.kind(), "identifier" | "member_expression") {
+        return None;
+    }
+    let callee_text = node_text(callee, source);
+    if !is_hook_name(callee_text) {
+        return None;
+    }
+    let hook = callee_text
+        .strip_prefix("React.")
+        .unwrap_or(callee_text)
+        .to_string();
+
+    Some(NativeHookUsage {
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        is_builtin: BUILTIN_HOOKS.contains(&hook.as_str()),
+        dependencies: dependency_array(node, source),
+        range: extract_range(node),
+        hook,
+    })
+}
+
+/// Entries of a trailing array-literal argument, when the call has more
+/// than one argument (`useEffect(fn, [a])`, `useImperativeHandle(ref, fn,
+/// [a])`). A lone array (`useState([])`) is an initial value, not deps.
+fn dependency_array(call: Node<'_>, source: &[u8]) -> Option<Vec<String>> {
+    let args = call.child_by_field_name("arguments")?;
+    let mut cursor = args.walk();
+    let named: Vec<Node<'_>> = args
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() != "comment")
+        .collect();
+    if named.len() < 2 {
+        return None;
+    }
+    let last = named.last()?;
+    if last.kind() != "array" {
+        return None;
+    }
+
+    let mut inner = last.walk();
+    let deps = last
+        .named_children(&mut inner)
+        .filter(|child| child.kind() != "comment")
+        .map(|child| node_text(child, source).to_string())
+        .collect();
+    Some(deps)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn react_hook_calls_are_attributed_to_their_component() {
+        let source = r#"export function Profile({ id }: ProfileProps) {
+  const [items, setItems] = React.useState([]);
+  const { user } = useAuth();
+  useEffect(() => {
+    load(id).then(setItems);
+  }, [id, user.token]);
+  return <ul>{items.length}</ul>;
+}
+
+export function useAuth() {
+  return useContext(AuthContext);
+}
+"#;
+
+        let parsed = parse_source("tsx", "src/profile.tsx", source);
+
+        let node_id = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .map(|symbol| symbol.node_id.clone())
+                .expect("symbol present")
+        };
+        let hook = |name: &str| {
+            parsed
+                .hooks
+                .iter()
+                .find(|usage| usage.hook == name)
+                .expect("hook usage present")
+        };
+
+        let state = hook("useState");
+        assert_eq!(state.caller_node_id, node_id("Profile"));
+        assert!(state.is_builtin);
+        assert_eq!(state.dependencies, None);
+
+        let auth = hook("useAuth");
+        assert_eq!(auth.caller_node_id, node_id("Profile"));
+        assert!(!auth.is_builtin);
+
+        let effect = hook("useEffect");
+        assert_eq!(
+            effect.dependencies,
+            Some(vec!["id".to_string(), "user.token".to_string()])
+        );
+
+        assert_eq!(hook("useContext").caller_node_id, node_id("useAuth"));
+        assert_eq!(parsed.hooks.len(), 4);
+    }
+}
@@ -0,0 +1,80 @@
+//! Per-file identifier index.
+//!
+//! Every identifier-like leaf in the file, deduplicated with its number of
+//! occurrences, so "find references to `name`" can skip files that never
+//! mention it without loading their contents.
+
+use std::collections::BTreeMap;
+
+use tree_sitter::Node;
+
+use crate::types::NativeIdentifierCount;
+
+use super::calls::common::node_text;
+use super::dead_code::is_name;
+
+/// Identifier counts, sorted by name.
+pub fn extract_identifiers(root: Node<'_>, source: &[u8]) -> Vec<NativeIdentifierCount> {
+    let mut counts: BTreeMap<&str, u32> = BTreeMap::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.child_count() == 0 {
+            if is_name(node) {
+                *counts.entry(node_text(node, source)).or_default() += 1;
+            }
+            continue;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    counts
+        .into_iter()
+        .filter(|(name, _)| !name.is_empty())
+        .map(|(name, count)| NativeIdentifierCount {
+            name: name.to_string(),
+            count,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn identifier_index_counts_each_name_once_per_occurrence() {
+        let source = r#"import { fetchUser } from "./api";
+
+// fetchUser in a comment does not count
+export async function load(id: string) {
+  const user = await fetchUser(id);
+  return user.name + "fetchUser";
+}
+"#;
+
+        let parsed = parse_source("ts", "names.ts", source);
+
+        let count = |name: &str| {
+            parsed
+                .identifiers
+                .iter()
+                .find(|identifier| identifier.name == name)
+                .map(|identifier| identifier.count)
+        };
+        assert_eq!(count("fetchUser"), Some(2));
+        assert_eq!(count("id"), Some(2));
+        assert_eq!(count("user"), Some(2));
+        assert_eq!(count("name"), Some(1));
+        assert_eq!(count("load"), Some(1));
+        assert_eq!(count("missing"), None);
+        let names: Vec<&str> = parsed
+            .identifiers
+            .iter()
+            .map(|identifier| identifier.name.as_str())
+            .collect();
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        assert_eq!(names, sorted);
+    }
+}
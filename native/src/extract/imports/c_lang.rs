@@ -2,7 +2,7 @@ use tree_sitter::Node;
 
 use crate::types::NativeParsedImport;
 
-use super::common::{extract_range, extract_string_value, node_text};
+use super::common::{classify_include, extract_range, extract_string_value, node_text};
 
 pub fn extract_imports_c(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
     let mut imports = Vec::new();
@@ -37,14 +37,16 @@ fn process_preproc_include(node: Node<'_>, source: &[u8]) -> Option<NativeParsed
         let is_relative = specifier.starts_with('.');
         let named_imports = vec![specifier.clone()];
         return Some(NativeParsedImport {
-            specifier,
             is_relative,
             is_external: false,
+            origin: Some(classify_include(&specifier, false).to_string()),
             named_imports,
             default_import: None,
             namespace_import: None,
             is_re_export: false,
             range: extract_range(node),
+            specifier,
+            ..Default::default()
         });
     }
 
@@ -61,14 +63,16 @@ fn process_preproc_include(node: Node<'_>, source: &[u8]) -> Option<NativeParsed
 
         let named_imports = vec![specifier.clone()];
         return Some(NativeParsedImport {
-            specifier,
             is_relative: false,
             is_external: true,
+            origin: Some(classify_include(&specifier, true).to_string()),
             named_imports,
             default_import: None,
             namespace_import: None,
             is_re_export: false,
             range: extract_range(node),
+            specifier,
+            ..Default::default()
         });
     }
 
@@ -57,3 +57,122 @@ pub fn extract_string_value(string_node: Node<'_>, source: &[u8]) -> Option<Stri
 
     None
 }
+
+/// Headers provided by the C and C++ standard libraries (without the `.h`
+/// suffix for C headers, which are matched either way).
+const C_CPP_STANDARD_HEADERS: &[&str] = &[
+    "assert",
+    "complex",
+    "ctype",
+    "errno",
+    "fenv",
+    "float",
+    "inttypes",
+    "iso646",
+    "limits",
+    "locale",
+    "math",
+    "setjmp",
+    "signal",
+    "stdalign",
+    "stdarg",
+    "stdatomic",
+    "stdbool",
+    "stddef",
+    "stdint",
+    "stdio",
+    "stdlib",
+    "stdnoreturn",
+    "string",
+    "tgmath",
+    "threads",
+    "time",
+    "uchar",
+    "wchar",
+    "wctype",
+    "algorithm",
+    "any",
+    "array",
+    "atomic",
+    "bitset",
+    "cassert",
+    "cctype",
+    "cerrno",
+    "cfloat",
+    "charconv",
+    "chrono",
+    "cinttypes",
+    "climits",
+    "cmath",
+    "compare",
+    "concepts",
+    "condition_variable",
+    "cstddef",
+    "cstdint",
+    "cstdio",
+    "cstdlib",
+    "cstring",
+    "ctime",
+    "deque",
+    "exception",
+    "execution",
+    "filesystem",
+    "format",
+    "forward_list",
+    "fstream",
+    "functional",
+    "future",
+    "initializer_list",
+    "iomanip",
+    "ios",
+    "iosfwd",
+    "iostream",
+    "istream",
+    "iterator",
+    "list",
+    "map",
+    "memory",
+    "mutex",
+    "new",
+    "numeric",
+    "optional",
+    "ostream",
+    "queue",
+    "random",
+    "ranges",
+    "ratio",
+    "regex",
+    "set",
+    "shared_mutex",
+    "span",
+    "sstream",
+    "stack",
+    "stdexcept",
+    "string_view",
+    "system_error",
+    "thread",
+    "tuple",
+    "type_traits",
+    "typeindex",
+    "typeinfo",
+    "unordered_map",
+    "unordered_set",
+    "utility",
+    "variant",
+    "vector",
+];
+
+/// Classify an `#include`: quoted includes are project-local ("internal"),
+/// angle-bracket includes are "stdlib" when they name a standard header and
+/// "external" (system or third-party library) otherwise.
+pub fn classify_include(specifier: &str, is_system: bool) -> &'static str {
+    if !is_system {
+        return "internal";
+    }
+    let header = specifier.strip_suffix(".h").unwrap_or(specifier);
+    if C_CPP_STANDARD_HEADERS.contains(&header) {
+        "stdlib"
+    } else {
+        "external"
+    }
+}
@@ -3,7 +3,8 @@ use tree_sitter::Node;
 use crate::types::NativeParsedImport;
 
 use super::common::{
-    extract_range, extract_string_value, find_child_by_kind, find_child_node, node_text,
+    classify_include, extract_range, extract_string_value, find_child_by_kind, find_child_node,
+    node_text,
 };
 
 pub fn extract_imports_cpp(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
@@ -48,12 +49,14 @@ fn process_preproc_include(node: Node<'_>, source: &[u8]) -> Option<NativeParsed
         return Some(NativeParsedImport {
             is_relative: specifier.starts_with('.'),
             is_external: false,
+            origin: Some(classify_include(&specifier, false).to_string()),
             named_imports: vec![specifier.clone()],
             default_import: None,
             namespace_import: None,
             is_re_export: false,
             specifier,
             range: extract_range(node),
+            ..Default::default()
         });
     }
 
@@ -70,12 +73,14 @@ fn process_preproc_include(node: Node<'_>, source: &[u8]) -> Option<NativeParsed
         return Some(NativeParsedImport {
             is_relative: false,
             is_external: true,
+            origin: Some(classify_include(&specifier, true).to_string()),
             named_imports: vec![specifier.clone()],
             default_import: None,
             namespace_import: None,
             is_re_export: false,
             specifier,
             range: extract_range(node),
+            ..Default::default()
         });
     }
 
@@ -92,6 +92,7 @@ fn process_using_directive(node: Node<'_>, source: &[u8]) -> Option<NativeParsed
         namespace_import: None,
         is_re_export: false,
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
@@ -0,0 +1,109 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use tree_sitter::Node;
+
+use crate::types::NativeParsedImport;
+
+use super::common::extract_range;
+
+static AT_RULE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^@(import|use|forward)\b").unwrap());
+static QUOTED: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"["']([^"']+)["']"#).unwrap());
+static USE_ALIAS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bas\s+([A-Za-z_*][\w*-]*)").unwrap());
+
+/// Extract `@import` (CSS and SCSS) plus Sass `@use` / `@forward` edges.
+///
+/// `@use "x" as y` records `y` as the namespace import; `@forward` is a
+/// re-export. Sass built-in modules (`sass:math`), `~package` shorthands, and
+/// absolute URLs are external; everything else is resolved relative to the
+/// stylesheet, matching Sass load-path semantics.
+pub fn extract_imports_css(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
+    let mut imports = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if matches!(node.kind(), "import_statement" | "at_rule") {
+            let text = node.utf8_text(source).unwrap_or("");
+            process_at_rule(text, node, &mut imports);
+            continue;
+        }
+        if node.is_error() {
+            process_error_at_rules(node, source, &mut imports);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    imports
+}
+
+/// The CSS grammar has no rule for `@use "x";` / `@forward "x";` (a string
+/// prelude), so Sass module rules land in an `ERROR` node as a flat run of
+/// `at_keyword`, `string_value` and `;` tokens. Re-slice each statement from
+/// its keyword to the next `;`.
+fn process_error_at_rules(node: Node<'_>, source: &[u8], imports: &mut Vec<NativeParsedImport>) {
+    let mut cursor = node.walk();
+    let children: Vec<Node<'_>> = node.children(&mut cursor).collect();
+    for (i, keyword) in children.iter().enumerate() {
+        if keyword.kind() != "at_keyword" {
+            continue;
+        }
+        let end = children[i + 1..]
+            .iter()
+            .find(|child| matches!(child.kind(), ";" | "at_keyword"))
+            .map_or(node.end_byte(), |child| child.start_byte());
+        let text = std::str::from_utf8(&source[keyword.start_byte()..end]).unwrap_or("");
+        process_at_rule(text, *keyword, imports);
+    }
+}
+
+fn process_at_rule(text: &str, node: Node<'_>, imports: &mut Vec<NativeParsedImport>) {
+    let text = text.trim();
+    let Some(caps) = AT_RULE.captures(text) else {
+        return;
+    };
+    let keyword = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+
+    // Only the prelude matters; a trailing `with (...)` block or media query
+    // list can't contain further module specifiers we care about.
+    let prelude = text.split(['{', ';']).next().unwrap_or(text);
+    let namespace_import = if keyword == "use" {
+        USE_ALIAS
+            .captures(prelude)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().to_string())
+    } else {
+        None
+    };
+
+    for spec in QUOTED.captures_iter(prelude) {
+        let specifier = spec[1].trim().to_string();
+        if specifier.is_empty() {
+            continue;
+        }
+
+        let is_external = specifier.starts_with("sass:")
+            || specifier.starts_with('~')
+            || specifier.contains("://")
+            || specifier.starts_with("//");
+
+        imports.push(NativeParsedImport {
+            is_relative: !is_external,
+            is_external,
+            named_imports: Vec::new(),
+            default_import: None,
+            namespace_import: namespace_import.clone(),
+            is_re_export: keyword == "forward",
+            range: extract_range(node),
+            specifier,
+            ..Default::default()
+        });
+    }
+}
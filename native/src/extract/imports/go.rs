@@ -4,13 +4,24 @@ use crate::types::NativeParsedImport;
 
 use super::common::{extract_range, extract_string_value, node_text};
 
-pub fn extract_imports_go(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
+/// Extract `import "path"` and grouped `import ( ... )` declarations,
+/// including aliased (`f "fmt"`), dot (`. "fmt"`) and blank (`_ "embed"`)
+/// imports.
+///
+/// Each import is classified by `origin`: standard library paths have no dot
+/// in their first element (the rule `go` itself uses), paths under
+/// `module_path` are module-internal, and everything else is external.
+pub fn extract_imports_go(
+    root: Node<'_>,
+    source: &[u8],
+    module_path: Option<&str>,
+) -> Vec<NativeParsedImport> {
     let mut imports = Vec::new();
     let mut stack = vec![root];
 
     while let Some(node) = stack.pop() {
         if node.kind() == "import_declaration" {
-            process_import_declaration(node, source, &mut imports);
+            process_import_declaration(node, source, module_path, &mut imports);
         }
 
         let child_count = node.child_count();
@@ -27,19 +38,20 @@ pub fn extract_imports_go(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImpo
 fn process_import_declaration(
     node: Node<'_>,
     source: &[u8],
+    module_path: Option<&str>,
     imports: &mut Vec<NativeParsedImport>,
 ) {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         if child.kind() == "import_spec" {
-            if let Some(parsed) = process_import_spec(child, source) {
+            if let Some(parsed) = process_import_spec(child, source, module_path) {
                 imports.push(parsed);
             }
         } else if child.kind() == "import_spec_list" {
             let mut list_cursor = child.walk();
             for import_spec in child.children(&mut list_cursor) {
                 if import_spec.kind() == "import_spec" {
-                    if let Some(parsed) = process_import_spec(import_spec, source) {
+                    if let Some(parsed) = process_import_spec(import_spec, source, module_path) {
                         imports.push(parsed);
                     }
                 }
@@ -48,7 +60,11 @@ fn process_import_declaration(
     }
 }
 
-fn process_import_spec(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
+fn process_import_spec(
+    node: Node<'_>,
+    source: &[u8],
+    module_path: Option<&str>,
+) -> Option<NativeParsedImport> {
     let path_node = node.child_by_field_name("path")?;
     let specifier = extract_string_value(path_node, source)
         .unwrap_or_else(|| node_text(path_node, source).trim_matches('"').to_string());
@@ -81,15 +97,90 @@ fn process_import_spec(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImpo
     };
 
     let is_relative = specifier.starts_with("./") || specifier.starts_with("../");
+    let origin = classify_import_path(&specifier, is_relative, module_path);
 
     Some(NativeParsedImport {
-        specifier,
         is_relative,
-        is_external: !is_relative,
+        is_external: origin == "external",
+        origin: Some(origin.to_string()),
+        specifier,
         named_imports,
         default_import: None,
         namespace_import,
         is_re_export,
         range: extract_range(node),
+        ..Default::default()
     })
 }
+
+fn classify_import_path(
+    specifier: &str,
+    is_relative: bool,
+    module_path: Option<&str>,
+) -> &'static str {
+    if is_relative {
+        return "internal";
+    }
+
+    if let Some(module) = module_path.map(|m| m.trim_end_matches('/')) {
+        if !module.is_empty()
+            && (specifier == module
+                || specifier
+                    .strip_prefix(module)
+                    .is_some_and(|rest| rest.starts_with('/')))
+        {
+            return "internal";
+        }
+    }
+
+    let first_element = specifier.split('/').next().unwrap_or(specifier);
+    if first_element.contains('.') {
+        "external"
+    } else {
+        "stdlib"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source_in_module;
+
+    #[test]
+    fn go_imports_are_classified_against_module_path() {
+        let source = r#"package main
+
+import (
+	"fmt"
+	log "github.com/sirupsen/logrus"
+	_ "embed"
+	"github.com/acme/app/internal/store"
+)
+"#;
+
+        let parsed = parse_source_in_module("go", "cmd/app/main.go", "github.com/acme/app", source);
+
+        let origin = |specifier: &str| {
+            parsed
+                .imports
+                .iter()
+                .find(|import| import.specifier == specifier)
+                .and_then(|import| import.origin.clone())
+        };
+
+        assert_eq!(origin("fmt").as_deref(), Some("stdlib"));
+        assert_eq!(origin("embed").as_deref(), Some("stdlib"));
+        assert_eq!(
+            origin("github.com/sirupsen/logrus").as_deref(),
+            Some("external")
+        );
+        assert_eq!(
+            origin("github.com/acme/app/internal/store").as_deref(),
+            Some("internal")
+        );
+        assert!(parsed
+            .imports
+            .iter()
+            .filter(|import| import.is_external)
+            .all(|import| import.specifier == "github.com/sirupsen/logrus"));
+    }
+}
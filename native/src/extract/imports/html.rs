@@ -0,0 +1,147 @@
+use tree_sitter::Node;
+
+use crate::types::NativeParsedImport;
+
+use super::common::{extract_range, node_text};
+
+/// Extract `<script src="...">` references as imports so server-rendered
+/// templates get an edge to the JS bundles they load.
+pub fn extract_imports_html(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
+    let mut imports = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "script_element" {
+            if let Some(parsed) = process_script_element(node, source) {
+                imports.push(parsed);
+            }
+            // Script bodies are raw text; nothing to descend into.
+            continue;
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    imports
+}
+
+fn process_script_element(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
+    let mut cursor = node.walk();
+    let start_tag = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "start_tag")?;
+
+    let specifier = find_attribute_value(start_tag, "src", source)?;
+    if specifier.is_empty() {
+        return None;
+    }
+
+    let is_external = specifier.contains("://") || specifier.starts_with("//");
+    let is_relative = !is_external && !specifier.starts_with('/');
+
+    Some(NativeParsedImport {
+        specifier,
+        is_relative,
+        is_external,
+        named_imports: Vec::new(),
+        default_import: None,
+        namespace_import: None,
+        is_re_export: false,
+        range: extract_range(node),
+        ..Default::default()
+    })
+}
+
+/// Return the (unquoted) value of attribute `name` on a `start_tag` /
+/// `self_closing_tag` node.
+fn find_attribute_value(tag: Node<'_>, name: &str, source: &[u8]) -> Option<String> {
+    let mut cursor = tag.walk();
+    for attribute in tag.named_children(&mut cursor) {
+        if attribute.kind() != "attribute" {
+            continue;
+        }
+
+        let mut attr_cursor = attribute.walk();
+        let mut children = attribute.named_children(&mut attr_cursor);
+        let Some(attr_name) = children.next() else {
+            continue;
+        };
+        if !node_text(attr_name, source).eq_ignore_ascii_case(name) {
+            continue;
+        }
+
+        let value = children.next()?;
+        let text = match value.kind() {
+            "quoted_attribute_value" => {
+                let mut value_cursor = value.walk();
+                let inner = value
+                    .named_children(&mut value_cursor)
+                    .find(|child| child.kind() == "attribute_value");
+                inner.map(|v| node_text(v, source)).unwrap_or("")
+            }
+            _ => node_text(value, source),
+        };
+        return Some(text.trim().to_string());
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn html_parser_links_scripts_handlers_and_custom_elements() {
+        let source = r#"<!doctype html>
+<html>
+  <head>
+    <script src="./js/app.js"></script>
+    <script src="https://cdn.example.com/lib.js"></script>
+  </head>
+  <body>
+    <button onclick="save(event); ui.toast('saved')">Save</button>
+    <a onclick="alert('load()')">Load</a>
+    <user-card></user-card>
+  </body>
+</html>
+"#;
+
+        let parsed = parse_source("html", "public/index.html", source);
+
+        let imports: Vec<(&str, bool, bool)> = parsed
+            .imports
+            .iter()
+            .map(|import| {
+                (
+                    import.specifier.as_str(),
+                    import.is_relative,
+                    import.is_external,
+                )
+            })
+            .collect();
+        assert_eq!(
+            imports,
+            vec![
+                ("./js/app.js", true, false),
+                ("https://cdn.example.com/lib.js", false, true),
+            ]
+        );
+
+        let callees: Vec<(&str, &str)> = parsed
+            .calls
+            .iter()
+            .map(|call| (call.callee_identifier.as_str(), call.call_type.as_str()))
+            .collect();
+        assert!(callees.contains(&("save", "function")));
+        assert!(callees.contains(&("ui.toast", "method")));
+        assert!(callees.contains(&("alert", "function")));
+        assert!(!callees.iter().any(|(callee, _)| *callee == "load"));
+        assert!(callees.contains(&("UserCard", "constructor")));
+    }
+}
@@ -59,6 +59,7 @@ fn process_import_declaration(node: Node<'_>, source: &[u8]) -> Option<NativePar
         namespace_import,
         is_re_export: false,
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
@@ -0,0 +1,117 @@
+use tree_sitter::Node;
+
+use crate::types::NativeParsedImport;
+
+use super::common::{extract_range, node_text};
+
+/// Extract `require("mod.name")` / `require "mod.name"` calls as imports.
+///
+/// When the result is bound to a local (`local util = require("util")`), the
+/// binding name is recorded as the namespace import so downstream resolution
+/// can link `util.fn()` call sites back to the required module.
+pub fn extract_imports_lua(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
+    let mut imports = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "function_call" {
+            if let Some(parsed) = process_require_call(node, source) {
+                imports.push(parsed);
+            }
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    imports
+}
+
+fn process_require_call(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
+    let name_node = node.child_by_field_name("name")?;
+    if name_node.kind() != "identifier" || node_text(name_node, source) != "require" {
+        return None;
+    }
+
+    let args = node.child_by_field_name("arguments")?;
+    let string_node = if args.kind() == "string" {
+        args
+    } else {
+        let mut cursor = args.walk();
+        let first = args.named_children(&mut cursor).next()?;
+        if first.kind() != "string" {
+            return None;
+        }
+        first
+    };
+
+    let specifier = extract_lua_string(string_node, source)?;
+    if specifier.is_empty() {
+        return None;
+    }
+
+    // Lua module names are dot-separated search-path lookups, not file paths;
+    // only an explicit leading `.` is treated as relative.
+    let is_relative = specifier.starts_with('.');
+
+    Some(NativeParsedImport {
+        is_external: !is_relative,
+        is_relative,
+        named_imports: Vec::new(),
+        default_import: None,
+        namespace_import: find_binding_name(node, source),
+        is_re_export: false,
+        range: extract_range(node),
+        specifier,
+        ..Default::default()
+    })
+}
+
+fn extract_lua_string(node: Node<'_>, source: &[u8]) -> Option<String> {
+    if let Some(content) = node.child_by_field_name("content") {
+        return Some(node_text(content, source).to_string());
+    }
+
+    let text = node_text(node, source);
+    let trimmed = text
+        .trim_start_matches(['"', '\'', '['])
+        .trim_end_matches(['"', '\'', ']']);
+    if trimmed.len() == text.len() {
+        return None;
+    }
+    Some(trimmed.to_string())
+}
+
+/// Resolve `local name = require(...)` / `name = require(...)` to `name`.
+fn find_binding_name(call: Node<'_>, source: &[u8]) -> Option<String> {
+    let expr_list = call.parent()?;
+    if expr_list.kind() != "expression_list" {
+        return None;
+    }
+    let assignment = expr_list.parent()?;
+    if assignment.kind() != "assignment_statement" {
+        return None;
+    }
+
+    let mut cursor = expr_list.walk();
+    let index = expr_list
+        .children_by_field_name("value", &mut cursor)
+        .position(|value| value.id() == call.id())?;
+
+    let mut assign_cursor = assignment.walk();
+    let targets = assignment
+        .named_children(&mut assign_cursor)
+        .find(|child| child.kind() == "variable_list")?;
+    let mut target_cursor = targets.walk();
+    let target = targets
+        .children_by_field_name("name", &mut target_cursor)
+        .nth(index)?;
+    if target.kind() != "identifier" {
+        return None;
+    }
+    Some(node_text(target, source).to_string())
+}
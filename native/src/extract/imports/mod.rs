@@ -1,31 +1,134 @@
-use tree_sitter::Node;
+pub(crate) mod c_lang;
+pub mod common;
+pub(crate) mod cpp;
+pub(crate) mod csharp;
+pub(crate) mod css;
+pub(crate) mod go;
+pub(crate) mod html;
+pub(crate) mod java;
+pub(crate) mod lua;
+pub(crate) mod php;
+pub(crate) mod python;
+pub(crate) mod rust_lang;
+pub(crate) mod shell;
+pub(crate) mod typescript;
+mod usage;
 
-use crate::types::NativeParsedImport;
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
 
-mod c_lang;
-pub mod common;
-mod cpp;
-mod csharp;
-mod go;
-mod java;
-mod php;
-mod python;
-mod rust_lang;
-mod shell;
-mod typescript;
-
-pub fn extract_imports(root: Node<'_>, source: &[u8], language: &str) -> Vec<NativeParsedImport> {
-    match language {
-        "c" => c_lang::extract_imports_c(root, source),
-        "cpp" => cpp::extract_imports_cpp(root, source),
-        "go" => go::extract_imports_go(root, source),
-        "java" => java::extract_imports_java(root, source),
-        "php" => php::extract_imports_php(root, source),
-        "py" => python::extract_imports_python(root, source),
-        "rs" => rust_lang::extract_imports_rust(root, source),
-        "sh" => shell::extract_imports_shell(root, source),
-        "cs" => csharp::extract_imports_csharp(root, source),
-        "ts" | "tsx" | "js" | "jsx" => typescript::extract_imports_ts(root, source),
-        _ => vec![],
+    #[test]
+    fn renamed_reexports_keep_original_and_alias() {
+        let source = r#"import { parse as parseConfig, load } from "./config";
+export { a as b, c } from "./x";
+export { default as Button } from "./button";
+"#;
+
+        let parsed = parse_source("ts", "src/index.ts", source);
+
+        let import = |specifier: &str| {
+            parsed
+                .imports
+                .iter()
+                .find(|import| import.specifier == specifier)
+                .unwrap_or_else(|| panic!("missing import {specifier}"))
+        };
+        let aliases = |specifier: &str| {
+            import(specifier)
+                .aliases
+                .iter()
+                .map(|alias| (alias.imported.as_str(), alias.local.as_str()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(aliases("./config"), vec![("parse", "parseConfig")]);
+        assert_eq!(
+            import("./config").named_imports,
+            vec!["parseConfig", "load"]
+        );
+
+        let reexport = import("./x");
+        assert!(reexport.is_re_export);
+        assert_eq!(reexport.named_imports, vec!["b", "c"]);
+        assert_eq!(aliases("./x"), vec![("a", "b")]);
+
+        assert_eq!(aliases("./button"), vec![("default", "Button")]);
+    }
+
+    #[test]
+    fn builtin_modules_are_classified_per_language() {
+        let cases = [
+            (
+                "ts",
+                "import fs from \"node:fs\";\nimport { test } from \"node:test\";\nimport { Worker } from \"worker_threads\";\nimport { readFile } from \"fs/promises\";\nimport express from \"express\";\nimport { util } from \"./util\";\n",
+                vec![
+                    ("node:fs", "stdlib"),
+                    ("node:test", "stdlib"),
+                    ("worker_threads", "stdlib"),
+                    ("fs/promises", "stdlib"),
+                    ("express", "external"),
+                    ("./util", "internal"),
+                ],
+            ),
+            (
+                "py",
+                "import asyncio\nimport logging.handlers\nfrom __future__ import annotations\nimport requests\nfrom .models import User\n",
+                vec![
+                    ("asyncio", "stdlib"),
+                    ("logging.handlers", "stdlib"),
+                    ("__future__", "stdlib"),
+                    ("requests", "external"),
+                    (".models", "internal"),
+                ],
+            ),
+        ];
+
+        for (language, source, expected) in cases {
+            let parsed = parse_source(language, &format!("src/main.{language}"), source);
+
+            for (specifier, origin) in expected {
+                let import = parsed
+                    .imports
+                    .iter()
+                    .find(|import| import.specifier == specifier)
+                    .unwrap_or_else(|| panic!("missing {language} import {specifier}"));
+                assert_eq!(import.origin.as_deref(), Some(origin), "{specifier}");
+                assert_eq!(import.is_external, origin == "external", "{specifier}");
+            }
+        }
+    }
+
+    #[test]
+    fn star_reexports_are_flagged() {
+        let cases = [
+            (
+                "ts",
+                "export * from \"./models\";\nexport * as api from \"./api\";\nexport { helper } from \"./helper\";\n",
+                vec![("./models", true), ("./api", false), ("./helper", false)],
+            ),
+            (
+                "rs",
+                "pub use crate::models::*;\nuse crate::prelude::*;\npub use crate::api::Client;\n",
+                vec![
+                    ("crate::models", true),
+                    ("crate::prelude", false),
+                    ("crate::api::Client", false),
+                ],
+            ),
+        ];
+
+        for (language, source, expected) in cases {
+            let parsed = parse_source(language, &format!("src/index.{language}"), source);
+
+            for (specifier, star) in expected {
+                let import = parsed
+                    .imports
+                    .iter()
+                    .find(|import| import.specifier == specifier)
+                    .unwrap_or_else(|| panic!("missing {language} import {specifier}"));
+                assert_eq!(import.is_star_re_export, star, "{specifier}");
+            }
+        }
     }
 }
@@ -78,6 +78,7 @@ fn parse_namespace_use_clause(node: Node<'_>, source: &[u8]) -> Option<NativePar
         namespace_import: None,
         is_re_export: false,
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
@@ -132,5 +133,6 @@ fn process_include_like_expression(node: Node<'_>, source: &[u8]) -> Option<Nati
         namespace_import: None,
         is_re_export: false,
         range: extract_range(node),
+        ..Default::default()
     })
 }
@@ -3,31 +3,218 @@ use tree_sitter::Node;
 use crate::types::NativeParsedImport;
 
 use super::common::{extract_range, find_child_node, node_text};
+use super::usage::count_binding_references;
 
+/// Top-level standard library modules (`sys.stdlib_module_names` minus
+/// private and easter-egg modules), including ones removed in 3.13 that
+/// older code still imports.
 const PYTHON_STDLIB_MODULES: &[&str] = &[
-    "os",
-    "sys",
-    "json",
-    "re",
-    "datetime",
-    "math",
+    "__future__",
+    "abc",
+    "aifc",
+    "argparse",
+    "array",
+    "ast",
+    "asynchat",
+    "asyncio",
+    "asyncore",
+    "atexit",
+    "audioop",
+    "base64",
+    "bdb",
+    "binascii",
+    "bisect",
+    "builtins",
+    "bz2",
+    "calendar",
+    "cgi",
+    "cgitb",
+    "chunk",
+    "cmath",
+    "cmd",
+    "code",
+    "codecs",
+    "codeop",
     "collections",
-    "itertools",
+    "colorsys",
+    "compileall",
+    "concurrent",
+    "configparser",
+    "contextlib",
+    "contextvars",
+    "copy",
+    "copyreg",
+    "cProfile",
+    "crypt",
+    "csv",
+    "ctypes",
+    "curses",
+    "dataclasses",
+    "datetime",
+    "dbm",
+    "decimal",
+    "difflib",
+    "dis",
+    "distutils",
+    "doctest",
+    "email",
+    "encodings",
+    "ensurepip",
+    "enum",
+    "errno",
+    "faulthandler",
+    "fcntl",
+    "filecmp",
+    "fileinput",
+    "fnmatch",
+    "fractions",
+    "ftplib",
     "functools",
-    "pathlib",
-    "typing",
-    "types",
+    "gc",
+    "getopt",
+    "getpass",
+    "gettext",
+    "glob",
+    "graphlib",
+    "grp",
+    "gzip",
+    "hashlib",
+    "heapq",
+    "hmac",
+    "html",
+    "http",
+    "imaplib",
+    "imghdr",
+    "imp",
+    "importlib",
+    "inspect",
     "io",
-    "string",
+    "ipaddress",
+    "itertools",
+    "json",
+    "keyword",
+    "lib2to3",
+    "linecache",
+    "locale",
+    "logging",
+    "lzma",
+    "mailbox",
+    "mailcap",
+    "marshal",
+    "math",
+    "mimetypes",
+    "mmap",
+    "modulefinder",
+    "msilib",
+    "msvcrt",
+    "multiprocessing",
+    "netrc",
+    "nis",
+    "nntplib",
+    "ntpath",
     "numbers",
+    "operator",
+    "optparse",
+    "os",
+    "ossaudiodev",
+    "pathlib",
+    "pdb",
+    "pickle",
+    "pickletools",
+    "pipes",
+    "pkgutil",
+    "platform",
+    "plistlib",
+    "poplib",
+    "posix",
+    "posixpath",
+    "pprint",
+    "profile",
+    "pstats",
+    "pty",
+    "pwd",
+    "py_compile",
+    "pyclbr",
+    "pydoc",
+    "pyexpat",
+    "queue",
+    "quopri",
     "random",
+    "re",
+    "readline",
+    "reprlib",
+    "resource",
+    "rlcompleter",
+    "runpy",
+    "sched",
+    "secrets",
+    "select",
+    "selectors",
+    "shelve",
+    "shlex",
+    "shutil",
+    "signal",
+    "site",
+    "smtpd",
+    "smtplib",
+    "sndhdr",
+    "socket",
+    "socketserver",
+    "spwd",
+    "sqlite3",
+    "ssl",
+    "stat",
     "statistics",
-    "decimal",
-    "fractions",
-    "heapq",
-    "bisect",
-    "array",
+    "string",
+    "stringprep",
+    "struct",
+    "subprocess",
+    "sunau",
+    "symtable",
+    "sys",
+    "sysconfig",
+    "syslog",
+    "tabnanny",
+    "tarfile",
+    "telnetlib",
+    "tempfile",
+    "termios",
+    "textwrap",
+    "threading",
+    "time",
+    "timeit",
+    "tkinter",
+    "token",
+    "tokenize",
+    "tomllib",
+    "trace",
+    "traceback",
+    "tracemalloc",
+    "tty",
+    "turtle",
+    "types",
+    "typing",
+    "unicodedata",
+    "unittest",
+    "urllib",
+    "uu",
+    "uuid",
+    "venv",
+    "warnings",
+    "wave",
     "weakref",
+    "webbrowser",
+    "winreg",
+    "winsound",
+    "wsgiref",
+    "xdrlib",
+    "xml",
+    "xmlrpc",
+    "zipapp",
+    "zipfile",
+    "zipimport",
+    "zlib",
+    "zoneinfo",
 ];
 
 pub fn extract_imports_python(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
@@ -38,6 +225,9 @@ pub fn extract_imports_python(root: Node<'_>, source: &[u8]) -> Vec<NativeParsed
         match node.kind() {
             "import_statement" => process_import_statement(node, source, &mut imports),
             "import_from_statement" => process_import_from_statement(node, source, &mut imports),
+            "future_import_statement" => {
+                process_future_import_statement(node, source, &mut imports)
+            }
             _ => {}
         }
 
@@ -49,9 +239,27 @@ pub fn extract_imports_python(root: Node<'_>, source: &[u8]) -> Vec<NativeParsed
         }
     }
 
+    count_binding_references(root, source, &mut imports, is_reference);
     imports
 }
 
+/// Identifiers other than attribute names (`obj.name`) and keyword argument
+/// names (`f(name=1)`).
+fn is_reference(node: Node<'_>) -> bool {
+    if node.kind() != "identifier" {
+        return false;
+    }
+    let Some(parent) = node.parent() else {
+        return true;
+    };
+    let field = match parent.kind() {
+        "attribute" => "attribute",
+        "keyword_argument" => "name",
+        _ => return true,
+    };
+    parent.child_by_field_name(field) != Some(node)
+}
+
 fn process_import_statement(node: Node<'_>, source: &[u8], imports: &mut Vec<NativeParsedImport>) {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -69,6 +277,7 @@ fn process_import_statement(node: Node<'_>, source: &[u8], imports: &mut Vec<Nat
                     Vec::new(),
                     namespace_import,
                     false,
+                    None,
                 ));
             }
             "aliased_import" => {
@@ -93,6 +302,7 @@ fn process_import_statement(node: Node<'_>, source: &[u8], imports: &mut Vec<Nat
                     Vec::new(),
                     alias,
                     false,
+                    None,
                 ));
             }
             _ => {}
@@ -100,6 +310,35 @@ fn process_import_statement(node: Node<'_>, source: &[u8], imports: &mut Vec<Nat
     }
 }
 
+/// `from __future__ import annotations` gets its own grammar node; record it
+/// like any other stdlib `from` import.
+fn process_future_import_statement(
+    node: Node<'_>,
+    source: &[u8],
+    imports: &mut Vec<NativeParsedImport>,
+) {
+    let mut cursor = node.walk();
+    let named_imports = node
+        .children_by_field_name("name", &mut cursor)
+        .filter_map(|name| match name.kind() {
+            "aliased_import" => name
+                .child_by_field_name("alias")
+                .or_else(|| name.child_by_field_name("name")),
+            _ => Some(name),
+        })
+        .map(|name| node_text(name, source).to_string())
+        .collect();
+    imports.push(build_import(
+        node,
+        "__future__",
+        false,
+        named_imports,
+        None,
+        false,
+        None,
+    ));
+}
+
 fn process_import_from_statement(
     node: Node<'_>,
     source: &[u8],
@@ -107,6 +346,11 @@ fn process_import_from_statement(
 ) {
     let specifier = extract_from_specifier(node, source);
     let is_relative = is_relative_specifier(&specifier) || has_relative_import_module(node);
+    // `from ..pkg import x` -> 2. Recorded on both the raw and the normalized
+    // record so the resolver can walk up the right number of packages.
+    let relative_level = is_relative
+        .then(|| specifier.chars().take_while(|c| *c == '.').count() as u32)
+        .filter(|level| *level > 0);
 
     let mut named_imports = Vec::new();
     let mut has_wildcard_import = false;
@@ -171,6 +415,7 @@ fn process_import_from_statement(
         named_imports.clone(),
         None,
         is_re_export,
+        relative_level,
     ));
 
     if emit_normalized_relative {
@@ -181,6 +426,7 @@ fn process_import_from_statement(
             named_imports,
             None,
             is_re_export,
+            relative_level,
         ));
     }
 }
@@ -211,16 +457,27 @@ fn build_import(
     named_imports: Vec<String>,
     namespace_import: Option<String>,
     is_re_export: bool,
+    relative_level: Option<u32>,
 ) -> NativeParsedImport {
+    let origin = if is_relative {
+        "internal"
+    } else if is_stdlib_module(specifier) {
+        "stdlib"
+    } else {
+        "external"
+    };
     NativeParsedImport {
         specifier: specifier.to_string(),
         is_relative,
-        is_external: !is_relative && !is_stdlib_module(specifier),
+        is_external: origin == "external",
         named_imports,
         default_import: None,
         namespace_import,
         is_re_export,
         range: extract_range(node),
+        relative_level,
+        origin: Some(origin.to_string()),
+        ..Default::default()
     }
 }
 
@@ -236,3 +493,42 @@ fn is_stdlib_module(specifier: &str) -> bool {
         .unwrap_or("");
     PYTHON_STDLIB_MODULES.contains(&first_part)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn python_relative_imports_record_their_level() {
+        let source = r#"import os
+from . import sibling
+from ..pkg import x
+from ...core.models import User
+"#;
+
+        let parsed = parse_source("py", "app/api/views.py", source);
+
+        let levels: Vec<(&str, bool, Option<u32>)> = parsed
+            .imports
+            .iter()
+            .map(|import| {
+                (
+                    import.specifier.as_str(),
+                    import.is_relative,
+                    import.relative_level,
+                )
+            })
+            .collect();
+        assert_eq!(
+            levels,
+            vec![
+                ("os", false, None),
+                (".", true, Some(1)),
+                ("..pkg", true, Some(2)),
+                ("pkg", true, Some(2)),
+                ("...core.models", true, Some(3)),
+                ("core.models", true, Some(3)),
+            ]
+        );
+    }
+}
@@ -15,8 +15,9 @@ pub fn extract_imports_rust(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedIm
                     imports.push(parsed);
                 }
             }
-            "use_declaration" => {
-                if let Some(parsed) = process_use_declaration(node, source) {
+            "use_declaration" => imports.extend(process_use_declaration(node, source)),
+            "extern_crate_declaration" => {
+                if let Some(parsed) = process_extern_crate(node, source) {
                     imports.push(parsed);
                 }
             }
@@ -34,7 +35,14 @@ pub fn extract_imports_rust(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedIm
     imports
 }
 
+/// `mod foo;` pulls in `foo.rs` / `foo/mod.rs` and is emitted as a relative
+/// import. Inline `mod foo { ... }` blocks don't reference another file and
+/// are only emitted as symbols.
 fn process_mod_item(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
+    if node.child_by_field_name("body").is_some() {
+        return None;
+    }
+
     let name_node = node.child_by_field_name("name")?;
     let module_name = node_text(name_node, source).to_string();
     if module_name.is_empty() {
@@ -50,55 +58,101 @@ fn process_mod_item(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport>
         namespace_import: None,
         is_re_export: false,
         range: extract_range(node),
+        origin: Some("internal".to_string()),
+        ..Default::default()
     })
 }
 
-fn process_use_declaration(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
-    let argument = node.child_by_field_name("argument")?;
-
-    let (specifier, named_imports): (String, Vec<String>) = match argument.kind() {
-        "use_wildcard" => parse_use_wildcard(argument, source)?,
-        "use_as_clause" => parse_use_as_clause(argument, source)?,
-        "scoped_use_list" => parse_scoped_use_list(argument, source)?,
-        "scoped_identifier" | "identifier" => {
-            let specifier = node_text(argument, source).to_string();
-            if specifier.is_empty() {
-                return None;
-            }
-
-            let imported_name = specifier.rsplit("::").next().unwrap_or("").to_string();
-            if imported_name.is_empty() {
-                return None;
-            }
-
-            (specifier, vec![imported_name])
-        }
-        _ => return None,
-    };
-
-    if specifier.is_empty() || named_imports.is_empty() {
+/// `extern crate foo;` / `extern crate foo as bar;`.
+fn process_extern_crate(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
+    let name_node = node.child_by_field_name("name")?;
+    let crate_name = node_text(name_node, source).to_string();
+    if crate_name.is_empty() {
         return None;
     }
 
-    let is_relative = is_relative_specifier(&specifier);
-    let is_external = is_external_specifier(&specifier, is_relative);
-
-    // TS rust.ts does NOT mark `pub use` statements as re-exports, so we
-    // emit false to match source-of-truth output.
-    let is_re_export = false;
+    let bound_name = node
+        .child_by_field_name("alias")
+        .map(|alias| node_text(alias, source).to_string())
+        .filter(|alias| !alias.is_empty())
+        .unwrap_or_else(|| crate_name.clone());
+    let origin = classify_path_root(&crate_name);
 
     Some(NativeParsedImport {
-        specifier,
-        is_relative,
-        is_external,
-        named_imports,
+        is_relative: false,
+        is_external: origin == "external",
+        named_imports: vec![bound_name],
         default_import: None,
         namespace_import: None,
-        is_re_export,
+        is_re_export: false,
         range: extract_range(node),
+        origin: Some(origin.to_string()),
+        specifier: crate_name,
+        ..Default::default()
     })
 }
 
+/// Flatten a `use` tree into one import per path prefix. Grouped imports
+/// keep their common prefix as the specifier (`use std::io::{self, Read}`
+/// -> `std::io` importing `io` and `Read`); nested groups
+/// (`use std::{fs, io::{Read, Write}}`) produce an extra import for each
+/// nested prefix. `pub use` (any `pub(...)` form) is flagged as a re-export.
+fn process_use_declaration(node: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
+    let Some(argument) = node.child_by_field_name("argument") else {
+        return vec![];
+    };
+
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    match argument.kind() {
+        "use_wildcard" => groups.extend(parse_use_wildcard(argument, source)),
+        "use_as_clause" => groups.extend(parse_use_as_clause(argument, source)),
+        "scoped_use_list" => parse_scoped_use_list(argument, "", source, &mut groups),
+        "use_list" => parse_use_list(argument, "", source, &mut groups),
+        "scoped_identifier" | "identifier" | "crate" | "self" | "super" => {
+            let specifier = node_text(argument, source).to_string();
+            let imported_name = specifier.rsplit("::").next().unwrap_or("").to_string();
+            if !specifier.is_empty() && !imported_name.is_empty() {
+                groups.push((specifier, vec![imported_name]));
+            }
+        }
+        _ => {}
+    }
+
+    let is_re_export = {
+        let mut cursor = node.walk();
+        let found = node
+            .children(&mut cursor)
+            .any(|child| child.kind() == "visibility_modifier");
+        found
+    };
+
+    groups
+        .into_iter()
+        .filter(|(specifier, named_imports)| !specifier.is_empty() && !named_imports.is_empty())
+        .map(|(specifier, named_imports)| {
+            let is_relative = is_relative_specifier(&specifier);
+            let is_external = is_external_specifier(&specifier, is_relative);
+            let origin = classify_path_root(&specifier);
+            let is_star_re_export = is_re_export && named_imports.iter().any(|name| name == "*");
+
+            NativeParsedImport {
+                is_relative,
+                is_external,
+                named_imports,
+                default_import: None,
+                namespace_import: None,
+                is_re_export,
+                range: extract_range(node),
+                relative_level: super_depth(&specifier),
+                origin: Some(origin.to_string()),
+                is_star_re_export,
+                specifier,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
 fn parse_use_wildcard(node: Node<'_>, source: &[u8]) -> Option<(String, Vec<String>)> {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -130,63 +184,129 @@ fn parse_use_as_clause(node: Node<'_>, source: &[u8]) -> Option<(String, Vec<Str
     Some((specifier, vec![alias]))
 }
 
-fn parse_scoped_use_list(node: Node<'_>, source: &[u8]) -> Option<(String, Vec<String>)> {
-    let path_node = node.child_by_field_name("path")?;
-    let specifier = node_text(path_node, source).to_string();
+fn parse_scoped_use_list(
+    node: Node<'_>,
+    prefix: &str,
+    source: &[u8],
+    groups: &mut Vec<(String, Vec<String>)>,
+) {
+    let path = node
+        .child_by_field_name("path")
+        .map(|path_node| node_text(path_node, source))
+        .unwrap_or("");
+    let specifier = join_path(prefix, path);
     if specifier.is_empty() {
-        return None;
+        return;
     }
 
-    let list_node = node.child_by_field_name("list");
+    match node.child_by_field_name("list") {
+        Some(list_node) => parse_use_list(list_node, &specifier, source, groups),
+        None => {
+            let imported = specifier.rsplit("::").next().unwrap_or("").to_string();
+            if !imported.is_empty() {
+                groups.push((specifier, vec![imported]));
+            }
+        }
+    }
+}
+
+/// Collect the names bound directly by a `{ ... }` list under `prefix`;
+/// nested scoped lists are flattened into their own groups after it.
+fn parse_use_list(
+    list_node: Node<'_>,
+    prefix: &str,
+    source: &[u8],
+    groups: &mut Vec<(String, Vec<String>)>,
+) {
     let mut named_imports = Vec::new();
+    let mut nested = Vec::new();
 
-    if let Some(list_node) = list_node {
-        let mut cursor = list_node.walk();
-        for child in list_node.children(&mut cursor) {
-            match child.kind() {
-                "use_as_clause" => {
-                    if let Some(alias_node) = child.child_by_field_name("alias") {
-                        let alias = node_text(alias_node, source).to_string();
-                        if !alias.is_empty() {
-                            named_imports.push(alias);
-                        }
-                    } else if let Some(name_node) = child.child_by_field_name("name") {
-                        let name = node_text(name_node, source).to_string();
-                        if !name.is_empty() {
-                            named_imports.push(name);
-                        }
+    let mut cursor = list_node.walk();
+    for child in list_node.children(&mut cursor) {
+        match child.kind() {
+            "use_as_clause" => {
+                if let Some(alias_node) = child.child_by_field_name("alias") {
+                    let alias = node_text(alias_node, source).to_string();
+                    if !alias.is_empty() {
+                        named_imports.push(alias);
                     }
-                }
-                "use_wildcard" => named_imports.push("*".to_string()),
-                "scoped_identifier" => {
-                    let value = node_text(child, source);
-                    let imported = value.rsplit("::").next().unwrap_or("").to_string();
-                    if !imported.is_empty() {
-                        named_imports.push(imported);
+                } else if let Some(name_node) = child.child_by_field_name("name") {
+                    let name = node_text(name_node, source).to_string();
+                    if !name.is_empty() {
+                        named_imports.push(name);
                     }
                 }
-                "identifier" => {
-                    let imported = node_text(child, source).to_string();
-                    if !imported.is_empty() && imported != "self" {
-                        named_imports.push(imported);
-                    }
+            }
+            "use_wildcard" => named_imports.push("*".to_string()),
+            "scoped_identifier" => {
+                let value = node_text(child, source);
+                let imported = value.rsplit("::").next().unwrap_or("").to_string();
+                if !imported.is_empty() {
+                    named_imports.push(imported);
+                }
+            }
+            "identifier" => {
+                let imported = node_text(child, source).to_string();
+                if !imported.is_empty() {
+                    named_imports.push(imported);
                 }
-                "self" => {}
-                _ => {}
             }
+            // `use std::io::{self, Read}` binds the module itself as `io`.
+            "self" => {
+                let module = prefix.rsplit("::").next().unwrap_or("");
+                if !module.is_empty() && !matches!(module, "crate" | "self" | "super") {
+                    named_imports.push(module.to_string());
+                }
+            }
+            "scoped_use_list" | "use_list" => nested.push(child),
+            _ => {}
         }
-    } else {
-        let imported = specifier.rsplit("::").next().unwrap_or("").to_string();
-        if !imported.is_empty() {
-            named_imports.push(imported);
+    }
+
+    if !named_imports.is_empty() {
+        groups.push((prefix.to_string(), named_imports));
+    }
+
+    for child in nested {
+        if child.kind() == "use_list" {
+            parse_use_list(child, prefix, source, groups);
+        } else {
+            parse_scoped_use_list(child, prefix, source, groups);
         }
     }
+}
 
-    if named_imports.is_empty() {
-        return None;
+fn join_path(prefix: &str, path: &str) -> String {
+    match (prefix.is_empty(), path.is_empty()) {
+        (true, _) => path.to_string(),
+        (_, true) => prefix.to_string(),
+        _ => format!("{prefix}::{path}"),
+    }
+}
+
+/// Classify a use path by its first segment: `crate`/`self`/`super` paths
+/// are internal to the crate, `std`/`core`/`alloc` (and the compiler-provided
+/// `proc_macro`/`test`) are stdlib, and anything else names a dependency.
+fn classify_path_root(specifier: &str) -> &'static str {
+    let root = specifier
+        .trim_start_matches("::")
+        .split("::")
+        .next()
+        .unwrap_or("");
+    match root {
+        "crate" | "self" | "super" => "internal",
+        "std" | "core" | "alloc" | "proc_macro" | "test" => "stdlib",
+        _ => "external",
     }
+}
 
-    Some((specifier, named_imports))
+/// Number of leading `super::` segments (`super::super::x` -> 2).
+fn super_depth(specifier: &str) -> Option<u32> {
+    let depth = specifier
+        .split("::")
+        .take_while(|segment| *segment == "super")
+        .count() as u32;
+    (depth > 0).then_some(depth)
 }
 
 fn is_relative_specifier(specifier: &str) -> bool {
@@ -212,3 +332,49 @@ fn is_external_specifier(specifier: &str, is_relative: bool) -> bool {
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn rust_use_trees_are_flattened_and_classified() {
+        let source = r#"use std::{fs, io::{self, Read}};
+use super::super::config::Settings;
+pub use crate::model::User as Account;
+use serde::*;
+
+mod store;
+mod inline { }
+"#;
+
+        let parsed = parse_source("rs", "src/api/handlers.rs", source);
+
+        let find = |specifier: &str| {
+            parsed
+                .imports
+                .iter()
+                .find(|import| import.specifier == specifier)
+                .unwrap_or_else(|| panic!("expected {specifier} import, got {:?}", parsed.imports))
+        };
+
+        assert_eq!(find("std").named_imports, vec!["fs"]);
+        assert_eq!(find("std::io").named_imports, vec!["io", "Read"]);
+        assert_eq!(find("std::io").origin.as_deref(), Some("stdlib"));
+
+        let settings = find("super::super::config::Settings");
+        assert_eq!(settings.origin.as_deref(), Some("internal"));
+        assert_eq!(settings.relative_level, Some(2));
+
+        let account = find("crate::model::User");
+        assert!(account.is_re_export);
+        assert_eq!(account.named_imports, vec!["Account"]);
+
+        assert_eq!(find("serde").origin.as_deref(), Some("external"));
+        assert!(find("store").is_relative);
+        assert!(parsed
+            .imports
+            .iter()
+            .all(|import| import.specifier != "inline"));
+    }
+}
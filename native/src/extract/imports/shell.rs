@@ -48,6 +48,7 @@ fn process_source_command(node: Node<'_>, source: &[u8]) -> Option<NativeParsedI
         specifier.truncate(specifier.len().saturating_sub(1));
     }
 
+    let specifier = normalize_script_dir_prefix(&specifier);
     if specifier.is_empty() {
         return None;
     }
@@ -64,9 +65,34 @@ fn process_source_command(node: Node<'_>, source: &[u8]) -> Option<NativeParsedI
         namespace_import: None,
         is_re_export: false,
         range: extract_range(node),
+        ..Default::default()
     })
 }
 
+/// Scripts usually source siblings through their own directory
+/// (`source "$(dirname "$0")/lib.sh"`, `. "$SCRIPT_DIR/lib.sh"`). A leading
+/// expansion before the first `/` is rewritten to `.` so the edge resolves
+/// relative to the sourcing script.
+fn normalize_script_dir_prefix(specifier: &str) -> String {
+    if !specifier.starts_with('$') {
+        return specifier.to_string();
+    }
+
+    let mut depth = 0usize;
+    for (index, ch) in specifier.char_indices() {
+        match ch {
+            '(' | '{' => depth += 1,
+            ')' | '}' => depth = depth.saturating_sub(1),
+            '/' if depth == 0 => {
+                return format!(".{}", specifier[index..].replace(['"', '\''], ""));
+            }
+            _ => {}
+        }
+    }
+
+    specifier.to_string()
+}
+
 fn find_first_argument(node: Node<'_>) -> Option<Node<'_>> {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
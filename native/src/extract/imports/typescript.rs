@@ -1,47 +1,77 @@
 use tree_sitter::Node;
 
-use crate::types::NativeParsedImport;
+use crate::types::{NativeImportAlias, NativeParsedImport, NativeSymbolAttribute};
 
 use super::common::{
     extract_range, extract_string_value, find_child_by_kind, find_child_node, node_text,
 };
+use super::usage::count_binding_references;
 
+/// Node.js core modules importable without the `node:` prefix. Subpaths
+/// (`fs/promises`, `stream/web`) are matched on their first segment.
 const BUILTIN_MODULES: &[&str] = &[
-    "fs",
-    "path",
-    "os",
-    "http",
-    "https",
-    "url",
-    "querystring",
-    "stream",
-    "util",
-    "events",
+    "assert",
+    "async_hooks",
     "buffer",
-    "crypto",
-    "timers",
-    "cluster",
     "child_process",
-    "net",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
     "dgram",
+    "diagnostics_channel",
     "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
     "readline",
     "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
     "vm",
+    "wasi",
+    "worker_threads",
     "zlib",
-    "assert",
-    "tty",
-    "module",
-    "process",
-    "console",
 ];
 
+/// Runtime schemes whose modules are always built in (`node:test` and
+/// `node:sqlite` exist only in prefixed form).
+const BUILTIN_SCHEMES: &[&str] = &["node:", "bun:"];
+
 pub fn extract_imports_ts(root: Node<'_>, source: &[u8]) -> Vec<NativeParsedImport> {
     let mut imports = Vec::new();
     walk_for_imports(root, source, &mut imports);
+    count_binding_references(root, source, &mut imports, is_reference);
     imports
 }
 
+fn is_reference(node: Node<'_>) -> bool {
+    matches!(
+        node.kind(),
+        "identifier" | "type_identifier" | "shorthand_property_identifier"
+    )
+}
+
 fn walk_for_imports(root: Node<'_>, source: &[u8], imports: &mut Vec<NativeParsedImport>) {
     let mut stack = vec![root];
 
@@ -53,6 +83,13 @@ fn walk_for_imports(root: Node<'_>, source: &[u8], imports: &mut Vec<NativeParse
                     imports.push(import);
                 }
             }
+            "call_expression" => {
+                if let Some(import) =
+                    parse_dynamic_import(node, source).or_else(|| parse_require(node, source))
+                {
+                    imports.push(import);
+                }
+            }
             _ => {}
         }
 
@@ -80,26 +117,251 @@ fn extract_source_specifier(node: Node<'_>, source: &[u8]) -> Option<String> {
     None
 }
 
+fn is_relative_specifier(specifier: &str) -> bool {
+    specifier.starts_with("./") || specifier.starts_with("../")
+}
+
+fn is_builtin_specifier(specifier: &str) -> bool {
+    if BUILTIN_SCHEMES
+        .iter()
+        .any(|scheme| specifier.starts_with(scheme))
+    {
+        return true;
+    }
+    let first_segment = specifier.split('/').next().unwrap_or(specifier);
+    BUILTIN_MODULES.contains(&first_segment)
+}
+
+/// "internal" for relative specifiers, "stdlib" for runtime builtins,
+/// "external" otherwise (including URL, `npm:` and `jsr:` specifiers).
+fn classify_specifier(specifier: &str, is_relative: bool) -> &'static str {
+    if is_relative {
+        "internal"
+    } else if is_builtin_specifier(specifier) {
+        "stdlib"
+    } else {
+        "external"
+    }
+}
+
+/// `https` in `https://deno.land/x/mod.ts`, `npm` in `npm:lodash@4`, `jsr`
+/// in `jsr:@std/path`. Single letters are Windows drive paths, not schemes.
+fn specifier_scheme(specifier: &str) -> Option<String> {
+    let (scheme, _) = specifier.split_once(':')?;
+    let valid = scheme.len() > 1
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then(|| scheme.to_ascii_lowercase())
+}
+
+/// Import record with everything derived from the specifier filled in.
+fn classified_import(node: Node<'_>, specifier: String) -> NativeParsedImport {
+    let is_relative = is_relative_specifier(&specifier);
+    let origin = classify_specifier(&specifier, is_relative);
+    NativeParsedImport {
+        is_relative,
+        is_external: origin == "external",
+        origin: Some(origin.to_string()),
+        scheme: specifier_scheme(&specifier),
+        range: extract_range(node),
+        specifier,
+        ..Default::default()
+    }
+}
+
+/// First argument of a call when it is a plain string literal.
+fn literal_specifier(call: Node<'_>, source: &[u8]) -> Option<String> {
+    let args = call.child_by_field_name("arguments")?;
+    let mut cursor = args.walk();
+    let first = args
+        .named_children(&mut cursor)
+        .find(|child| child.kind() != "comment")?;
+    let specifier = match first.kind() {
+        "string" => extract_string_value(first, source)?,
+        "template_string" if first.named_child_count() == 0 => {
+            node_text(first, source).trim_matches('`').to_string()
+        }
+        _ => return None,
+    };
+    (!specifier.is_empty()).then_some(specifier)
+}
+
+/// CommonJS `require('y')` with a literal specifier, bound the way the
+/// result is used:
+///
+/// - `const x = require('y')` -> namespace import `x`
+/// - `const { a, b: c } = require('y')` -> named imports `a`, `c` (alias
+///   `b` -> `c`)
+/// - `const a = require('y').a` -> named import `a`
+/// - a bare `require('y')` -> no bindings (side-effect import)
+fn parse_require(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
+    let callee = node.child_by_field_name("function")?;
+    if callee.kind() != "identifier" || node_text(callee, source) != "require" {
+        return None;
+    }
+    let specifier = literal_specifier(node, source)?;
+
+    let mut import = classified_import(node, specifier);
+
+    // `require('y').a`: the binding takes one property of the module.
+    let mut value = node;
+    let mut property = None;
+    if let Some(parent) = node.parent().filter(|p| p.kind() == "member_expression") {
+        property = parent
+            .child_by_field_name("property")
+            .map(|prop| node_text(prop, source).to_string());
+        value = parent;
+    }
+
+    let Some(declarator) = value
+        .parent()
+        .filter(|parent| parent.kind() == "variable_declarator")
+        .filter(|declarator| declarator.child_by_field_name("value") == Some(value))
+    else {
+        return Some(import);
+    };
+    let Some(name) = declarator.child_by_field_name("name") else {
+        return Some(import);
+    };
+    // The declarator binds the names, so it is the import's extent.
+    import.range = extract_range(declarator);
+
+    match (name.kind(), property) {
+        ("identifier", Some(property)) => {
+            let local = node_text(name, source).to_string();
+            if local != property {
+                import.aliases.push(NativeImportAlias {
+                    imported: property,
+                    local: local.clone(),
+                });
+            }
+            import.named_imports.push(local);
+        }
+        ("identifier", None) => {
+            import.namespace_import = Some(node_text(name, source).to_string());
+        }
+        ("object_pattern", None) => {
+            let mut cursor = name.walk();
+            for entry in name.named_children(&mut cursor) {
+                match entry.kind() {
+                    "shorthand_property_identifier_pattern" => {
+                        import
+                            .named_imports
+                            .push(node_text(entry, source).to_string());
+                    }
+                    "pair_pattern" => {
+                        let (Some(key), Some(bound)) = (
+                            entry.child_by_field_name("key"),
+                            entry.child_by_field_name("value"),
+                        ) else {
+                            continue;
+                        };
+                        if bound.kind() != "identifier" {
+                            continue;
+                        }
+                        let local = node_text(bound, source).to_string();
+                        import.aliases.push(NativeImportAlias {
+                            imported: node_text(key, source).to_string(),
+                            local: local.clone(),
+                        });
+                        import.named_imports.push(local);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Some(import)
+}
+
+/// `import('./x')` with a literal specifier. Computed specifiers
+/// (`import(path)`, templates with substitutions) cannot be resolved and
+/// remain visible only as `dynamic` calls.
+fn parse_dynamic_import(node: Node<'_>, source: &[u8]) -> Option<NativeParsedImport> {
+    let callee = node.child_by_field_name("function")?;
+    if callee.kind() != "import"
+        && !(callee.kind() == "identifier" && node_text(callee, source) == "import")
+    {
+        return None;
+    }
+
+    let specifier = literal_specifier(node, source)?;
+
+    Some(NativeParsedImport {
+        is_dynamic: true,
+        attributes: dynamic_import_attributes(node, source),
+        ..classified_import(node, specifier)
+    })
+}
+
+/// `{ with: { type: 'json' } }` (or `assert:`) passed as the second
+/// argument of `import()`.
+fn dynamic_import_attributes(call: Node<'_>, source: &[u8]) -> Vec<NativeSymbolAttribute> {
+    let Some(args) = call.child_by_field_name("arguments") else {
+        return Vec::new();
+    };
+    let mut cursor = args.walk();
+    let Some(options) = args
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() != "comment")
+        .nth(1)
+        .filter(|options| options.kind() == "object")
+    else {
+        return Vec::new();
+    };
+
+    let mut inner = options.walk();
+    for pair in options.named_children(&mut inner) {
+        if pair.kind() != "pair" {
+            continue;
+        }
+        let key = pair
+            .child_by_field_name("key")
+            .map(|key| unquoted_name(key, source));
+        let value = pair.child_by_field_name("value");
+        if let (Some("with" | "assert"), Some(value)) = (key.as_deref(), value) {
+            if value.kind() == "object" {
+                return object_attributes(value, source);
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// `{ type: 'json', "resolution-mode": "import" }` -> key/value pairs.
+fn object_attributes(object: Node<'_>, source: &[u8]) -> Vec<NativeSymbolAttribute> {
+    let mut attributes = Vec::new();
+
+    let mut cursor = object.walk();
+    for pair in object.named_children(&mut cursor) {
+        if pair.kind() != "pair" {
+            continue;
+        }
+        let (Some(key), Some(value)) = (
+            pair.child_by_field_name("key"),
+            pair.child_by_field_name("value"),
+        ) else {
+            continue;
+        };
+        attributes.push(NativeSymbolAttribute {
+            key: unquoted_name(key, source),
+            value: unquoted_name(value, source),
+        });
+    }
+
+    attributes
+}
+
 fn parse_import_node(node: Node<'_>, specifier: &str, source: &[u8]) -> NativeParsedImport {
     let is_re_export = node.kind() == "export_statement";
-    let is_relative = specifier.starts_with("./") || specifier.starts_with("../");
-    let normalized_builtin = specifier
-        .strip_prefix("node:")
-        .unwrap_or(specifier)
-        .split('/')
-        .next()
-        .unwrap_or(specifier);
-    let is_external = !is_relative && !BUILTIN_MODULES.contains(&normalized_builtin);
 
     let mut result = NativeParsedImport {
-        specifier: specifier.to_string(),
-        is_relative,
-        is_external,
-        named_imports: Vec::new(),
-        default_import: None,
-        namespace_import: None,
         is_re_export,
-        range: extract_range(node),
+        ..classified_import(node, specifier.to_string())
     };
 
     let mut cursor = node.walk();
@@ -113,6 +375,7 @@ fn parse_import_node(node: Node<'_>, specifier: &str, source: &[u8]) -> NativePa
                 if let Some(named_node) = find_child_node(child, "named_imports") {
                     let names = extract_named_imports(named_node, source);
                     result.named_imports.extend(names);
+                    result.aliases.extend(extract_aliases(named_node, source));
                 }
 
                 if let Some(ns_node) = find_child_node(child, "namespace_import") {
@@ -124,16 +387,31 @@ fn parse_import_node(node: Node<'_>, specifier: &str, source: &[u8]) -> NativePa
             "named_imports" => {
                 let names = extract_named_imports(child, source);
                 result.named_imports.extend(names);
+                result.aliases.extend(extract_aliases(child, source));
             }
             "export_clause" => {
                 let names = extract_named_imports(child, source);
                 result.named_imports.extend(names);
+                result.aliases.extend(extract_aliases(child, source));
             }
             "namespace_import" => {
                 if let Some(name) = find_child_by_kind(child, "identifier", source) {
                     result.namespace_import = Some(name);
                 }
             }
+            "*" if is_re_export => {
+                result.is_star_re_export = true;
+            }
+            "namespace_export" => {
+                if let Some(name) = child.named_child(0) {
+                    result.namespace_import = Some(unquoted_name(name, source));
+                }
+            }
+            "import_attribute" => {
+                if let Some(object) = find_child_node(child, "object") {
+                    result.attributes = object_attributes(object, source);
+                }
+            }
             "identifier" => {
                 if is_re_export && result.default_import.is_none() {
                     let child_idx = child_index_in_parent(child, node);
@@ -200,6 +478,43 @@ fn extract_named_imports(node: Node<'_>, source: &[u8]) -> Vec<String> {
     names
 }
 
+/// `a as b` specifiers of an import / export clause. `default` and string
+/// module export names (`export { "x-y" as z }`) are kept as written, minus
+/// quotes.
+fn extract_aliases(node: Node<'_>, source: &[u8]) -> Vec<NativeImportAlias> {
+    let mut aliases = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() != "import_specifier" && child.kind() != "export_specifier" {
+            continue;
+        }
+        let (Some(name), Some(alias)) = (
+            child.child_by_field_name("name"),
+            child.child_by_field_name("alias"),
+        ) else {
+            continue;
+        };
+        let imported = unquoted_name(name, source);
+        let local = unquoted_name(alias, source);
+        if !imported.is_empty() && !local.is_empty() && imported != local {
+            aliases.push(NativeImportAlias { imported, local });
+        }
+    }
+
+    aliases
+}
+
+/// Identifier or string-literal text, without quotes.
+fn unquoted_name(node: Node<'_>, source: &[u8]) -> String {
+    if node.kind() == "string" {
+        if let Some(value) = extract_string_value(node, source) {
+            return value;
+        }
+    }
+    node_text(node, source).to_string()
+}
+
 fn find_all_children_by_kind(parent: Node<'_>, kind: &str, source: &[u8]) -> Vec<String> {
     let mut results = Vec::new();
     let mut cursor = parent.walk();
@@ -220,3 +535,158 @@ fn child_index_in_parent(child: Node<'_>, parent: Node<'_>) -> usize {
     }
     0
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn dynamic_imports_are_recorded_as_imports() {
+        let source = r#"import { route } from "./router";
+
+const Settings = lazy(() => import("./pages/settings"));
+
+export async function loadChart(name: string) {
+  const mod = await import(`./charts/${name}`);
+  const lib = await import("chart-lib");
+  return [mod, lib];
+}
+"#;
+
+        let parsed = parse_source("ts", "src/app.ts", source);
+
+        let dynamic: Vec<_> = parsed
+            .imports
+            .iter()
+            .filter(|import| import.is_dynamic)
+            .map(|import| {
+                (
+                    import.specifier.as_str(),
+                    import.is_relative,
+                    import.is_external,
+                )
+            })
+            .collect();
+        assert_eq!(
+            dynamic,
+            vec![
+                ("./pages/settings", true, false),
+                ("chart-lib", false, true)
+            ]
+        );
+        assert!(parsed
+            .imports
+            .iter()
+            .any(|import| import.specifier == "./router" && !import.is_dynamic));
+    }
+
+    #[test]
+    fn require_calls_are_recorded_as_imports_with_bindings() {
+        let source = r#"const express = require("express");
+const { join, resolve: resolvePath } = require("path");
+const helper = require("./util").format;
+require("./polyfill");
+"#;
+
+        let parsed = parse_source("js", "src/server.js", source);
+
+        let import = |specifier: &str| {
+            parsed
+                .imports
+                .iter()
+                .find(|import| import.specifier == specifier)
+                .unwrap_or_else(|| panic!("missing import {specifier}"))
+        };
+        let aliases = |specifier: &str| {
+            import(specifier)
+                .aliases
+                .iter()
+                .map(|alias| (alias.imported.as_str(), alias.local.as_str()))
+                .collect::<Vec<_>>()
+        };
+
+        let express = import("express");
+        assert_eq!(express.namespace_import.as_deref(), Some("express"));
+        assert!(express.is_external);
+
+        let path = import("path");
+        assert_eq!(path.named_imports, vec!["join", "resolvePath"]);
+        assert_eq!(aliases("path"), vec![("resolve", "resolvePath")]);
+        assert!(!path.is_external);
+
+        assert_eq!(import("./util").named_imports, vec!["helper"]);
+        assert_eq!(aliases("./util"), vec![("format", "helper")]);
+
+        let polyfill = import("./polyfill");
+        assert!(polyfill.is_relative);
+        assert!(polyfill.named_imports.is_empty());
+        assert!(polyfill.namespace_import.is_none());
+    }
+
+    #[test]
+    fn import_attributes_are_recorded() {
+        let source = r#"import data from "./data.json" with { type: "json" };
+import sheet from "./theme.css" with { type: "css" };
+import { helper } from "./helper";
+
+export async function loadLocale(locale: string) {
+  return import("./locales/en.json", { with: { type: "json" } });
+}
+"#;
+
+        let parsed = parse_source("ts", "src/data.ts", source);
+
+        let attributes = |specifier: &str| {
+            parsed
+                .imports
+                .iter()
+                .find(|import| import.specifier == specifier)
+                .unwrap_or_else(|| panic!("missing import {specifier}"))
+                .attributes
+                .iter()
+                .map(|attr| (attr.key.clone(), attr.value.clone()))
+                .collect::<Vec<_>>()
+        };
+        let pair = |key: &str, value: &str| vec![(key.to_string(), value.to_string())];
+
+        assert_eq!(attributes("./data.json"), pair("type", "json"));
+        assert_eq!(attributes("./theme.css"), pair("type", "css"));
+        assert_eq!(attributes("./locales/en.json"), pair("type", "json"));
+        assert!(attributes("./helper").is_empty());
+    }
+
+    #[test]
+    fn url_and_registry_specifiers_carry_their_scheme() {
+        let source = r#"import { serve } from "https://deno.land/std@0.200.0/http/server.ts";
+import chalk from "npm:chalk@5";
+import { join } from "jsr:@std/path";
+import { readFile } from "node:fs/promises";
+import { local } from "./local.ts";
+"#;
+
+        let parsed = parse_source("ts", "main.ts", source);
+
+        let summary: Vec<_> = parsed
+            .imports
+            .iter()
+            .map(|import| {
+                (
+                    import.scheme.as_deref(),
+                    import.is_relative,
+                    import.is_external,
+                    import.origin.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Some("https"), false, true, Some("external")),
+                (Some("npm"), false, true, Some("external")),
+                (Some("jsr"), false, true, Some("external")),
+                (Some("node"), false, false, Some("stdlib")),
+                (None, true, false, Some("internal")),
+            ]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::types::{NativeBindingUsage, NativeParsedImport};
+
+use super::common::node_text;
+
+/// Count, for every binding an import introduces (default, namespace and
+/// named imports), the identifier references to it elsewhere in the file.
+///
+/// Matching is by name, not by scope: a local that shadows an import is
+/// counted as a reference to it. Identifiers inside an import's own range
+/// (the declaration itself) are skipped, as are re-exports, whose names are
+/// never bound locally. `is_reference` picks the nodes that are variable
+/// references (e.g. not attribute names after a dot).
+pub(crate) fn count_binding_references(
+    root: Node<'_>,
+    source: &[u8],
+    imports: &mut [NativeParsedImport],
+    is_reference: fn(Node<'_>) -> bool,
+) {
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for import in imports.iter().filter(|import| !import.is_re_export) {
+        for name in bindings(import) {
+            counts.insert(name.to_string(), 0);
+        }
+    }
+    if counts.is_empty() {
+        return;
+    }
+
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if is_reference(node) {
+            if let Some(count) = counts.get_mut(node_text(node, source)) {
+                if !inside_declaration(node, imports) {
+                    *count += 1;
+                }
+            }
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    for import in imports.iter_mut().filter(|import| !import.is_re_export) {
+        import.binding_usages = bindings(import)
+            .map(|name| NativeBindingUsage {
+                name: name.to_string(),
+                references: counts.get(name).copied().unwrap_or(0),
+            })
+            .collect();
+    }
+}
+
+/// Local names bound by an import. `*` (wildcard imports) binds nothing
+/// nameable.
+fn bindings(import: &NativeParsedImport) -> impl Iterator<Item = &str> {
+    import
+        .default_import
+        .iter()
+        .chain(import.namespace_import.iter())
+        .chain(import.named_imports.iter())
+        .map(String::as_str)
+        .filter(|name| !name.is_empty() && *name != "*")
+}
+
+fn inside_declaration(node: Node<'_>, imports: &[NativeParsedImport]) -> bool {
+    let start = node.start_position();
+    let end = node.end_position();
+    let from = (start.row as u32 + 1, start.column as u32);
+    let to = (end.row as u32 + 1, end.column as u32);
+    imports.iter().any(|import| {
+        let range = &import.range;
+        from >= (range.start_line, range.start_col) && to <= (range.end_line, range.end_col)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn imported_bindings_record_reference_counts() {
+        let cases = [
+            (
+                "tsx",
+                r#"import React, { useState, useMemo } from "react";
+import type { User } from "./types";
+import * as api from "./api";
+const lodash = require("lodash");
+export { helper } from "./helper";
+
+export function Profile({ user }: { user: User }) {
+  const [name, setName] = useState(user.name);
+  api.track(name);
+  api.save({ name });
+  return <div>{name}</div>;
+}
+"#,
+                vec![
+                    ("React", 0),
+                    ("useState", 1),
+                    ("useMemo", 0),
+                    ("User", 1),
+                    ("api", 2),
+                    ("lodash", 0),
+                ],
+            ),
+            (
+                "py",
+                "import os\nfrom typing import Optional, List\nfrom .models import User as Account\n\ndef load(path: Optional[str]) -> Account:\n    return Account(os.path.join(path, \"x\"), List=1)\n",
+                vec![("os", 1), ("Optional", 1), ("List", 0), ("Account", 2)],
+            ),
+        ];
+
+        for (language, source, expected) in cases {
+            let parsed = parse_source(language, &format!("src/profile.{language}"), source);
+
+            for (name, references) in expected {
+                let usage = parsed
+                    .imports
+                    .iter()
+                    .flat_map(|import| import.binding_usages.iter())
+                    .find(|usage| usage.name == name)
+                    .unwrap_or_else(|| panic!("missing {language} binding {name}"));
+                assert_eq!(usage.references, references, "{language} {name}");
+            }
+            assert!(parsed
+                .imports
+                .iter()
+                .filter(|import| import.is_re_export)
+                .all(|import| import.binding_usages.is_empty()));
+        }
+    }
+}
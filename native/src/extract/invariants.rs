@@ -2,7 +2,14 @@ use regex::Regex;
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
-use crate::types::NativeParsedSymbol;
+use tree_sitter::Node;
+
+use crate::types::{NativeBehaviorRecord, NativeParsedSymbol, NativeRange};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_at_range, node_text};
+use super::roles::is_test_path;
+use super::routes::common::callable_node_id;
+use super::side_effects::evidence;
 
 /// Extract invariants from a symbol's code and JSDoc.
 ///
@@ -11,10 +18,14 @@ use crate::types::NativeParsedSymbol;
 /// Detects:
 /// - JSDoc @param with "must", "required", "should be", "cannot be"
 /// - JSDoc @throws
-/// - `assert()` calls
-/// - Guard clauses: `if (!x) throw/return`
-/// - Null/undefined checks: `if (x === null || x === undefined) throw`
-pub fn extract_invariants(symbol: &NativeParsedSymbol, file_content: &str) -> Vec<String> {
+/// - Explicit raises, panics and error propagation
+///
+/// Guard clauses, assertions, panics and schema validation need the
+/// syntax tree and are added by [`annotate_code_invariants`].
+pub fn extract_invariants(
+    symbol: &NativeParsedSymbol,
+    file_content: &str,
+) -> Vec<NativeBehaviorRecord> {
     let mut invariants = Vec::new();
 
     // Extract JSDoc invariants
@@ -24,118 +35,102 @@ pub fn extract_invariants(symbol: &NativeParsedSymbol, file_content: &str) -> Ve
     // Extract code-level invariants
     let lines = get_symbol_lines(symbol, file_content);
 
-    static RE_ASSERT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"assert\(([^)]+)\)").unwrap());
-    static RE_ASSERT_NO_PARENS: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"\bassert\s+(.+)").unwrap());
-    static RE_GUARD: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"if\s*\(!([^)]+)\)\s*(?:\{|throw|return)").unwrap());
-    static RE_THROW_GUARD: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"if\s*\(([^)]+)\)\s*(?:\{|throw|return)").unwrap());
-    static RE_PY_RAISE: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"raise\s+\w+(?:Error|Exception)\s*\(").unwrap());
-    static RE_PY_IF_NOT: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"if\s+not\s+([^:]+)").unwrap());
-    static RE_GO_ERR_GUARD: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"if\s+err\s*!=\s*nil").unwrap());
-    static RE_PANIC: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"panic\s*\(").unwrap());
-    static RE_RUST_ASSERT: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"assert(?:_eq|_ne)?!\s*\(").unwrap());
-    static RE_RUST_UNWRAP_EXPECT: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"\.(?:unwrap|expect)\s*\(").unwrap());
-    static RE_RUST_TRY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\?(?:\s|;)").unwrap());
-    static RE_JAVA_NONNULL: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"Objects\.requireNonNull\s*\(").unwrap());
-
-    for line in &lines {
-        // assert() calls
-        if line.contains("assert(") {
-            if let Some(caps) = RE_ASSERT.captures(line) {
-                invariants.push(format!("Asserts: {}", &caps[1]));
-            }
-        }
-
-        if let Some(caps) = RE_ASSERT_NO_PARENS.captures(line) {
-            invariants.push(format!("Asserts: {}", caps[1].trim()));
-        }
-
-        // Guard clauses: if (!x) throw/return
-        if line.contains("if (!") || line.contains("if (! ") {
-            if let Some(caps) = RE_GUARD.captures(line) {
-                invariants.push(format!("Requires: {}", caps[1].trim()));
-            }
-        }
-
-        // if (condition) throw new / return false
-        if line.contains("if (") && (line.contains("throw new") || line.contains("return false")) {
-            if let Some(caps) = RE_THROW_GUARD.captures(line) {
-                let condition = caps[1].trim();
-                if condition.contains('!')
-                    || condition.contains("undefined")
-                    || condition.contains("null")
-                {
-                    invariants.push(format!("Requires: {condition}"));
-                }
-            }
-        }
-
-        if RE_PY_RAISE.is_match(line) {
-            invariants.push("Raises explicit error".to_string());
-        }
-
-        if let Some(caps) = RE_PY_IF_NOT.captures(line) {
-            invariants.push(format!("Requires: {}", caps[1].trim()));
-        }
-
-        if RE_GO_ERR_GUARD.is_match(line) {
-            invariants.push("Requires: err == nil".to_string());
-        }
-
-        if RE_PANIC.is_match(line) {
-            invariants.push("Panics on violated assumption".to_string());
-        }
-
-        if RE_RUST_ASSERT.is_match(line) {
-            invariants.push("Asserts: rust assertion macro".to_string());
-        }
-
-        if RE_RUST_UNWRAP_EXPECT.is_match(line) {
-            invariants.push("Requires: result is Ok/Some".to_string());
-        }
-
-        if RE_RUST_TRY.is_match(line) {
-            invariants.push("Propagates errors via ? operator".to_string());
-        }
+    /// (pattern, invariant, category, confidence); `?` also matches
+    /// ternaries and `.expect(` assertion chains, so those score low.
+    static LINE_PATTERNS: LazyLock<Vec<(Regex, &str, &str, f64)>> = LazyLock::new(|| {
+        [
+            (
+                r"raise\s+\w+(?:Error|Exception)\s*\(",
+                "Raises explicit error",
+                "raises",
+                0.8,
+            ),
+            (
+                r"if\s+err\s*!=\s*nil",
+                "Requires: err == nil",
+                "requires",
+                0.8,
+            ),
+            (
+                r"panic\s*\(",
+                "Panics on violated assumption",
+                "panics",
+                0.7,
+            ),
+            (
+                r"\.(?:unwrap|expect)\s*\(",
+                "Requires: result is Ok/Some",
+                "requires",
+                0.5,
+            ),
+            (
+                r"\?(?:\s|;)",
+                "Propagates errors via ? operator",
+                "propagates",
+                0.4,
+            ),
+            (
+                r"Objects\.requireNonNull\s*\(",
+                "Requires: non-null argument",
+                "requires",
+                0.9,
+            ),
+        ]
+        .into_iter()
+        .map(|(pattern, text, category, confidence)| {
+            (Regex::new(pattern).unwrap(), text, category, confidence)
+        })
+        .collect()
+    });
 
-        if RE_JAVA_NONNULL.is_match(line) {
-            invariants.push("Requires: non-null argument".to_string());
+    for (offset, line) in lines.iter().enumerate() {
+        let line_number = symbol.range.start_line.max(1) + offset as u32;
+        for (pattern, text, category, confidence) in LINE_PATTERNS.iter() {
+            if let Some(found) = pattern.find(line) {
+                invariants.push(NativeBehaviorRecord {
+                    text: text.to_string(),
+                    category: category.to_string(),
+                    evidence: evidence(line),
+                    range: NativeRange {
+                        start_line: line_number,
+                        start_col: found.start() as u32,
+                        end_line: line_number,
+                        end_col: found.end() as u32,
+                    },
+                    confidence: *confidence,
+                });
+            }
         }
     }
 
     // Deduplicate while preserving order
     let mut seen = HashSet::new();
-    invariants.retain(|item| seen.insert(item.clone()));
+    invariants.retain(|item| seen.insert(item.text.clone()));
     invariants
 }
 
-fn extract_jsdoc_invariants(symbol: &NativeParsedSymbol, file_content: &str) -> Vec<String> {
+fn extract_jsdoc_invariants(
+    symbol: &NativeParsedSymbol,
+    file_content: &str,
+) -> Vec<NativeBehaviorRecord> {
     let mut invariants = Vec::new();
     let lines: Vec<&str> = file_content.lines().collect();
     let start_line = symbol.range.start_line as usize;
 
-    // Walk backwards to find JSDoc
-    let mut jsdoc_lines: Vec<String> = Vec::new();
+    // Walk backwards to find JSDoc; entries are (line index, text).
+    let mut jsdoc_lines: Vec<(usize, String)> = Vec::new();
     let mut i = if start_line > 0 { start_line - 1 } else { 0 };
 
     while i < lines.len() {
         let line = lines[i].trim();
 
         if line.starts_with("/**") {
-            jsdoc_lines.insert(0, line.to_string());
+            jsdoc_lines.insert(0, (i, line.to_string()));
             break;
         }
 
         if line.starts_with('*') || line.starts_with("*/") {
-            jsdoc_lines.insert(0, line.to_string());
+            jsdoc_lines.insert(0, (i, line.to_string()));
             if i == 0 {
                 break;
             }
@@ -154,25 +149,29 @@ fn extract_jsdoc_invariants(symbol: &NativeParsedSymbol, file_content: &str) ->
         break;
     }
 
-    let jsdoc_text: String = jsdoc_lines
-        .iter()
-        .map(|l| {
-            l.trim_start_matches(|c: char| c.is_whitespace())
-                .trim_start_matches("/**")
-                .trim_start_matches("*/")
-                .trim_start_matches('*')
-                .trim_start()
-                .to_string()
-        })
-        .collect::<Vec<_>>()
-        .join("\n");
-
     static RE_PARAM: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"@param\s+(\{[^}]+\})?\s*(\w+)\s+(.+)").unwrap());
     static RE_THROWS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"@throws\s+(.+)").unwrap());
 
-    for line in jsdoc_text.lines() {
-        let trimmed = line.trim();
+    for (index, l) in &jsdoc_lines {
+        let trimmed = l
+            .trim_start_matches(|c: char| c.is_whitespace())
+            .trim_start_matches("/**")
+            .trim_start_matches("*/")
+            .trim_start_matches('*')
+            .trim();
+        let documented = |text: String, category: &str| NativeBehaviorRecord {
+            text,
+            category: category.to_string(),
+            evidence: evidence(trimmed),
+            range: NativeRange {
+                start_line: *index as u32 + 1,
+                start_col: 0,
+                end_line: *index as u32 + 1,
+                end_col: lines[*index].len() as u32,
+            },
+            confidence: 0.8,
+        };
 
         if let Some(caps) = RE_PARAM.captures(trimmed) {
             let param_name = &caps[2];
@@ -183,12 +182,15 @@ fn extract_jsdoc_invariants(symbol: &NativeParsedSymbol, file_content: &str) ->
                 || lower.contains("should be")
                 || lower.contains("cannot be")
             {
-                invariants.push(format!("@param {param_name}: {description}"));
+                invariants.push(documented(
+                    format!("@param {param_name}: {description}"),
+                    "requires",
+                ));
             }
         }
 
         if let Some(caps) = RE_THROWS.captures(trimmed) {
-            invariants.push(format!("@throws {}", caps[1].trim()));
+            invariants.push(documented(format!("@throws {}", caps[1].trim()), "throws"));
         }
     }
 
@@ -201,3 +203,1151 @@ fn get_symbol_lines<'a>(symbol: &NativeParsedSymbol, file_content: &'a str) -> V
     let end = (symbol.range.end_line as usize).min(lines.len());
     lines[start..end].to_vec()
 }
+
+/// Symbol kinds whose body is checked for guards and assertions.
+const CALLABLE_KINDS: &[&str] = &["function", "method", "constructor"];
+
+const BODIES: &[&str] = &["statement_block", "block", "compound_statement"];
+
+const NESTED_DECLARATIONS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "method_declaration",
+    "function_definition",
+    "lambda",
+    "func_literal",
+    "closure_expression",
+    "function_item",
+    "class_declaration",
+    "class_definition",
+];
+
+/// Rust `if` is an expression, wrapped in an `expression_statement` unless
+/// it ends the block.
+const IF_STATEMENTS: &[&str] = &["if_statement", "if_expression"];
+
+/// Statements that leave the function.
+const THROWS: &[&str] = &["throw_statement", "raise_statement"];
+const RETURNS: &[&str] = &["return_statement", "return_expression"];
+
+/// Rust macros that abort instead of returning.
+const PANIC_MACROS: &[&str] = &["panic", "unreachable", "todo", "unimplemented"];
+
+/// Rust assertion macros and the comparison `assert_eq!` / `assert_ne!`
+/// stand for.
+const ASSERT_MACROS: &[(&str, Option<&str>)] = &[
+    ("assert", None),
+    ("debug_assert", None),
+    ("assert_eq", Some("==")),
+    ("debug_assert_eq", Some("==")),
+    ("assert_ne", Some("!=")),
+    ("debug_assert_ne", Some("!=")),
+];
+
+/// Go constructors of the error returned by a failing guard.
+const GO_ERROR_CALLS: &[&str] = &["errors.New", "fmt.Errorf"];
+
+/// Returned values that signal rejected input; any other early return is
+/// a fast path, not a precondition.
+const FAILURE_VALUES: &[&str] = &["null", "undefined", "None", "false", "False", "nil", "-1"];
+
+/// Statements that may precede or sit between guard clauses.
+const PROLOGUE_STATEMENTS: &[&str] = &[
+    "comment",
+    "line_comment",
+    "block_comment",
+    "lexical_declaration",
+    "variable_declaration",
+    "local_variable_declaration",
+    "short_var_declaration",
+    "var_declaration",
+    "let_declaration",
+    "assert_statement",
+];
+
+/// Calls whose first argument is asserted.
+const ASSERT_CALLS: &[&str] = &["assert", "assert.ok", "console.assert", "invariant"];
+
+/// Zod / joi methods that validate their first argument against the
+/// receiver schema.
+const SCHEMA_METHODS: &[&str] = &[
+    "parse",
+    "safeParse",
+    "parseAsync",
+    "safeParseAsync",
+    "validate",
+    "validateAsync",
+];
+
+/// `Joi.assert(value, schema)` / `Joi.attempt(value, schema)`.
+const JOI_CALLS: &[&str] = &["Joi.assert", "Joi.attempt"];
+
+/// Comparison operators and their negation.
+const NEGATED_OPERATORS: &[(&str, &str)] = &[
+    ("===", "!=="),
+    ("!==", "==="),
+    ("==", "!="),
+    ("!=", "=="),
+    ("<", ">="),
+    (">=", "<"),
+    (">", "<="),
+    ("<=", ">"),
+    ("is", "is not"),
+    ("is not", "is"),
+    ("in", "not in"),
+    ("not in", "in"),
+];
+
+/// Append the invariants read from the syntax tree of every function,
+/// method and constructor:
+///
+/// - `Requires:` the negated condition of each leading guard clause, an
+///   `if` with no `else` whose branch ends in a throw / raise / panic or a
+///   bare or failure-value return (`if (!user) throw ...` requires `user`;
+///   Go `if err != nil { return nil, err }` requires `err == nil`)
+/// - `Asserts:` the condition of `assert(x)`, `console.assert(x)`,
+///   `invariant(x)` calls, `assert x` statements and Rust `assert!` /
+///   `debug_assert!` / `assert_eq!` / `assert_ne!`
+/// - `Requires:` / `Panics:` the message of Rust `panic!` and Go `panic`,
+///   and `Ensures: unreachable` for `unreachable!()`
+/// - `Validates:` arguments checked against a Zod or joi schema
+///   (`UserSchema.parse(input)`, `Joi.assert(input, schema)`)
+///
+/// Conditions are taken from the tree, so a guard whose condition spans
+/// several lines is read whole.
+pub fn annotate_code_invariants(
+    symbols: &mut [NativeParsedSymbol],
+    root: Node<'_>,
+    source: &[u8],
+    language: &str,
+) {
+    for symbol in symbols
+        .iter_mut()
+        .filter(|symbol| CALLABLE_KINDS.contains(&symbol.kind.as_str()))
+    {
+        let Some(body) = node_at_range(root, &symbol.range).and_then(function_body) else {
+            continue;
+        };
+        let mut found = guard_requirements(body, source, language);
+        collect_checks(body, source, &mut found);
+        for record in found {
+            add_invariant(symbol, record);
+        }
+    }
+}
+
+/// Append `record` unless the symbol already lists its text.
+fn add_invariant(symbol: &mut NativeParsedSymbol, record: NativeBehaviorRecord) {
+    if !symbol.invariants.contains(&record.text) {
+        symbol.invariants.push(record.text.clone());
+        symbol.invariant_records.push(record);
+    }
+}
+
+/// A record read from `node`.
+fn tree_record(
+    text: String,
+    category: &str,
+    node: Node<'_>,
+    source: &[u8],
+    confidence: f64,
+) -> NativeBehaviorRecord {
+    NativeBehaviorRecord {
+        text,
+        category: category.to_string(),
+        evidence: evidence(node_text(node, source)),
+        range: extract_range(node),
+        confidence,
+    }
+}
+
+/// The block body of the function declared at `node`, looking through
+/// export and variable wrappers (`export const f = () => { ... }`).
+fn function_body(node: Node<'_>) -> Option<Node<'_>> {
+    let mut queue = std::collections::VecDeque::from([node]);
+    while let Some(current) = queue.pop_front() {
+        if let Some(body) = current
+            .child_by_field_name("body")
+            .filter(|body| BODIES.contains(&body.kind()))
+        {
+            return Some(body);
+        }
+        let mut cursor = current.walk();
+        queue.extend(current.named_children(&mut cursor));
+    }
+    None
+}
+
+fn guard_requirements(body: Node<'_>, source: &[u8], language: &str) -> Vec<NativeBehaviorRecord> {
+    let mut records = Vec::new();
+    for (index, statement) in statements(body).into_iter().enumerate() {
+        let inner = unwrap_statement(statement);
+        if IF_STATEMENTS.contains(&inner.kind()) {
+            if !is_guard(inner, source) {
+                break;
+            }
+            if let Some(condition) = inner.child_by_field_name("condition") {
+                let mut requirements = Vec::new();
+                negate(condition, source, language, &mut requirements);
+                records.extend(requirements.into_iter().map(|requirement| {
+                    tree_record(
+                        format!("Requires: {requirement}"),
+                        "requires",
+                        inner,
+                        source,
+                        0.9,
+                    )
+                }));
+            }
+        } else if !PROLOGUE_STATEMENTS.contains(&statement.kind())
+            && !is_docstring(statement, index)
+            && !is_check_statement(statement, source)
+        {
+            break;
+        }
+    }
+    records
+}
+
+/// An `if` without `else` whose branch ends by leaving the function.
+fn is_guard(statement: Node<'_>, source: &[u8]) -> bool {
+    if statement.child_by_field_name("alternative").is_some() {
+        return false;
+    }
+    let Some(consequence) = statement.child_by_field_name("consequence") else {
+        return false;
+    };
+    let exit = if BODIES.contains(&consequence.kind()) {
+        statements(consequence)
+            .into_iter()
+            .rfind(|child| !child.kind().ends_with("comment"))
+    } else {
+        Some(consequence)
+    };
+    exit.is_some_and(|exit| is_exit(unwrap_statement(exit), source))
+}
+
+fn is_exit(statement: Node<'_>, source: &[u8]) -> bool {
+    if THROWS.contains(&statement.kind()) {
+        return true;
+    }
+    if statement.kind() == "macro_invocation" {
+        return macro_name(statement, source).is_some_and(|name| PANIC_MACROS.contains(&name));
+    }
+    if statement.kind() == "call_expression" {
+        // Go `panic(...)`.
+        return statement
+            .child_by_field_name("function")
+            .is_some_and(|function| node_text(function, source) == "panic");
+    }
+    if !RETURNS.contains(&statement.kind()) {
+        return false;
+    }
+    match statement.named_child(0) {
+        None => true,
+        Some(value) => is_failure_value(value, source),
+    }
+}
+
+/// The statements of a block; Go wraps them in a `statement_list`.
+fn statements(block: Node<'_>) -> Vec<Node<'_>> {
+    let mut statements = Vec::new();
+    let mut cursor = block.walk();
+    for child in block.named_children(&mut cursor) {
+        if child.kind() == "statement_list" {
+            let mut inner = child.walk();
+            statements.extend(child.named_children(&mut inner));
+        } else {
+            statements.push(child);
+        }
+    }
+    statements
+}
+
+/// `null` / `None` / `false`, Rust `Err(...)`, or a Go result list ending
+/// in an error (`return nil, err`, `return 0, errors.New("...")`).
+fn is_failure_value(value: Node<'_>, source: &[u8]) -> bool {
+    if FAILURE_VALUES.contains(&node_text(value, source).trim()) {
+        return true;
+    }
+    match value.kind() {
+        "call_expression" => value
+            .child_by_field_name("function")
+            .map(|function| node_text(function, source))
+            .is_some_and(|callee| callee == "Err" || GO_ERROR_CALLS.contains(&callee)),
+        "expression_list" => {
+            let mut cursor = value.walk();
+            let last = value.named_children(&mut cursor).last();
+            last.is_some_and(|last| match last.kind() {
+                "identifier" => {
+                    let name = node_text(last, source);
+                    name == "err" || name.ends_with("Err")
+                }
+                _ => is_failure_value(last, source),
+            })
+        }
+        _ => false,
+    }
+}
+
+/// The expression of a Rust / Go `expression_statement`, else the
+/// statement itself.
+fn unwrap_statement(statement: Node<'_>) -> Node<'_> {
+    match statement.named_child(0) {
+        Some(expression)
+            if statement.kind() == "expression_statement"
+                && statement.named_child_count() == 1
+                && (IF_STATEMENTS.contains(&expression.kind())
+                    || RETURNS.contains(&expression.kind())
+                    || matches!(expression.kind(), "macro_invocation" | "call_expression")) =>
+        {
+            expression
+        }
+        _ => statement,
+    }
+}
+
+/// A Python docstring: a string expression as the first statement.
+fn is_docstring(statement: Node<'_>, index: usize) -> bool {
+    index == 0
+        && statement.kind() == "expression_statement"
+        && statement
+            .named_child(0)
+            .is_some_and(|expression| expression.kind() == "string")
+}
+
+/// An expression statement that only asserts or validates.
+fn is_check_statement(statement: Node<'_>, source: &[u8]) -> bool {
+    if statement.kind() != "expression_statement" {
+        return false;
+    }
+    let mut checks = Vec::new();
+    if let Some(expression) = statement.named_child(0) {
+        let expression = if expression.kind() == "await_expression" {
+            expression.named_child(0).unwrap_or(expression)
+        } else {
+            expression
+        };
+        check(expression, source, &mut checks);
+    }
+    !checks.is_empty()
+}
+
+/// Push what must hold for `condition` to be false.
+fn negate(condition: Node<'_>, source: &[u8], language: &str, out: &mut Vec<String>) {
+    let condition = unwrap_parens(condition);
+    // C# unary expressions have no `operator` field; the first token is it.
+    let operator = condition
+        .child_by_field_name("operator")
+        .or_else(|| condition.child(0).filter(|first| !first.is_named()))
+        .map(|operator| node_text(operator, source));
+    match (condition.kind(), operator) {
+        ("unary_expression" | "prefix_unary_expression", Some("!")) | ("not_operator", _) => {
+            if let Some(argument) = condition
+                .child_by_field_name("argument")
+                .or_else(|| condition.child_by_field_name("operand"))
+                .or_else(|| condition.named_child(0))
+            {
+                out.push(text(unwrap_parens(argument), source));
+            }
+        }
+        // `a || b` fails when either holds, so both must be false.
+        ("binary_expression", Some("||")) | ("boolean_operator", Some("or")) => {
+            for side in ["left", "right"] {
+                if let Some(side) = condition.child_by_field_name(side) {
+                    negate(side, source, language, out);
+                }
+            }
+        }
+        _ => out.push(negated_comparison(condition, source).unwrap_or_else(|| {
+            let condition = text(condition, source);
+            if language == "py" {
+                format!("not ({condition})")
+            } else {
+                format!("!({condition})")
+            }
+        })),
+    }
+}
+
+/// `a === null` -> `a !== null`; Python `x is None` -> `x is not None`.
+fn negated_comparison(condition: Node<'_>, source: &[u8]) -> Option<String> {
+    let (left, operator, right) = match condition.kind() {
+        "binary_expression" => (
+            condition.child_by_field_name("left")?,
+            node_text(condition.child_by_field_name("operator")?, source).to_string(),
+            condition.child_by_field_name("right")?,
+        ),
+        "comparison_operator" => {
+            let mut cursor = condition.walk();
+            let operands: Vec<Node<'_>> = condition.named_children(&mut cursor).collect();
+            let [left, right] = operands[..] else {
+                return None;
+            };
+            let operator = node_text(condition, source)
+                .get(
+                    left.end_byte() - condition.start_byte()
+                        ..right.start_byte() - condition.start_byte(),
+                )?
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+            (left, operator, right)
+        }
+        _ => return None,
+    };
+    let &(_, negated) = NEGATED_OPERATORS
+        .iter()
+        .find(|(operator_text, _)| *operator_text == operator)?;
+    Some(format!(
+        "{} {negated} {}",
+        text(left, source),
+        text(right, source)
+    ))
+}
+
+/// Assertions and schema validations anywhere in the body, skipping
+/// nested functions and classes.
+fn collect_checks(body: Node<'_>, source: &[u8], out: &mut Vec<NativeBehaviorRecord>) {
+    let mut stack = vec![body];
+    while let Some(node) = stack.pop() {
+        if NESTED_DECLARATIONS.contains(&node.kind()) {
+            continue;
+        }
+        check(node, source, out);
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+}
+
+fn check(node: Node<'_>, source: &[u8], out: &mut Vec<NativeBehaviorRecord>) {
+    match node.kind() {
+        // Python / Java `assert x`.
+        "assert_statement" => {
+            if let Some(condition) = node.named_child(0) {
+                out.push(tree_record(
+                    format!("Asserts: {}", text(unwrap_parens(condition), source)),
+                    "asserts",
+                    node,
+                    source,
+                    0.95,
+                ));
+            }
+        }
+        "macro_invocation" => check_macro(node, source, out),
+        "call_expression" | "call" => {
+            let (Some(function), Some(arguments)) = (
+                node.child_by_field_name("function"),
+                node.child_by_field_name("arguments"),
+            ) else {
+                return;
+            };
+            let callee = node_text(function, source);
+            let first = arguments.named_child(0);
+            if callee == "panic" && node.kind() == "call_expression" {
+                // Go `panic(...)`.
+                if let Some(first) = first {
+                    out.push(panic_invariant(&text(first, source), node, source));
+                }
+            } else if ASSERT_CALLS.contains(&callee) {
+                if let Some(first) = first {
+                    out.push(tree_record(
+                        format!("Asserts: {}", text(first, source)),
+                        "asserts",
+                        node,
+                        source,
+                        0.95,
+                    ));
+                }
+            } else if JOI_CALLS.contains(&callee) {
+                if let (Some(value), Some(schema)) = (first, arguments.named_child(1)) {
+                    out.push(validation(value, schema, node, source));
+                }
+            } else if let Some(schema) = schema_receiver(function, source) {
+                if let Some(value) = first {
+                    out.push(validation(value, schema, node, source));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Schema checks are recognized by name, so they score below asserts.
+fn validation(
+    value: Node<'_>,
+    schema: Node<'_>,
+    call: Node<'_>,
+    source: &[u8],
+) -> NativeBehaviorRecord {
+    tree_record(
+        format!(
+            "Validates: {} against {}",
+            text(value, source),
+            schema_name(schema, source)
+        ),
+        "validates",
+        call,
+        source,
+        0.85,
+    )
+}
+
+/// Rust `assert!(x)` / `assert_eq!(a, b)` / `panic!("...")` /
+/// `unreachable!()`.
+fn check_macro(node: Node<'_>, source: &[u8], out: &mut Vec<NativeBehaviorRecord>) {
+    let Some(name) = macro_name(node, source) else {
+        return;
+    };
+    let arguments = macro_arguments(node, source);
+    if let Some(&(_, comparison)) = ASSERT_MACROS.iter().find(|(macro_, _)| *macro_ == name) {
+        let assertion = match (comparison, arguments.as_slice()) {
+            (None, [condition, ..]) => format!("Asserts: {condition}"),
+            (Some(operator), [left, right, ..]) => format!("Asserts: {left} {operator} {right}"),
+            _ => return,
+        };
+        out.push(tree_record(assertion, "asserts", node, source, 0.95));
+    } else if name == "unreachable" {
+        let ensures = match arguments.first() {
+            Some(message) => format!("Ensures: unreachable ({})", unquote(message)),
+            None => "Ensures: unreachable".to_string(),
+        };
+        out.push(tree_record(ensures, "ensures", node, source, 0.9));
+    } else if name == "panic" {
+        if let Some(message) = arguments.first() {
+            out.push(panic_invariant(message, node, source));
+        }
+    }
+}
+
+/// A panic message that states a requirement (`"must be positive"`,
+/// `"expected a header"`) is a `Requires:`; any other is a `Panics:`.
+/// Reading the requirement from prose is the weaker claim.
+fn panic_invariant(message: &str, node: Node<'_>, source: &[u8]) -> NativeBehaviorRecord {
+    let message = unquote(message);
+    let lower = message.to_ascii_lowercase();
+    if ["must ", "expected ", "should "]
+        .iter()
+        .any(|prefix| lower.starts_with(prefix) || lower.contains(&format!(" {prefix}")))
+    {
+        tree_record(
+            format!("Requires: {message}"),
+            "requires",
+            node,
+            source,
+            0.7,
+        )
+    } else {
+        tree_record(format!("Panics: {message}"), "panics", node, source, 0.9)
+    }
+}
+
+fn unquote(text: &str) -> &str {
+    text.strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .unwrap_or(text)
+}
+
+/// `std::assert!` -> `assert`.
+fn macro_name<'a>(node: Node<'a>, source: &'a [u8]) -> Option<&'a str> {
+    let name = node_text(node.child_by_field_name("macro")?, source);
+    Some(name.rsplit("::").next().unwrap_or(name))
+}
+
+/// The comma-separated arguments of a macro, split at the top level of
+/// its token tree.
+fn macro_arguments(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    let mut cursor = node.walk();
+    let Some(tokens) = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "token_tree")
+    else {
+        return Vec::new();
+    };
+    let inner = node_text(tokens, source);
+    let inner = inner.get(1..inner.len().saturating_sub(1)).unwrap_or("");
+
+    let mut arguments = Vec::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (index, c) in inner.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                arguments.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    arguments.push(&inner[start..]);
+    arguments
+        .into_iter()
+        .map(|argument| argument.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|argument| !argument.is_empty())
+        .collect()
+}
+
+/// `UserSchema.parse` / `z.string().email().safeParse` -> the schema.
+fn schema_receiver<'a>(function: Node<'a>, source: &[u8]) -> Option<Node<'a>> {
+    if function.kind() != "member_expression" {
+        return None;
+    }
+    let method = node_text(function.child_by_field_name("property")?, source);
+    let object = function.child_by_field_name("object")?;
+    let object_text = node_text(object, source);
+    let is_schema = object_text.to_ascii_lowercase().contains("schema")
+        || object_text.starts_with("z.")
+        || object_text.starts_with("Joi.");
+    (SCHEMA_METHODS.contains(&method) && is_schema).then_some(object)
+}
+
+/// A named schema, or "inline schema" for a built expression.
+fn schema_name(schema: Node<'_>, source: &[u8]) -> String {
+    if matches!(schema.kind(), "identifier" | "member_expression")
+        && !node_text(schema, source).contains('(')
+    {
+        node_text(schema, source).to_string()
+    } else {
+        "inline schema".to_string()
+    }
+}
+
+fn unwrap_parens(mut node: Node<'_>) -> Node<'_> {
+    while node.kind() == "parenthesized_expression" {
+        match node.named_child(0) {
+            Some(inner) => node = inner,
+            None => break,
+        }
+    }
+    node
+}
+
+/// Node text with whitespace runs collapsed, so multi-line conditions read
+/// as one line.
+fn text(node: Node<'_>, source: &[u8]) -> String {
+    node_text(node, source)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Append `Expects:` invariants for the assertions made by tests.
+///
+/// Jest / Vitest / chai `expect(x).toBe(y)` chains and chai / node
+/// `assert.equal(x, y)` calls document the test that makes them. These and
+/// pytest `assert` statements also document the function under test when
+/// it is declared in the same file and the subject calls it by name
+/// (`expect(add(1, 2)).toBe(3)` attaches `Expects: add(1, 2) toBe 3` to
+/// `add`).
+///
+/// Only test code counts: test files, and functions named `test*` /
+/// `Test*`.
+pub fn annotate_test_expectations(
+    symbols: &mut [NativeParsedSymbol],
+    root: Node<'_>,
+    source: &[u8],
+    rel_path: &str,
+) {
+    let test_file = is_test_path(rel_path);
+    let mut found: Vec<(String, NativeBehaviorRecord)> = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if let Some(expectation) = test_expectation(node, source) {
+            let caller = find_enclosing_symbol(node, symbols);
+            let caller_symbol = symbols.iter().find(|symbol| symbol.node_id == caller);
+            let in_test = test_file || caller_symbol.is_some_and(is_test_symbol);
+            if in_test {
+                let record = tree_record(expectation.text, "expects", node, source, 0.9);
+                let tested = expectation
+                    .subject
+                    .and_then(|subject| tested_symbol(subject, source, symbols))
+                    .filter(|tested| *tested != caller);
+                if let Some(tested) = tested {
+                    // Matched by name only.
+                    let mut tested_record = record.clone();
+                    tested_record.confidence = 0.8;
+                    found.push((tested, tested_record));
+                }
+                if expectation.documents_test && caller_symbol.is_some() {
+                    found.push((caller, record));
+                }
+            }
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    for (node_id, record) in found {
+        if let Some(symbol) = symbols.iter_mut().find(|symbol| symbol.node_id == node_id) {
+            add_invariant(symbol, record);
+        }
+    }
+}
+
+struct Expectation<'a> {
+    text: String,
+    /// The asserted expression, searched for the function under test.
+    subject: Option<Node<'a>>,
+    /// Whether the test itself gets the invariant; a pytest `assert` is
+    /// already an `Asserts:` of its test.
+    documents_test: bool,
+}
+
+fn test_expectation<'a>(node: Node<'a>, source: &[u8]) -> Option<Expectation<'a>> {
+    match node.kind() {
+        "assert_statement" => {
+            let condition = unwrap_parens(node.named_child(0)?);
+            Some(Expectation {
+                text: format!("Expects: {}", text(condition, source)),
+                subject: Some(condition),
+                documents_test: false,
+            })
+        }
+        "call_expression" | "member_expression" if is_chain_end(node) => {
+            expect_chain(node, source).or_else(|| assert_call(node, source))
+        }
+        _ => None,
+    }
+}
+
+/// `expect(x).not.toBe(y)` / chai `expect(x).to.be.true` -> `x not.toBe y`
+/// / `x to.be.true`.
+fn expect_chain<'a>(node: Node<'a>, source: &[u8]) -> Option<Expectation<'a>> {
+    let (matcher_end, arguments) = if node.kind() == "call_expression" {
+        let arguments = node.child_by_field_name("arguments")?;
+        (arguments.start_byte(), Some(arguments))
+    } else {
+        (node.end_byte(), None)
+    };
+
+    let mut current = node;
+    let root = loop {
+        let next = match current.kind() {
+            "member_expression" => current.child_by_field_name("object")?,
+            "call_expression" => current.child_by_field_name("function")?,
+            _ => return None,
+        };
+        if next.kind() == "call_expression"
+            && next
+                .child_by_field_name("function")
+                .is_some_and(|function| node_text(function, source) == "expect")
+        {
+            break next;
+        }
+        current = next;
+    };
+    let subject = root.child_by_field_name("arguments")?.named_child(0)?;
+    let matcher = std::str::from_utf8(source.get(root.end_byte()..matcher_end)?).ok()?;
+    // Whitespace first: a chain broken across lines starts with `\n  .not`.
+    let matcher = matcher.split_whitespace().collect::<Vec<_>>().join("");
+    let matcher = matcher.trim_start_matches(['.', '?']);
+    let mut text = format!("Expects: {} {matcher}", self::text(subject, source));
+    if let Some(expected) = arguments.and_then(|arguments| argument_list(arguments, source)) {
+        text.push(' ');
+        text.push_str(&expected);
+    }
+    Some(Expectation {
+        text,
+        subject: Some(subject),
+        documents_test: true,
+    })
+}
+
+/// chai / node `assert.equal(add(1, 2), 3)` -> `add(1, 2) equal 3`.
+fn assert_call<'a>(node: Node<'a>, source: &[u8]) -> Option<Expectation<'a>> {
+    let function = node
+        .child_by_field_name("function")
+        .filter(|function| function.kind() == "member_expression")?;
+    let object = function.child_by_field_name("object")?;
+    if node_text(object, source) != "assert" {
+        return None;
+    }
+    let method = node_text(function.child_by_field_name("property")?, source);
+    let arguments = node.child_by_field_name("arguments")?;
+    let subject = arguments.named_child(0)?;
+    let mut text = format!("Expects: {} {method}", self::text(subject, source));
+    let mut cursor = arguments.walk();
+    let rest: Vec<String> = arguments
+        .named_children(&mut cursor)
+        .skip(1)
+        .filter(|argument| !argument.kind().ends_with("comment"))
+        .map(|argument| self::text(argument, source))
+        .collect();
+    if !rest.is_empty() {
+        text.push(' ');
+        text.push_str(&rest.join(", "));
+    }
+    Some(Expectation {
+        text,
+        subject: Some(subject),
+        documents_test: true,
+    })
+}
+
+/// Whether `node` is the last link of a member / call chain.
+fn is_chain_end(node: Node<'_>) -> bool {
+    let Some(parent) = node.parent() else {
+        return true;
+    };
+    let continues = match parent.kind() {
+        "member_expression" => parent.child_by_field_name("object") == Some(node),
+        "call_expression" => parent.child_by_field_name("function") == Some(node),
+        _ => false,
+    };
+    !continues
+}
+
+/// `(a, b)` -> `a, b`; `None` for `()`.
+fn argument_list(arguments: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut cursor = arguments.walk();
+    let list: Vec<String> = arguments
+        .named_children(&mut cursor)
+        .filter(|argument| !argument.kind().ends_with("comment"))
+        .map(|argument| text(argument, source))
+        .collect();
+    (!list.is_empty()).then(|| list.join(", "))
+}
+
+/// The same-file function called by name in `subject`, or named by it
+/// (`expect(parse).toThrow()`).
+fn tested_symbol(
+    subject: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<String> {
+    let mut stack = vec![subject];
+    while let Some(node) = stack.pop() {
+        if matches!(node.kind(), "call_expression" | "call") {
+            let callee = node
+                .child_by_field_name("function")
+                .filter(|function| function.kind() == "identifier")
+                .and_then(|function| callable_node_id(node_text(function, source), symbols));
+            if callee.is_some() {
+                return callee;
+            }
+        }
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+    (subject.kind() == "identifier")
+        .then(|| callable_node_id(node_text(subject, source), symbols))
+        .flatten()
+}
+
+fn is_test_symbol(symbol: &NativeParsedSymbol) -> bool {
+    symbol.name.starts_with("test") || symbol.name.starts_with("Test")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn invariants_are_read_from_guard_clauses_asserts_and_schemas() {
+        let cases = [
+            (
+                "users.ts",
+                "ts",
+                r#"export function createUser(input: unknown, options?: Options) {
+  // validate first
+  const data = UserSchema.parse(input);
+  if (
+    !options ||
+    options.retries === undefined
+  ) {
+    throw new Error("options required");
+  }
+  assert(data.age >= 0);
+  if (cache.has(data.id)) return cache.get(data.id);
+  if (!data.name) throw new Error("late");
+  return data;
+}
+"#,
+            ),
+            (
+                "bank.py",
+                "py",
+                r#"def withdraw(account, amount):
+    """Withdraw money."""
+    if amount <= 0:
+        raise ValueError("amount must be positive")
+    if account is None:
+        return None
+    assert account.balance >= amount
+    account.balance -= amount
+"#,
+            ),
+        ];
+
+        let mut invariants = Vec::new();
+        for (rel_path, language, source) in cases {
+            let parsed = parse_source(language, rel_path, source);
+            let function = parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.kind == "function")
+                .expect("function symbol");
+            invariants.push(function.invariants.clone());
+        }
+
+        let ts = &invariants[0];
+        assert!(ts.contains(&"Requires: options".to_string()));
+        assert!(ts.contains(&"Requires: options.retries !== undefined".to_string()));
+        assert!(ts.contains(&"Asserts: data.age >= 0".to_string()));
+        assert!(ts.contains(&"Validates: input against UserSchema".to_string()));
+        // Guards after the first non-guard statement are not preconditions.
+        assert!(!ts.iter().any(|invariant| invariant.contains("cache")));
+        assert!(!ts.contains(&"Requires: data.name".to_string()));
+
+        let py = &invariants[1];
+        assert!(py.contains(&"Requires: amount > 0".to_string()));
+        assert!(py.contains(&"Requires: account is not None".to_string()));
+        assert!(py.contains(&"Asserts: account.balance >= amount".to_string()));
+    }
+
+    #[test]
+    fn rust_and_go_invariants_cover_asserts_panics_and_error_guards() {
+        let cases = [
+            (
+                "bank.rs",
+                "rs",
+                r#"pub fn withdraw(balance: u64, amount: u64) -> Result<u64, String> {
+    if amount == 0 {
+        return Err("amount must be positive".to_string());
+    }
+    assert!(balance >= amount, "insufficient funds");
+    debug_assert_eq!(amount % 1, 0);
+    match amount {
+        1 => Ok(balance - 1),
+        _ if amount > balance => unreachable!("checked above"),
+        _ => Ok(balance - amount),
+    }
+}
+
+pub fn port(value: &str) -> u16 {
+    if value.is_empty() {
+        panic!("port must be set");
+    }
+    value.parse().unwrap()
+}
+"#,
+            ),
+            (
+                "config.go",
+                "go",
+                r#"package config
+
+func Load(path string) (*Config, error) {
+	if path == "" {
+		return nil, errors.New("path required")
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	if len(data) > maxSize {
+		panic("config must be smaller than maxSize")
+	}
+	return parse(data)
+}
+"#,
+            ),
+        ];
+
+        let mut parsed_files = Vec::new();
+        for (rel_path, language, source) in cases {
+            parsed_files.push(parse_source(language, rel_path, source));
+        }
+        let invariants = |file: usize, name: &str| {
+            parsed_files[file]
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .map(|symbol| symbol.invariants.clone())
+                .unwrap_or_default()
+        };
+
+        let withdraw = invariants(0, "withdraw");
+        assert!(withdraw.contains(&"Requires: amount != 0".to_string()));
+        assert!(withdraw.contains(&"Asserts: balance >= amount".to_string()));
+        assert!(withdraw.contains(&"Asserts: amount % 1 == 0".to_string()));
+        assert!(withdraw.contains(&"Ensures: unreachable (checked above)".to_string()));
+        let port = invariants(0, "port");
+        assert!(port.contains(&"Requires: !(value.is_empty())".to_string()));
+        assert!(port.contains(&"Requires: port must be set".to_string()));
+
+        let load = invariants(1, "Load");
+        assert!(load.contains(&"Requires: path != \"\"".to_string()));
+        assert!(load.contains(&"Requires: err == nil".to_string()));
+        assert!(load.contains(&"Requires: len(data) <= maxSize".to_string()));
+        assert!(load.contains(&"Requires: config must be smaller than maxSize".to_string()));
+    }
+
+    #[test]
+    fn test_assertions_document_the_tested_function() {
+        let cases = [
+            (
+                "math.test.ts",
+                "ts",
+                r#"export function add(a: number, b: number) {
+  return a + b;
+}
+
+describe("add", () => {
+  it("sums", () => {
+    expect(add(1, 2)).toBe(3);
+    expect(add(-1, 1))
+      .not.toBeGreaterThan(0);
+  });
+});
+
+export function checkTotals(total: boolean) {
+  assert.equal(add(2, 2), 4);
+  expect(total).to.be.true;
+}
+"#,
+            ),
+            (
+                "test_calc.py",
+                "py",
+                r#"def double(x):
+    return x * 2
+
+def test_double():
+    assert double(2) == 4
+"#,
+            ),
+            (
+                "math.ts",
+                "ts",
+                r#"export function add(a: number, b: number) {
+  return a + b;
+}
+
+export function selfCheck() {
+  expect(add(1, 1)).toBe(2);
+}
+"#,
+            ),
+        ];
+
+        let mut parsed_files = Vec::new();
+        for (rel_path, language, source) in cases {
+            parsed_files.push(parse_source(language, rel_path, source));
+        }
+        let invariants = |file: usize, name: &str| {
+            parsed_files[file]
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .map(|symbol| symbol.invariants.clone())
+                .unwrap_or_default()
+        };
+
+        let add = invariants(0, "add");
+        assert!(add.contains(&"Expects: add(1, 2) toBe 3".to_string()));
+        assert!(add.contains(&"Expects: add(-1, 1) not.toBeGreaterThan 0".to_string()));
+        assert!(add.contains(&"Expects: add(2, 2) equal 4".to_string()));
+        let check_totals = invariants(0, "checkTotals");
+        assert!(check_totals.contains(&"Expects: add(2, 2) equal 4".to_string()));
+        assert!(check_totals.contains(&"Expects: total to.be.true".to_string()));
+
+        let double = invariants(1, "double");
+        assert_eq!(double, vec!["Expects: double(2) == 4".to_string()]);
+        let test_double = invariants(1, "test_double");
+        assert!(test_double.contains(&"Asserts: double(2) == 4".to_string()));
+        assert!(!test_double
+            .iter()
+            .any(|invariant| invariant.starts_with("Expects:")));
+
+        // Outside test code `expect` is an ordinary call.
+        assert!(invariants(2, "add").is_empty());
+    }
+
+    #[test]
+    fn invariant_and_side_effect_records_carry_evidence_and_confidence() {
+        let source = r#"export function load(id: number, path: string) {
+  if (!id) {
+    throw new Error("missing id");
+  }
+  const text = fs.readFileSync(path, "utf8");
+  return text;
+}
+
+export function touch(path: string) {
+  return open(path);
+}
+"#;
+
+        let parsed = parse_source("ts", "records.ts", source);
+
+        let symbol = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .expect("symbol")
+        };
+        let load = symbol("load");
+        let texts: Vec<&str> = load
+            .invariant_records
+            .iter()
+            .map(|record| record.text.as_str())
+            .collect();
+        assert_eq!(texts, load.invariants);
+
+        let guard = load
+            .invariant_records
+            .iter()
+            .find(|record| record.text == "Requires: id")
+            .expect("guard record");
+        assert_eq!(guard.category, "requires");
+        assert_eq!(guard.range.start_line, 2);
+        assert_eq!(guard.range.end_line, 4);
+        assert!(guard.evidence.starts_with("if (!id) { throw new Error"));
+
+        assert_eq!(load.side_effects, vec!["Filesystem I/O".to_string()]);
+        let read = &load.side_effect_records[0];
+        assert_eq!(read.category, "filesystem");
+        assert_eq!(
+            read.evidence,
+            "const text = fs.readFileSync(path, \"utf8\");"
+        );
+        assert_eq!(read.range.start_line, 5);
+        assert_eq!(read.range.start_col, 15);
+
+        // A bare `open(` may be anything; it is reported, but weakly.
+        let open = &symbol("touch").side_effect_records[0];
+        assert_eq!(open.text, "Filesystem I/O");
+        assert!(open.confidence < read.confidence);
+    }
+}
@@ -0,0 +1,545 @@
+//! Per-language extractor dispatch.
+//!
+//! Each tree-sitter grammar gets its own [`LanguageExtractor`] implementation
+//! that owns the node-kind handling for that grammar. `parse_single_file`
+//! looks the extractor up once per file and runs symbols, imports, and calls
+//! through it, so adding depth to one language never touches another.
+
+use tree_sitter::Node;
+
+use crate::types::{
+    NativeEventUsage, NativeFileFlags, NativeGraphqlResolver, NativeGrpcBinding, NativeHookUsage,
+    NativeHttpRoute, NativeOrmEntity, NativeParsedCall, NativeParsedImport, NativeParsedSymbol,
+};
+
+use super::{calls, events, file_flags, graphql, grpc, hooks, imports, orm, routes, symbols};
+
+/// Per-file inputs shared by every extraction pass.
+pub struct ExtractContext<'a> {
+    pub source: &'a [u8],
+    pub repo_id: &'a str,
+    pub rel_path: &'a str,
+    /// Enclosing Go module path, used to classify Go imports as internal.
+    pub module_path: Option<&'a str>,
+}
+
+/// Symbol, import, and call extraction for one tree-sitter grammar.
+///
+/// Grammars without imports or calls (e.g. JSON) rely on the empty defaults.
+pub trait LanguageExtractor: Sync {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol>;
+
+    fn imports(&self, _root: Node<'_>, _ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        vec![]
+    }
+
+    fn calls(
+        &self,
+        _root: Node<'_>,
+        _ctx: &ExtractContext<'_>,
+        _symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        vec![]
+    }
+
+    /// React hook usages; only the TS/JS family has them.
+    fn hooks(
+        &self,
+        _root: Node<'_>,
+        _ctx: &ExtractContext<'_>,
+        _symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeHookUsage> {
+        vec![]
+    }
+
+    /// Event-emitter listener registrations and emissions; only the TS/JS
+    /// family has them.
+    fn events(
+        &self,
+        _root: Node<'_>,
+        _ctx: &ExtractContext<'_>,
+        _symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeEventUsage> {
+        vec![]
+    }
+
+    /// HTTP route registrations; only grammars with a supported web
+    /// framework (Express / Fastify, FastAPI / Flask, Spring) have them.
+    fn routes(
+        &self,
+        _root: Node<'_>,
+        _ctx: &ExtractContext<'_>,
+        _symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeHttpRoute> {
+        vec![]
+    }
+
+    /// ORM entity classes with their table, columns and relations; only
+    /// grammars with a supported ORM (TypeORM / sequelize-typescript,
+    /// SQLAlchemy, Entity Framework) have them.
+    fn orm_entities(
+        &self,
+        _root: Node<'_>,
+        _ctx: &ExtractContext<'_>,
+        _symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeOrmEntity> {
+        vec![]
+    }
+
+    /// GraphQL resolvers bound to schema fields; only grammars with a
+    /// supported server library (Apollo-style maps / NestJS / TypeGraphQL,
+    /// Ariadne / Strawberry / Graphene) have them.
+    fn graphql_resolvers(
+        &self,
+        _root: Node<'_>,
+        _ctx: &ExtractContext<'_>,
+        _symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGraphqlResolver> {
+        vec![]
+    }
+
+    /// gRPC service registrations and the methods implementing their RPCs;
+    /// only grammars with a supported gRPC library have them.
+    fn grpc_bindings(
+        &self,
+        _root: Node<'_>,
+        _ctx: &ExtractContext<'_>,
+        _symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGrpcBinding> {
+        vec![]
+    }
+
+    /// Module-level flags; `None` for grammars without a module system that
+    /// runs code on import.
+    fn file_flags(&self, _root: Node<'_>, _ctx: &ExtractContext<'_>) -> Option<NativeFileFlags> {
+        None
+    }
+}
+
+/// Look up the extractor for a language identifier (see `lang::get_language`).
+pub fn extractor_for(language: &str) -> Option<&'static dyn LanguageExtractor> {
+    let extractor: &'static dyn LanguageExtractor = match language {
+        "c" => &CExtractor,
+        "cpp" => &CppExtractor,
+        "css" | "scss" => &CssExtractor,
+        "go" => &GoExtractor,
+        "html" => &HtmlExtractor,
+        "java" => &JavaExtractor,
+        "json" => &JsonExtractor,
+        "lua" => &LuaExtractor,
+        "php" => &PhpExtractor,
+        "py" => &PythonExtractor,
+        "rs" => &RustExtractor,
+        "sh" => &ShellExtractor,
+        "cs" => &CSharpExtractor,
+        "ts" | "tsx" | "js" | "jsx" => &TypeScriptExtractor,
+        _ => return None,
+    };
+    Some(extractor)
+}
+
+struct CExtractor;
+
+impl LanguageExtractor for CExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::c_lang::extract_symbols_c(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::c_lang::extract_imports_c(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::c_lang::extract_calls_c(root, ctx.source, symbols)
+    }
+}
+
+struct CppExtractor;
+
+impl LanguageExtractor for CppExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::cpp::extract_symbols_cpp(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::cpp::extract_imports_cpp(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::cpp::extract_calls_cpp(root, ctx.source, symbols)
+    }
+}
+
+struct CssExtractor;
+
+impl LanguageExtractor for CssExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::css::extract_symbols_css(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::css::extract_imports_css(root, ctx.source)
+    }
+}
+
+struct GoExtractor;
+
+impl LanguageExtractor for GoExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::go::extract_symbols_go(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::go::extract_imports_go(root, ctx.source, ctx.module_path)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::go::extract_calls_go(root, ctx.source, symbols)
+    }
+
+    fn grpc_bindings(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGrpcBinding> {
+        grpc::go::extract_grpc_bindings_go(root, ctx.source, symbols)
+    }
+}
+
+/// HTML declares no symbols of its own; it contributes `<script src>`
+/// imports and template-to-script calls.
+struct HtmlExtractor;
+
+impl LanguageExtractor for HtmlExtractor {
+    fn symbols(&self, _root: Node<'_>, _ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        vec![]
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::html::extract_imports_html(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::html::extract_calls_html(root, ctx.source, symbols)
+    }
+}
+
+struct JavaExtractor;
+
+impl LanguageExtractor for JavaExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::java::extract_symbols_java(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::java::extract_imports_java(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::java::extract_calls_java(root, ctx.source, symbols)
+    }
+
+    fn routes(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeHttpRoute> {
+        routes::java::extract_routes_java(root, ctx.source, symbols)
+    }
+
+    fn grpc_bindings(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGrpcBinding> {
+        grpc::java::extract_grpc_bindings_java(root, ctx.source, symbols)
+    }
+}
+
+struct JsonExtractor;
+
+impl LanguageExtractor for JsonExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::json::extract_symbols_json(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+}
+
+struct LuaExtractor;
+
+impl LanguageExtractor for LuaExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::lua::extract_symbols_lua(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::lua::extract_imports_lua(root, ctx.source)
+    }
+}
+
+struct PhpExtractor;
+
+impl LanguageExtractor for PhpExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::php::extract_symbols_php(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::php::extract_imports_php(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::php::extract_calls_php(root, ctx.source, symbols)
+    }
+}
+
+struct PythonExtractor;
+
+impl LanguageExtractor for PythonExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::python::extract_symbols_python(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::python::extract_imports_python(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::python::extract_calls_python(root, ctx.source, symbols)
+    }
+
+    fn routes(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeHttpRoute> {
+        routes::python::extract_routes_python(root, ctx.source, symbols)
+    }
+
+    fn orm_entities(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeOrmEntity> {
+        orm::python::extract_orm_entities_python(root, ctx.source, symbols)
+    }
+
+    fn graphql_resolvers(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGraphqlResolver> {
+        graphql::python::extract_graphql_resolvers_python(root, ctx.source, symbols)
+    }
+
+    fn grpc_bindings(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGrpcBinding> {
+        grpc::python::extract_grpc_bindings_python(root, ctx.source, symbols)
+    }
+}
+
+struct RustExtractor;
+
+impl LanguageExtractor for RustExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::rust_lang::extract_symbols_rust(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::rust_lang::extract_imports_rust(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::rust_lang::extract_calls_rust(root, ctx.source, symbols)
+    }
+}
+
+struct ShellExtractor;
+
+impl LanguageExtractor for ShellExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::shell::extract_symbols_shell(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::shell::extract_imports_shell(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::shell::extract_calls_shell(root, ctx.source, symbols)
+    }
+}
+
+struct CSharpExtractor;
+
+impl LanguageExtractor for CSharpExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::csharp::extract_symbols_csharp(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::csharp::extract_imports_csharp(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::csharp::extract_calls_csharp(root, ctx.source, symbols)
+    }
+
+    fn orm_entities(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeOrmEntity> {
+        orm::csharp::extract_orm_entities_csharp(root, ctx.source, symbols)
+    }
+
+    fn grpc_bindings(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGrpcBinding> {
+        grpc::csharp::extract_grpc_bindings_csharp(root, ctx.source, symbols)
+    }
+}
+
+/// Shared by TypeScript, TSX, JavaScript, and JSX; the grammar differences
+/// are absorbed by `lang::get_language`.
+struct TypeScriptExtractor;
+
+impl LanguageExtractor for TypeScriptExtractor {
+    fn symbols(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedSymbol> {
+        symbols::typescript::extract_symbols_ts(root, ctx.source, ctx.repo_id, ctx.rel_path)
+    }
+
+    fn imports(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Vec<NativeParsedImport> {
+        imports::typescript::extract_imports_ts(root, ctx.source)
+    }
+
+    fn calls(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeParsedCall> {
+        calls::typescript::extract_calls_ts(root, ctx.source, symbols)
+    }
+
+    fn hooks(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeHookUsage> {
+        hooks::extract_hooks_ts(root, ctx.source, symbols)
+    }
+
+    fn events(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeEventUsage> {
+        events::extract_events_ts(root, ctx.source, symbols)
+    }
+
+    fn routes(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeHttpRoute> {
+        routes::typescript::extract_routes_ts(root, ctx.source, symbols)
+    }
+
+    fn orm_entities(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeOrmEntity> {
+        orm::typescript::extract_orm_entities_ts(root, ctx.source, symbols)
+    }
+
+    fn graphql_resolvers(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGraphqlResolver> {
+        graphql::typescript::extract_graphql_resolvers_ts(root, ctx.source, symbols)
+    }
+
+    fn grpc_bindings(
+        &self,
+        root: Node<'_>,
+        ctx: &ExtractContext<'_>,
+        symbols: &[NativeParsedSymbol],
+    ) -> Vec<NativeGrpcBinding> {
+        grpc::typescript::extract_grpc_bindings_ts(root, ctx.source, symbols)
+    }
+
+    fn file_flags(&self, root: Node<'_>, ctx: &ExtractContext<'_>) -> Option<NativeFileFlags> {
+        Some(file_flags::extract_file_flags_ts(root, ctx.source))
+    }
+}
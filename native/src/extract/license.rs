@@ -0,0 +1,171 @@
+//! License headers and copyright notices.
+//!
+//! Only the top of the file is read: an `SPDX-License-Identifier:` tag
+//! wins, otherwise the header prose of the common licenses is recognised
+//! and mapped to its SPDX identifier. `Copyright` / `©` lines are collected
+//! either way. Works on raw text, so files without a grammar get it too.
+
+use crate::types::NativeLicenseHeader;
+
+/// Lines scanned from the top of the file.
+const HEADER_LINES: usize = 50;
+
+const SPDX_TAG: &str = "SPDX-License-Identifier:";
+
+/// Header phrases (matched against whitespace-collapsed text) and the SPDX
+/// identifier they imply; the first match wins, so more specific phrases
+/// come first.
+const HEADER_PHRASES: &[(&str, &str)] = &[
+    (
+        "Licensed under the Apache License, Version 2.0",
+        "Apache-2.0",
+    ),
+    ("Permission is hereby granted, free of charge", "MIT"),
+    ("GNU Affero General Public License", "AGPL-3.0"),
+    ("GNU Lesser General Public License", "LGPL"),
+    ("GNU General Public License", "GPL"),
+    ("Mozilla Public License, v. 2.0", "MPL-2.0"),
+    ("Mozilla Public License Version 2.0", "MPL-2.0"),
+    ("governed by a BSD-style license", "BSD-3-Clause"),
+    ("Neither the name of", "BSD-3-Clause"),
+    (
+        "Redistribution and use in source and binary forms",
+        "BSD-2-Clause",
+    ),
+    (
+        "Permission to use, copy, modify, and/or distribute this software for any purpose",
+        "ISC",
+    ),
+    ("released into the public domain", "Unlicense"),
+];
+
+/// The license header of `content`, or `None` when the top of the file
+/// names neither a license nor a copyright holder.
+pub fn detect_license(content: &str) -> Option<NativeLicenseHeader> {
+    let mut spdx_id = None;
+    let mut copyrights: Vec<String> = Vec::new();
+    let mut prose = String::new();
+
+    for line in content.lines().take(HEADER_LINES) {
+        let text = strip_comment_markers(line);
+        if text.is_empty() {
+            continue;
+        }
+        if let Some((_, expression)) = text.split_once(SPDX_TAG) {
+            let expression = strip_comment_markers(expression);
+            if spdx_id.is_none() && !expression.is_empty() {
+                spdx_id = Some(expression.to_string());
+            }
+        } else if is_copyright(text) && !copyrights.iter().any(|seen| seen == text) {
+            copyrights.push(text.to_string());
+        }
+
+        for word in text.split_whitespace() {
+            if !prose.is_empty() {
+                prose.push(' ');
+            }
+            prose.push_str(word);
+        }
+    }
+
+    let (license, source) = match spdx_id {
+        Some(id) => (Some(id), Some("spdx")),
+        None => match header_license(&prose) {
+            Some(id) => (Some(id), Some("header")),
+            None => (None, None),
+        },
+    };
+    if license.is_none() && copyrights.is_empty() {
+        return None;
+    }
+    Some(NativeLicenseHeader {
+        license,
+        source: source.map(str::to_string),
+        copyrights,
+    })
+}
+
+/// The SPDX identifier implied by the header prose. GPL-family licenses get
+/// their version when the text names one (`version 3 of the License`).
+fn header_license(prose: &str) -> Option<String> {
+    let &(_, id) = HEADER_PHRASES
+        .iter()
+        .find(|(phrase, _)| prose.contains(phrase))?;
+    if !matches!(id, "GPL" | "LGPL") {
+        return Some(id.to_string());
+    }
+    let version = [("3", "3.0"), ("2.1", "2.1"), ("2", "2.0")]
+        .into_iter()
+        .find(|(named, _)| prose.contains(&format!("version {named}")))
+        .map(|(_, version)| version);
+    Some(match version {
+        Some(version) => format!("{id}-{version}"),
+        None => id.to_string(),
+    })
+}
+
+fn is_copyright(text: &str) -> bool {
+    let lower = text.to_ascii_lowercase();
+    lower.starts_with("copyright ")
+        || lower.starts_with("copyright:")
+        || lower.starts_with("(c) ")
+        || text.starts_with('©')
+}
+
+/// `// x`, `/* x */`, ` * x`, `# x`, `-- x`, `<!-- x -->`, `; x` -> `x`.
+fn strip_comment_markers(line: &str) -> &str {
+    line.trim()
+        .trim_start_matches(['/', '*', '#', '-', '!', '<', ';', '%', '"', '\''])
+        .trim_end_matches("-->")
+        .trim_end_matches("*/")
+        .trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spdx_tag_takes_precedence_over_header_prose() {
+        let header = detect_license(
+            "// SPDX-License-Identifier: Apache-2.0 OR MIT\n\
+             // Permission is hereby granted, free of charge, to any person\n\
+             fn main() {}\n",
+        )
+        .expect("license");
+        assert_eq!(header.license.as_deref(), Some("Apache-2.0 OR MIT"));
+        assert_eq!(header.source.as_deref(), Some("spdx"));
+    }
+
+    #[test]
+    fn header_prose_spanning_lines_maps_to_an_spdx_id() {
+        let header = detect_license(
+            "/*\n \
+             * Copyright 2024 Acme Corp.\n \
+             *\n \
+             * Licensed under the Apache License,\n \
+             * Version 2.0 (the \"License\");\n \
+             */\n",
+        )
+        .expect("license");
+        assert_eq!(header.license.as_deref(), Some("Apache-2.0"));
+        assert_eq!(header.source.as_deref(), Some("header"));
+        assert_eq!(header.copyrights, vec!["Copyright 2024 Acme Corp."]);
+    }
+
+    #[test]
+    fn gpl_headers_carry_their_version() {
+        let header = detect_license(
+            "# This program is free software: you can redistribute it under the terms\n\
+             # of the GNU General Public License as published by the Free Software\n\
+             # Foundation, either version 3 of the License, or (at your option)\n",
+        )
+        .expect("license");
+        assert_eq!(header.license.as_deref(), Some("GPL-3.0"));
+    }
+
+    #[test]
+    fn files_without_a_header_have_no_license() {
+        assert!(detect_license("const copyright = 'x';\nexport {};\n").is_none());
+    }
+}
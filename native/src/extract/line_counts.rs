@@ -0,0 +1,100 @@
+//! Per-file line counts.
+//!
+//! The content is already in memory during parsing, so counting here saves
+//! the TS side a second read of every file.
+
+use tree_sitter::Node;
+
+use crate::types::NativeLineCounts;
+
+/// Classify each line of `content` as blank (whitespace only), comment
+/// (everything non-blank is inside comment nodes of the tree rooted at
+/// `root`) or code (anything else, including lines mixing code and a
+/// trailing comment).
+pub fn count_lines(root: Node<'_>, content: &str) -> NativeLineCounts {
+    let comments = comment_ranges(root);
+    let mut counts = NativeLineCounts::default();
+    let mut comment_index = 0;
+    let mut line_start = 0;
+
+    for line in content.split_inclusive('\n') {
+        counts.total += 1;
+        let mut has_text = false;
+        let mut has_code = false;
+        for (offset, byte) in line.bytes().enumerate() {
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+            has_text = true;
+            let position = line_start + offset;
+            while comments
+                .get(comment_index)
+                .is_some_and(|&(_, end)| end <= position)
+            {
+                comment_index += 1;
+            }
+            let in_comment = comments
+                .get(comment_index)
+                .is_some_and(|&(start, _)| start <= position);
+            if !in_comment {
+                has_code = true;
+                break;
+            }
+        }
+
+        if has_code {
+            counts.code += 1;
+        } else if has_text {
+            counts.comment += 1;
+        } else {
+            counts.blank += 1;
+        }
+        line_start += line.len();
+    }
+
+    counts
+}
+
+/// Byte ranges of comment nodes (`comment`, `line_comment`,
+/// `block_comment`, ...), in source order.
+fn comment_ranges(root: Node<'_>) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.kind().ends_with("comment") {
+            ranges.push((node.start_byte(), node.end_byte()));
+            continue;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    ranges.sort_unstable();
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn files_report_code_comment_and_blank_line_counts() {
+        let source = r#"// Entry point.
+/**
+ * Runs the app.
+ */
+export function main() {
+
+  start(); // inline comment
+    /* trailing */
+}
+"#;
+
+        let parsed = parse_source("ts", "src/main.ts", source);
+
+        let counts = parsed.line_counts.expect("missing line counts");
+        assert_eq!(
+            (counts.total, counts.code, counts.comment, counts.blank),
+            (9, 3, 5, 1)
+        );
+    }
+}
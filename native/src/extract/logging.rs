@@ -0,0 +1,281 @@
+//! Logging calls with severity and message text.
+//!
+//! "Where is this log line emitted" is a grep for the message, but the
+//! message is usually split from its logger by formatting arguments; this
+//! pass records each logging call with its normalized level and the first
+//! string argument, as written (`${}` / `%s` placeholders included).
+//!
+//! Recognised forms:
+//!
+//! - a level method on a logger-like receiver: `console.warn`,
+//!   `logger.info`, `this.log.debug`, `logging.error`, `log.Printf`,
+//!   `slog.Info`, `LOG.warn`, `_logger.LogError`, `Log.d`
+//! - Rust level macros: `info!`, `tracing::warn!`, `log::error!`
+
+use tree_sitter::Node;
+
+use crate::types::{NativeLogCall, NativeParsedSymbol};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use super::string_literals::{literal_value, STRING_KINDS};
+
+/// Call node kinds across the supported grammars.
+const CALL_KINDS: &[&str] = &[
+    "call_expression",
+    "call",
+    "method_invocation",
+    "invocation_expression",
+    "member_call_expression",
+];
+
+/// Receiver names (last segment, lowercased) that are loggers, besides
+/// anything ending in `logger`.
+const LOGGERS: &[&str] = &[
+    "console", "log", "_log", "logging", "slog", "logrus", "zap", "winston", "pino", "syslog",
+];
+
+/// Lowercased method names per level. `C#`'s `LogWarning` style is matched
+/// after dropping the `log` prefix.
+const LEVELS: &[(&str, &[&str])] = &[
+    ("trace", &["trace", "tracef", "v", "verbose"]),
+    ("debug", &["debug", "debugf", "debugw", "d"]),
+    (
+        "info",
+        &[
+            "info",
+            "infof",
+            "infow",
+            "information",
+            "log",
+            "print",
+            "printf",
+            "println",
+            "notice",
+            "i",
+        ],
+    ),
+    ("warn", &["warn", "warnf", "warnw", "warning", "w"]),
+    ("error", &["error", "errorf", "errorw", "exception", "e"]),
+    (
+        "fatal",
+        &[
+            "fatal", "fatalf", "fatalln", "critical", "panic", "panicf", "panicln", "wtf",
+        ],
+    ),
+];
+
+/// Rust logging macros (last path segment).
+const LEVEL_MACROS: &[&str] = &["trace", "debug", "info", "warn", "error"];
+
+pub fn extract_log_calls(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeLogCall> {
+    let mut log_calls = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        let kind = node.kind();
+        let found = if CALL_KINDS.contains(&kind) {
+            log_call(node, source)
+        } else if kind == "macro_invocation" {
+            log_macro(node, source)
+        } else {
+            None
+        };
+        if let Some((level, logger, message)) = found {
+            log_calls.push(NativeLogCall {
+                level: level.to_string(),
+                logger,
+                message,
+                caller_node_id: find_enclosing_symbol(node, symbols),
+                range: extract_range(node),
+            });
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    log_calls
+}
+
+/// `logger.info("msg")` -> `("info", "logger", Some("msg"))`.
+fn log_call(node: Node<'_>, source: &[u8]) -> Option<(&'static str, String, Option<String>)> {
+    let (receiver, method) = receiver_and_method(node, source)?;
+    let receiver_name = receiver
+        .rsplit(['.', ':', '>'])
+        .next()
+        .unwrap_or(&receiver)
+        .trim_start_matches('$')
+        .to_ascii_lowercase();
+    if !receiver_name.ends_with("logger") && !LOGGERS.contains(&receiver_name.as_str()) {
+        return None;
+    }
+    let level = level(&method)?;
+    let message = node
+        .child_by_field_name("arguments")
+        .and_then(|arguments| first_string(arguments, source));
+    Some((level, receiver, message))
+}
+
+/// `tracing::info!("msg {}", x)` -> `("info", "tracing", Some("msg {}"))`.
+fn log_macro(node: Node<'_>, source: &[u8]) -> Option<(&'static str, String, Option<String>)> {
+    let path = node_text(node.child_by_field_name("macro")?, source);
+    let (logger, name) = match path.rsplit_once("::") {
+        Some((logger, name)) => (logger, name),
+        None => ("log", path),
+    };
+    let &level = LEVEL_MACROS.iter().find(|level| **level == name)?;
+    let mut cursor = node.walk();
+    let message = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "token_tree")
+        .and_then(|tree| first_string(tree, source));
+    Some((level, logger.to_string(), message))
+}
+
+/// Receiver and method name of a method call, as written. Java's
+/// `method_invocation` and PHP's `member_call_expression` keep them in
+/// `object` / `name`.
+fn receiver_and_method(node: Node<'_>, source: &[u8]) -> Option<(String, String)> {
+    if let Some(function) = node.child_by_field_name("function") {
+        let text = compact(node_text(function, source));
+        let split = text.rfind(['.', ':', '>'])?;
+        let receiver = text[..split].trim_end_matches(['.', ':', '-']).to_string();
+        let method = text[split + 1..].to_string();
+        return (!receiver.is_empty()).then_some((receiver, method));
+    }
+    let object = node.child_by_field_name("object")?;
+    let name = node.child_by_field_name("name")?;
+    Some((
+        compact(node_text(object, source)),
+        node_text(name, source).to_string(),
+    ))
+}
+
+fn level(method: &str) -> Option<&'static str> {
+    let method = method.to_ascii_lowercase();
+    let method = match method.strip_prefix("log") {
+        Some(rest) if !rest.is_empty() => rest,
+        _ => method.as_str(),
+    };
+    LEVELS
+        .iter()
+        .find(|(_, methods)| methods.contains(&method))
+        .map(|&(level, _)| level)
+}
+
+/// The first string literal directly inside `arguments` (arguments may be
+/// wrapped in an `argument` node).
+fn first_string(arguments: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut cursor = arguments.walk();
+    let found = arguments.named_children(&mut cursor).find_map(|argument| {
+        let value = if argument.kind() == "argument" {
+            argument.named_child(0)?
+        } else {
+            argument
+        };
+        STRING_KINDS
+            .contains(&value.kind())
+            .then(|| literal_value(node_text(value, source)))
+            .flatten()
+            .map(str::to_string)
+    });
+    found
+}
+
+fn compact(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn logging_calls_record_level_logger_and_message() {
+        let cases = [
+            (
+                "ts",
+                "job.ts",
+                r#"export function run(id: string) {
+  console.log(`starting ${id}`);
+  this.logger.warn({ id }, "slow job");
+  catalog.error("not a logger");
+}
+"#,
+                vec![
+                    ("info", "console", Some("starting ${id}")),
+                    ("warn", "this.logger", Some("slow job")),
+                ],
+            ),
+            (
+                "py",
+                "job.py",
+                r#"def run(id):
+    logging.info("starting %s", id)
+    logger.exception("failed")
+"#,
+                vec![
+                    ("info", "logging", Some("starting %s")),
+                    ("error", "logger", Some("failed")),
+                ],
+            ),
+            (
+                "go",
+                "job.go",
+                r#"package job
+
+func run(id string) {
+	log.Printf("starting %s", id)
+	slog.Error("failed", "id", id)
+}
+"#,
+                vec![
+                    ("info", "log", Some("starting %s")),
+                    ("error", "slog", Some("failed")),
+                ],
+            ),
+            (
+                "rs",
+                "job.rs",
+                r#"fn run(id: u32) {
+    tracing::info!(id, "starting {}", id);
+    warn!("slow");
+}
+"#,
+                vec![
+                    ("info", "tracing", Some("starting {}")),
+                    ("warn", "log", Some("slow")),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let log_calls: Vec<(&str, &str, Option<&str>)> = parsed
+                .log_calls
+                .iter()
+                .map(|call| {
+                    (
+                        call.level.as_str(),
+                        call.logger.as_str(),
+                        call.message.as_deref(),
+                    )
+                })
+                .collect();
+            assert_eq!(log_calls, expected, "{language}");
+            assert!(parsed
+                .log_calls
+                .iter()
+                .all(|call| call.caller_node_id.starts_with("run:")));
+        }
+    }
+}
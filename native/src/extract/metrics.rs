@@ -0,0 +1,371 @@
+//! Per-symbol code metrics computed from the syntax tree.
+//!
+//! Cyclomatic complexity is 1 plus the number of decision points in a
+//! callable's body: branches, loops, `case` / `match` arms, `catch` clauses
+//! and short-circuit operators. Nested named functions are symbols of their
+//! own and are not counted toward the enclosing one; anonymous callbacks
+//! are.
+//!
+//! When requested, Halstead volume and a maintainability-index estimate are
+//! added. Operands are identifiers and literals; every other token is an
+//! operator. The index uses the 0–100 scaling of
+//! `171 - 5.2 ln(V) - 0.23 CC - 16.2 ln(LOC)`.
+
+use std::collections::HashSet;
+
+use serde::Serialize;
+use tree_sitter::Node;
+
+use crate::types::NativeParsedSymbol;
+
+use super::calls::common::{node_at_range, node_text};
+
+/// Symbol kinds that have a body to measure.
+const CALLABLE_KINDS: &[&str] = &["function", "method", "constructor"];
+
+/// Nodes that each add one path through the code.
+const DECISION_POINTS: &[&str] = &[
+    "if_statement",
+    "if_expression",
+    "elif_clause",
+    "ternary_expression",
+    "conditional_expression",
+    "for_statement",
+    "for_in_statement",
+    "enhanced_for_statement",
+    "foreach_statement",
+    "for_range_loop",
+    "for_expression",
+    "while_statement",
+    "while_expression",
+    "do_statement",
+    "loop_expression",
+    "switch_case",
+    "switch_section",
+    "case_clause",
+    "expression_case",
+    "type_case",
+    "communication_case",
+    "match_arm",
+    "catch_clause",
+    "except_clause",
+    "boolean_operator",
+    "for_in_clause",
+    "if_clause",
+];
+
+/// Short-circuit operators of `binary_expression` nodes.
+const SHORT_CIRCUIT_OPERATORS: &[&str] = &["&&", "||", "??"];
+
+/// Function bodies. A declaration inside one is nested; one outside (e.g.
+/// the `function_declaration` of an `export_statement` symbol) is the
+/// symbol itself.
+const BODIES: &[&str] = &[
+    "statement_block",
+    "block",
+    "compound_statement",
+    "class_body",
+];
+
+/// Named function declarations; inside a body these are separate symbols.
+const NESTED_DECLARATIONS: &[&str] = &[
+    "function_declaration",
+    "generator_function_declaration",
+    "method_definition",
+    "method_declaration",
+    "function_definition",
+    "function_item",
+    "local_function_statement",
+    "class_declaration",
+    "class_definition",
+];
+
+/// Literal nodes taken whole as one operand, without their quote tokens.
+const STRING_LITERALS: &[&str] = &[
+    "string",
+    "template_string",
+    "string_literal",
+    "raw_string_literal",
+    "interpreted_string_literal",
+    "char_literal",
+    "character_literal",
+    "encapsed_string",
+];
+
+/// Leaf kinds that are operands besides `*identifier` nodes.
+const LEAF_OPERANDS: &[&str] = &[
+    "number",
+    "integer",
+    "float",
+    "integer_literal",
+    "float_literal",
+    "number_literal",
+    "decimal_integer_literal",
+    "decimal_floating_point_literal",
+    "int_literal",
+    "true",
+    "false",
+    "null",
+    "none",
+    "nil",
+    "undefined",
+    "this",
+    "self",
+];
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SymbolMetrics {
+    cyclomatic: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    halstead_volume: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maintainability_index: Option<f64>,
+}
+
+/// Fill `metrics_json` on every function, method and constructor symbol.
+/// `include_halstead` adds Halstead volume and the maintainability index.
+pub fn annotate_symbol_metrics(
+    symbols: &mut [NativeParsedSymbol],
+    root: Node<'_>,
+    source: &[u8],
+    include_halstead: bool,
+) {
+    for symbol in symbols
+        .iter_mut()
+        .filter(|symbol| CALLABLE_KINDS.contains(&symbol.kind.as_str()))
+    {
+        let Some(node) = node_at_range(root, &symbol.range) else {
+            continue;
+        };
+        let cyclomatic = cyclomatic_complexity(node, source);
+        let mut metrics = SymbolMetrics {
+            cyclomatic,
+            halstead_volume: None,
+            maintainability_index: None,
+        };
+        if include_halstead {
+            let volume = halstead_volume(node, source);
+            let lines = node.end_position().row - node.start_position().row + 1;
+            metrics.halstead_volume = Some(round_to(volume, 2));
+            metrics.maintainability_index = Some(round_to(
+                maintainability_index(volume, cyclomatic, lines),
+                1,
+            ));
+        }
+        symbol.metrics_json = serde_json::to_string(&metrics).ok();
+    }
+}
+
+fn cyclomatic_complexity(node: Node<'_>, source: &[u8]) -> u32 {
+    let mut complexity = 1;
+    let mut stack = vec![(node, false)];
+    while let Some((current, in_body)) = stack.pop() {
+        let kind = current.kind();
+        if in_body && NESTED_DECLARATIONS.contains(&kind) {
+            continue;
+        }
+        if DECISION_POINTS.contains(&kind) {
+            complexity += 1;
+        }
+        if kind == "binary_expression"
+            && current
+                .child_by_field_name("operator")
+                .is_some_and(|operator| {
+                    SHORT_CIRCUIT_OPERATORS.contains(&node_text(operator, source))
+                })
+        {
+            complexity += 1;
+        }
+
+        let in_body = in_body || BODIES.contains(&kind);
+        let mut cursor = current.walk();
+        stack.extend(current.children(&mut cursor).map(|child| (child, in_body)));
+    }
+    complexity
+}
+
+/// `N * log2(n)`: total operator and operand occurrences times the log of
+/// the distinct vocabulary.
+fn halstead_volume(node: Node<'_>, source: &[u8]) -> f64 {
+    let mut operators = HashSet::new();
+    let mut operands = HashSet::new();
+    let mut occurrences = 0usize;
+    let mut stack = vec![(node, false)];
+    while let Some((current, in_body)) = stack.pop() {
+        let kind = current.kind();
+        if (in_body && NESTED_DECLARATIONS.contains(&kind)) || kind.ends_with("comment") {
+            continue;
+        }
+        if STRING_LITERALS.contains(&kind) {
+            operands.insert(node_text(current, source));
+            occurrences += 1;
+            continue;
+        }
+        if current.child_count() == 0 {
+            let text = node_text(current, source);
+            if text.is_empty() {
+                continue;
+            }
+            if kind.ends_with("identifier") || LEAF_OPERANDS.contains(&kind) {
+                operands.insert(text);
+            } else {
+                operators.insert(text);
+            }
+            occurrences += 1;
+            continue;
+        }
+
+        let in_body = in_body || BODIES.contains(&kind);
+        let mut cursor = current.walk();
+        stack.extend(current.children(&mut cursor).map(|child| (child, in_body)));
+    }
+    let vocabulary = operators.len() + operands.len();
+    if vocabulary < 2 {
+        return 0.0;
+    }
+    occurrences as f64 * (vocabulary as f64).log2()
+}
+
+fn maintainability_index(volume: f64, cyclomatic: u32, lines: usize) -> f64 {
+    let raw = 171.0
+        - 5.2 * volume.max(1.0).ln()
+        - 0.23 * f64::from(cyclomatic)
+        - 16.2 * (lines.max(1) as f64).ln();
+    (raw * 100.0 / 171.0).clamp(0.0, 100.0)
+}
+
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::{parse_source, parse_source_with_options};
+    use crate::types::NativeParseOptions;
+
+    #[test]
+    fn callables_report_cyclomatic_complexity() {
+        let cases = [
+            (
+                "ts",
+                "check.ts",
+                r#"export function check(user: User, items: Item[]) {
+  if (!user || user.banned) {
+    return false;
+  }
+  for (const item of items) {
+    switch (item.kind) {
+      case "a":
+        break;
+      case "b":
+        break;
+      default:
+        break;
+    }
+  }
+  function helper() {
+    if (a) { b(); }
+  }
+  return items.some((item) => item.ok ?? false);
+}
+
+export class Gate {
+  open() {
+    return this.ready ? 1 : 0;
+  }
+}
+"#,
+                vec![("check", 7), ("helper", 2), ("open", 2)],
+            ),
+            (
+                "py",
+                "check.py",
+                r#"def check(user, items):
+    if user is None or user.banned:
+        return False
+    elif user.admin:
+        return True
+    try:
+        return [i for i in items if i.ok]
+    except ValueError:
+        return []
+"#,
+                vec![("check", 7)],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            for (name, cyclomatic) in expected {
+                let symbol = parsed
+                    .symbols
+                    .iter()
+                    .find(|symbol| symbol.name == name)
+                    .unwrap_or_else(|| panic!("{language}: missing symbol {name}"));
+                assert_eq!(
+                    symbol.metrics_json.as_deref(),
+                    Some(format!(r#"{{"cyclomatic":{cyclomatic}}}"#).as_str()),
+                    "{language} {name}"
+                );
+            }
+            let class = parsed.symbols.iter().find(|symbol| symbol.kind == "class");
+            assert!(class.is_none_or(|class| class.metrics_json.is_none()));
+        }
+    }
+
+    #[test]
+    fn halstead_metrics_are_added_when_requested() {
+        let source = r#"export function add(a: number, b: number) {
+  return a + b;
+}
+
+export function score(items: Item[], limit: number) {
+  let total = 0;
+  for (const item of items) {
+    if (item.weight > limit && item.active) {
+      total += item.weight * 2;
+    } else if (item.bonus) {
+      total -= item.bonus / 3;
+    }
+  }
+  return total > 100 ? 100 : total;
+}
+"#;
+
+        let options = NativeParseOptions {
+            halstead_metrics: Some(true),
+            ..Default::default()
+        };
+
+        let parsed = parse_source_with_options("ts", "health.ts", source, &options);
+        let plain = parse_source("ts", "health.ts", source);
+
+        let metrics = |name: &str| -> serde_json::Value {
+            let symbol = parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .unwrap_or_else(|| panic!("missing symbol {name}"));
+            serde_json::from_str(symbol.metrics_json.as_deref().expect("metrics_json"))
+                .expect("metrics_json is JSON")
+        };
+        let add = metrics("add");
+        let score = metrics("score");
+
+        let volume = |metrics: &serde_json::Value| metrics["halsteadVolume"].as_f64().unwrap();
+        let index = |metrics: &serde_json::Value| metrics["maintainabilityIndex"].as_f64().unwrap();
+        assert!(volume(&add) > 0.0);
+        assert!(volume(&score) > volume(&add));
+        assert!((0.0..=100.0).contains(&index(&score)));
+        assert!(index(&add) > index(&score));
+
+        assert!(plain
+            .symbols
+            .iter()
+            .filter_map(|symbol| symbol.metrics_json.as_deref())
+            .all(|json| !json.contains("halstead")));
+    }
+}
@@ -0,0 +1,138 @@
+//! MinHash signatures for approximate similarity search.
+//!
+//! The body's k-gram shingles (the same normalized tokens and hashes as
+//! [`super::winnowing`]) are run through `SIGNATURE_LENGTH` independent
+//! hash functions, keeping the minimum of each. The fraction of positions
+//! at which two signatures agree estimates the Jaccard similarity of the
+//! two shingle sets, so "functions similar to this one" becomes a
+//! fixed-width vector comparison (or LSH banding) in the graph DB.
+
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::types::NativeParsedSymbol;
+
+use super::calls::common::node_at_range;
+use super::winnowing::{kgram_hashes, normalized_tokens};
+
+/// Values per signature.
+pub const SIGNATURE_LENGTH: usize = 32;
+
+const CALLABLE_KINDS: &[&str] = &["function", "method", "constructor"];
+
+/// Fill `minhash_signature` on every function, method and constructor with
+/// at least one k-gram.
+pub fn annotate_minhash_signatures(
+    symbols: &mut [NativeParsedSymbol],
+    root: Node<'_>,
+    source: &[u8],
+) {
+    for symbol in symbols
+        .iter_mut()
+        .filter(|symbol| CALLABLE_KINDS.contains(&symbol.kind.as_str()))
+    {
+        let Some(node) = node_at_range(root, &symbol.range) else {
+            continue;
+        };
+        let shingles: HashSet<u32> = kgram_hashes(&normalized_tokens(node, source))
+            .into_iter()
+            .collect();
+        symbol.minhash_signature = signature(&shingles);
+    }
+}
+
+/// `None` for an empty set, whose signature would match nothing.
+fn signature(shingles: &HashSet<u32>) -> Option<Vec<u32>> {
+    if shingles.is_empty() {
+        return None;
+    }
+    let signature = (0..SIGNATURE_LENGTH as u64)
+        .map(|seed| {
+            shingles
+                .iter()
+                .map(|&shingle| permute(shingle, seed))
+                .min()
+                .unwrap_or(u32::MAX)
+        })
+        .collect();
+    Some(signature)
+}
+
+/// The `seed`-th hash function: splitmix64 of the shingle mixed with the
+/// seed, folded to 32 bits.
+fn permute(shingle: u32, seed: u64) -> u32 {
+    let mut z = (u64::from(shingle) ^ seed.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+        .wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+    ((z >> 32) ^ z) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::test_support::{parse_source, parse_source_with_options};
+    use crate::types::NativeParseOptions;
+
+    fn agreement(a: &[u32], b: &[u32]) -> f64 {
+        let same = a.iter().zip(b).filter(|(x, y)| x == y).count();
+        same as f64 / a.len() as f64
+    }
+
+    #[test]
+    fn signature_agreement_tracks_set_overlap() {
+        let base: HashSet<u32> = (0..200).collect();
+        let similar: HashSet<u32> = (20..220).collect();
+        let unrelated: HashSet<u32> = (10_000..10_200).collect();
+
+        let base_signature = signature(&base).expect("signature");
+        assert_eq!(base_signature.len(), SIGNATURE_LENGTH);
+        assert_eq!(signature(&base), Some(base_signature.clone()));
+        assert!(
+            agreement(&base_signature, &signature(&similar).expect("signature"))
+                > agreement(&base_signature, &signature(&unrelated).expect("signature"))
+        );
+        assert!(signature(&HashSet::new()).is_none());
+    }
+
+    #[test]
+    fn minhash_signatures_are_added_when_requested() {
+        let source = r#"def total(items):
+    result = 0
+    for item in items:
+        result += item.price
+    return result
+
+def tally(rows):
+    count = 0
+    for row in rows:
+        count += row.size
+    return count
+"#;
+
+        let options = NativeParseOptions {
+            minhash_signatures: Some(true),
+            ..Default::default()
+        };
+
+        let parsed = parse_source_with_options("py", "similar.py", source, &options);
+        let plain = parse_source("py", "similar.py", source);
+
+        let signature = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.minhash_signature.clone())
+                .expect("missing minhash signature")
+        };
+        assert_eq!(signature("total").len(), 32);
+        assert_eq!(signature("total"), signature("tally"));
+        assert!(plain
+            .symbols
+            .iter()
+            .all(|symbol| symbol.minhash_signature.is_none()));
+    }
+}
@@ -1,10 +1,39 @@
 pub mod calls;
+pub mod comments;
+pub mod dead_code;
+pub mod deprecation;
+pub mod env_vars;
+pub mod events;
+pub mod exceptions;
+pub mod file_flags;
 pub mod fingerprint;
+pub mod graphql;
+pub mod grpc;
+pub mod hooks;
+pub mod identifiers;
 pub mod imports;
 pub mod invariants;
+pub mod language;
+pub mod license;
+pub mod line_counts;
+pub mod logging;
+pub mod metrics;
+pub mod minhash;
+pub mod mutations;
+pub mod nesting;
+pub mod orm;
+pub mod purity;
 pub mod roles;
+pub mod routes;
 pub mod search_text;
+pub mod secrets;
 pub mod side_effects;
+pub mod string_literals;
 pub mod summary;
 pub mod symbol_id;
 pub mod symbols;
+pub mod tagged_templates;
+pub mod text;
+pub mod trigrams;
+pub mod type_refs;
+pub mod winnowing;
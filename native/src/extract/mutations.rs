@@ -0,0 +1,361 @@
+//! Parameter reassignment and in-place mutation.
+//!
+//! A function that writes `opts.retries = 3` or `items.push(x)` changes
+//! its caller's data; one that writes `opts = {...}` only rebinds its own
+//! name. Both are recorded on the signature so agents can warn about the
+//! first kind. Receivers (`self`, `this`, `cls`) are not reported, and
+//! nested named functions are skipped since their parameters may shadow.
+
+use tree_sitter::Node;
+
+use crate::types::NativeParsedSymbol;
+
+use super::calls::common::{node_at_range, node_text};
+
+/// Symbol kinds with parameters.
+const CALLABLE_KINDS: &[&str] = &["function", "method", "constructor"];
+
+/// Writes whose target is the `left` field.
+const ASSIGNMENTS: &[&str] = &[
+    "assignment_expression",
+    "augmented_assignment_expression",
+    "compound_assignment_expr",
+    "assignment",
+    "augmented_assignment",
+    "assignment_statement",
+];
+
+/// `x++`, `--x`, Go `x++`.
+const UPDATES: &[&str] = &[
+    "update_expression",
+    "postfix_unary_expression",
+    "prefix_unary_expression",
+    "inc_statement",
+    "dec_statement",
+];
+
+/// Destructuring targets (`a, b = ...`); each element is a target.
+const TARGET_LISTS: &[&str] = &[
+    "expression_list",
+    "pattern_list",
+    "tuple_pattern",
+    "tuple",
+    "array_pattern",
+    "list_pattern",
+];
+
+/// Property / element / dereference accesses; writing through one mutates
+/// the object it starts from.
+const ACCESSES: &[&str] = &[
+    "member_expression",
+    "subscript_expression",
+    "attribute",
+    "subscript",
+    "field_expression",
+    "index_expression",
+    "selector_expression",
+    "member_access_expression",
+    "element_access_expression",
+    "field_access",
+    "array_access",
+    "pointer_expression",
+    "unary_expression",
+    "parenthesized_expression",
+    "non_null_expression",
+];
+
+/// Methods that change their receiver in place.
+const MUTATING_METHODS: &[&str] = &[
+    "push",
+    "pop",
+    "shift",
+    "unshift",
+    "splice",
+    "sort",
+    "reverse",
+    "fill",
+    "copyWithin",
+    "set",
+    "delete",
+    "clear",
+    "add",
+    "append",
+    "extend",
+    "insert",
+    "remove",
+    "update",
+    "setdefault",
+    "popitem",
+    "push_str",
+    "truncate",
+    "retain",
+    "drain",
+    "dedup",
+];
+
+const RECEIVERS: &[&str] = &["self", "this", "cls"];
+
+/// Function bodies; a declaration inside one is a separate symbol.
+const BODIES: &[&str] = &["statement_block", "block", "compound_statement"];
+
+const NESTED_DECLARATIONS: &[&str] = &[
+    "function_declaration",
+    "method_definition",
+    "method_declaration",
+    "function_definition",
+    "function_item",
+    "local_function_statement",
+    "class_declaration",
+    "class_definition",
+];
+
+/// Fill `reassigned_params` / `mutated_params` on the signature of every
+/// function, method and constructor that has parameters.
+pub fn annotate_param_mutations(symbols: &mut [NativeParsedSymbol], root: Node<'_>, source: &[u8]) {
+    for symbol in symbols
+        .iter_mut()
+        .filter(|symbol| CALLABLE_KINDS.contains(&symbol.kind.as_str()))
+    {
+        let Some(signature) = symbol.signature.as_mut() else {
+            continue;
+        };
+        let Some(params) = signature.params.as_ref() else {
+            continue;
+        };
+        let names: Vec<&str> = params
+            .iter()
+            .map(|param| param_name(&param.name))
+            .filter(|name| !name.is_empty() && !RECEIVERS.contains(name))
+            .collect();
+        if names.is_empty() {
+            continue;
+        }
+        let Some(node) = node_at_range(root, &symbol.range) else {
+            continue;
+        };
+
+        let mut writes = Writes::default();
+        collect_writes(node, source, &mut writes);
+        let in_order = |written: &[String]| {
+            let found: Vec<String> = names
+                .iter()
+                .filter(|name| written.iter().any(|w| w == *name))
+                .map(|name| name.to_string())
+                .collect();
+            (!found.is_empty()).then_some(found)
+        };
+        signature.reassigned_params = in_order(&writes.reassigned);
+        signature.mutated_params = in_order(&writes.mutated);
+    }
+}
+
+/// `...rest`, `*args`, `**kwargs`, `&mut x`, `mut x`, `$x` -> the bare name.
+fn param_name(name: &str) -> &str {
+    let name = name.trim_start_matches(['.', '*', '&', '$']);
+    let name = name.strip_prefix("mut ").unwrap_or(name).trim();
+    if name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        name
+    } else {
+        ""
+    }
+}
+
+#[derive(Default)]
+struct Writes {
+    reassigned: Vec<String>,
+    mutated: Vec<String>,
+}
+
+fn collect_writes(node: Node<'_>, source: &[u8], writes: &mut Writes) {
+    let mut stack = vec![(node, false)];
+    while let Some((current, in_body)) = stack.pop() {
+        let kind = current.kind();
+        if in_body && NESTED_DECLARATIONS.contains(&kind) {
+            continue;
+        }
+
+        if ASSIGNMENTS.contains(&kind) {
+            if let Some(left) = current.child_by_field_name("left") {
+                record_target(left, source, writes);
+            }
+        } else if UPDATES.contains(&kind) {
+            let target = current
+                .child_by_field_name("argument")
+                .or_else(|| current.child_by_field_name("operand"))
+                .or_else(|| current.named_child(0));
+            if let Some(target) = target {
+                record_target(target, source, writes);
+            }
+        } else if is_delete(current, source) {
+            // `delete opts.key`, Python `del opts[key]`
+            if let Some(target) = current
+                .child_by_field_name("argument")
+                .or_else(|| current.named_child(0))
+            {
+                record_target(target, source, writes);
+            }
+        } else if let Some(receiver) = mutating_call_receiver(current, source) {
+            if let Some(name) = identifier_name(receiver, source) {
+                writes.mutated.push(name);
+            }
+        }
+
+        let in_body = in_body || BODIES.contains(&kind);
+        let mut cursor = current.walk();
+        stack.extend(current.children(&mut cursor).map(|child| (child, in_body)));
+    }
+}
+
+fn is_delete(node: Node<'_>, source: &[u8]) -> bool {
+    match node.kind() {
+        "delete_statement" => true,
+        "unary_expression" => node
+            .child_by_field_name("operator")
+            .is_some_and(|operator| node_text(operator, source) == "delete"),
+        _ => false,
+    }
+}
+
+/// `items.push(x)` -> `items`.
+fn mutating_call_receiver<'a>(node: Node<'a>, source: &[u8]) -> Option<Node<'a>> {
+    if !matches!(
+        node.kind(),
+        "call_expression" | "call" | "method_invocation"
+    ) {
+        return None;
+    }
+    let (object, method) = match node.child_by_field_name("function") {
+        Some(callee) => (
+            callee
+                .child_by_field_name("object")
+                .or_else(|| callee.child_by_field_name("value"))
+                .or_else(|| callee.child_by_field_name("operand"))?,
+            callee
+                .child_by_field_name("property")
+                .or_else(|| callee.child_by_field_name("attribute"))
+                .or_else(|| callee.child_by_field_name("field"))?,
+        ),
+        None => (
+            node.child_by_field_name("object")?,
+            node.child_by_field_name("name")?,
+        ),
+    };
+    MUTATING_METHODS
+        .contains(&node_text(method, source))
+        .then_some(object)
+}
+
+/// Record an assignment target: a bare name is reassigned, a property /
+/// element / dereference write mutates the object it starts from.
+fn record_target(target: Node<'_>, source: &[u8], writes: &mut Writes) {
+    if TARGET_LISTS.contains(&target.kind()) {
+        let mut cursor = target.walk();
+        for element in target.named_children(&mut cursor) {
+            record_target(element, source, writes);
+        }
+        return;
+    }
+    if let Some(name) = identifier_name(target, source) {
+        writes.reassigned.push(name);
+        return;
+    }
+    let mut current = target;
+    while ACCESSES.contains(&current.kind()) {
+        let Some(object) = current
+            .child_by_field_name("object")
+            .or_else(|| current.child_by_field_name("value"))
+            .or_else(|| current.child_by_field_name("operand"))
+            .or_else(|| current.child_by_field_name("argument"))
+            .or_else(|| current.named_child(0))
+        else {
+            return;
+        };
+        if let Some(name) = identifier_name(object, source) {
+            writes.mutated.push(name);
+            return;
+        }
+        current = object;
+    }
+}
+
+fn identifier_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+    matches!(node.kind(), "identifier" | "variable_name")
+        .then(|| node_text(node, source).trim_start_matches('$').to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn function_parameters_record_reassignment_and_in_place_mutation() {
+        fn as_strs(names: &Option<Vec<String>>) -> Option<Vec<&str>> {
+            names
+                .as_ref()
+                .map(|names| names.iter().map(String::as_str).collect())
+        }
+
+        let cases = [
+            (
+                "ts",
+                "apply.ts",
+                "apply",
+                r#"export function apply(opts: Options, items: string[], count: number, label: string) {
+  opts.retries = 3;
+  items.push(label);
+  count += 1;
+  function inner(opts: Options) {
+    opts = {};
+  }
+}
+"#,
+                Some(vec!["count"]),
+                Some(vec!["opts", "items"]),
+            ),
+            (
+                "py",
+                "apply.py",
+                "Worker.apply",
+                r#"class Worker:
+    def apply(self, opts, items, name):
+        self.name = name
+        opts["retries"] = 3
+        items.append(name)
+        name = name.strip()
+"#,
+                Some(vec!["name"]),
+                Some(vec!["opts", "items"]),
+            ),
+            (
+                "go",
+                "apply.go",
+                "apply",
+                r#"package main
+
+func apply(cfg *Config, n int) {
+	cfg.Retries = n
+}
+"#,
+                None,
+                Some(vec!["cfg"]),
+            ),
+        ];
+
+        for (language, rel_path, name, source, reassigned, mutated) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let signature = parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.signature.as_ref())
+                .expect("apply should have a signature");
+            assert_eq!(
+                as_strs(&signature.reassigned_params),
+                reassigned,
+                "{language}"
+            );
+            assert_eq!(as_strs(&signature.mutated_params), mutated, "{language}");
+        }
+    }
+}
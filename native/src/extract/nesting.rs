@@ -0,0 +1,133 @@
+//! Containment between the symbols extracted from one file.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::types::{NativeParsedSymbol, NativeRange};
+
+/// Set `parent_symbol_id` on every symbol to the innermost other symbol whose
+/// range encloses it. Members declared outside their type's body (Rust
+/// `impl` blocks, Go methods) fall back to the type named by their `owner`
+/// attribute or Go receiver.
+///
+/// Variables never act as parents, so a field's initializer does not adopt
+/// the lambdas or enum members defined inside it.
+pub fn assign_parent_symbols(symbols: &mut [NativeParsedSymbol]) {
+    let mut order: Vec<usize> = (0..symbols.len()).collect();
+    // Outer symbols sort before the symbols they contain.
+    order.sort_by(|&a, &b| {
+        let (ra, rb) = (&symbols[a].range, &symbols[b].range);
+        start(ra).cmp(&start(rb)).then(end(rb).cmp(&end(ra)))
+    });
+
+    let mut parents: Vec<Option<usize>> = vec![None; symbols.len()];
+    let mut open: Vec<usize> = Vec::new();
+    for &index in &order {
+        let range = &symbols[index].range;
+        while let Some(&top) = open.last() {
+            if end(&symbols[top].range) <= start(range) {
+                open.pop();
+            } else {
+                break;
+            }
+        }
+        parents[index] = open
+            .iter()
+            .rev()
+            .copied()
+            .find(|&candidate| strictly_contains(&symbols[candidate].range, range));
+        if symbols[index].kind != "variable" {
+            open.push(index);
+        }
+    }
+
+    let types_by_name: HashMap<&str, usize> = symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, symbol)| matches!(symbol.kind.as_str(), "class" | "interface" | "type"))
+        .map(|(index, symbol)| (symbol.name.as_str(), index))
+        .collect();
+    for (index, parent) in parents.iter_mut().enumerate() {
+        if parent.is_some() {
+            continue;
+        }
+        *parent = declared_owner(&symbols[index])
+            .and_then(|owner| types_by_name.get(owner).copied())
+            .filter(|&owner_index| owner_index != index);
+    }
+
+    let parent_ids: Vec<Option<String>> = parents
+        .iter()
+        .map(|parent| parent.map(|p| symbols[p].symbol_id.clone()))
+        .collect();
+    for (symbol, parent_id) in symbols.iter_mut().zip(parent_ids) {
+        symbol.parent_symbol_id = parent_id;
+    }
+}
+
+/// Type a member was declared against when it sits outside the type's body:
+/// the `owner` attribute (Rust impls), or the Go receiver without pointer
+/// and type arguments (`*Stack[T]` -> `Stack`).
+fn declared_owner(symbol: &NativeParsedSymbol) -> Option<&str> {
+    if let Some(owner) = symbol.attributes.iter().find(|attr| attr.key == "owner") {
+        return Some(owner.value.as_str());
+    }
+    let receiver = symbol.signature.as_ref()?.receiver.as_deref()?;
+    let receiver = receiver.trim_start_matches('*');
+    Some(receiver.split('[').next().unwrap_or(receiver))
+}
+
+fn start(range: &NativeRange) -> (u32, u32) {
+    (range.start_line, range.start_col)
+}
+
+fn end(range: &NativeRange) -> (u32, u32) {
+    (range.end_line, range.end_col)
+}
+
+fn strictly_contains(outer: &NativeRange, inner: &NativeRange) -> bool {
+    let starts_before = start(outer).cmp(&start(inner)) != Ordering::Greater;
+    let ends_after = end(outer).cmp(&end(inner)) != Ordering::Less;
+    starts_before && ends_after && (start(outer), end(outer)) != (start(inner), end(inner))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+    use crate::types::NativeParsedSymbol;
+
+    #[test]
+    fn symbols_record_their_enclosing_parent() {
+        let ts_source = r#"export class Store {
+  load() {
+    function retry() {}
+  }
+}
+export function main() {}
+"#;
+        let rs_source = "pub struct Stack;\n\nimpl Stack {\n    pub fn push(&mut self) {}\n}\n";
+
+        let ts = parse_source("ts", "src/store.ts", ts_source);
+        let rs = parse_source("rs", "src/stack.rs", rs_source);
+
+        let find = |symbols: &[NativeParsedSymbol], name: &str| {
+            symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .cloned()
+                .unwrap_or_else(|| panic!("missing symbol {name}"))
+        };
+
+        let store = find(&ts.symbols, "Store");
+        let load = find(&ts.symbols, "load");
+        let retry = find(&ts.symbols, "retry");
+        assert_eq!(store.parent_symbol_id, None);
+        assert_eq!(load.parent_symbol_id.as_ref(), Some(&store.symbol_id));
+        assert_eq!(retry.parent_symbol_id.as_ref(), Some(&load.symbol_id));
+        assert_eq!(find(&ts.symbols, "main").parent_symbol_id, None);
+
+        let stack = find(&rs.symbols, "Stack");
+        let push = find(&rs.symbols, "push");
+        assert_eq!(push.parent_symbol_id.as_ref(), Some(&stack.symbol_id));
+    }
+}
@@ -0,0 +1,45 @@
+//! Helpers shared by the per-framework ORM extractors.
+
+/// Relation decorators / attributes and the cardinality they declare
+/// (TypeORM, sequelize-typescript).
+const RELATIONS: &[(&str, &str)] = &[
+    ("OneToOne", "one_to_one"),
+    ("OneToMany", "one_to_many"),
+    ("ManyToOne", "many_to_one"),
+    ("ManyToMany", "many_to_many"),
+    ("HasOne", "one_to_one"),
+    ("HasMany", "one_to_many"),
+    ("BelongsTo", "many_to_one"),
+    ("BelongsToMany", "many_to_many"),
+];
+
+/// Normalized relation kind of a relation decorator name.
+pub fn relation_kind(name: &str) -> Option<&'static str> {
+    RELATIONS
+        .iter()
+        .find(|(decorator, _)| *decorator == name)
+        .map(|&(_, kind)| kind)
+}
+
+/// `List<Order>` / `ICollection<Order>` -> `Order`; `Order?` -> `Order`.
+pub fn element_type(type_text: &str) -> &str {
+    let type_text = type_text.trim().trim_end_matches('?');
+    match type_text.split_once('<') {
+        Some((_, inner)) => inner.trim_end_matches('>').trim(),
+        None => type_text.trim_end_matches("[]"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_type_unwraps_collections_and_nullables() {
+        assert_eq!(element_type("ICollection<Order>"), "Order");
+        assert_eq!(element_type("Photo[]"), "Photo");
+        assert_eq!(element_type("Customer?"), "Customer");
+        assert_eq!(relation_kind("HasMany"), Some("one_to_many"));
+        assert_eq!(relation_kind("Column"), None);
+    }
+}
@@ -0,0 +1,209 @@
+//! Entity Framework entity classes.
+//!
+//! A class is an entity when it carries `[Table("users")]` or is the type
+//! argument of a `DbSet<T>` property declared in the same file (the
+//! `DbContext`). Scalar properties are columns (`[Key]`, `Id` or
+//! `<Class>Id` is the primary key); collection properties
+//! (`ICollection<Order>`) are `one_to_many` relations and other class-typed
+//! properties `many_to_one` navigations. `[ForeignKey("Customer")]` adds a
+//! `foreign_key` relation and `[NotMapped]` properties are skipped.
+
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, node_text};
+use crate::extract::routes::common::{declared_node_id, string_value};
+use crate::types::{NativeOrmColumn, NativeOrmEntity, NativeOrmRelation, NativeParsedSymbol};
+
+use super::common::element_type;
+
+/// Property types mapped to a column.
+const SCALAR_TYPES: &[&str] = &[
+    "bool",
+    "byte",
+    "byte[]",
+    "char",
+    "DateOnly",
+    "DateTime",
+    "DateTimeOffset",
+    "decimal",
+    "double",
+    "float",
+    "Guid",
+    "int",
+    "long",
+    "sbyte",
+    "short",
+    "string",
+    "TimeOnly",
+    "TimeSpan",
+    "uint",
+    "ulong",
+    "ushort",
+];
+
+/// Generic types whose element is the other side of a one-to-many.
+const COLLECTION_TYPES: &[&str] = &[
+    "ICollection",
+    "IList",
+    "List",
+    "IEnumerable",
+    "HashSet",
+    "ISet",
+    "Collection",
+];
+
+pub fn extract_orm_entities_csharp(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeOrmEntity> {
+    let mut classes = Vec::new();
+    let mut db_sets = HashSet::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "class_declaration" => classes.push(node),
+            "property_declaration" => {
+                let type_text = node
+                    .child_by_field_name("type")
+                    .map(|type_node| node_text(type_node, source))
+                    .unwrap_or("");
+                if type_text.starts_with("DbSet<") {
+                    db_sets.insert(element_type(type_text).to_string());
+                }
+            }
+            _ => {}
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    classes
+        .into_iter()
+        .filter_map(|node| entity(node, &db_sets, source, symbols))
+        .collect()
+}
+
+fn entity(
+    node: Node<'_>,
+    db_sets: &HashSet<String>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeOrmEntity> {
+    let name_node = node.child_by_field_name("name")?;
+    let class_name = node_text(name_node, source);
+    let table = attributes(node, source)
+        .into_iter()
+        .find(|(name, _)| *name == "Table")
+        .map(|(_, first)| first);
+    if table.is_none() && !db_sets.contains(class_name) {
+        return None;
+    }
+
+    let mut entity = NativeOrmEntity {
+        framework: "entity_framework".to_string(),
+        class_name: class_name.to_string(),
+        class_node_id: declared_node_id(name_node, symbols),
+        table: table.flatten(),
+        columns: Vec::new(),
+        relations: Vec::new(),
+        range: extract_range(node),
+    };
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for property in body
+            .named_children(&mut cursor)
+            .filter(|member| member.kind() == "property_declaration")
+        {
+            add_property(property, class_name, source, &mut entity);
+        }
+    }
+    Some(entity)
+}
+
+fn add_property(property: Node<'_>, class_name: &str, source: &[u8], entity: &mut NativeOrmEntity) {
+    let (Some(name_node), Some(type_node)) = (
+        property.child_by_field_name("name"),
+        property.child_by_field_name("type"),
+    ) else {
+        return;
+    };
+    let field = node_text(name_node, source);
+    let type_text = node_text(type_node, source);
+    let attributes = attributes(property, source);
+    if attributes.iter().any(|(name, _)| *name == "NotMapped") {
+        return;
+    }
+    for (_, target) in attributes.iter().filter(|(name, _)| *name == "ForeignKey") {
+        entity.relations.push(NativeOrmRelation {
+            kind: "foreign_key".to_string(),
+            field: field.to_string(),
+            target: target.clone(),
+        });
+    }
+
+    let base = type_text.trim_end_matches('?');
+    let generic = base.split_once('<').map(|(generic, _)| generic);
+    if SCALAR_TYPES.contains(&base) {
+        let primary_key = attributes.iter().any(|(name, _)| *name == "Key")
+            || field == "Id"
+            || field == format!("{class_name}Id");
+        entity.columns.push(NativeOrmColumn {
+            name: field.to_string(),
+            column_type: Some(type_text.to_string()),
+            primary_key,
+        });
+    } else if generic.is_some_and(|generic| COLLECTION_TYPES.contains(&generic)) {
+        entity.relations.push(NativeOrmRelation {
+            kind: "one_to_many".to_string(),
+            field: field.to_string(),
+            target: Some(element_type(base).to_string()),
+        });
+    } else if generic.is_none() && base.starts_with(char::is_uppercase) {
+        entity.relations.push(NativeOrmRelation {
+            kind: "many_to_one".to_string(),
+            field: field.to_string(),
+            target: Some(base.to_string()),
+        });
+    }
+}
+
+/// `[Table("users")]`, `[Key]` on a declaration -> `("Table", Some("users"))`,
+/// `("Key", None)`.
+fn attributes<'a>(node: Node<'a>, source: &'a [u8]) -> Vec<(&'a str, Option<String>)> {
+    let mut found = Vec::new();
+    let mut cursor = node.walk();
+    for list in node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "attribute_list")
+    {
+        let mut inner = list.walk();
+        for attribute in list
+            .named_children(&mut inner)
+            .filter(|child| child.kind() == "attribute")
+        {
+            let Some(name) = attribute.child_by_field_name("name") else {
+                continue;
+            };
+            let name = node_text(name, source);
+            let name = name.strip_suffix("Attribute").unwrap_or(name);
+            let mut args = attribute.walk();
+            let first = attribute
+                .named_children(&mut args)
+                .find(|child| child.kind() == "attribute_argument_list")
+                .and_then(|arguments| arguments.named_child(0))
+                .and_then(|argument| argument.named_child(0))
+                .and_then(|value| string_value(value, source));
+            found.push((name, first));
+        }
+    }
+    found
+}
@@ -0,0 +1,126 @@
+pub mod common;
+pub(crate) mod csharp;
+pub(crate) mod python;
+pub(crate) mod typescript;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn orm_entities_record_table_columns_and_relations() {
+        let cases = [
+            (
+                "ts",
+                "user.entity.ts",
+                r#"@Entity("users")
+export class User {
+  @PrimaryGeneratedColumn()
+  id: number;
+
+  @Column("varchar")
+  name: string;
+
+  @OneToMany(() => Photo, (photo) => photo.user)
+  photos: Photo[];
+
+  helper = 1;
+}
+"#,
+                ("typeorm", "User", Some("users")),
+                vec![
+                    ("id", Some("number"), true),
+                    ("name", Some("varchar"), false),
+                ],
+                vec![("one_to_many", "photos", Some("Photo"))],
+            ),
+            (
+                "py",
+                "models.py",
+                r#"class Address(Base):
+    __tablename__ = "addresses"
+
+    id = Column(Integer, primary_key=True)
+    user_id = Column(Integer, ForeignKey("users.id"))
+    user = relationship("User", back_populates="addresses")
+"#,
+                ("sqlalchemy", "Address", Some("addresses")),
+                vec![
+                    ("id", Some("Integer"), true),
+                    ("user_id", Some("Integer"), false),
+                ],
+                vec![
+                    ("foreign_key", "user_id", Some("users.id")),
+                    ("relationship", "user", Some("User")),
+                ],
+            ),
+            (
+                "cs",
+                "Shop.cs",
+                r#"public class ShopContext : DbContext {
+    public DbSet<Order> Orders { get; set; }
+}
+
+public class Order {
+    public int Id { get; set; }
+    public decimal? Total { get; set; }
+    public Customer Customer { get; set; }
+    public ICollection<LineItem> Items { get; set; }
+}
+"#,
+                ("entity_framework", "Order", None),
+                vec![
+                    ("Id", Some("int"), true),
+                    ("Total", Some("decimal?"), false),
+                ],
+                vec![
+                    ("many_to_one", "Customer", Some("Customer")),
+                    ("one_to_many", "Items", Some("LineItem")),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected_entity, expected_columns, expected_relations) in
+            cases
+        {
+            let parsed = parse_source(language, rel_path, source);
+
+            assert_eq!(parsed.orm_entities.len(), 1, "{language}");
+            let entity = &parsed.orm_entities[0];
+            assert_eq!(
+                (
+                    entity.framework.as_str(),
+                    entity.class_name.as_str(),
+                    entity.table.as_deref()
+                ),
+                expected_entity,
+                "{language}"
+            );
+            assert!(entity.class_node_id.is_some(), "{language}");
+            let columns: Vec<(&str, Option<&str>, bool)> = entity
+                .columns
+                .iter()
+                .map(|column| {
+                    (
+                        column.name.as_str(),
+                        column.column_type.as_deref(),
+                        column.primary_key,
+                    )
+                })
+                .collect();
+            assert_eq!(columns, expected_columns, "{language}");
+            let relations: Vec<(&str, &str, Option<&str>)> = entity
+                .relations
+                .iter()
+                .map(|relation| {
+                    (
+                        relation.kind.as_str(),
+                        relation.field.as_str(),
+                        relation.target.as_deref(),
+                    )
+                })
+                .collect();
+            assert_eq!(relations, expected_relations, "{language}");
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! SQLAlchemy (and Flask-SQLAlchemy) declarative models.
+//!
+//! A class is a model when its body sets `__tablename__`, or when it has a
+//! base class and at least one column. Columns are `name = Column(...)` /
+//! `name: Mapped[int] = mapped_column(...)` assignments (also `db.Column`);
+//! `relationship("Address")` assignments are relations, and a
+//! `ForeignKey("users.id")` inside a column adds a `foreign_key` relation.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, node_text};
+use crate::extract::routes::common::{declared_node_id, string_value};
+use crate::types::{NativeOrmColumn, NativeOrmEntity, NativeOrmRelation, NativeParsedSymbol};
+
+use super::common::element_type;
+
+/// Column factories (last segment of the callee).
+const COLUMN_FACTORIES: &[&str] = &["Column", "mapped_column"];
+
+pub fn extract_orm_entities_python(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeOrmEntity> {
+    let mut entities = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "class_definition" {
+            entities.extend(entity(node, source, symbols));
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    entities
+}
+
+fn entity(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeOrmEntity> {
+    let name_node = node.child_by_field_name("name")?;
+    let body = node.child_by_field_name("body")?;
+    let mut entity = NativeOrmEntity {
+        framework: "sqlalchemy".to_string(),
+        class_name: node_text(name_node, source).to_string(),
+        class_node_id: declared_node_id(name_node, symbols),
+        table: None,
+        columns: Vec::new(),
+        relations: Vec::new(),
+        range: extract_range(node),
+    };
+
+    let mut cursor = body.walk();
+    for statement in body.named_children(&mut cursor) {
+        let Some(assignment) = statement
+            .named_child(0)
+            .filter(|_| statement.kind() == "expression_statement")
+            .filter(|assignment| assignment.kind() == "assignment")
+        else {
+            continue;
+        };
+        let Some(left) = assignment
+            .child_by_field_name("left")
+            .filter(|left| left.kind() == "identifier")
+        else {
+            continue;
+        };
+        let field = node_text(left, source);
+        let Some(right) = assignment.child_by_field_name("right") else {
+            continue;
+        };
+        if field == "__tablename__" {
+            entity.table = string_value(right, source);
+            continue;
+        }
+        let Some(factory) = call_name(right, source) else {
+            continue;
+        };
+        if COLUMN_FACTORIES.contains(&factory) {
+            add_column(field, assignment, right, source, &mut entity);
+        } else if factory == "relationship" {
+            let target = first_argument(right).and_then(|first| match first.kind() {
+                "identifier" | "attribute" => Some(node_text(first, source).to_string()),
+                _ => string_value(first, source),
+            });
+            entity.relations.push(NativeOrmRelation {
+                kind: "relationship".to_string(),
+                field: field.to_string(),
+                target,
+            });
+        }
+    }
+
+    let has_base = node.child_by_field_name("superclasses").is_some();
+    (entity.table.is_some() || (has_base && !entity.columns.is_empty())).then_some(entity)
+}
+
+fn add_column(
+    field: &str,
+    assignment: Node<'_>,
+    call: Node<'_>,
+    source: &[u8],
+    entity: &mut NativeOrmEntity,
+) {
+    let mut column_type = None;
+    let mut primary_key = false;
+    if let Some(arguments) = call.child_by_field_name("arguments") {
+        let mut cursor = arguments.walk();
+        for argument in arguments.named_children(&mut cursor) {
+            match argument.kind() {
+                "keyword_argument" => {
+                    let is_primary = argument
+                        .child_by_field_name("name")
+                        .is_some_and(|name| node_text(name, source) == "primary_key");
+                    let is_true = argument
+                        .child_by_field_name("value")
+                        .is_some_and(|value| node_text(value, source) == "True");
+                    primary_key |= is_primary && is_true;
+                }
+                _ if call_name(argument, source) == Some("ForeignKey") => {
+                    entity.relations.push(NativeOrmRelation {
+                        kind: "foreign_key".to_string(),
+                        field: field.to_string(),
+                        target: first_argument(argument)
+                            .and_then(|target| string_value(target, source)),
+                    });
+                }
+                // `Column("name", String)` names the column first.
+                "string" => {}
+                _ if column_type.is_none() => {
+                    column_type = Some(node_text(argument, source).to_string());
+                }
+                _ => {}
+            }
+        }
+    }
+    // `id: Mapped[int] = mapped_column(primary_key=True)`
+    let column_type = column_type.or_else(|| {
+        let annotation = node_text(assignment.child_by_field_name("type")?, source);
+        let inner = annotation
+            .strip_prefix("Mapped[")
+            .and_then(|rest| rest.strip_suffix(']'))
+            .unwrap_or(annotation);
+        Some(element_type(inner).to_string())
+    });
+    entity.columns.push(NativeOrmColumn {
+        name: field.to_string(),
+        column_type,
+        primary_key,
+    });
+}
+
+/// Last segment of a call's callee (`db.Column(...)` -> `Column`).
+fn call_name<'a>(node: Node<'a>, source: &'a [u8]) -> Option<&'a str> {
+    if node.kind() != "call" {
+        return None;
+    }
+    let text = node_text(node.child_by_field_name("function")?, source);
+    Some(text.rsplit('.').next().unwrap_or(text))
+}
+
+fn first_argument(call: Node<'_>) -> Option<Node<'_>> {
+    call.child_by_field_name("arguments")?.named_child(0)
+}
@@ -0,0 +1,221 @@
+//! TypeORM and sequelize-typescript entity classes.
+//!
+//! `@Entity("users")` (TypeORM) or `@Table({ tableName: "users" })`
+//! (sequelize-typescript) marks the class. Fields decorated with `@Column`,
+//! `@PrimaryGeneratedColumn` and friends are columns; `@OneToMany(() =>
+//! Photo)`, `@BelongsTo(() => User)` and the other relation decorators are
+//! relations to the class their type function returns. sequelize's
+//! `@ForeignKey(() => User)` is recorded as a `foreign_key` relation.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, node_text};
+use crate::extract::routes::common::{declared_node_id, string_value};
+use crate::types::{NativeOrmColumn, NativeOrmEntity, NativeOrmRelation, NativeParsedSymbol};
+
+use super::common::relation_kind;
+
+const CLASS_KINDS: &[&str] = &["class_declaration", "abstract_class_declaration"];
+
+/// TypeORM column decorators; the `Primary*` ones mark the primary key.
+const COLUMN_DECORATORS: &[&str] = &[
+    "Column",
+    "PrimaryColumn",
+    "PrimaryGeneratedColumn",
+    "ObjectIdColumn",
+    "CreateDateColumn",
+    "UpdateDateColumn",
+    "DeleteDateColumn",
+    "VersionColumn",
+];
+
+pub fn extract_orm_entities_ts(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeOrmEntity> {
+    let mut entities = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if CLASS_KINDS.contains(&node.kind()) {
+            entities.extend(entity(node, source, symbols));
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    entities
+}
+
+fn entity(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeOrmEntity> {
+    let name_node = node.child_by_field_name("name")?;
+    let export = node
+        .parent()
+        .filter(|parent| parent.kind() == "export_statement");
+    let (framework, table) = export
+        .into_iter()
+        .chain(std::iter::once(node))
+        .flat_map(decorators)
+        .find_map(|decorator| {
+            let (name, arguments) = decorator_call(decorator, source)?;
+            let framework = match name {
+                "Entity" => "typeorm",
+                "Table" => "sequelize",
+                _ => return None,
+            };
+            let table = arguments.and_then(|arguments| {
+                let first = arguments.named_child(0)?;
+                string_value(first, source)
+                    .or_else(|| object_property(first, &["name", "tableName"], source))
+            });
+            Some((framework, table))
+        })?;
+
+    let mut entity = NativeOrmEntity {
+        framework: framework.to_string(),
+        class_name: node_text(name_node, source).to_string(),
+        class_node_id: declared_node_id(name_node, symbols),
+        table,
+        columns: Vec::new(),
+        relations: Vec::new(),
+        range: extract_range(node),
+    };
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for member in body
+            .named_children(&mut cursor)
+            .filter(|member| member.kind() == "public_field_definition")
+        {
+            add_field(member, source, &mut entity);
+        }
+    }
+    Some(entity)
+}
+
+fn add_field(member: Node<'_>, source: &[u8], entity: &mut NativeOrmEntity) {
+    let Some(name_node) = member.child_by_field_name("name") else {
+        return;
+    };
+    let field = node_text(name_node, source).to_string();
+    let annotation = member.child_by_field_name("type").map(|annotation| {
+        node_text(annotation, source)
+            .trim_start_matches(':')
+            .trim()
+            .to_string()
+    });
+
+    let mut column: Option<NativeOrmColumn> = None;
+    let mut primary_key = false;
+    for decorator in decorators(member) {
+        let Some((name, arguments)) = decorator_call(decorator, source) else {
+            continue;
+        };
+        let first = arguments.and_then(|arguments| arguments.named_child(0));
+        if COLUMN_DECORATORS.contains(&name) {
+            primary_key |= name.starts_with("Primary") || name == "ObjectIdColumn";
+            // `@Column("varchar")`, `@Column({ type: "int" })`,
+            // `@Column(DataType.STRING)`.
+            let column_type = first
+                .and_then(|first| {
+                    string_value(first, source)
+                        .or_else(|| object_property(first, &["type"], source))
+                        .or_else(|| {
+                            (first.kind() == "member_expression")
+                                .then(|| node_text(first, source).to_string())
+                        })
+                })
+                .or_else(|| annotation.clone());
+            column = Some(NativeOrmColumn {
+                name: field.clone(),
+                column_type,
+                primary_key: false,
+            });
+        } else if name == "PrimaryKey" {
+            primary_key = true;
+        } else if let Some(kind) =
+            relation_kind(name).or((name == "ForeignKey").then_some("foreign_key"))
+        {
+            entity.relations.push(NativeOrmRelation {
+                kind: kind.to_string(),
+                field: field.clone(),
+                target: first.and_then(|first| relation_target(first, source)),
+            });
+        }
+    }
+    if let Some(mut column) = column {
+        column.primary_key = primary_key;
+        entity.columns.push(column);
+    }
+}
+
+/// `() => Photo` / `(type) => Photo` -> `Photo`; `"Photo"` -> `Photo`.
+fn relation_target(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let target = if node.kind() == "arrow_function" {
+        node.child_by_field_name("body")?
+    } else {
+        node
+    };
+    match target.kind() {
+        "identifier" | "member_expression" => Some(node_text(target, source).to_string()),
+        _ => string_value(target, source),
+    }
+}
+
+/// The `decorator` children of a class, export statement or field.
+fn decorators(node: Node<'_>) -> Vec<Node<'_>> {
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "decorator")
+        .collect();
+    found
+}
+
+/// `@Name(args)` -> `("Name", Some(args))`; `@Name` -> `("Name", None)`.
+fn decorator_call<'a>(
+    decorator: Node<'a>,
+    source: &'a [u8],
+) -> Option<(&'a str, Option<Node<'a>>)> {
+    let expression = decorator.named_child(0)?;
+    match expression.kind() {
+        "identifier" => Some((node_text(expression, source), None)),
+        "call_expression" => {
+            let function = expression.child_by_field_name("function")?;
+            Some((
+                node_text(function, source),
+                expression.child_by_field_name("arguments"),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// String value of the first of `keys` set in an object literal.
+fn object_property(node: Node<'_>, keys: &[&str], source: &[u8]) -> Option<String> {
+    if node.kind() != "object" {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let found = node.named_children(&mut cursor).find_map(|pair| {
+        if pair.kind() != "pair" {
+            return None;
+        }
+        let key = node_text(pair.child_by_field_name("key")?, source);
+        let key = key.trim_matches(['"', '\'']);
+        if !keys.contains(&key) {
+            return None;
+        }
+        string_value(pair.child_by_field_name("value")?, source)
+    });
+    found
+}
@@ -0,0 +1,223 @@
+//! Purity classification of functions and methods.
+//!
+//! Each callable is "pure", "read_only" or "effectful":
+//!
+//! - effectful: a side effect other than environment access
+//!   ([`super::side_effects`]), an in-place mutation of a parameter
+//!   ([`super::mutations`]), a write to `this` / `self` state, a Python
+//!   `global` / `nonlocal` declaration, or a call to an effectful function
+//!   of the same file
+//! - read_only: reads the environment or `this` / `self` state, or calls a
+//!   read-only function of the same file
+//! - pure: none of the above
+//!
+//! Calls into other files and libraries are not followed, so "pure" means
+//! "no effect visible from this file", which the side-effect detectors
+//! already narrow to calls that are not known I/O.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::types::{NativeParsedCall, NativeParsedSymbol};
+
+use super::calls::common::{callee_path, line_starts};
+
+const CALLABLE_KINDS: &[&str] = &["function", "method"];
+
+/// Side effect that only reads.
+const READ_EFFECTS: &[&str] = &["Environment access"];
+
+/// Receivers of same-file method calls.
+const SELF_RECEIVERS: &[&str] = &["this", "self", "Self", "$this"];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Purity {
+    Pure,
+    ReadOnly,
+    Effectful,
+}
+
+impl Purity {
+    fn as_str(self) -> &'static str {
+        match self {
+            Purity::Pure => "pure",
+            Purity::ReadOnly => "read_only",
+            Purity::Effectful => "effectful",
+        }
+    }
+}
+
+static SELF_WRITE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:\b(?:this|self)\.|\$this->)[\w$]+(?:\[[^\]]*\])?\s*(?:[-+*/%|&^]|<<|>>|\?\?)?=[^=]|(?:\+\+|--)\s*(?:this|self)\.|(?:\b(?:this|self)\.|\$this->)[\w$]+\s*(?:\+\+|--)",
+    )
+    .unwrap()
+});
+
+static SELF_READ: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(?:this|self)\.[\w$]+|\$this->[\w$]+").unwrap());
+
+static PYTHON_SCOPE_WRITE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^\s*(?:global|nonlocal)\s+\w").unwrap());
+
+/// Set `purity` on every function and method.
+pub fn classify_purity(
+    symbols: &mut [NativeParsedSymbol],
+    calls: &[NativeParsedCall],
+    source: &str,
+) {
+    let lines: Vec<&str> = source.lines().collect();
+    let callables: Vec<usize> = (0..symbols.len())
+        .filter(|&index| CALLABLE_KINDS.contains(&symbols[index].kind.as_str()))
+        .collect();
+    let mut purity: HashMap<&str, Purity> = callables
+        .iter()
+        .map(|&index| {
+            let symbol = &symbols[index];
+            (symbol.node_id.as_str(), own_purity(symbol, &lines))
+        })
+        .collect();
+
+    // Same-file callees: bare names and `this.` / `self.` members.
+    let starts = line_starts(source);
+    let mut edges: Vec<(&str, &str)> = Vec::new();
+    for call in calls {
+        if !purity.contains_key(call.caller_node_id.as_str()) {
+            continue;
+        }
+        let path = callee_path(source, &starts, &call.range).unwrap_or(&call.callee_identifier);
+        let name = match path.rsplit_once(['.', ':', '>']) {
+            Some((receiver, name)) => {
+                let receiver = receiver.trim_end_matches(['.', ':', '-', '?']);
+                if !SELF_RECEIVERS.contains(&receiver) {
+                    continue;
+                }
+                name
+            }
+            None => path,
+        };
+        for &index in &callables {
+            let callee = &symbols[index];
+            if callee.name == name && callee.node_id != call.caller_node_id {
+                edges.push((call.caller_node_id.as_str(), callee.node_id.as_str()));
+            }
+        }
+    }
+
+    // Propagate along calls until nothing changes; purity only rises, so
+    // this terminates.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &(caller, callee) in &edges {
+            let callee_purity = purity[callee];
+            let caller_purity = purity.get_mut(caller).expect("caller is a callable");
+            if callee_purity > *caller_purity {
+                *caller_purity = callee_purity;
+                changed = true;
+            }
+        }
+    }
+
+    let purity: HashMap<String, &'static str> = purity
+        .into_iter()
+        .map(|(node_id, purity)| (node_id.to_string(), purity.as_str()))
+        .collect();
+    for &index in &callables {
+        let symbol = &mut symbols[index];
+        symbol.purity = purity.get(&symbol.node_id).map(|purity| purity.to_string());
+    }
+}
+
+fn own_purity(symbol: &NativeParsedSymbol, lines: &[&str]) -> Purity {
+    let writes_params = symbol
+        .signature
+        .as_ref()
+        .and_then(|signature| signature.mutated_params.as_ref())
+        .is_some_and(|params| !params.is_empty());
+    let writes_outside = symbol
+        .side_effects
+        .iter()
+        .any(|effect| !READ_EFFECTS.contains(&effect.as_str()));
+    let start = (symbol.range.start_line as usize).saturating_sub(1);
+    let end = (symbol.range.end_line as usize).min(lines.len());
+    let body = lines.get(start..end).unwrap_or_default().join("\n");
+
+    if writes_params
+        || writes_outside
+        || SELF_WRITE.is_match(&body)
+        || PYTHON_SCOPE_WRITE.is_match(&body)
+    {
+        Purity::Effectful
+    } else if !symbol.side_effects.is_empty() || SELF_READ.is_match(&body) {
+        Purity::ReadOnly
+    } else {
+        Purity::Pure
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn functions_are_classified_by_purity() {
+        let source = r#"export function add(a: number, b: number) {
+  return a + b;
+}
+
+export function twice(x: number) {
+  return add(x, x);
+}
+
+export function loadConfig() {
+  return process.env.CONFIG;
+}
+
+export function save(data: string) {
+  fs.writeFileSync("out.txt", data);
+}
+
+export function report() {
+  save(String(add(1, 2)));
+}
+
+export function append(items: string[]) {
+  items.push("x");
+}
+
+export class Counter {
+  count = 0;
+
+  increment() {
+    this.count += 1;
+  }
+
+  current() {
+    return this.count;
+  }
+}
+"#;
+
+        let parsed = parse_source("ts", "purity.ts", source);
+
+        let purity = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.purity.as_deref())
+        };
+        assert_eq!(purity("add"), Some("pure"));
+        assert_eq!(purity("twice"), Some("pure"));
+        assert_eq!(purity("loadConfig"), Some("read_only"));
+        assert_eq!(purity("save"), Some("effectful"));
+        assert_eq!(purity("report"), Some("effectful"));
+        assert_eq!(purity("append"), Some("effectful"));
+        assert_eq!(purity("increment"), Some("effectful"));
+        assert_eq!(purity("current"), Some("read_only"));
+        assert_eq!(purity("Counter"), None);
+    }
+}
@@ -5,7 +5,6 @@ use crate::types::NativeParsedSymbol;
 pub fn extract_role_tags(symbol: &NativeParsedSymbol, rel_path: &str) -> Vec<String> {
     let name = symbol.name.to_lowercase();
     let path = rel_path.replace('\\', "/").to_lowercase();
-    let file_name = path.rsplit('/').next().unwrap_or("");
     let name_tokens = split_identifier_like_text(&symbol.name)
         .into_iter()
         .map(|part| part.to_lowercase())
@@ -46,13 +45,7 @@ pub fn extract_role_tags(symbol: &NativeParsedSymbol, rel_path: &str) -> Vec<Str
         tags.push("middleware".to_string());
     }
 
-    if path.starts_with("tests/")
-        || path.contains("/tests/")
-        || path.contains(".test.")
-        || path.contains(".spec.")
-        || (file_name.starts_with("test_") && file_name.ends_with(".py"))
-        || file_name.ends_with("_test.py")
-    {
+    if is_test_path(rel_path) {
         tags.push("test".to_string());
     }
 
@@ -97,6 +90,19 @@ pub fn extract_role_tags(symbol: &NativeParsedSymbol, rel_path: &str) -> Vec<Str
     tags
 }
 
+/// Whether `rel_path` is a test file (`tests/`, `*.test.*`, `*.spec.*`,
+/// pytest `test_*.py` / `*_test.py`).
+pub fn is_test_path(rel_path: &str) -> bool {
+    let path = rel_path.replace('\\', "/").to_lowercase();
+    let file_name = path.rsplit('/').next().unwrap_or("");
+    path.starts_with("tests/")
+        || path.contains("/tests/")
+        || path.contains(".test.")
+        || path.contains(".spec.")
+        || (file_name.starts_with("test_") && file_name.ends_with(".py"))
+        || file_name.ends_with("_test.py")
+}
+
 fn split_identifier_like_text(input: &str) -> Vec<String> {
     let mut result = Vec::new();
     let mut current = String::new();
@@ -173,6 +179,7 @@ mod tests {
             decorators: vec![],
             search_text: String::new(),
             summary_quality: None,
+            ..Default::default()
         }
     }
 
@@ -0,0 +1,72 @@
+//! Helpers shared by the per-framework route extractors.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{find_enclosing_symbol, node_text};
+use crate::extract::string_literals::literal_value;
+use crate::types::NativeParsedSymbol;
+
+/// Verb-named registration methods and decorators (`app.get`,
+/// `@router.post`).
+pub const HTTP_METHODS: &[&str] = &["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// Contents of a plain string literal; `None` for any other node.
+pub fn string_value(node: Node<'_>, source: &[u8]) -> Option<String> {
+    if !matches!(node.kind(), "string" | "string_literal" | "template_string") {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let interpolated = node
+        .named_children(&mut cursor)
+        .any(|child| matches!(child.kind(), "template_substitution" | "interpolation"));
+    if interpolated {
+        return None;
+    }
+    literal_value(node_text(node, source)).map(str::to_string)
+}
+
+/// Route paths start with `/` (or are a catch-all `*`).
+pub fn is_route_path(path: &str) -> bool {
+    path.starts_with('/') || path == "*"
+}
+
+/// Join a controller / router prefix and a route path with exactly one `/`
+/// between them.
+pub fn join_paths(prefix: &str, path: &str) -> String {
+    let prefix = prefix.trim_matches('/');
+    let path = path.trim_start_matches('/');
+    match (prefix.is_empty(), path.is_empty()) {
+        (true, true) => "/".to_string(),
+        (false, true) => format!("/{prefix}"),
+        (true, false) => format!("/{path}"),
+        (false, false) => format!("/{prefix}/{path}"),
+    }
+}
+
+/// nodeId of the function or method named by `name_node` (the `name` field
+/// of its declaration).
+pub fn declared_node_id(name_node: Node<'_>, symbols: &[NativeParsedSymbol]) -> Option<String> {
+    let node_id = find_enclosing_symbol(name_node, symbols);
+    (node_id != "<module>").then_some(node_id)
+}
+
+/// nodeId of a function or method declared in the file under `name`.
+pub fn callable_node_id(name: &str, symbols: &[NativeParsedSymbol]) -> Option<String> {
+    symbols
+        .iter()
+        .find(|symbol| symbol.name == name && matches!(symbol.kind.as_str(), "function" | "method"))
+        .map(|symbol| symbol.node_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_paths_normalizes_slashes() {
+        assert_eq!(join_paths("/api/", "/users/{id}"), "/api/users/{id}");
+        assert_eq!(join_paths("api", ""), "/api");
+        assert_eq!(join_paths("", "users"), "/users");
+        assert_eq!(join_paths("", ""), "/");
+    }
+}
@@ -0,0 +1,395 @@
+//! File-system routes (Next.js, SvelteKit).
+//!
+//! These frameworks derive the URL from the file's path, so there is no
+//! registration call to find; with `NativeParseOptions::route_framework`
+//! set, the path is mapped instead:
+//!
+//! - Next.js pages router: `pages/blog/[slug].tsx` is `GET /blog/[slug]`
+//!   (`index` maps to the directory, `_app` / `_document` are skipped) and
+//!   `pages/api/*` is an `ALL` API route. The handler is the default
+//!   export.
+//! - Next.js app router: `app/(shop)/cart/page.tsx` is `GET /cart`; a
+//!   `route.ts` registers one route per exported `GET` / `POST` / ...
+//!   function. Route groups `(x)` and parallel slots `@x` add no segment,
+//!   and `_private` folders are not routable.
+//! - SvelteKit: `src/routes/blog/[slug]/+page.server.ts` is `GET
+//!   /blog/[slug]` handled by its `load` export (plus `POST` for
+//!   `actions`); `+server.ts` exports one function per method.
+//!
+//! Dynamic segments keep the framework's `[param]` syntax.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, node_text};
+use crate::types::{NativeHttpRoute, NativeParsedSymbol, NativeRange};
+
+use super::common::{callable_node_id, declared_node_id, HTTP_METHODS};
+
+/// Pages-router files that are not pages.
+const SPECIAL_PAGES: &[&str] = &["_app", "_document", "_error", "_middleware"];
+
+pub fn extract_file_routes(
+    framework: &str,
+    rel_path: &str,
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeHttpRoute> {
+    let segments: Vec<&str> = rel_path.split(['/', '\\']).collect();
+    let Some((file_name, directories)) = segments.split_last() else {
+        return Vec::new();
+    };
+    // `page.tsx` -> `page`; `+page.server.ts` -> `+page.server`.
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(*file_name, |(stem, _)| stem);
+    let exports = Exports::collect(root, source, symbols);
+
+    match framework {
+        "nextjs" => {
+            if let Some(index) = directories.iter().position(|dir| *dir == "app") {
+                app_router_routes(&directories[index + 1..], stem, &exports)
+            } else if let Some(index) = directories.iter().position(|dir| *dir == "pages") {
+                pages_router_routes(&directories[index + 1..], stem, &exports)
+            } else {
+                Vec::new()
+            }
+        }
+        "sveltekit" => directories
+            .windows(2)
+            .position(|pair| pair == ["src", "routes"])
+            .map(|index| sveltekit_routes(&directories[index + 2..], stem, &exports))
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+fn pages_router_routes(
+    directories: &[&str],
+    stem: &str,
+    exports: &Exports,
+) -> Vec<NativeHttpRoute> {
+    if SPECIAL_PAGES.contains(&stem) {
+        return Vec::new();
+    }
+    let Some(default) = &exports.default else {
+        return Vec::new();
+    };
+    let mut path_segments = directories.to_vec();
+    if stem != "index" {
+        path_segments.push(stem);
+    }
+    let method = if directories.first() == Some(&"api") {
+        "ALL"
+    } else {
+        "GET"
+    };
+    vec![route(method, &path_segments, default)]
+}
+
+fn app_router_routes(directories: &[&str], stem: &str, exports: &Exports) -> Vec<NativeHttpRoute> {
+    if directories.iter().any(|dir| dir.starts_with('_')) {
+        return Vec::new();
+    }
+    let path_segments: Vec<&str> = directories
+        .iter()
+        .copied()
+        .filter(|dir| !is_group(dir) && !dir.starts_with('@'))
+        .collect();
+    match stem {
+        "page" => exports
+            .default
+            .iter()
+            .map(|default| route("GET", &path_segments, default))
+            .collect(),
+        "route" => method_routes(&path_segments, exports),
+        _ => Vec::new(),
+    }
+}
+
+fn sveltekit_routes(directories: &[&str], stem: &str, exports: &Exports) -> Vec<NativeHttpRoute> {
+    let path_segments: Vec<&str> = directories
+        .iter()
+        .copied()
+        .filter(|dir| !is_group(dir))
+        .collect();
+    match stem {
+        "+page" | "+page.server" => {
+            let mut routes: Vec<NativeHttpRoute> = exports
+                .named("load")
+                .map(|load| route("GET", &path_segments, load))
+                .into_iter()
+                .collect();
+            routes.extend(
+                exports
+                    .named("actions")
+                    .map(|actions| route("POST", &path_segments, actions)),
+            );
+            routes
+        }
+        "+server" => method_routes(&path_segments, exports),
+        _ => Vec::new(),
+    }
+}
+
+/// One route per exported upper-case HTTP method function.
+fn method_routes(path_segments: &[&str], exports: &Exports) -> Vec<NativeHttpRoute> {
+    exports
+        .named
+        .iter()
+        .filter(|export| {
+            HTTP_METHODS
+                .iter()
+                .any(|method| method.to_ascii_uppercase() == export.name)
+        })
+        .map(|export| route(&export.name, path_segments, export))
+        .collect()
+}
+
+/// `(group)` directories.
+fn is_group(dir: &str) -> bool {
+    dir.starts_with('(') && dir.ends_with(')')
+}
+
+fn route(method: &str, path_segments: &[&str], export: &Export) -> NativeHttpRoute {
+    NativeHttpRoute {
+        method: method.to_string(),
+        path: format!("/{}", path_segments.join("/")),
+        handler: export.local.clone(),
+        handler_node_id: export.node_id.clone(),
+        caller_node_id: "<module>".to_string(),
+        range: export.range.clone(),
+    }
+}
+
+/// A module export: its exported name, the local binding it refers to and
+/// that binding's nodeId.
+struct Export {
+    name: String,
+    local: Option<String>,
+    node_id: Option<String>,
+    range: NativeRange,
+}
+
+#[derive(Default)]
+struct Exports {
+    default: Option<Export>,
+    named: Vec<Export>,
+}
+
+impl Exports {
+    fn collect(root: Node<'_>, source: &[u8], symbols: &[NativeParsedSymbol]) -> Self {
+        let mut exports = Exports::default();
+        let mut cursor = root.walk();
+        for statement in root
+            .named_children(&mut cursor)
+            .filter(|statement| statement.kind() == "export_statement")
+        {
+            let range = extract_range(statement);
+            let is_default = {
+                let mut inner = statement.walk();
+                let found = statement
+                    .children(&mut inner)
+                    .any(|child| child.kind() == "default");
+                found
+            };
+            if is_default {
+                // `export default function Page() {}` / `export default Page;`
+                // / `export default () => ...`.
+                let target = statement
+                    .child_by_field_name("declaration")
+                    .or_else(|| statement.child_by_field_name("value"));
+                let (local, node_id) = match target {
+                    Some(target) if target.kind() == "identifier" => {
+                        let name = node_text(target, source).to_string();
+                        let node_id = symbol_node_id(&name, symbols);
+                        (Some(name), node_id)
+                    }
+                    Some(target) => match target.child_by_field_name("name") {
+                        Some(name) => (
+                            Some(node_text(name, source).to_string()),
+                            declared_node_id(name, symbols),
+                        ),
+                        None => (None, declared_node_id(target, symbols)),
+                    },
+                    None => (None, None),
+                };
+                exports.default = Some(Export {
+                    name: "default".to_string(),
+                    local,
+                    node_id,
+                    range,
+                });
+            } else if let Some(declaration) = statement.child_by_field_name("declaration") {
+                for name in declared_names(declaration) {
+                    let text = node_text(name, source).to_string();
+                    exports.named.push(Export {
+                        name: text.clone(),
+                        local: Some(text),
+                        node_id: declared_node_id(name, symbols),
+                        range: range.clone(),
+                    });
+                }
+            } else {
+                // `export { handler as GET }`.
+                let mut inner = statement.walk();
+                let clause = statement
+                    .named_children(&mut inner)
+                    .find(|child| child.kind() == "export_clause");
+                let Some(clause) = clause else {
+                    continue;
+                };
+                let mut specifiers = clause.walk();
+                for specifier in clause
+                    .named_children(&mut specifiers)
+                    .filter(|child| child.kind() == "export_specifier")
+                {
+                    let Some(local) = specifier.child_by_field_name("name") else {
+                        continue;
+                    };
+                    let local = node_text(local, source).to_string();
+                    let name = specifier
+                        .child_by_field_name("alias")
+                        .map(|alias| node_text(alias, source).to_string())
+                        .unwrap_or_else(|| local.clone());
+                    exports.named.push(Export {
+                        name,
+                        node_id: symbol_node_id(&local, symbols),
+                        local: Some(local),
+                        range: range.clone(),
+                    });
+                }
+            }
+        }
+        exports
+    }
+
+    fn named(&self, name: &str) -> Option<&Export> {
+        self.named.iter().find(|export| export.name == name)
+    }
+}
+
+/// Name nodes bound by an exported declaration (`function GET`, `const
+/// load = ...`).
+fn declared_names(declaration: Node<'_>) -> Vec<Node<'_>> {
+    if let Some(name) = declaration.child_by_field_name("name") {
+        return vec![name];
+    }
+    let mut cursor = declaration.walk();
+    let names = declaration
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "variable_declarator")
+        .filter_map(|declarator| declarator.child_by_field_name("name"))
+        .filter(|name| name.kind() == "identifier")
+        .collect();
+    names
+}
+
+/// nodeId of the function declared under `name`, else of any symbol with
+/// that name (`const Page = () => ...`).
+fn symbol_node_id(name: &str, symbols: &[NativeParsedSymbol]) -> Option<String> {
+    callable_node_id(name, symbols).or_else(|| {
+        symbols
+            .iter()
+            .find(|symbol| symbol.name == name)
+            .map(|symbol| symbol.node_id.clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::{parse_source, parse_source_with_options};
+    use crate::types::NativeParseOptions;
+
+    #[test]
+    fn file_convention_routes_follow_the_route_framework() {
+        let cases = [
+            (
+                "nextjs",
+                "web/pages/blog/[slug].tsx",
+                "tsx",
+                "export default function BlogPost() {\n  return null;\n}\n",
+                vec![("GET", "/blog/[slug]", Some("BlogPost"))],
+            ),
+            (
+                "nextjs",
+                "web/pages/api/users/index.ts",
+                "ts",
+                "const handler = (req, res) => res.end();\nexport default handler;\n",
+                vec![("ALL", "/api/users", Some("handler"))],
+            ),
+            (
+                "nextjs",
+                "web/pages/_app.tsx",
+                "tsx",
+                "export default function App() {}\n",
+                vec![],
+            ),
+            (
+                "nextjs",
+                "src/app/(shop)/cart/page.tsx",
+                "tsx",
+                "export default function CartPage() {}\n",
+                vec![("GET", "/cart", Some("CartPage"))],
+            ),
+            (
+                "nextjs",
+                "src/app/api/items/[id]/route.ts",
+                "ts",
+                "export async function GET() {}\nexport const POST = async () => {};\nexport function helper() {}\n",
+                vec![
+                    ("GET", "/api/items/[id]", Some("GET")),
+                    ("POST", "/api/items/[id]", Some("POST")),
+                ],
+            ),
+            (
+                "sveltekit",
+                "src/routes/(app)/blog/[slug]/+page.server.ts",
+                "ts",
+                "export async function load() {}\nexport const actions = {};\n",
+                vec![
+                    ("GET", "/blog/[slug]", Some("load")),
+                    ("POST", "/blog/[slug]", Some("actions")),
+                ],
+            ),
+            (
+                "sveltekit",
+                "src/routes/api/+server.ts",
+                "ts",
+                "async function list() {}\nexport { list as GET };\n",
+                vec![("GET", "/api", Some("list"))],
+            ),
+        ];
+
+        for (framework, rel_path, language, source, expected) in cases.into_iter() {
+            let options = NativeParseOptions {
+                route_framework: Some(framework.to_string()),
+                ..Default::default()
+            };
+
+            let parsed = parse_source_with_options(language, rel_path, source, &options);
+            let plain = parse_source(language, rel_path, source);
+
+            let routes: Vec<(&str, &str, Option<&str>)> = parsed
+                .routes
+                .iter()
+                .map(|route| {
+                    (
+                        route.method.as_str(),
+                        route.path.as_str(),
+                        route.handler.as_deref(),
+                    )
+                })
+                .collect();
+            assert_eq!(routes, expected, "{rel_path}");
+            assert!(
+                parsed
+                    .routes
+                    .iter()
+                    .all(|route| route.handler_node_id.is_some()),
+                "{rel_path}"
+            );
+            assert!(plain.routes.is_empty(), "{rel_path}");
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Spring MVC mapping annotations.
+//!
+//! `@GetMapping("/{id}")` and friends name the method;
+//! `@RequestMapping(path = "/x", method = RequestMethod.POST)` lists it, and
+//! matches every method when it does not. A `@RequestMapping` on the class
+//! prefixes the paths of its methods. The annotated method is the handler.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::types::{NativeHttpRoute, NativeParsedSymbol};
+
+use super::common::{declared_node_id, join_paths, string_value};
+
+const CLASS_KINDS: &[&str] = &["class_declaration", "interface_declaration"];
+
+pub fn extract_routes_java(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeHttpRoute> {
+    let mut routes = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "method_declaration" {
+            process_method(node, source, symbols, &mut routes);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    routes
+}
+
+fn process_method(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    routes: &mut Vec<NativeHttpRoute>,
+) {
+    let Some(name_node) = node.child_by_field_name("name") else {
+        return;
+    };
+    let prefixes = class_prefixes(node, source);
+
+    for annotation in annotations(node) {
+        let Some(mapping) = mapping(annotation, source) else {
+            continue;
+        };
+        for prefix in &prefixes {
+            for path in &mapping.paths {
+                for method in &mapping.methods {
+                    routes.push(NativeHttpRoute {
+                        method: method.clone(),
+                        path: join_paths(prefix, path),
+                        handler: Some(node_text(name_node, source).to_string()),
+                        handler_node_id: declared_node_id(name_node, symbols),
+                        caller_node_id: find_enclosing_symbol(annotation, symbols),
+                        range: extract_range(annotation),
+                    });
+                }
+            }
+        }
+    }
+}
+
+struct Mapping {
+    methods: Vec<String>,
+    paths: Vec<String>,
+}
+
+/// Method(s) and path(s) of a mapping annotation; `None` for any other
+/// annotation.
+fn mapping(annotation: Node<'_>, source: &[u8]) -> Option<Mapping> {
+    let name = node_text(annotation.child_by_field_name("name")?, source);
+    let name = name.rsplit('.').next().unwrap_or(name);
+    let verb = match name {
+        "GetMapping" => Some("GET"),
+        "PostMapping" => Some("POST"),
+        "PutMapping" => Some("PUT"),
+        "PatchMapping" => Some("PATCH"),
+        "DeleteMapping" => Some("DELETE"),
+        "RequestMapping" => None,
+        _ => return None,
+    };
+
+    let mut paths = Vec::new();
+    let mut methods = Vec::new();
+    if let Some(arguments) = annotation.child_by_field_name("arguments") {
+        let mut cursor = arguments.walk();
+        for argument in arguments.named_children(&mut cursor) {
+            if argument.kind() != "element_value_pair" {
+                paths.extend(string_values(argument, source));
+                continue;
+            }
+            let (Some(key), Some(value)) = (
+                argument.child_by_field_name("key"),
+                argument.child_by_field_name("value"),
+            ) else {
+                continue;
+            };
+            match node_text(key, source) {
+                "value" | "path" => paths.extend(string_values(value, source)),
+                "method" => methods.extend(request_methods(value, source)),
+                _ => {}
+            }
+        }
+    }
+    if paths.is_empty() {
+        paths.push(String::new());
+    }
+    if let Some(verb) = verb {
+        methods = vec![verb.to_string()];
+    } else if methods.is_empty() {
+        methods.push("ALL".to_string());
+    }
+    Some(Mapping { methods, paths })
+}
+
+/// Paths of every enclosing class's `@RequestMapping`, joined outermost
+/// first; `[""]` when there is none.
+fn class_prefixes(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    let mut prefixes = vec![String::new()];
+    let mut current = node.parent();
+    while let Some(ancestor) = current {
+        if CLASS_KINDS.contains(&ancestor.kind()) {
+            let class_paths = annotations(ancestor)
+                .into_iter()
+                .filter_map(|annotation| mapping(annotation, source))
+                .flat_map(|mapping| mapping.paths)
+                .collect::<Vec<_>>();
+            if !class_paths.is_empty() {
+                prefixes = class_paths
+                    .iter()
+                    .flat_map(|outer| prefixes.iter().map(move |inner| join_paths(outer, inner)))
+                    .collect();
+            }
+        }
+        current = ancestor.parent();
+    }
+    prefixes
+}
+
+/// The annotations in a declaration's `modifiers`.
+fn annotations(node: Node<'_>) -> Vec<Node<'_>> {
+    let mut cursor = node.walk();
+    let Some(modifiers) = node
+        .children(&mut cursor)
+        .find(|child| child.kind() == "modifiers")
+    else {
+        return Vec::new();
+    };
+    let mut inner = modifiers.walk();
+    let found = modifiers
+        .named_children(&mut inner)
+        .filter(|child| matches!(child.kind(), "annotation" | "marker_annotation"))
+        .collect();
+    found
+}
+
+/// A string or a `{ "a", "b" }` array of them.
+fn string_values(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    if node.kind() == "element_value_array_initializer" {
+        let mut cursor = node.walk();
+        let values = node
+            .named_children(&mut cursor)
+            .filter_map(|item| string_value(item, source))
+            .collect();
+        return values;
+    }
+    string_value(node, source).into_iter().collect()
+}
+
+/// `RequestMethod.GET` or `{ RequestMethod.GET, RequestMethod.HEAD }`.
+fn request_methods(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    let constant = |item: Node<'_>| {
+        let text = node_text(item, source);
+        text.rsplit('.').next().unwrap_or(text).to_string()
+    };
+    if node.kind() == "element_value_array_initializer" {
+        let mut cursor = node.walk();
+        let methods = node.named_children(&mut cursor).map(constant).collect();
+        return methods;
+    }
+    vec![constant(node)]
+}
@@ -0,0 +1,92 @@
+pub mod common;
+pub(crate) mod file_conventions;
+pub(crate) mod java;
+pub(crate) mod python;
+pub(crate) mod typescript;
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn http_route_registrations_record_method_path_and_handler() {
+        let cases = [
+            (
+                "ts",
+                "server.ts",
+                r#"function listUsers(req, res) {}
+
+export function register(app, fastify) {
+  app.get("/users", auth, listUsers);
+  app.post("/users", (req, res) => res.send());
+  cache.get("users", fallback);
+  fastify.route({ method: ["PUT", "PATCH"], url: "/users/:id", handler: listUsers });
+}
+"#,
+                vec![
+                    ("GET", "/users", Some("listUsers"), true),
+                    ("POST", "/users", None, false),
+                    ("PUT", "/users/:id", Some("listUsers"), true),
+                    ("PATCH", "/users/:id", Some("listUsers"), true),
+                ],
+            ),
+            (
+                "py",
+                "api.py",
+                r#"@app.get("/items/{item_id}")
+async def read_item(item_id: int):
+    return item_id
+
+@app.route("/login", methods=["GET", "POST"])
+def login():
+    pass
+"#,
+                vec![
+                    ("GET", "/items/{item_id}", Some("read_item"), true),
+                    ("GET", "/login", Some("login"), true),
+                    ("POST", "/login", Some("login"), true),
+                ],
+            ),
+            (
+                "java",
+                "UserController.java",
+                r#"@RestController
+@RequestMapping("/api/users")
+public class UserController {
+    @GetMapping("/{id}")
+    public User show(@PathVariable long id) { return null; }
+
+    @RequestMapping(path = "/search", method = RequestMethod.POST)
+    public List<User> search() { return null; }
+
+    @DeleteMapping
+    public void clear() {}
+}
+"#,
+                vec![
+                    ("GET", "/api/users/{id}", Some("show"), true),
+                    ("POST", "/api/users/search", Some("search"), true),
+                    ("DELETE", "/api/users", Some("clear"), true),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let routes: Vec<(&str, &str, Option<&str>, bool)> = parsed
+                .routes
+                .iter()
+                .map(|route| {
+                    (
+                        route.method.as_str(),
+                        route.path.as_str(),
+                        route.handler.as_deref(),
+                        route.handler_node_id.is_some(),
+                    )
+                })
+                .collect();
+            assert_eq!(routes, expected, "{language}");
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! FastAPI / Flask style route decorators.
+//!
+//! `@app.get("/users/{id}")` and `@router.post(...)` name the method;
+//! Flask's `@app.route("/x", methods=["GET", "POST"])` and FastAPI's
+//! `@app.api_route(...)` list them (Flask defaults to GET). The decorated
+//! function is the handler.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::types::{NativeHttpRoute, NativeParsedSymbol};
+
+use super::common::{declared_node_id, is_route_path, string_value, HTTP_METHODS};
+
+/// Decorators that take the methods as a `methods=[...]` keyword.
+const ROUTE_DECORATORS: &[&str] = &["route", "api_route"];
+
+pub fn extract_routes_python(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeHttpRoute> {
+    let mut routes = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "decorated_definition" {
+            process_decorated(node, source, symbols, &mut routes);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    routes
+}
+
+fn process_decorated(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    routes: &mut Vec<NativeHttpRoute>,
+) {
+    let Some(name_node) = node
+        .child_by_field_name("definition")
+        .filter(|definition| definition.kind() == "function_definition")
+        .and_then(|definition| definition.child_by_field_name("name"))
+    else {
+        return;
+    };
+    let handler = node_text(name_node, source).to_string();
+    let handler_node_id = declared_node_id(name_node, symbols);
+
+    let mut cursor = node.walk();
+    for decorator in node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "decorator")
+    {
+        let Some(call) = decorator
+            .named_child(0)
+            .filter(|expression| expression.kind() == "call")
+        else {
+            continue;
+        };
+        let Some(decorator_name) = call
+            .child_by_field_name("function")
+            .filter(|function| function.kind() == "attribute")
+            .and_then(|function| function.child_by_field_name("attribute"))
+            .map(|attribute| node_text(attribute, source))
+        else {
+            continue;
+        };
+        let Some(args) = call.child_by_field_name("arguments") else {
+            continue;
+        };
+
+        let Some(path) = args
+            .named_child(0)
+            .and_then(|first| string_value(first, source))
+            .filter(|path| is_route_path(path))
+        else {
+            continue;
+        };
+        let methods = if HTTP_METHODS.contains(&decorator_name) {
+            vec![decorator_name.to_ascii_uppercase()]
+        } else if ROUTE_DECORATORS.contains(&decorator_name) {
+            let listed = listed_methods(args, source);
+            if listed.is_empty() {
+                vec!["GET".to_string()]
+            } else {
+                listed
+            }
+        } else {
+            continue;
+        };
+
+        for method in methods {
+            routes.push(NativeHttpRoute {
+                method,
+                path: path.clone(),
+                handler: Some(handler.clone()),
+                handler_node_id: handler_node_id.clone(),
+                caller_node_id: find_enclosing_symbol(decorator, symbols),
+                range: extract_range(decorator),
+            });
+        }
+    }
+}
+
+/// The strings of a `methods=[...]` keyword argument, upper-cased.
+fn listed_methods(args: Node<'_>, source: &[u8]) -> Vec<String> {
+    let mut cursor = args.walk();
+    let Some(list) = args
+        .named_children(&mut cursor)
+        .filter(|arg| arg.kind() == "keyword_argument")
+        .find(|arg| {
+            arg.child_by_field_name("name")
+                .is_some_and(|name| node_text(name, source) == "methods")
+        })
+        .and_then(|arg| arg.child_by_field_name("value"))
+    else {
+        return Vec::new();
+    };
+    let mut inner = list.walk();
+    let methods = list
+        .named_children(&mut inner)
+        .filter_map(|item| string_value(item, source))
+        .map(|method| method.to_ascii_uppercase())
+        .collect();
+    methods
+}
@@ -0,0 +1,166 @@
+//! Express / Fastify / Koa-router / Hono style route registrations.
+//!
+//! `app.get("/users/:id", handler)` registers one route per call; Fastify's
+//! `fastify.route({ method, url, handler })` takes the same parts as an
+//! object.
+
+use tree_sitter::Node;
+
+use crate::extract::calls::common::{extract_range, find_enclosing_symbol, node_text};
+use crate::types::{NativeHttpRoute, NativeParsedSymbol};
+
+use super::common::{callable_node_id, is_route_path, string_value, HTTP_METHODS};
+
+pub fn extract_routes_ts(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeHttpRoute> {
+    let mut routes = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression" {
+            process_route_call(node, source, symbols, &mut routes);
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    routes
+}
+
+fn process_route_call(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    routes: &mut Vec<NativeHttpRoute>,
+) {
+    let Some(callee) = node.child_by_field_name("function") else {
+        return;
+    };
+    if callee.kind() != "member_expression" {
+        return;
+    }
+    let Some(method) = callee
+        .child_by_field_name("property")
+        .map(|property| node_text(property, source))
+    else {
+        return;
+    };
+    let Some(args) = node.child_by_field_name("arguments") else {
+        return;
+    };
+    let mut cursor = args.walk();
+    let named: Vec<Node<'_>> = args
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() != "comment")
+        .collect();
+
+    if method == "route" {
+        if let &[options] = &named[..] {
+            if options.kind() == "object" {
+                process_route_options(node, options, source, symbols, routes);
+            }
+        }
+        return;
+    }
+    if !(HTTP_METHODS.contains(&method) || method == "all") || named.len() < 2 {
+        return;
+    }
+    let Some(path) = string_value(named[0], source).filter(|path| is_route_path(path)) else {
+        return;
+    };
+    // Middleware may sit between the path and the handler; the handler is
+    // last.
+    let handler = named
+        .last()
+        .copied()
+        .and_then(|arg| handler_name(arg, source));
+    routes.push(route(node, method, path, handler, symbols));
+}
+
+/// `fastify.route({ method: ["GET", "HEAD"], url: "/x", handler })`
+fn process_route_options(
+    node: Node<'_>,
+    options: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+    routes: &mut Vec<NativeHttpRoute>,
+) {
+    let mut methods = Vec::new();
+    let mut path = None;
+    let mut handler = None;
+    let mut cursor = options.walk();
+    for entry in options.named_children(&mut cursor) {
+        match entry.kind() {
+            "pair" => {
+                let (Some(key), Some(value)) = (
+                    entry.child_by_field_name("key"),
+                    entry.child_by_field_name("value"),
+                ) else {
+                    continue;
+                };
+                match node_text(key, source).trim_matches(['"', '\'']) {
+                    "method" => {
+                        if value.kind() == "array" {
+                            let mut inner = value.walk();
+                            methods.extend(
+                                value
+                                    .named_children(&mut inner)
+                                    .filter_map(|item| string_value(item, source)),
+                            );
+                        } else {
+                            methods.extend(string_value(value, source));
+                        }
+                    }
+                    "url" | "path" => path = string_value(value, source),
+                    "handler" => handler = handler_name(value, source),
+                    _ => {}
+                }
+            }
+            "shorthand_property_identifier" if node_text(entry, source) == "handler" => {
+                handler = Some("handler".to_string());
+            }
+            _ => {}
+        }
+    }
+
+    let Some(path) = path.filter(|path| is_route_path(path)) else {
+        return;
+    };
+    for method in methods {
+        routes.push(route(node, &method, path.clone(), handler.clone(), symbols));
+    }
+}
+
+/// Handlers passed by name; inline functions have none.
+fn handler_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+    matches!(node.kind(), "identifier" | "member_expression")
+        .then(|| node_text(node, source).to_string())
+}
+
+fn route(
+    node: Node<'_>,
+    method: &str,
+    path: String,
+    handler: Option<String>,
+    symbols: &[NativeParsedSymbol],
+) -> NativeHttpRoute {
+    let handler_node_id = handler
+        .as_deref()
+        .and_then(|name| callable_node_id(name, symbols));
+    NativeHttpRoute {
+        method: method.to_ascii_uppercase(),
+        path,
+        handler,
+        handler_node_id,
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        range: extract_range(node),
+    }
+}
@@ -20,6 +20,10 @@ pub fn build_search_text(
     parts.extend(role_tags.iter().cloned());
     parts.extend(split_path_tokens(rel_path));
     parts.extend(extract_signature_terms(&symbol.signature));
+    for attribute in &symbol.attributes {
+        parts.push(attribute.value.clone());
+        parts.extend(split_identifier_like_text(&attribute.value));
+    }
 
     let mut seen = HashSet::new();
     let mut normalized = Vec::new();
@@ -118,6 +122,7 @@ mod tests {
                 }]),
                 returns: None,
                 generics: None,
+                ..Default::default()
             }),
             summary: "Handle login requests".to_string(),
             invariants: vec![],
@@ -126,6 +131,7 @@ mod tests {
             decorators: vec![],
             search_text: String::new(),
             summary_quality: None,
+            ..Default::default()
         }
     }
 
@@ -2,7 +2,7 @@ use regex::Regex;
 use std::collections::HashSet;
 use std::sync::LazyLock;
 
-use crate::types::NativeParsedSymbol;
+use crate::types::{NativeBehaviorRecord, NativeParsedSymbol, NativeRange};
 
 /// Detect side effects in a symbol's code.
 ///
@@ -14,60 +14,95 @@ use crate::types::NativeParsedSymbol;
 /// - Database query (db.query, pool.execute, etc.)
 /// - Global state mutation (globalThis, window, document, localStorage)
 /// - Environment access (process.env, process.cwd, import.meta.env)
-pub fn extract_side_effects(symbol: &NativeParsedSymbol, file_content: &str) -> Vec<String> {
+///
+/// The patterns above are shared by every language; `LANGUAGE_PATTERNS`
+/// adds the standard library and common client idioms of Python, Go, Rust
+/// and Java on top.
+///
+/// Each effect is reported once, with the first line that shows it as
+/// evidence and the confidence of the pattern that matched.
+pub fn extract_side_effects(
+    symbol: &NativeParsedSymbol,
+    file_content: &str,
+    language: &str,
+) -> Vec<NativeBehaviorRecord> {
     let mut effects = Vec::new();
     let lines = get_symbol_lines(symbol, file_content);
 
-    static NETWORK_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    static NETWORK_PATTERNS: LazyLock<Vec<(Regex, f64)>> = LazyLock::new(|| {
         vec![
-            Regex::new(r"fetch\s*\(").unwrap(),
-            Regex::new(r"axios\.").unwrap(),
-            Regex::new(r"http\.request\s*\(").unwrap(),
-            Regex::new(r"http\.get\s*\(").unwrap(),
-            Regex::new(r"http\.post\s*\(").unwrap(),
-            Regex::new(r"XMLHttpRequest").unwrap(),
-            Regex::new(r"requests\.(?:get|post|put|delete|patch)\s*\(").unwrap(),
-            Regex::new(r"http\.(?:Get|Post|NewRequest)\s*\(").unwrap(),
-            Regex::new(r"reqwest::(?:get|Client)").unwrap(),
-            Regex::new(r"HttpClient|HttpURLConnection|URL\s*\(").unwrap(),
+            (Regex::new(r"fetch\s*\(").unwrap(), 0.7),
+            (Regex::new(r"axios\.").unwrap(), 0.9),
+            (Regex::new(r"http\.request\s*\(").unwrap(), 0.9),
+            (Regex::new(r"http\.get\s*\(").unwrap(), 0.9),
+            (Regex::new(r"http\.post\s*\(").unwrap(), 0.9),
+            (Regex::new(r"XMLHttpRequest").unwrap(), 0.9),
+            (
+                Regex::new(r"requests\.(?:get|post|put|delete|patch)\s*\(").unwrap(),
+                0.9,
+            ),
+            (
+                Regex::new(r"http\.(?:Get|Post|NewRequest)\s*\(").unwrap(),
+                0.9,
+            ),
+            (Regex::new(r"reqwest::(?:get|Client)").unwrap(), 0.9),
+            (
+                Regex::new(r"HttpClient|HttpURLConnection|URL\s*\(").unwrap(),
+                0.7,
+            ),
         ]
     });
 
-    static FS_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    static FS_PATTERNS: LazyLock<Vec<(Regex, f64)>> = LazyLock::new(|| {
         vec![
-            Regex::new(r"fs\.readFile").unwrap(),
-            Regex::new(r"fs\.writeFile").unwrap(),
-            Regex::new(r"fs\.appendFile").unwrap(),
-            Regex::new(r"fs\.unlink").unwrap(),
-            Regex::new(r"fs\.mkdir").unwrap(),
-            Regex::new(r"fs\.rmdir").unwrap(),
-            Regex::new(r"fs\.existsSync").unwrap(),
-            Regex::new(r"fs\.readFileSync").unwrap(),
-            Regex::new(r"fs\.writeFileSync").unwrap(),
-            Regex::new(r"readFileSync").unwrap(),
-            Regex::new(r"writeFileSync").unwrap(),
-            Regex::new(r"open\s*\(").unwrap(),
-            Regex::new(r"os\.(?:path|listdir|makedirs|remove)").unwrap(),
-            Regex::new(r"os\.(?:Open|Create|ReadFile)\s*\(").unwrap(),
-            Regex::new(r"std::fs::(?:read|write|create|remove|rename)").unwrap(),
-            Regex::new(r"new\s+File(?:Input|Output)Stream\s*\(").unwrap(),
-            Regex::new(r"Files\.(?:read|write)").unwrap(),
+            (Regex::new(r"fs\.readFile").unwrap(), 0.9),
+            (Regex::new(r"fs\.writeFile").unwrap(), 0.9),
+            (Regex::new(r"fs\.appendFile").unwrap(), 0.9),
+            (Regex::new(r"fs\.unlink").unwrap(), 0.9),
+            (Regex::new(r"fs\.mkdir").unwrap(), 0.9),
+            (Regex::new(r"fs\.rmdir").unwrap(), 0.9),
+            (Regex::new(r"fs\.existsSync").unwrap(), 0.9),
+            (Regex::new(r"fs\.readFileSync").unwrap(), 0.9),
+            (Regex::new(r"fs\.writeFileSync").unwrap(), 0.9),
+            (Regex::new(r"readFileSync").unwrap(), 0.8),
+            (Regex::new(r"writeFileSync").unwrap(), 0.8),
+            (Regex::new(r"open\s*\(").unwrap(), 0.4),
+            (
+                Regex::new(r"os\.(?:path|listdir|makedirs|remove)").unwrap(),
+                0.6,
+            ),
+            (
+                Regex::new(r"os\.(?:Open|Create|ReadFile)\s*\(").unwrap(),
+                0.9,
+            ),
+            (
+                Regex::new(r"std::fs::(?:read|write|create|remove|rename)").unwrap(),
+                0.9,
+            ),
+            (
+                Regex::new(r"new\s+File(?:Input|Output)Stream\s*\(").unwrap(),
+                0.9,
+            ),
+            (Regex::new(r"Files\.(?:read|write)").unwrap(), 0.9),
         ]
     });
 
-    static DB_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    static DB_PATTERNS: LazyLock<Vec<(Regex, f64)>> = LazyLock::new(|| {
         vec![
-            Regex::new(r"db\.query\s*\(").unwrap(),
-            Regex::new(r"db\.execute\s*\(").unwrap(),
-            Regex::new(r"pool\.query\s*\(").unwrap(),
-            Regex::new(r"pool\.execute\s*\(").unwrap(),
-            Regex::new(r"connection\.query").unwrap(),
-            Regex::new(r"connection\.execute").unwrap(),
-            Regex::new(r"client\.query").unwrap(),
-            Regex::new(r"\.query\s*\(").unwrap(),
-            Regex::new(r"sqlite3\.connect\s*\(").unwrap(),
-            Regex::new(r"sql\.Open\s*\(").unwrap(),
-            Regex::new(r"DriverManager\.getConnection\s*\(").unwrap(),
+            (Regex::new(r"db\.query\s*\(").unwrap(), 0.9),
+            (Regex::new(r"db\.execute\s*\(").unwrap(), 0.9),
+            (Regex::new(r"pool\.query\s*\(").unwrap(), 0.9),
+            (Regex::new(r"pool\.execute\s*\(").unwrap(), 0.9),
+            (Regex::new(r"connection\.query").unwrap(), 0.9),
+            (Regex::new(r"connection\.execute").unwrap(), 0.9),
+            (Regex::new(r"client\.query").unwrap(), 0.7),
+            (Regex::new(r"\.query\s*\(").unwrap(), 0.5),
+            (Regex::new(r"sqlite3\.connect\s*\(").unwrap(), 0.9),
+            (Regex::new(r"sql\.Open\s*\(").unwrap(), 0.9),
+            (
+                Regex::new(r"DriverManager\.getConnection\s*\(").unwrap(),
+                0.9,
+            ),
         ]
     });
 
@@ -82,95 +117,259 @@ pub fn extract_side_effects(symbol: &NativeParsedSymbol, file_content: &str) ->
         ]
     });
 
-    static ENV_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    static ENV_PATTERNS: LazyLock<Vec<(Regex, f64)>> = LazyLock::new(|| {
         vec![
-            Regex::new(r"process\.env").unwrap(),
-            Regex::new(r"process\.cwd").unwrap(),
-            Regex::new(r"import\.meta\.env").unwrap(),
-            Regex::new(r"os\.environ").unwrap(),
-            Regex::new(r"os\.Getenv\s*\(").unwrap(),
-            Regex::new(r"std::env::(?:var|vars|args)").unwrap(),
-            Regex::new(r"System\.getenv\s*\(").unwrap(),
+            (Regex::new(r"process\.env").unwrap(), 0.9),
+            (Regex::new(r"process\.cwd").unwrap(), 0.9),
+            (Regex::new(r"import\.meta\.env").unwrap(), 0.9),
+            (Regex::new(r"os\.environ").unwrap(), 0.9),
+            (Regex::new(r"os\.Getenv\s*\(").unwrap(), 0.9),
+            (Regex::new(r"std::env::(?:var|vars|args)").unwrap(), 0.9),
+            (Regex::new(r"System\.getenv\s*\(").unwrap(), 0.9),
         ]
     });
 
-    static PROCESS_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    static PROCESS_PATTERNS: LazyLock<Vec<(Regex, f64)>> = LazyLock::new(|| {
         vec![
-            Regex::new(r"subprocess\.(?:run|call|Popen)\s*\(").unwrap(),
-            Regex::new(r"exec\.Command\s*\(").unwrap(),
-            Regex::new(r"std::process::Command").unwrap(),
-            Regex::new(r"Runtime\.getRuntime\(\)\.exec\s*\(").unwrap(),
+            (
+                Regex::new(r"subprocess\.(?:run|call|Popen)\s*\(").unwrap(),
+                0.9,
+            ),
+            (Regex::new(r"exec\.Command\s*\(").unwrap(), 0.9),
+            (Regex::new(r"std::process::Command").unwrap(), 0.9),
+            (
+                Regex::new(r"Runtime\.getRuntime\(\)\.exec\s*\(").unwrap(),
+                0.9,
+            ),
         ]
     });
 
-    for line in &lines {
+    for (offset, line) in lines.iter().enumerate() {
+        let line_number = symbol.range.start_line.max(1) + offset as u32;
+        let mut push = |category: &str, effect: &str, found: regex::Match<'_>, confidence: f64| {
+            effects.push(record(
+                category,
+                effect,
+                line,
+                line_number,
+                found,
+                confidence,
+            ));
+        };
+        let first_match = |patterns: &'static [(Regex, f64)]| {
+            patterns.iter().find_map(|(pattern, confidence)| {
+                pattern.find(line).map(|found| (found, *confidence))
+            })
+        };
+
         // Network I/O
-        for pattern in NETWORK_PATTERNS.iter() {
-            if pattern.is_match(line) {
-                effects.push("Network I/O".to_string());
-                break;
-            }
+        if let Some((found, confidence)) = first_match(&NETWORK_PATTERNS) {
+            push("network", NETWORK, found, confidence);
         }
 
         // Filesystem I/O
-        for pattern in FS_PATTERNS.iter() {
-            if pattern.is_match(line) {
-                effects.push("Filesystem I/O".to_string());
-                break;
-            }
+        if let Some((found, confidence)) = first_match(&FS_PATTERNS) {
+            push("filesystem", FILESYSTEM, found, confidence);
         }
 
         // Database query
-        for pattern in DB_PATTERNS.iter() {
-            if pattern.is_match(line) {
-                effects.push("Database query".to_string());
-                break;
-            }
+        if let Some((found, confidence)) = first_match(&DB_PATTERNS) {
+            push("database", DATABASE, found, confidence);
         }
 
         // Global state mutation
-        for pattern in GLOBAL_PATTERNS.iter() {
-            if pattern.is_match(line) && !line.contains("//") && !line.contains("/*") {
+        if let Some(found) = GLOBAL_PATTERNS
+            .iter()
+            .find_map(|pattern| pattern.find(line))
+        {
+            if !line.contains("//") && !line.contains("/*") {
                 if line.contains("window.") && !line.contains("window.addEventListener") {
-                    effects.push("Global state mutation (window)".to_string());
+                    push("global_state", "Global state mutation (window)", found, 0.6);
                 } else if line.contains("document.") && line.contains('=') {
-                    effects.push("DOM mutation".to_string());
+                    push("dom", "DOM mutation", found, 0.6);
                 } else if line.contains("globalThis.")
                     || line.contains("localStorage.")
                     || line.contains("sessionStorage.")
                 {
-                    effects.push("Global state mutation".to_string());
+                    push("global_state", "Global state mutation", found, 0.7);
                 }
-                break;
             }
         }
 
         // Environment access
-        for pattern in ENV_PATTERNS.iter() {
-            if pattern.is_match(line) {
-                effects.push("Environment access".to_string());
-                break;
-            }
+        if let Some((found, confidence)) = first_match(&ENV_PATTERNS) {
+            push("environment", ENVIRONMENT, found, confidence);
         }
 
         // Process spawning
-        for pattern in PROCESS_PATTERNS.iter() {
-            if pattern.is_match(line) {
-                effects.push("Process spawning".to_string());
-                break;
+        if let Some((found, confidence)) = first_match(&PROCESS_PATTERNS) {
+            push("process", PROCESS, found, confidence);
+        }
+
+        for (_, effect, pattern, confidence) in LANGUAGE_PATTERNS
+            .iter()
+            .filter(|(pattern_language, _, _, _)| *pattern_language == language)
+        {
+            if let Some(found) = pattern.find(line) {
+                push(category(effect), effect, found, *confidence);
             }
         }
     }
 
     // Deduplicate
     let mut seen = HashSet::new();
-    effects.retain(|item| seen.insert(item.clone()));
+    effects.retain(|item| seen.insert(item.text.clone()));
     effects
 }
 
+fn record(
+    category: &str,
+    effect: &str,
+    line: &str,
+    line_number: u32,
+    found: regex::Match<'_>,
+    confidence: f64,
+) -> NativeBehaviorRecord {
+    NativeBehaviorRecord {
+        text: effect.to_string(),
+        category: category.to_string(),
+        evidence: evidence(line),
+        range: NativeRange {
+            start_line: line_number,
+            start_col: found.start() as u32,
+            end_line: line_number,
+            end_col: found.end() as u32,
+        },
+        confidence,
+    }
+}
+
+/// `text` with whitespace runs collapsed, cut to 160 characters.
+pub fn evidence(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(160)
+        .collect()
+}
+
+fn category(effect: &str) -> &'static str {
+    match effect {
+        NETWORK => "network",
+        FILESYSTEM => "filesystem",
+        DATABASE => "database",
+        ENVIRONMENT => "environment",
+        _ => "process",
+    }
+}
+
+const NETWORK: &str = "Network I/O";
+const FILESYSTEM: &str = "Filesystem I/O";
+const DATABASE: &str = "Database query";
+const ENVIRONMENT: &str = "Environment access";
+const PROCESS: &str = "Process spawning";
+
+/// Per-language detectors: (language id, effect, pattern, confidence).
+static LANGUAGE_PATTERNS: LazyLock<Vec<(&str, &str, Regex, f64)>> = LazyLock::new(|| {
+    [
+        // Python: requests / httpx / urllib, pathlib and shutil, DB-API
+        // cursors and SQLAlchemy sessions, os.system and friends.
+        ("py", NETWORK, r"\b(?:requests|httpx)\.(?:get|post|put|delete|patch|head|request|Client|Session|AsyncClient)\b", 0.85),
+        ("py", NETWORK, r"\burlopen\s*\(|urllib\.request\.|aiohttp\.ClientSession|socket\.socket\s*\(", 0.85),
+        ("py", FILESYSTEM, r"\.(?:read_text|write_text|read_bytes|write_bytes|unlink|touch)\s*\(", 0.7),
+        ("py", FILESYSTEM, r"\bshutil\.(?:copy\w*|move|rmtree)\s*\(|\bos\.(?:rename|unlink|rmdir|mkdir|replace)\s*\(", 0.85),
+        ("py", DATABASE, r"\bcursor\.execute(?:many)?\s*\(|\b(?:psycopg2?|pymysql|sqlite3)\.connect\s*\(", 0.85),
+        ("py", DATABASE, r"\bsession\.(?:add|add_all|commit|delete|execute|query)\s*\(", 0.7),
+        ("py", ENVIRONMENT, r"\bos\.getenv\s*\(", 0.85),
+        ("py", PROCESS, r"\bsubprocess\.(?:check_output|check_call|getoutput)\s*\(|\bos\.(?:system|popen|exec\w*|spawn\w*)\s*\(", 0.85),
+        // Go: net/http and net, os file functions, database/sql, os/exec.
+        ("go", NETWORK, r"\bhttp\.(?:Get|Post|PostForm|Head|NewRequestWithContext|ListenAndServe\w*|Serve)\s*\(", 0.85),
+        ("go", NETWORK, r"\bnet\.(?:Dial\w*|Listen\w*)\s*\(|\.Do\s*\(\s*req\b|\bgrpc\.Dial\w*\s*\(", 0.8),
+        ("go", FILESYSTEM, r"\bos\.(?:OpenFile|ReadFile|WriteFile|Remove|RemoveAll|Mkdir|MkdirAll|Rename|ReadDir|Truncate)\s*\(", 0.85),
+        ("go", FILESYSTEM, r"\bioutil\.(?:ReadFile|WriteFile|ReadDir|TempFile)\s*\(", 0.85),
+        ("go", DATABASE, r"\.(?:Query|QueryRow|QueryContext|QueryRowContext|Exec|ExecContext|Prepare|PrepareContext)\s*\(", 0.6),
+        ("go", ENVIRONMENT, r"\bos\.(?:LookupEnv|Setenv|Unsetenv|Environ)\s*\(", 0.85),
+        ("go", PROCESS, r"\bexec\.CommandContext\s*\(|\bsyscall\.(?:Exec|ForkExec)\s*\(", 0.85),
+        // Rust: reqwest / hyper and std::net, std::fs and tokio::fs,
+        // sqlx / diesel / rusqlite, std::env, Command.
+        ("rs", NETWORK, r"\breqwest::|\bhyper::(?:Client|Server)|\b(?:TcpStream|TcpListener|UdpSocket)::(?:connect|bind)\b", 0.85),
+        ("rs", FILESYSTEM, r"\b(?:std::|tokio::)?fs::(?:read\w*|write|create_dir\w*|remove_\w+|rename|copy|metadata)\s*\(", 0.85),
+        ("rs", FILESYSTEM, r"\bFile::(?:open|create)\s*\(|\bOpenOptions::new\s*\(", 0.85),
+        ("rs", DATABASE, r"\bsqlx::query\w*!?\s*\(|\bdiesel::(?:insert_into|update|delete)\b|\brusqlite::Connection\b", 0.85),
+        ("rs", DATABASE, r"\.(?:fetch_one|fetch_all|fetch_optional|execute)\s*\(\s*&", 0.6),
+        ("rs", ENVIRONMENT, r"\benv::(?:var|vars|var_os|set_var|remove_var|args)\s*\(", 0.85),
+        ("rs", PROCESS, r"\bCommand::new\s*\(", 0.85),
+        // Java: sockets / URL connections / HTTP clients, java.io and
+        // java.nio.file, JDBC and JPA, system properties, ProcessBuilder.
+        ("java", NETWORK, r"\bnew\s+(?:Socket|ServerSocket)\s*\(|\.openConnection\s*\(|\bRestTemplate\b|\bWebClient\.", 0.85),
+        ("java", FILESYSTEM, r"\bjava\.io\.|\bnew\s+(?:File|FileReader|FileWriter|RandomAccessFile|PrintWriter)\s*\(", 0.85),
+        ("java", FILESYSTEM, r"\bFiles\.(?:newBuffered\w+|lines|delete\w*|create\w*|copy|move|list|walk|exists)\s*\(", 0.85),
+        ("java", DATABASE, r"\.(?:prepareStatement|createStatement|prepareCall|executeQuery|executeUpdate|executeBatch)\s*\(", 0.85),
+        ("java", DATABASE, r"\b(?:entityManager|em)\.(?:persist|merge|remove|find|createQuery|createNativeQuery)\s*\(|\bjdbcTemplate\.", 0.85),
+        ("java", ENVIRONMENT, r"\bSystem\.(?:getProperty|setProperty|getenv)\s*\(", 0.85),
+        ("java", PROCESS, r"\bnew\s+ProcessBuilder\s*\(", 0.85),
+    ]
+    .into_iter()
+    .map(|(language, effect, pattern, confidence)| {
+        (language, effect, Regex::new(pattern).unwrap(), confidence)
+    })
+    .collect()
+});
+
 fn get_symbol_lines<'a>(symbol: &NativeParsedSymbol, file_content: &'a str) -> Vec<&'a str> {
     let lines: Vec<&str> = file_content.lines().collect();
     let start = (symbol.range.start_line as usize).saturating_sub(1);
     let end = (symbol.range.end_line as usize).min(lines.len());
     lines[start..end].to_vec()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::NativeRange;
+
+    fn effects(source: &str, language: &str) -> Vec<String> {
+        let symbol = NativeParsedSymbol {
+            range: NativeRange {
+                start_line: 1,
+                start_col: 0,
+                end_line: source.lines().count() as u32,
+                end_col: 0,
+            },
+            ..Default::default()
+        };
+        extract_side_effects(&symbol, source, language)
+            .into_iter()
+            .map(|effect| effect.text)
+            .collect()
+    }
+
+    #[test]
+    fn language_detectors_add_standard_library_effects() {
+        assert_eq!(
+            effects("resp = httpx.get(url)\nos.system(\"ls\")", "py"),
+            vec![NETWORK, PROCESS]
+        );
+        assert_eq!(
+            effects(
+                "data, err := os.ReadFile(path)\nrows, err := db.QueryContext(ctx, q)",
+                "go"
+            ),
+            vec![FILESYSTEM, DATABASE]
+        );
+        assert_eq!(
+            effects(
+                "let body = reqwest::get(url).await?;\nlet home = env::var(\"HOME\");",
+                "rs"
+            ),
+            vec![NETWORK, ENVIRONMENT]
+        );
+        assert_eq!(
+            effects("PreparedStatement s = conn.prepareStatement(sql);", "java"),
+            vec![DATABASE]
+        );
+        // Language detectors only apply to their own language.
+        assert!(effects("rows, err := db.QueryContext(ctx, q)", "rs").is_empty());
+    }
+}
@@ -0,0 +1,300 @@
+//! String literal extraction with a role heuristic.
+//!
+//! Translation keys, endpoints, queries and file paths are usually plain
+//! strings, so matching them across files needs the literals themselves.
+//! Each one is tagged with the first role that fits:
+//!
+//! - `i18n`: first argument of a translation function (`t("home.title")`,
+//!   `gettext(...)`) or the value of an `i18nKey` JSX attribute.
+//! - `sql`: starts with a statement keyword and contains a second one
+//!   (`SELECT ... FROM`, `UPDATE ... SET`).
+//! - `url`: has a scheme (`https://`), or is a rooted path whose last
+//!   segment has no file extension (`/api/users/:id`).
+//! - `path`: relative (`./`, `../`, `~/`) or a path with a file extension
+//!   (`assets/logo.svg`, `/etc/hosts.conf`).
+//!
+//! Import sources, bare string statements (docstrings, `"use strict"`) and
+//! interpolated strings are skipped.
+
+use tree_sitter::Node;
+
+use crate::types::{NativeParsedSymbol, NativeStringLiteral};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+
+/// String literal node kinds across the supported grammars.
+pub const STRING_KINDS: &[&str] = &[
+    "string",
+    "template_string",
+    "string_literal",
+    "raw_string_literal",
+    "interpreted_string_literal",
+    "verbatim_string_literal",
+    "encapsed_string",
+];
+
+/// Children that make a literal dynamic.
+const INTERPOLATIONS: &[&str] = &[
+    "template_substitution",
+    "interpolation",
+    "string_interpolation",
+    "interpolated_string_expression",
+    // PHP `"Hello $name"`
+    "variable_name",
+];
+
+/// Parents whose string child is an import source, not data.
+const IMPORT_CONTEXTS: &[&str] = &[
+    "import_statement",
+    "export_statement",
+    "import_spec",
+    "import_declaration",
+    "preproc_include",
+    "extern_crate_declaration",
+];
+
+/// Callee names (last path segment) of translation functions.
+const I18N_FUNCTIONS: &[&str] = &[
+    "t",
+    "$t",
+    "_",
+    "__",
+    "tr",
+    "gettext",
+    "ngettext",
+    "pgettext",
+    "translate",
+];
+
+/// Keywords a SQL statement starts with.
+const SQL_LEADING: &[&str] = &[
+    "select", "insert", "update", "delete", "create", "alter", "drop", "with", "merge",
+];
+
+/// Keywords of which at least one must follow the leading one.
+const SQL_FOLLOWING: &[&str] = &[
+    "from", "into", "set", "table", "where", "values", "join", "index", "view", "as",
+];
+
+/// Collect the string literals under `root`.
+pub fn extract_string_literals(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeStringLiteral> {
+    let mut literals = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if STRING_KINDS.contains(&node.kind()) {
+            if let Some(literal) = process_literal(node, source, symbols) {
+                literals.push(literal);
+            }
+            continue;
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    literals
+}
+
+fn process_literal(
+    node: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Option<NativeStringLiteral> {
+    let parent = node.parent()?;
+    if IMPORT_CONTEXTS.contains(&parent.kind())
+        || matches!(parent.kind(), "expression_statement" | "literal_type")
+    {
+        return None;
+    }
+    // C# `$"..."` wraps a plain string_literal; Python f-strings carry
+    // `interpolation` children.
+    if has_interpolation(node) || INTERPOLATIONS.contains(&parent.kind()) {
+        return None;
+    }
+    let value = literal_value(node_text(node, source))?;
+    if value.is_empty() {
+        return None;
+    }
+
+    let role = if is_i18n_context(node, parent, source) {
+        Some("i18n")
+    } else {
+        classify(value)
+    };
+
+    Some(NativeStringLiteral {
+        value: value.to_string(),
+        role: role.map(str::to_string),
+        caller_node_id: find_enclosing_symbol(node, symbols),
+        range: extract_range(node),
+    })
+}
+
+fn has_interpolation(node: Node<'_>) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .any(|child| INTERPOLATIONS.contains(&child.kind()));
+    found
+}
+
+/// Text between the quotes, after any prefix (`r`, `b`, `f`, `@`, `r#`).
+pub fn literal_value(text: &str) -> Option<&str> {
+    let start = text.find(['"', '\'', '`'])?;
+    let body = text[start..].trim_end_matches('#');
+    let quote = body.chars().next()?;
+    let width = if body.len() >= 6 && body.starts_with(&quote.to_string().repeat(3)) {
+        3
+    } else {
+        1
+    };
+    if body.len() < width * 2 || !body.ends_with(quote) {
+        return None;
+    }
+    body.get(width..body.len() - width)
+}
+
+/// `t("key")`, `i18n.t("key")`, `<Trans i18nKey="key" />`.
+fn is_i18n_context(node: Node<'_>, parent: Node<'_>, source: &[u8]) -> bool {
+    if parent.kind() == "jsx_attribute" {
+        return parent
+            .named_child(0)
+            .is_some_and(|name| node_text(name, source) == "i18nKey");
+    }
+    if !matches!(parent.kind(), "arguments" | "argument_list") {
+        return false;
+    }
+    let Some(call) = parent.parent() else {
+        return false;
+    };
+    let is_first = parent
+        .named_child(0)
+        .is_some_and(|first| first.id() == node.id());
+    let callee = call
+        .child_by_field_name("function")
+        .map(|callee| node_text(callee, source))
+        .unwrap_or_default();
+    let name = callee.rsplit(['.', ':']).next().unwrap_or(callee);
+    is_first && I18N_FUNCTIONS.contains(&name)
+}
+
+fn classify(value: &str) -> Option<&'static str> {
+    if is_sql(value) {
+        Some("sql")
+    } else if is_url(value) {
+        Some("url")
+    } else if is_path(value) {
+        Some("path")
+    } else {
+        None
+    }
+}
+
+pub fn is_sql(value: &str) -> bool {
+    let mut words = value.split_whitespace().map(|word| {
+        word.trim_matches(|c: char| !c.is_ascii_alphabetic())
+            .to_ascii_lowercase()
+    });
+    let Some(first) = words.next() else {
+        return false;
+    };
+    SQL_LEADING.contains(&first.as_str())
+        && words.any(|word| SQL_FOLLOWING.contains(&word.as_str()))
+}
+
+fn is_url(value: &str) -> bool {
+    if value.chars().any(char::is_whitespace) {
+        return false;
+    }
+    if let Some((scheme, _)) = value.split_once("://") {
+        return !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'));
+    }
+    value.len() > 1 && value.starts_with('/') && !value.starts_with("//") && !has_extension(value)
+}
+
+fn is_path(value: &str) -> bool {
+    if value.chars().any(char::is_whitespace) {
+        return false;
+    }
+    if ["./", "../", "~/", ".\\", "..\\"]
+        .iter()
+        .any(|prefix| value.starts_with(prefix))
+    {
+        return true;
+    }
+    value.contains(['/', '\\']) && has_extension(value)
+}
+
+fn has_extension(value: &str) -> bool {
+    let last = value.rsplit(['/', '\\']).next().unwrap_or(value);
+    last.rsplit_once('.').is_some_and(|(stem, ext)| {
+        !stem.is_empty() && !ext.is_empty() && ext.chars().all(|c| c.is_ascii_alphanumeric())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::{parse_source, parse_source_with_options};
+    use crate::types::NativeParseOptions;
+
+    #[test]
+    fn string_literals_are_collected_with_a_role_when_requested() {
+        let source = r#""use strict";
+import { t } from "./i18n";
+
+type Mode = "light" | "dark";
+
+export async function loadUser(id: string) {
+  const title = t("users.title");
+  const rows = await db.query("SELECT * FROM users WHERE id = ?", [id]);
+  const res = await fetch("/api/users/:id");
+  const docs = "https://example.com/docs";
+  const logo = "assets/logo.svg";
+  const greeting = `Hello ${id}`;
+  return { title, rows, res, docs, logo, greeting, label: "plain" };
+}
+"#;
+
+        let options = NativeParseOptions {
+            string_literals: Some(true),
+            ..Default::default()
+        };
+
+        let parsed = parse_source_with_options("ts", "strings.ts", source, &options);
+        let plain = parse_source("ts", "strings.ts", source);
+
+        let literals: Vec<(&str, Option<&str>)> = parsed
+            .string_literals
+            .iter()
+            .map(|literal| (literal.value.as_str(), literal.role.as_deref()))
+            .collect();
+        assert_eq!(
+            literals,
+            vec![
+                ("users.title", Some("i18n")),
+                ("SELECT * FROM users WHERE id = ?", Some("sql")),
+                ("/api/users/:id", Some("url")),
+                ("https://example.com/docs", Some("url")),
+                ("assets/logo.svg", Some("path")),
+                ("plain", None),
+            ]
+        );
+        assert!(parsed
+            .string_literals
+            .iter()
+            .all(|literal| literal.caller_node_id.starts_with("loadUser:")));
+        assert!(plain.string_literals.is_empty());
+    }
+}
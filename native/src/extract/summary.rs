@@ -37,6 +37,10 @@ const SUFFIX_PATTERNS: &[(&str, &str)] = &[
 /// Maximum lines to scan in a function body for behavioral signals.
 const MAX_BODY_SCAN_LINES: usize = 200;
 
+/// Maximum height of a multi-line Rust attribute skipped when looking for
+/// the rustdoc comment above an item.
+const MAX_ATTRIBUTE_LINES: usize = 20;
+
 /// Behavioral signals detected by scanning a function body.
 #[derive(Debug, Default)]
 struct BodySignals {
@@ -815,6 +819,10 @@ pub fn generate_summary(symbol: &NativeParsedSymbol, file_content: &str, languag
         }
     }
 
+    if let Some(s) = generate_component_summary(symbol) {
+        return s;
+    }
+
     // Dispatch to per-kind generators for non-function/method symbols.
     match symbol.kind.as_str() {
         "function" | "method" => {
@@ -866,10 +874,22 @@ pub fn has_doc_comment(symbol: &NativeParsedSymbol, file_content: &str, language
     !jsdoc.description.is_empty()
 }
 
+/// The deprecation note of a symbol's doc comment: a JSDoc / Javadoc /
+/// PHPDoc `@deprecated` tag, a Go-style `Deprecated:` paragraph, or a
+/// Sphinx `.. deprecated::` directive. `Some("")` when the tag has no text.
+pub fn doc_deprecation(
+    symbol: &NativeParsedSymbol,
+    file_content: &str,
+    language: &str,
+) -> Option<String> {
+    extract_doc_comment(symbol, file_content, language).deprecated
+}
+
 struct JSDoc {
     description: String,
     params: Vec<JSDocParam>,
     throws: Vec<String>,
+    deprecated: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -878,57 +898,103 @@ struct JSDocParam {
     description: String,
 }
 
-fn extract_jsdoc(symbol: &NativeParsedSymbol, file_content: &str) -> JSDoc {
-    let lines: Vec<&str> = file_content.lines().collect();
-    let start_line = symbol.range.start_line as usize;
-    let jsdoc_lines = extract_preceding_block_comment(&lines, start_line, "/**");
-    parse_doc_comment(&jsdoc_lines.join("\n"))
-}
-
 fn extract_doc_comment(symbol: &NativeParsedSymbol, file_content: &str, language: &str) -> JSDoc {
     let lines: Vec<&str> = file_content.lines().collect();
-    let start_line = symbol.range.start_line as usize;
+    // `range.start_line` is 1-based, so as a 0-based index it already points
+    // one past the declaration line, i.e. where a Python body begins. The
+    // preceding-comment scanners want the declaration line's own index.
+    let decl_line = (symbol.range.start_line as usize).saturating_sub(1);
 
     match language {
         "py" => {
-            if let Some(docstring) = extract_python_docstring(&lines, start_line) {
+            if let Some(docstring) = python_body_start(&lines, decl_line)
+                .and_then(|body| extract_python_docstring(&lines, body))
+            {
                 return parse_doc_comment(&docstring);
             }
 
-            let comment_lines = extract_preceding_line_comments(&lines, start_line, &["#"]);
+            let comment_lines = extract_preceding_line_comments(&lines, decl_line, &["#"]);
             parse_doc_comment(&comment_lines.join("\n"))
         }
-        "go" => {
-            let comment_lines = extract_preceding_line_comments(&lines, start_line, &["//"]);
+        "go" | "proto" => {
+            let comment_lines = extract_preceding_line_comments(&lines, decl_line, &["//"]);
             parse_doc_comment(&comment_lines.join("\n"))
         }
         "rs" => {
-            let comment_lines =
-                extract_preceding_line_comments(&lines, start_line, &["///", "//!"]);
+            // Outer attributes (`#[derive(..)]`, `#[cfg(..)]`) sit between a
+            // rustdoc comment and the item they document. `//!` is an inner
+            // doc for the enclosing module, never for the next item.
+            let doc_line = skip_preceding_attributes(&lines, decl_line);
+            let block = extract_preceding_block_comment(&lines, doc_line, "/**");
+            if !block.is_empty() {
+                return parse_doc_comment(&block.join("\n"));
+            }
+            let comment_lines = extract_preceding_line_comments(&lines, doc_line, &["///"]);
             parse_doc_comment(&comment_lines.join("\n"))
         }
         "cs" => {
-            let comment_lines = extract_preceding_line_comments(&lines, start_line, &["///"]);
-            parse_doc_comment(&comment_lines.join("\n"))
+            let comment_lines = extract_preceding_line_comments(&lines, decl_line, &["///"]);
+            parse_doc_comment(&normalize_xml_doc(&comment_lines).join("\n"))
         }
         "c" | "cpp" => {
-            let block = extract_preceding_block_comment(&lines, start_line, "/**");
+            let block = extract_preceding_block_comment(&lines, decl_line, "/**");
             if !block.is_empty() {
                 parse_doc_comment(&block.join("\n"))
             } else {
-                let line_comments = extract_preceding_line_comments(&lines, start_line, &["///"]);
+                let line_comments = extract_preceding_line_comments(&lines, decl_line, &["///"]);
                 parse_doc_comment(&line_comments.join("\n"))
             }
         }
         "sh" => {
-            let comment_lines = extract_preceding_line_comments(&lines, start_line, &["#"]);
+            let comment_lines = extract_preceding_line_comments(&lines, decl_line, &["#"]);
+            parse_doc_comment(&comment_lines.join("\n"))
+        }
+        "lua" => {
+            // LuaLS / EmmyLua annotations use `---`; plain `--` comments are
+            // the common fallback.
+            let comment_lines = extract_preceding_line_comments(&lines, decl_line, &["---", "--"]);
             parse_doc_comment(&comment_lines.join("\n"))
         }
-        "ts" | "tsx" | "js" | "jsx" | "java" | "php" => extract_jsdoc(symbol, file_content),
-        _ => extract_jsdoc(symbol, file_content),
+        // JSDoc, Javadoc, and PHPDoc all use `/** ... */` with `@tags`.
+        _ => {
+            let block = extract_preceding_block_comment(&lines, decl_line, "/**");
+            parse_doc_comment(&block.join("\n"))
+        }
     }
 }
 
+/// Index of the first line of a Python `def`/`class` body, given the index
+/// of the symbol's first line (which may be a decorator). Returns `None` for
+/// one-line definitions (`def f(): return 1`), which cannot have a docstring.
+fn python_body_start(lines: &[&str], decl_line: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    let mut in_header = false;
+
+    for (index, line) in lines.iter().enumerate().skip(decl_line) {
+        let code = line.split('#').next().unwrap_or("");
+        let trimmed = code.trim_start();
+        if !in_header {
+            if trimmed.starts_with('@') || trimmed.is_empty() {
+                continue;
+            }
+            in_header = true;
+        }
+
+        for (offset, c) in code.char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                ':' if depth == 0 => {
+                    return code[offset + 1..].trim().is_empty().then_some(index + 1);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    None
+}
+
 fn extract_python_docstring(lines: &[&str], start_line: usize) -> Option<String> {
     let mut i = start_line.min(lines.len());
 
@@ -982,6 +1048,59 @@ fn extract_python_docstring(lines: &[&str], start_line: usize) -> Option<String>
     None
 }
 
+/// Walk back over `#[...]` attribute lines directly above `decl_line`.
+fn skip_preceding_attributes(lines: &[&str], decl_line: usize) -> usize {
+    let mut cursor = decl_line.min(lines.len());
+    while cursor > 0 {
+        let line = lines[cursor - 1].trim();
+        if line.starts_with("#[") {
+            cursor -= 1;
+            continue;
+        }
+        // Last line of a multi-line attribute: jump to its `#[` opener.
+        if line.ends_with(']') && !line.starts_with("//") {
+            let opener = (0..cursor - 1)
+                .rev()
+                .take(MAX_ATTRIBUTE_LINES)
+                .find(|&index| lines[index].trim_start().starts_with("#["));
+            if let Some(opener) = opener {
+                cursor = opener;
+                continue;
+            }
+        }
+        break;
+    }
+    cursor
+}
+
+/// Rewrite C# XML doc tags into the `@tag` form `parse_doc_comment`
+/// understands, so `<param>`/`<returns>` text stays out of the description.
+fn normalize_xml_doc(lines: &[String]) -> Vec<String> {
+    static RE_PARAM: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r#"^<param\s+name="([^"]+)"\s*>(.*?)(?:</param>)?$"#).unwrap());
+    static RE_EXCEPTION: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"^<exception\s+cref="([^"]+)"\s*>(.*?)(?:</exception>)?$"#).unwrap()
+    });
+    static RE_SECTION: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^<(returns|remarks|example|value|typeparam)\b[^>]*>(.*?)(?:</\w+>)?$").unwrap()
+    });
+
+    lines
+        .iter()
+        .map(|line| {
+            if let Some(caps) = RE_PARAM.captures(line) {
+                format!("@param {} {}", &caps[1], &caps[2])
+            } else if let Some(caps) = RE_EXCEPTION.captures(line) {
+                format!("@throws {} {}", &caps[1], &caps[2])
+            } else if let Some(caps) = RE_SECTION.captures(line) {
+                format!("@{} {}", &caps[1], &caps[2])
+            } else {
+                line.clone()
+            }
+        })
+        .collect()
+}
+
 fn extract_preceding_line_comments(
     lines: &[&str],
     start_line: usize,
@@ -1043,6 +1162,11 @@ fn extract_preceding_block_comment(
         return Vec::new();
     }
 
+    // Single-line `/** ... */`.
+    if last.starts_with(block_start) {
+        return vec![last.to_string()];
+    }
+
     let mut collected = vec![last.to_string()];
     cursor -= 1;
 
@@ -1069,6 +1193,16 @@ fn parse_doc_comment(doc_comment: &str) -> JSDoc {
     static RE_JSDOC_END: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s*\*/$").unwrap());
     static RE_JSDOC_MID: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\s*\*\s?").unwrap());
     static RE_XML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]+>").unwrap());
+    // `<see cref="Foo"/>` (C#) and `{@link Foo}` (Javadoc/JSDoc) keep the
+    // referenced name rather than disappearing with the markup.
+    static RE_XML_REF: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(
+            r#"<(?:see|seealso|paramref|typeparamref)\s+(?:cref|name|langword)="([^"]+)"\s*/>"#,
+        )
+        .unwrap()
+    });
+    static RE_INLINE_TAG: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\{@(?:link|linkplain|code|literal)\s+([^}]*)\}").unwrap());
 
     let jsdoc_text: String = doc_comment
         .lines()
@@ -1076,6 +1210,8 @@ fn parse_doc_comment(doc_comment: &str) -> JSDoc {
             let s = RE_JSDOC_START.replace(line, "");
             let s = RE_JSDOC_END.replace(&s, "");
             let s = RE_JSDOC_MID.replace(&s, "");
+            let s = RE_XML_REF.replace_all(&s, "$1");
+            let s = RE_INLINE_TAG.replace_all(&s, "$1");
             let s = RE_XML_TAG.replace_all(&s, "");
             s.trim().to_string()
         })
@@ -1086,6 +1222,7 @@ fn parse_doc_comment(doc_comment: &str) -> JSDoc {
         description: String::new(),
         params: Vec::new(),
         throws: Vec::new(),
+        deprecated: None,
     };
 
     static RE_PARAM: LazyLock<Regex> =
@@ -1125,8 +1262,28 @@ fn parse_doc_comment(doc_comment: &str) -> JSDoc {
                         .unwrap_or_default(),
                 );
             }
+        } else if let Some(note) = trimmed
+            .strip_prefix("@deprecated")
+            .or_else(|| trimmed.strip_prefix("Deprecated:"))
+            .or_else(|| trimmed.strip_prefix(".. deprecated::"))
+        {
+            current_section = "deprecated";
+            jsdoc.deprecated = Some(note.trim().to_string());
         } else if trimmed.starts_with('@') {
             current_section = "description";
+        } else if trimmed.starts_with("# ") {
+            // Markdown section heading in rustdoc (`# Errors`, `# Examples`).
+            current_section = "heading";
+        } else if current_section == "deprecated" {
+            // The note runs to the end of its paragraph.
+            if trimmed.is_empty() {
+                current_section = "description";
+            } else if let Some(note) = jsdoc.deprecated.as_mut() {
+                if !note.is_empty() {
+                    note.push(' ');
+                }
+                note.push_str(trimmed);
+            }
         } else if current_section == "description" && !trimmed.is_empty() {
             if !jsdoc.description.is_empty() {
                 jsdoc.description.push(' ');
@@ -1210,6 +1367,70 @@ fn generate_class_summary(symbol: &NativeParsedSymbol) -> Option<String> {
     Some(format!("Class encapsulating {} behavior", words))
 }
 
+/// React components (tagged `component` by the TS extractor) are described
+/// by what they render and their props contract rather than by body shape.
+fn generate_component_summary(symbol: &NativeParsedSymbol) -> Option<String> {
+    symbol
+        .attributes
+        .iter()
+        .find(|attr| attr.key == "component")?;
+    let words = split_camel_case(&symbol.name).join(" ").to_lowercase();
+    let props = symbol
+        .signature
+        .as_ref()
+        .and_then(|sig| sig.props_type.as_deref())
+        .map(describe_props)
+        .filter(|props| !props.is_empty());
+    Some(match props {
+        Some(props) => format!("React component rendering {} with props {}", words, props),
+        None => format!("React component rendering {}", words),
+    })
+}
+
+/// `UserCardProps` stays as is; an inline `{ label: string; onClick?: () => void }`
+/// is reduced to its property names (`label, onClick`).
+fn describe_props(props: &str) -> String {
+    let Some(body) = props
+        .strip_prefix('{')
+        .and_then(|rest| rest.strip_suffix('}'))
+    else {
+        return props.to_string();
+    };
+
+    let mut names = Vec::new();
+    let mut depth = 0i32;
+    let mut member = String::new();
+    let mut prev = ' ';
+    for c in body.chars().chain(std::iter::once(';')) {
+        let after_equals = prev == '=';
+        prev = c;
+        match c {
+            '{' | '(' | '[' | '<' => depth += 1,
+            // `=>` in a function type is not a closing bracket.
+            '>' if after_equals => {}
+            '}' | ')' | ']' | '>' => depth -= 1,
+            ',' | ';' if depth == 0 => {
+                let name = member
+                    .split(':')
+                    .next()
+                    .unwrap_or("")
+                    .trim()
+                    .trim_end_matches('?')
+                    .trim_start_matches("readonly ")
+                    .trim();
+                if !name.is_empty() {
+                    names.push(name.to_string());
+                }
+                member.clear();
+                continue;
+            }
+            _ => {}
+        }
+        member.push(c);
+    }
+    names.join(", ")
+}
+
 fn generate_interface_summary(symbol: &NativeParsedSymbol) -> Option<String> {
     let name = &symbol.name;
     let chars: Vec<char> = name.chars().collect();
@@ -1319,7 +1540,8 @@ fn generate_constructor_summary(_symbol: &NativeParsedSymbol) -> Option<String>
 mod tests {
     use super::*;
     use crate::types::{
-        NativeParsedSymbol, NativeRange, NativeSymbolSignature, NativeSymbolSignatureParam,
+        NativeParsedSymbol, NativeRange, NativeSymbolAttribute, NativeSymbolSignature,
+        NativeSymbolSignatureParam,
     };
 
     fn make_symbol(name: &str, kind: &str) -> NativeParsedSymbol {
@@ -1341,6 +1563,7 @@ mod tests {
                 params: None,
                 returns: None,
                 generics: None,
+                ..Default::default()
             }),
             summary: String::new(),
             invariants: vec![],
@@ -1349,6 +1572,7 @@ mod tests {
             decorators: vec![],
             search_text: String::new(),
             summary_quality: None,
+            ..Default::default()
         }
     }
 
@@ -1432,6 +1656,75 @@ mod tests {
         assert_eq!(result, None);
     }
 
+    fn symbol_at_line(name: &str, kind: &str, start_line: u32) -> NativeParsedSymbol {
+        let mut symbol = make_symbol(name, kind);
+        symbol.range.start_line = start_line;
+        symbol.range.end_line = start_line;
+        symbol
+    }
+
+    #[test]
+    fn test_rustdoc_above_attributes() {
+        let source = "/// Parses the config file.\n///\n/// # Errors\n#[derive(Debug)]\n#[serde(\n    rename_all = \"camelCase\",\n)]\npub struct Config;\n";
+        let s = symbol_at_line("Config", "class", 8);
+        let doc = extract_doc_comment(&s, source, "rs");
+        assert_eq!(doc.description, "Parses the config file.");
+        assert_eq!(generate_summary(&s, source, "rs"), "Parses the config file");
+    }
+
+    #[test]
+    fn test_python_docstring_after_multiline_signature() {
+        let source = "@cached\ndef load(\n    path: str,\n) -> dict:\n    \"\"\"Load settings from disk.\"\"\"\n    return {}\n";
+        let s = symbol_at_line("load", "function", 1);
+        assert!(has_doc_comment(&s, source, "py"));
+        assert_eq!(
+            generate_summary(&s, source, "py"),
+            "Load settings from disk"
+        );
+    }
+
+    #[test]
+    fn test_go_and_javadoc_comments() {
+        let go = "package x\n\n// Serve starts the HTTP listener.\nfunc Serve() {}\n";
+        let s = symbol_at_line("Serve", "function", 4);
+        assert_eq!(
+            generate_summary(&s, go, "go"),
+            "Serve starts the HTTP listener"
+        );
+
+        let java = "/**\n * Returns a {@link User} by id.\n * @param id the id\n */\npublic User find(long id) {}\n";
+        let s = symbol_at_line("find", "method", 5);
+        let doc = extract_doc_comment(&s, java, "java");
+        assert_eq!(doc.description, "Returns a User by id.");
+        assert_eq!(doc.params.len(), 1);
+    }
+
+    #[test]
+    fn test_csharp_xml_doc_keeps_params_out_of_description() {
+        let source = "/// <summary>\n/// Sends the <see cref=\"Message\"/>.\n/// </summary>\n/// <param name=\"message\">Payload to send.</param>\n/// <exception cref=\"IOException\">On failure.</exception>\npublic void Send(Message message) {}\n";
+        let s = symbol_at_line("Send", "method", 6);
+        let doc = extract_doc_comment(&s, source, "cs");
+        assert_eq!(doc.description, "Sends the Message.");
+        assert_eq!(doc.params.len(), 1);
+        assert_eq!(doc.params[0].name, "message");
+        assert_eq!(doc.throws, vec!["IOException On failure.".to_string()]);
+    }
+
+    #[test]
+    fn test_component_summary_lists_inline_props() {
+        let mut s = make_symbol("UserCard", "function");
+        s.attributes.push(NativeSymbolAttribute {
+            key: "component".to_string(),
+            value: "function".to_string(),
+        });
+        s.signature.as_mut().unwrap().props_type =
+            Some("{ user: User; onSelect?: (id: string) => void }".to_string());
+        assert_eq!(
+            generate_summary(&s, "", "tsx"),
+            "React component rendering user card with props user, onSelect"
+        );
+    }
+
     #[test]
     fn test_variable_no_summary() {
         let s = make_symbol("count", "variable");
@@ -1,10 +1,18 @@
-use crate::parse::content_hash::hash_content;
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::content_hash::{hash_content, to_nfc};
+use crate::types::{NativeFileInput, NativeParsedFile, NativeParsedSymbol};
+
+/// `NativeParseOptions::symbol_id_scheme` value selecting
+/// `generate_qualified_symbol_id`.
+pub const QUALIFIED_SCHEME: &str = "qualified";
 
 /// Generate a stable unique identifier for a symbol.
 ///
 /// Exact parity with TypeScript `generateSymbolId` in `indexer/fingerprints.ts`.
 ///
-/// Algorithm: `sha256("{repoId}:{relPath}:{kind}:{name}:{astFingerprint}")`
+/// Algorithm: `sha256("{repoId}:{relPath}:{kind}:{name}:{astFingerprint}")`,
+/// with `relPath` and `name` normalized to NFC (see `to_nfc`).
 pub fn generate_symbol_id(
     repo_id: &str,
     rel_path: &str,
@@ -12,10 +20,158 @@ pub fn generate_symbol_id(
     name: &str,
     ast_fingerprint: &str,
 ) -> String {
+    let (rel_path, name) = (to_nfc(rel_path), to_nfc(name));
     let combined = format!("{repo_id}:{rel_path}:{kind}:{name}:{ast_fingerprint}");
     hash_content(&combined)
 }
 
+/// Generate a symbol identifier that does not depend on the file path, so
+/// moving or renaming a file keeps its symbols' identities.
+///
+/// Algorithm: `sha256("{repoId}:{kind}:{qualifiedName}:{astFingerprint}")`,
+/// where `qualifiedName` joins the names of the enclosing symbols and the
+/// symbol's own name with `.` (e.g. `UserService.find`), normalized to NFC.
+/// Identical declarations in different files would share an ID under this
+/// scheme; `disambiguate_qualified_symbol_ids` fixes those up per batch.
+pub fn generate_qualified_symbol_id(
+    repo_id: &str,
+    kind: &str,
+    qualified_name: &str,
+    ast_fingerprint: &str,
+) -> String {
+    let qualified_name = to_nfc(qualified_name);
+    let combined = format!("{repo_id}:{kind}:{qualified_name}:{ast_fingerprint}");
+    hash_content(&combined)
+}
+
+/// Replace the path-based `symbol_id` of every symbol in one file with its
+/// `generate_qualified_symbol_id`, remapping `parent_symbol_id` to match.
+/// Must run after `nesting::assign_parent_symbols`.
+pub fn apply_qualified_symbol_ids(symbols: &mut [NativeParsedSymbol], repo_id: &str) {
+    let index_by_id: HashMap<&str, usize> = symbols
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.symbol_id.as_str(), i))
+        .collect();
+
+    let new_ids: Vec<String> = symbols
+        .iter()
+        .map(|symbol| {
+            generate_qualified_symbol_id(
+                repo_id,
+                &symbol.kind,
+                &qualified_name(symbol, symbols, &index_by_id),
+                &symbol.ast_fingerprint,
+            )
+        })
+        .collect();
+    replace_symbol_ids(symbols, new_ids);
+}
+
+/// Give qualified symbol IDs that collide across files of one batch a
+/// module prefix. Boilerplate such as `export default function handler` in
+/// many files would otherwise produce duplicate IDs within a repo. Each
+/// colliding symbol is re-derived with its file's module path (`rel_path`
+/// without the extension) in front of its qualified name, e.g.
+/// `src/api/users:handler`; those IDs depend on the path again, but only
+/// for symbols that could not be told apart without it.
+///
+/// `files` and `parsed` are the batch inputs and their results, in order.
+/// Must run after `apply_qualified_symbol_ids`.
+pub fn disambiguate_qualified_symbol_ids(
+    files: &[NativeFileInput],
+    parsed: &mut [NativeParsedFile],
+) {
+    let mut files_by_id: HashMap<&str, HashSet<usize>> = HashMap::new();
+    for (file_index, file) in parsed.iter().enumerate() {
+        for symbol in &file.symbols {
+            files_by_id
+                .entry(symbol.symbol_id.as_str())
+                .or_default()
+                .insert(file_index);
+        }
+    }
+    let colliding: HashSet<String> = files_by_id
+        .into_iter()
+        .filter(|(_, file_indexes)| file_indexes.len() > 1)
+        .map(|(id, _)| id.to_string())
+        .collect();
+    if colliding.is_empty() {
+        return;
+    }
+
+    for (input, file) in files.iter().zip(parsed.iter_mut()) {
+        let symbols = &mut file.symbols;
+        if !symbols.iter().any(|s| colliding.contains(&s.symbol_id)) {
+            continue;
+        }
+        let module = input
+            .rel_path
+            .rsplit_once('.')
+            .filter(|(stem, ext)| !stem.is_empty() && !stem.ends_with('/') && !ext.contains('/'))
+            .map_or(input.rel_path.as_str(), |(stem, _)| stem);
+        let index_by_id: HashMap<&str, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.symbol_id.as_str(), i))
+            .collect();
+        let new_ids: Vec<String> = symbols
+            .iter()
+            .map(|symbol| {
+                if !colliding.contains(&symbol.symbol_id) {
+                    return symbol.symbol_id.clone();
+                }
+                generate_qualified_symbol_id(
+                    &input.repo_id,
+                    &symbol.kind,
+                    &format!("{module}:{}", qualified_name(symbol, symbols, &index_by_id)),
+                    &symbol.ast_fingerprint,
+                )
+            })
+            .collect();
+        replace_symbol_ids(symbols, new_ids);
+    }
+}
+
+/// `symbol`'s name prefixed with the names of its enclosing symbols, joined
+/// with `.`.
+fn qualified_name(
+    symbol: &NativeParsedSymbol,
+    symbols: &[NativeParsedSymbol],
+    index_by_id: &HashMap<&str, usize>,
+) -> String {
+    let mut names = vec![symbol.name.as_str()];
+    let mut parent = symbol.parent_symbol_id.as_deref();
+    // Bounded by the symbol count in case of a malformed cycle.
+    while let Some(index) = parent.and_then(|id| index_by_id.get(id).copied()) {
+        if names.len() > symbols.len() {
+            break;
+        }
+        names.push(symbols[index].name.as_str());
+        parent = symbols[index].parent_symbol_id.as_deref();
+    }
+    names.reverse();
+    names.join(".")
+}
+
+/// Set each symbol's ID to the matching entry of `new_ids`, remapping
+/// `parent_symbol_id` to match.
+fn replace_symbol_ids(symbols: &mut [NativeParsedSymbol], new_ids: Vec<String>) {
+    let remap: HashMap<String, String> = symbols
+        .iter()
+        .zip(&new_ids)
+        .map(|(s, id)| (s.symbol_id.clone(), id.clone()))
+        .collect();
+    for (symbol, id) in symbols.iter_mut().zip(new_ids) {
+        symbol.symbol_id = id;
+        if let Some(parent) = symbol.parent_symbol_id.as_mut() {
+            if let Some(new_parent) = remap.get(parent.as_str()) {
+                *parent = new_parent.clone();
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,4 +197,98 @@ mod tests {
         assert_eq!(id.len(), 64);
         assert!(id.chars().all(|c| c.is_ascii_hexdigit()));
     }
+
+    #[test]
+    fn test_qualified_id_ignores_path() {
+        let make = |rel_path: &str| {
+            let mut symbols = vec![
+                NativeParsedSymbol {
+                    symbol_id: generate_symbol_id("repo", rel_path, "class", "Svc", "f1"),
+                    kind: "class".into(),
+                    name: "Svc".into(),
+                    ast_fingerprint: "f1".into(),
+                    ..Default::default()
+                },
+                NativeParsedSymbol {
+                    symbol_id: generate_symbol_id("repo", rel_path, "method", "find", "f2"),
+                    kind: "method".into(),
+                    name: "find".into(),
+                    ast_fingerprint: "f2".into(),
+                    ..Default::default()
+                },
+            ];
+            symbols[1].parent_symbol_id = Some(symbols[0].symbol_id.clone());
+            apply_qualified_symbol_ids(&mut symbols, "repo");
+            symbols
+        };
+
+        let before = make("src/svc.ts");
+        let after = make("lib/moved/svc.ts");
+        assert_eq!(before[0].symbol_id, after[0].symbol_id);
+        assert_eq!(before[1].symbol_id, after[1].symbol_id);
+        assert_eq!(
+            before[1].symbol_id,
+            generate_qualified_symbol_id("repo", "method", "Svc.find", "f2")
+        );
+        assert_eq!(
+            before[1].parent_symbol_id.as_ref(),
+            Some(&before[0].symbol_id)
+        );
+    }
+
+    #[test]
+    fn test_nfd_and_nfc_names_share_an_id() {
+        let nfc = generate_symbol_id("repo", "src/caf\u{e9}.ts", "function", "caf\u{e9}", "f");
+        let nfd = generate_symbol_id("repo", "src/cafe\u{301}.ts", "function", "cafe\u{301}", "f");
+        assert_eq!(nfc, nfd);
+    }
+
+    #[test]
+    fn test_qualified_ids_colliding_across_files_get_a_module_prefix() {
+        let file = |rel_path: &str, fingerprint: &str| {
+            let input = NativeFileInput {
+                rel_path: rel_path.into(),
+                absolute_path: rel_path.into(),
+                repo_id: "repo".into(),
+                language: "ts".into(),
+                module_path: None,
+            };
+            let mut symbols = vec![NativeParsedSymbol {
+                symbol_id: generate_symbol_id("repo", rel_path, "function", "handler", fingerprint),
+                kind: "function".into(),
+                name: "handler".into(),
+                ast_fingerprint: fingerprint.into(),
+                ..Default::default()
+            }];
+            apply_qualified_symbol_ids(&mut symbols, "repo");
+            let parsed = NativeParsedFile {
+                rel_path: rel_path.into(),
+                symbols,
+                ..Default::default()
+            };
+            (input, parsed)
+        };
+
+        let (inputs, mut parsed): (Vec<_>, Vec<_>) = [
+            file("api/users.ts", "same"),
+            file("api/orders.ts", "same"),
+            file("api/health.ts", "other"),
+        ]
+        .into_iter()
+        .unzip();
+        disambiguate_qualified_symbol_ids(&inputs, &mut parsed);
+
+        let ids: Vec<&str> = parsed
+            .iter()
+            .map(|f| f.symbols[0].symbol_id.as_str())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                generate_qualified_symbol_id("repo", "function", "api/users:handler", "same"),
+                generate_qualified_symbol_id("repo", "function", "api/orders:handler", "same"),
+                generate_qualified_symbol_id("repo", "function", "handler", "other"),
+            ]
+        );
+    }
 }
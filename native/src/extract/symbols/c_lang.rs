@@ -1,6 +1,6 @@
 use tree_sitter::Node;
 
-use crate::types::NativeParsedSymbol;
+use crate::types::{NativeParsedSymbol, NativeSymbolAttribute};
 
 use super::common::{find_child_node, make_symbol, node_text, ParamInfo};
 
@@ -35,6 +35,16 @@ pub fn extract_symbols_c(
                     symbols.push(symbol);
                 }
             }
+            "declaration" => {
+                if let Some(symbol) = process_function_prototype(node, source, repo_id, rel_path) {
+                    symbols.push(symbol);
+                }
+            }
+            "preproc_def" | "preproc_function_def" => {
+                if let Some(symbol) = process_preproc_define(node, source, repo_id, rel_path) {
+                    symbols.push(symbol);
+                }
+            }
             _ => {}
         }
 
@@ -79,9 +89,131 @@ fn process_function_definition(
         &[],
     );
     symbol.exported = true;
+    symbol.attributes.push(declaration_attribute("definition"));
     Some(symbol)
 }
 
+/// A file-scope function declaration without a body (`int add(int, int);`),
+/// typically the public API of a header. Block-scope declarations inside
+/// function bodies are ignored.
+fn process_function_prototype(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Option<NativeParsedSymbol> {
+    if has_function_ancestor(node) {
+        return None;
+    }
+
+    let declarator = node.child_by_field_name("declarator")?;
+    let function_declarator = find_function_declarator(declarator)?;
+    let name_node = find_identifier_in_declarator(function_declarator)?;
+    let name = node_text(name_node, source).to_string();
+    if name.is_empty() {
+        return None;
+    }
+
+    let params = extract_function_params(function_declarator, source);
+    let returns = extract_return_type(node, source);
+    let is_static = {
+        let mut cursor = node.walk();
+        let found = node.children(&mut cursor).any(|child| {
+            child.kind() == "storage_class_specifier" && node_text(child, source) == "static"
+        });
+        found
+    };
+
+    let mut symbol = make_symbol(
+        &name,
+        "function",
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &params,
+        returns.as_deref(),
+        &[],
+        if is_static { "private" } else { "public" },
+        &[],
+    );
+    symbol.exported = !is_static;
+    symbol.attributes.push(declaration_attribute("prototype"));
+    Some(symbol)
+}
+
+/// `#define NAME value` and `#define NAME(a, b) body`. Function-like macros
+/// become functions with their macro parameters; object-like macros become
+/// variables. Shared with the C++ extractor.
+pub(super) fn process_preproc_define(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Option<NativeParsedSymbol> {
+    let name_node = node.child_by_field_name("name")?;
+    let name = node_text(name_node, source).to_string();
+    if name.is_empty() {
+        return None;
+    }
+
+    let (kind, params) = match node.child_by_field_name("parameters") {
+        Some(parameters) => {
+            let mut cursor = parameters.walk();
+            let params = parameters
+                .named_children(&mut cursor)
+                .map(|param| ParamInfo {
+                    name: node_text(param, source).to_string(),
+                    type_annotation: None,
+                })
+                .filter(|param| !param.name.is_empty())
+                .collect::<Vec<_>>();
+            ("function", params)
+        }
+        None => ("variable", Vec::new()),
+    };
+
+    let mut symbol = make_symbol(
+        &name,
+        kind,
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &params,
+        None,
+        &[],
+        "public",
+        &[],
+    );
+    symbol.exported = true;
+    symbol.attributes.push(NativeSymbolAttribute {
+        key: "macro".to_string(),
+        value: "define".to_string(),
+    });
+    Some(symbol)
+}
+
+/// Whether a function symbol comes from a definition (with a body) or a
+/// bodiless prototype.
+pub(super) fn declaration_attribute(form: &str) -> NativeSymbolAttribute {
+    NativeSymbolAttribute {
+        key: "declaration".to_string(),
+        value: form.to_string(),
+    }
+}
+
+fn has_function_ancestor(node: Node<'_>) -> bool {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == "function_definition" {
+            return true;
+        }
+        current = parent.parent();
+    }
+    false
+}
+
 fn process_struct_specifier(
     node: Node<'_>,
     source: &[u8],
@@ -311,3 +443,57 @@ fn is_type_like_child(kind: &str) -> bool {
             | "type_qualifier"
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn c_parser_separates_prototypes_macros_and_include_kinds() {
+        let source = r#"#include <stdio.h>
+#include <curl/curl.h>
+#include "util.h"
+
+#define MAX_USERS 64
+#define SQUARE(x) ((x) * (x))
+
+int add(int a, int b);
+
+int add(int a, int b) { return a + b; }
+"#;
+
+        let parsed = parse_source("c", "include/math.h", source);
+
+        let declaration_forms: Vec<_> = parsed
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.name == "add")
+            .flat_map(|symbol| symbol.attributes.iter())
+            .filter(|attr| attr.key == "declaration")
+            .map(|attr| attr.value.as_str())
+            .collect();
+        assert_eq!(declaration_forms, vec!["prototype", "definition"]);
+
+        let square = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "SQUARE")
+            .expect("expected function-like macro");
+        assert_eq!(square.kind, "function");
+        assert!(parsed
+            .symbols
+            .iter()
+            .any(|symbol| symbol.name == "MAX_USERS" && symbol.kind == "variable"));
+
+        let origin = |specifier: &str| {
+            parsed
+                .imports
+                .iter()
+                .find(|import| import.specifier == specifier)
+                .and_then(|import| import.origin.clone())
+        };
+        assert_eq!(origin("stdio.h").as_deref(), Some("stdlib"));
+        assert_eq!(origin("curl/curl.h").as_deref(), Some("external"));
+        assert_eq!(origin("util.h").as_deref(), Some("internal"));
+    }
+}
@@ -1,11 +1,17 @@
 use tree_sitter::Node;
 
-use crate::extract::fingerprint::generate_ast_fingerprint;
+use crate::extract::fingerprint::{
+    generate_ast_fingerprint, generate_body_fingerprint, generate_body_hash,
+    generate_signature_hash, FINGERPRINT_VERSION,
+};
 use crate::extract::symbol_id::generate_symbol_id;
 use crate::types::{
     NativeParsedSymbol, NativeRange, NativeSymbolSignature, NativeSymbolSignatureParam,
+    NativeTypeParameter,
 };
 
+use super::decorators::parse_decorator;
+
 pub struct ParamInfo {
     pub name: String,
     pub type_annotation: Option<String>,
@@ -60,10 +66,46 @@ pub fn make_symbol(
     decorators: &[String],
 ) -> NativeParsedSymbol {
     let fingerprint = generate_ast_fingerprint(node, source);
-    let symbol_id = generate_symbol_id(repo_id, rel_path, kind, name, &fingerprint);
+    let range = extract_range(node);
 
+    let mut symbol = make_symbol_from_parts(
+        name,
+        kind,
+        fingerprint,
+        range,
+        repo_id,
+        rel_path,
+        params,
+        returns,
+        generics,
+        visibility,
+        decorators,
+    );
+    symbol.body_fingerprint = Some(generate_body_fingerprint(node, source, name));
+    symbol.signature_hash = Some(generate_signature_hash(node, source));
+    symbol.body_hash = generate_body_hash(node, source);
+    symbol
+}
+
+/// Build a symbol from a precomputed fingerprint and range. Used by
+/// extractors that don't have a tree-sitter node for the symbol (e.g. the
+/// line-based extractors in `extract::text`).
+#[allow(clippy::too_many_arguments)]
+pub fn make_symbol_from_parts(
+    name: &str,
+    kind: &str,
+    fingerprint: String,
+    range: NativeRange,
+    repo_id: &str,
+    rel_path: &str,
+    params: &[ParamInfo],
+    returns: Option<&str>,
+    generics: &[String],
+    visibility: &str,
+    decorators: &[String],
+) -> NativeParsedSymbol {
+    let symbol_id = generate_symbol_id(repo_id, rel_path, kind, name, &fingerprint);
     let signature = build_signature(params, returns, generics);
-    let range = extract_range(node);
 
     // Phase 1 Task 1.2: stable per-file nodeId, format `name:startLine:startCol`.
     // Must match the caller_node_id format produced by
@@ -86,8 +128,27 @@ pub fn make_symbol(
         side_effects: vec![],
         role_tags: vec![],
         decorators: decorators.to_vec(),
+        decorator_metadata: decorators
+            .iter()
+            .filter_map(|decorator| parse_decorator(decorator))
+            .collect(),
         search_text: String::new(),
         summary_quality: None,
+        attributes: vec![],
+        parent_symbol_id: None,
+        metrics_json: None,
+        unreferenced: false,
+        deprecated: false,
+        deprecation_message: None,
+        winnow_fingerprints: None,
+        minhash_signature: None,
+        purity: None,
+        invariant_records: vec![],
+        side_effect_records: vec![],
+        body_fingerprint: None,
+        fingerprint_version: FINGERPRINT_VERSION,
+        signature_hash: None,
+        body_hash: None,
     }
 }
 
@@ -143,9 +204,73 @@ fn build_signature_inner(
         } else {
             Some(generics.to_vec())
         },
+        ..Default::default()
     }
 }
 
+/// Modifier flags recorded in `NativeSymbolSignature::modifiers`, in the
+/// order they are stored.
+pub const MODIFIERS: &[&str] = &[
+    "abstract",
+    "static",
+    "override",
+    "readonly",
+    "async",
+    "generator",
+];
+
+/// Record declaration modifiers on the symbol's signature, creating one when
+/// the symbol has none (e.g. a `static readonly` field). Names outside
+/// [`MODIFIERS`] are ignored and duplicates collapse.
+pub fn apply_modifiers(symbol: &mut NativeParsedSymbol, modifiers: &[&str]) {
+    let ordered: Vec<String> = MODIFIERS
+        .iter()
+        .filter(|modifier| modifiers.contains(modifier))
+        .map(|modifier| modifier.to_string())
+        .collect();
+    if ordered.is_empty() {
+        return;
+    }
+    symbol
+        .signature
+        .get_or_insert_with(NativeSymbolSignature::default)
+        .modifiers = Some(ordered);
+}
+
+/// Build a type parameter, trimming `constraint` / `default` and dropping
+/// them when empty.
+pub fn type_parameter(
+    name: &str,
+    constraint: Option<&str>,
+    default: Option<&str>,
+) -> NativeTypeParameter {
+    let clean = |text: Option<&str>| {
+        text.map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    };
+    NativeTypeParameter {
+        name: name.trim().to_string(),
+        constraint: clean(constraint),
+        default: clean(default),
+    }
+}
+
+/// Record structured type parameters on the symbol's signature, creating
+/// one when the symbol has none. No-op when `type_parameters` is empty.
+pub fn apply_type_parameters(
+    symbol: &mut NativeParsedSymbol,
+    type_parameters: Vec<NativeTypeParameter>,
+) {
+    if type_parameters.is_empty() {
+        return;
+    }
+    symbol
+        .signature
+        .get_or_insert_with(NativeSymbolSignature::default)
+        .type_parameters = Some(type_parameters);
+}
+
 /// Like `make_symbol` but forces an always-present signature object.
 /// Matches the TS source-of-truth behaviour for function / method /
 /// class / interface / type declarations: those emit a `signature`
@@ -171,3 +296,62 @@ pub fn make_symbol_with_forced_signature(
     symbol.signature = build_signature_forced(params, returns, generics);
     symbol
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn type_parameters_record_constraints_and_defaults() {
+        let cases = [
+            (
+                "ts",
+                "src/store.ts",
+                "export class Store<T extends Entity = User, K> {}\n",
+                "Store",
+                vec![("T", Some("Entity"), Some("User")), ("K", None, None)],
+            ),
+            (
+                "rs",
+                "src/lib.rs",
+                "pub fn merge<'a, T: Clone = u8, const N: usize>(x: &'a T) where T: Send {}\n",
+                "merge",
+                vec![
+                    ("'a", None, None),
+                    ("T", Some("Clone + Send"), Some("u8")),
+                    ("N", Some("usize"), None),
+                ],
+            ),
+            (
+                "go",
+                "pkg/maps.go",
+                "package pkg\n\nfunc Keys[K comparable, V any](m map[K]V) []K { return nil }\n",
+                "Keys",
+                vec![("K", Some("comparable"), None), ("V", Some("any"), None)],
+            ),
+        ];
+
+        for (language, rel_path, source, symbol_name, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let parameters = parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == symbol_name)
+                .and_then(|symbol| symbol.signature.as_ref())
+                .and_then(|signature| signature.type_parameters.clone())
+                .unwrap_or_default();
+            let actual: Vec<(&str, Option<&str>, Option<&str>)> = parameters
+                .iter()
+                .map(|parameter| {
+                    (
+                        parameter.name.as_str(),
+                        parameter.constraint.as_deref(),
+                        parameter.default.as_deref(),
+                    )
+                })
+                .collect();
+            assert_eq!(actual, expected, "{language}");
+        }
+    }
+}
@@ -56,6 +56,13 @@ pub fn extract_symbols_cpp(
                     process_declaration(node, source, repo_id, rel_path, &mut symbols);
                 }
             }
+            "preproc_def" | "preproc_function_def" => {
+                if let Some(symbol) =
+                    super::c_lang::process_preproc_define(node, source, repo_id, rel_path)
+                {
+                    symbols.push(symbol);
+                }
+            }
             _ => {}
         }
 
@@ -328,6 +335,13 @@ fn process_function_like(
         &[],
     );
     symbol.exported = exported;
+    symbol.attributes.push(super::c_lang::declaration_attribute(
+        if node.kind() == "function_definition" {
+            "definition"
+        } else {
+            "prototype"
+        },
+    ));
     Some(symbol)
 }
 
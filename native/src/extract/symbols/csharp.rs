@@ -1,8 +1,11 @@
 use tree_sitter::Node;
 
-use crate::types::NativeParsedSymbol;
+use crate::types::{NativeParsedSymbol, NativeSymbolAttribute, NativeTypeParameter};
 
-use super::common::{find_child_by_kind, find_child_node, make_symbol, node_text, ParamInfo};
+use super::common::{
+    apply_modifiers, apply_type_parameters, find_child_by_kind, find_child_node, make_symbol,
+    node_text, type_parameter, ParamInfo,
+};
 
 pub fn extract_symbols_csharp(
     root: Node<'_>,
@@ -11,35 +14,63 @@ pub fn extract_symbols_csharp(
     rel_path: &str,
 ) -> Vec<NativeParsedSymbol> {
     let mut symbols = Vec::new();
+    let file_namespace = find_file_scoped_namespace(root, source);
     let mut stack = vec![root];
 
     while let Some(node) = stack.pop() {
+        let declared_from = symbols.len();
         match node.kind() {
-            "namespace_declaration" => {
+            "namespace_declaration" | "file_scoped_namespace_declaration" => {
                 if let Some(symbol) = process_namespace_declaration(node, source, repo_id, rel_path)
                 {
                     symbols.push(symbol);
                 }
             }
             "class_declaration" => {
-                if let Some(symbol) = process_type_like(node, source, repo_id, rel_path, "class") {
+                if let Some(symbol) = process_type_like(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    "class",
+                    file_namespace.as_deref(),
+                ) {
                     symbols.push(symbol);
                 }
             }
             "interface_declaration" => {
-                if let Some(symbol) =
-                    process_type_like(node, source, repo_id, rel_path, "interface")
-                {
+                if let Some(symbol) = process_type_like(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    "interface",
+                    file_namespace.as_deref(),
+                ) {
                     symbols.push(symbol);
                 }
             }
             "struct_declaration" | "record_declaration" => {
-                if let Some(symbol) = process_type_like(node, source, repo_id, rel_path, "class") {
+                if let Some(symbol) = process_type_like(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    "class",
+                    file_namespace.as_deref(),
+                ) {
                     symbols.push(symbol);
                 }
             }
             "enum_declaration" => {
-                if let Some(symbol) = process_type_like(node, source, repo_id, rel_path, "type") {
+                if let Some(symbol) = process_type_like(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    "type",
+                    file_namespace.as_deref(),
+                ) {
                     symbols.push(symbol);
                 }
             }
@@ -56,13 +87,31 @@ pub fn extract_symbols_csharp(
                 }
             }
             "property_declaration" => {
-                if let Some(symbol) = process_variable_like(node, source, repo_id, rel_path) {
+                if let Some(mut symbol) = process_variable_like(node, source, repo_id, rel_path) {
+                    symbol.attributes.push(attribute("member", "property"));
+                    if let Some(ty) = node.child_by_field_name("type") {
+                        symbol
+                            .attributes
+                            .push(attribute("type", node_text(ty, source)));
+                    }
                     symbols.push(symbol);
                 }
             }
+            "event_declaration" | "event_field_declaration" => {
+                process_event_declaration(node, source, repo_id, rel_path, &mut symbols);
+            }
             _ => {}
         }
 
+        if symbols.len() > declared_from {
+            push_scope_attributes(
+                node,
+                source,
+                file_namespace.as_deref(),
+                &mut symbols[declared_from..],
+            );
+        }
+
         let child_count = node.child_count();
         for i in (0..child_count).rev() {
             if let Some(child) = node.child(i) {
@@ -116,11 +165,23 @@ fn process_type_like(
     repo_id: &str,
     rel_path: &str,
     kind: &str,
+    file_namespace: Option<&str>,
 ) -> Option<NativeParsedSymbol> {
-    let name = extract_identifier(node, source)?;
+    let name = node
+        .child_by_field_name("name")
+        .map(|name_node| node_text(name_node, source).to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| extract_identifier(node, source))?;
     let generics = extract_type_parameters(node, source);
     let visibility = extract_visibility(node, source);
     let decorators = extract_decorators(node, source);
+    // Positional records (`record Point(int X, int Y)`) declare their
+    // primary constructor parameters on the type itself.
+    let params = if node.kind() == "record_declaration" {
+        extract_parameters(node, source)
+    } else {
+        Vec::new()
+    };
 
     // TS csharp.ts emits signature for class/interface/struct/record but NOT
     // for enum (which maps to kind "type" here). Force signature only for
@@ -133,7 +194,7 @@ fn process_type_like(
             source,
             repo_id,
             rel_path,
-            &[],
+            &params,
             None,
             &generics,
             &visibility,
@@ -147,7 +208,7 @@ fn process_type_like(
             source,
             repo_id,
             rel_path,
-            &[],
+            &params,
             None,
             &generics,
             &visibility,
@@ -155,6 +216,26 @@ fn process_type_like(
         )
     };
     symbol.exported = visibility == "public";
+    push_modifiers(node, source, &mut symbol);
+    apply_type_parameters(&mut symbol, extract_type_parameter_details(node, source));
+
+    // `Ns.Outer.Name` identifies the type across files, which is what ties
+    // the pieces of a `partial` class back together.
+    let scope = enclosing_scope_names(node, source, file_namespace);
+    if let Some(owner) = enclosing_type_name(node, source) {
+        symbol.attributes.push(attribute("owner", &owner));
+    }
+    let qualified_name = scope
+        .into_iter()
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join(".");
+    symbol
+        .attributes
+        .push(attribute("qualifiedName", &qualified_name));
+    if has_modifier(node, source, "partial") {
+        symbol.attributes.push(attribute("partial", "true"));
+    }
     Some(symbol)
 }
 
@@ -196,6 +277,12 @@ fn process_method_declaration(
         &extract_decorators(node, source),
     );
     symbol.exported = visibility == "public";
+    push_modifiers(node, source, &mut symbol);
+    apply_type_parameters(&mut symbol, extract_type_parameter_details(node, source));
+    if modifiers.iter().any(|m| m == "async") {
+        symbol.attributes.push(attribute("async", "true"));
+    }
+    push_owner_attribute(node, source, &mut symbol);
     Some(symbol)
 }
 
@@ -223,6 +310,8 @@ fn process_constructor_declaration(
         &extract_decorators(node, source),
     );
     symbol.exported = visibility == "public";
+    push_modifiers(node, source, &mut symbol);
+    push_owner_attribute(node, source, &mut symbol);
     Some(symbol)
 }
 
@@ -249,9 +338,211 @@ fn process_variable_like(
         &extract_decorators(node, source),
     );
     symbol.exported = visibility == "public";
+    push_modifiers(node, source, &mut symbol);
+    push_owner_attribute(node, source, &mut symbol);
     Some(symbol)
 }
 
+/// `event EventHandler Changed;` declares one event per declarator;
+/// `event EventHandler Changed { add; remove; }` declares a single one.
+fn process_event_declaration(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let names: Vec<String> = match node.child_by_field_name("name") {
+        Some(name_node) => vec![node_text(name_node, source).to_string()],
+        None => {
+            let Some(declaration) = find_child_node(node, "variable_declaration") else {
+                return;
+            };
+            let mut cursor = declaration.walk();
+            let names = declaration
+                .children(&mut cursor)
+                .filter(|child| child.kind() == "variable_declarator")
+                .filter_map(|declarator| extract_identifier(declarator, source))
+                .collect();
+            names
+        }
+    };
+
+    let visibility = extract_visibility(node, source);
+    let decorators = extract_decorators(node, source);
+    for name in names.into_iter().filter(|name| !name.is_empty()) {
+        let mut symbol = make_symbol(
+            &name,
+            "variable",
+            node,
+            source,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+            &[],
+            &visibility,
+            &decorators,
+        );
+        symbol.exported = visibility == "public";
+        push_modifiers(node, source, &mut symbol);
+        symbol.attributes.push(attribute("member", "event"));
+        push_owner_attribute(node, source, &mut symbol);
+        symbols.push(symbol);
+    }
+}
+
+const TYPE_DECLARATION_KINDS: &[&str] = &[
+    "class_declaration",
+    "interface_declaration",
+    "struct_declaration",
+    "record_declaration",
+    "enum_declaration",
+];
+
+/// Namespace and enclosing type names around `node`, outermost first.
+fn enclosing_scope_names(
+    node: Node<'_>,
+    source: &[u8],
+    file_namespace: Option<&str>,
+) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == "namespace_declaration"
+            || TYPE_DECLARATION_KINDS.contains(&parent.kind())
+        {
+            if let Some(name_node) = parent.child_by_field_name("name") {
+                let name = node_text(name_node, source);
+                if !name.is_empty() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        current = parent.parent();
+    }
+    if let Some(namespace) = file_namespace {
+        names.push(namespace.to_string());
+    }
+    names.reverse();
+    names
+}
+
+/// Dotted path of the namespaces enclosing `node` (block-scoped ones plus a
+/// file-scoped `namespace X;`), outermost first.
+fn enclosing_namespace(
+    node: Node<'_>,
+    source: &[u8],
+    file_namespace: Option<&str>,
+) -> Option<String> {
+    let mut names = Vec::new();
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == "namespace_declaration" {
+            if let Some(name_node) = parent.child_by_field_name("name") {
+                let name = node_text(name_node, source);
+                if !name.is_empty() {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        current = parent.parent();
+    }
+    if let Some(namespace) = file_namespace {
+        names.push(namespace.to_string());
+    }
+    if names.is_empty() {
+        return None;
+    }
+    names.reverse();
+    Some(names.join("."))
+}
+
+/// Tag the symbols declared by `node` with their enclosing `namespace`, and
+/// give members (which keep bare names) a `Ns.Type.Member` `qualifiedName`
+/// like the one types already carry.
+fn push_scope_attributes(
+    node: Node<'_>,
+    source: &[u8],
+    file_namespace: Option<&str>,
+    declared: &mut [NativeParsedSymbol],
+) {
+    let file_namespace =
+        file_namespace.filter(|_| node.kind() != "file_scoped_namespace_declaration");
+    let namespace = enclosing_namespace(node, source, file_namespace);
+    let scope = enclosing_scope_names(node, source, file_namespace);
+    for symbol in declared {
+        if let Some(namespace) = &namespace {
+            symbol.attributes.push(attribute("namespace", namespace));
+        }
+        let has_qualified_name = symbol
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "qualifiedName");
+        if !has_qualified_name && !scope.is_empty() {
+            let qualified_name = scope
+                .iter()
+                .map(String::as_str)
+                .chain(std::iter::once(symbol.name.as_str()))
+                .collect::<Vec<_>>()
+                .join(".");
+            symbol
+                .attributes
+                .push(attribute("qualifiedName", &qualified_name));
+        }
+    }
+}
+
+fn enclosing_type_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if TYPE_DECLARATION_KINDS.contains(&parent.kind()) {
+            return parent
+                .child_by_field_name("name")
+                .map(|name_node| node_text(name_node, source).to_string())
+                .filter(|name| !name.is_empty());
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+fn push_owner_attribute(node: Node<'_>, source: &[u8], symbol: &mut NativeParsedSymbol) {
+    if let Some(owner) = enclosing_type_name(node, source) {
+        symbol.attributes.push(attribute("owner", &owner));
+    }
+}
+
+/// C# 10 `namespace Acme.Api;` applies to the rest of the file; its members
+/// are siblings of the declaration rather than children.
+fn find_file_scoped_namespace(root: Node<'_>, source: &[u8]) -> Option<String> {
+    let declaration = find_child_node(root, "file_scoped_namespace_declaration")?;
+    let name_node = declaration.child_by_field_name("name")?;
+    let name = node_text(name_node, source).to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+fn has_modifier(node: Node<'_>, source: &[u8], modifier: &str) -> bool {
+    extract_modifiers(node, source)
+        .iter()
+        .any(|m| m == modifier)
+}
+
+/// Record the `abstract` / `static` / `override` / `readonly` / `async`
+/// keywords on the symbol's signature.
+fn push_modifiers(node: Node<'_>, source: &[u8], symbol: &mut NativeParsedSymbol) {
+    let modifiers = extract_modifiers(node, source);
+    let names: Vec<&str> = modifiers.iter().map(String::as_str).collect();
+    apply_modifiers(symbol, &names);
+}
+
+fn attribute(key: &str, value: &str) -> NativeSymbolAttribute {
+    NativeSymbolAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
 fn extract_identifier(node: Node<'_>, source: &[u8]) -> Option<String> {
     if node.kind() == "identifier" {
         let text = node_text(node, source).to_string();
@@ -298,14 +589,15 @@ fn extract_visibility(node: Node<'_>, source: &[u8]) -> String {
     String::new()
 }
 
+/// tree-sitter-c-sharp emits each keyword as its own `modifier` child of the
+/// declaration (there is no wrapping `modifiers` node).
 fn extract_modifiers(node: Node<'_>, source: &[u8]) -> Vec<String> {
-    let Some(modifiers) = find_child_node(node, "modifiers") else {
-        return Vec::new();
-    };
-
     let mut out = Vec::new();
-    let mut cursor = modifiers.walk();
-    for child in modifiers.children(&mut cursor) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() != "modifier" {
+            continue;
+        }
         let text = node_text(child, source);
         if !text.is_empty() {
             out.push(text.to_string());
@@ -332,6 +624,44 @@ fn extract_type_parameters(node: Node<'_>, source: &[u8]) -> Vec<String> {
     generics
 }
 
+/// `<T, TKey> where T : class, new()` -> `T` with constraint `class, new()`
+/// and `TKey` without one. C# type parameters have no defaults.
+fn extract_type_parameter_details(node: Node<'_>, source: &[u8]) -> Vec<NativeTypeParameter> {
+    let Some(type_params) = find_child_node(node, "type_parameter_list") else {
+        return Vec::new();
+    };
+
+    let mut cursor = node.walk();
+    let constraints: Vec<(&str, &str)> = node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "type_parameter_constraints_clause")
+        .filter_map(|clause| {
+            let text = node_text(clause, source).trim();
+            let text = text.strip_prefix("where").unwrap_or(text);
+            let (target, constraint) = text.split_once(':')?;
+            Some((target.trim(), constraint.trim()))
+        })
+        .collect();
+
+    let mut param_cursor = type_params.walk();
+    let parameters = type_params
+        .named_children(&mut param_cursor)
+        .filter(|child| child.kind() == "type_parameter")
+        .filter_map(|child| {
+            let name = child
+                .child_by_field_name("name")
+                .or_else(|| find_child_node(child, "identifier"))?;
+            let name = node_text(name, source);
+            let constraint = constraints
+                .iter()
+                .find(|(target, _)| *target == name)
+                .map(|(_, constraint)| *constraint);
+            Some(type_parameter(name, constraint, None))
+        })
+        .collect();
+    parameters
+}
+
 fn extract_parameters(node: Node<'_>, source: &[u8]) -> Vec<ParamInfo> {
     let Some(param_list) = find_child_node(node, "parameter_list") else {
         return Vec::new();
@@ -379,3 +709,57 @@ fn extract_decorators(node: Node<'_>, source: &[u8]) -> Vec<String> {
     }
     decorators
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+    use crate::types::NativeParsedSymbol;
+
+    #[test]
+    fn csharp_parser_records_partial_classes_properties_and_events() {
+        let source = r#"namespace Acme.Orders;
+
+public partial class OrderService
+{
+    public string Name { get; set; }
+
+    public event EventHandler Changed;
+
+    public async Task<int> SaveAsync() { return 1; }
+}
+"#;
+
+        let parsed = parse_source("cs", "src/Orders/OrderService.cs", source);
+
+        let find = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .unwrap_or_else(|| panic!("expected {name} symbol, got {:?}", parsed.symbols))
+        };
+        let attr = |symbol: &NativeParsedSymbol, key: &str| {
+            symbol
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+
+        assert_eq!(find("Acme.Orders").kind, "module");
+
+        let service = find("OrderService");
+        assert_eq!(attr(service, "partial").as_deref(), Some("true"));
+        assert_eq!(
+            attr(service, "qualifiedName").as_deref(),
+            Some("Acme.Orders.OrderService")
+        );
+
+        let name = find("Name");
+        assert_eq!(attr(name, "member").as_deref(), Some("property"));
+        assert_eq!(attr(name, "owner").as_deref(), Some("OrderService"));
+
+        assert_eq!(attr(find("Changed"), "member").as_deref(), Some("event"));
+        assert_eq!(attr(find("SaveAsync"), "async").as_deref(), Some("true"));
+    }
+}
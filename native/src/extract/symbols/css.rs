@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::types::NativeParsedSymbol;
+
+use super::common::{make_symbol, node_text};
+
+/// Extract class (`.btn`) and ID (`#header`) selectors from CSS/SCSS rule
+/// sets. Each distinct selector is emitted once, anchored on the first rule
+/// set that declares it, with the leading sigil kept in the name so classes
+/// and IDs with the same identifier stay distinct.
+pub fn extract_symbols_css(
+    root: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Vec<NativeParsedSymbol> {
+    let mut symbols = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "rule_set" {
+            if let Some(selectors) = find_selectors(node) {
+                for name in collect_selector_names(selectors, source) {
+                    if !seen.insert(name.clone()) {
+                        continue;
+                    }
+                    let mut symbol = make_symbol(
+                        &name,
+                        "variable",
+                        node,
+                        source,
+                        repo_id,
+                        rel_path,
+                        &[],
+                        None,
+                        &[],
+                        "public",
+                        &[],
+                    );
+                    symbol.exported = true;
+                    symbols.push(symbol);
+                }
+            }
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    symbols
+}
+
+fn find_selectors(rule_set: Node<'_>) -> Option<Node<'_>> {
+    let mut cursor = rule_set.walk();
+    let found = rule_set
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "selectors");
+    found
+}
+
+fn collect_selector_names(selectors: Node<'_>, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut stack = vec![selectors];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            // Pseudo-classes (`:hover`) also name themselves with a
+            // `class_name` child; only `class_selector` ones are classes.
+            "class_name"
+                if node
+                    .parent()
+                    .is_some_and(|parent| parent.kind() == "class_selector") =>
+            {
+                let text = node_text(node, source);
+                if !text.is_empty() {
+                    names.push(format!(".{text}"));
+                }
+            }
+            "id_name" => {
+                let text = node_text(node, source);
+                if !text.is_empty() {
+                    names.push(format!("#{text}"));
+                }
+            }
+            _ => {}
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn scss_parser_emits_selectors_and_module_edges() {
+        let source = r#"@use "sass:math";
+@use "./theme" as t;
+@forward "./mixins";
+@import "reset.css";
+
+#header .btn {
+  color: red;
+}
+
+.btn:hover,
+.btn-primary {
+  color: blue;
+}
+"#;
+
+        let parsed = parse_source("scss", "styles/main.scss", source);
+
+        let mut selectors: Vec<&str> = parsed
+            .symbols
+            .iter()
+            .map(|symbol| symbol.name.as_str())
+            .collect();
+        selectors.sort_unstable();
+        assert_eq!(selectors, vec!["#header", ".btn", ".btn-primary"]);
+
+        let imports: Vec<(&str, bool, Option<&str>, bool)> = parsed
+            .imports
+            .iter()
+            .map(|import| {
+                (
+                    import.specifier.as_str(),
+                    import.is_external,
+                    import.namespace_import.as_deref(),
+                    import.is_re_export,
+                )
+            })
+            .collect();
+        assert_eq!(
+            imports,
+            vec![
+                ("sass:math", true, None, false),
+                ("./theme", false, Some("t"), false),
+                ("./mixins", false, None, true),
+                ("reset.css", false, None, false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::types::{NativeDecorator, NativeSymbolAttribute};
+
+/// Parse raw decorator / annotation / attribute text into its name and
+/// literal arguments:
+///
+/// - `@Get(':id')` -> `Get`, arguments `[":id"]`
+/// - `@Component({ selector: 'app-root' })` -> `Component`, option
+///   `selector=app-root`
+/// - `@RequestMapping(value = "/users")` / `@app.route("/", methods=["GET"])`
+///   -> named arguments become options
+/// - `#[derive(Debug, Clone)]` / `[HttpGet("x")]` -> sigil and brackets stripped
+///
+/// String literals lose their quotes; any other argument is kept as written.
+pub(super) fn parse_decorator(text: &str) -> Option<NativeDecorator> {
+    let text = strip_sigil(text.trim());
+    let (name, arguments) = match text.find('(') {
+        Some(open) if text.ends_with(')') => (&text[..open], Some(&text[open + 1..text.len() - 1])),
+        _ => (text, None),
+    };
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut decorator = NativeDecorator {
+        name: name.to_string(),
+        arguments: Vec::new(),
+        options: Vec::new(),
+    };
+    for argument in split_top_level(arguments.unwrap_or("")) {
+        if let Some(body) = argument
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+        {
+            decorator
+                .options
+                .extend(split_top_level(body).filter_map(|entry| option(entry, ':')));
+        } else if let Some(named) = option(argument, '=') {
+            decorator.options.push(named);
+        } else {
+            decorator.arguments.push(literal_value(argument));
+        }
+    }
+    Some(decorator)
+}
+
+fn strip_sigil(text: &str) -> &str {
+    if let Some(rest) = text
+        .strip_prefix("#![")
+        .or_else(|| text.strip_prefix("#["))
+        .or_else(|| text.strip_prefix('['))
+    {
+        return rest.strip_suffix(']').unwrap_or(rest).trim();
+    }
+    text.strip_prefix('@').unwrap_or(text).trim()
+}
+
+/// `key: value` / `key = value` -> option, when `key` is a plain or quoted
+/// identifier (so `a == b` or `x => y` are left alone).
+fn option(entry: &str, separator: char) -> Option<NativeSymbolAttribute> {
+    let (key, value) = entry.split_once(separator)?;
+    if separator == '=' && (value.starts_with('=') || value.starts_with('>')) {
+        return None;
+    }
+    let key = unquote(key.trim());
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$' || c == '-')
+    {
+        return None;
+    }
+    Some(NativeSymbolAttribute {
+        key: key.to_string(),
+        value: literal_value(value),
+    })
+}
+
+fn literal_value(text: &str) -> String {
+    unquote(text.trim()).to_string()
+}
+
+fn unquote(text: &str) -> &str {
+    for quote in ['"', '\'', '`'] {
+        if text.len() >= 2 && text.starts_with(quote) && text.ends_with(quote) {
+            return &text[1..text.len() - 1];
+        }
+    }
+    text
+}
+
+/// Split on commas outside brackets and string literals, dropping empty
+/// pieces (trailing commas).
+fn split_top_level(text: &str) -> impl Iterator<Item = &str> {
+    let mut pieces = Vec::new();
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        if let Some(open) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == open {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' | '`' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                pieces.push(&text[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    pieces.push(&text[start..]);
+    pieces
+        .into_iter()
+        .map(str::trim)
+        .filter(|piece| !piece.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn decorators_are_parsed_into_name_and_literal_arguments() {
+        let source = r#"@Component({ selector: 'app-users', standalone: true })
+export class UsersComponent {}
+
+@Controller("users")
+export class UsersController {
+  @Get(':id')
+  find(@Param('id') id: string) {}
+}
+"#;
+
+        let parsed = parse_source("ts", "src/users.ts", source);
+
+        let metadata = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .expect("symbol present")
+                .decorator_metadata
+                .clone()
+        };
+
+        let component = metadata("UsersComponent");
+        assert_eq!(component.len(), 1);
+        assert_eq!(component[0].name, "Component");
+        assert!(component[0].arguments.is_empty());
+        let options: Vec<(&str, &str)> = component[0]
+            .options
+            .iter()
+            .map(|option| (option.key.as_str(), option.value.as_str()))
+            .collect();
+        assert_eq!(
+            options,
+            vec![("selector", "app-users"), ("standalone", "true")]
+        );
+
+        let controller = metadata("UsersController");
+        assert_eq!(controller[0].name, "Controller");
+        assert_eq!(controller[0].arguments, vec!["users"]);
+
+        let route = metadata("find");
+        assert_eq!(route.len(), 1);
+        assert_eq!(route[0].name, "Get");
+        assert_eq!(route[0].arguments, vec![":id"]);
+    }
+}
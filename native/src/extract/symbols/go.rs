@@ -1,8 +1,10 @@
 use tree_sitter::Node;
 
-use crate::types::NativeParsedSymbol;
+use crate::types::{NativeParsedSymbol, NativeSymbolAttribute, NativeTypeParameter};
 
-use super::common::{find_child_node, make_symbol, node_text, ParamInfo};
+use super::common::{
+    apply_type_parameters, find_child_node, make_symbol, node_text, type_parameter, ParamInfo,
+};
 
 pub fn extract_symbols_go(
     root: Node<'_>,
@@ -28,9 +30,7 @@ pub fn extract_symbols_go(
                 }
             }
             "type_declaration" => {
-                if let Some(symbol) = process_type_declaration(node, source, repo_id, rel_path) {
-                    symbols.push(symbol);
-                }
+                process_type_declaration(node, source, repo_id, rel_path, &mut symbols);
             }
             "const_declaration" => {
                 process_const_or_var_declaration(
@@ -130,6 +130,7 @@ fn process_function_declaration(
         &[],
     );
     symbol.exported = is_exported_name(&name);
+    apply_type_parameters(&mut symbol, extract_type_parameter_details(node, source));
     Some(symbol)
 }
 
@@ -148,7 +149,8 @@ fn process_method_declaration(
     // TS go.ts extractParameters for methods inserts TWO synthetic params
     // for the receiver: first the receiver type as name+type, then the
     // receiver name with the receiver type as type. Replicate that.
-    if let Some((receiver_type, receiver_name)) = extract_receiver_info(node, source) {
+    let receiver_info = extract_receiver_info(node, source);
+    if let Some((receiver_type, receiver_name)) = receiver_info.clone() {
         params.insert(
             0,
             ParamInfo {
@@ -180,35 +182,106 @@ fn process_method_declaration(
         "",
         &[],
     );
+    // The synthetic params above are kept for parity; the receiver is also
+    // recorded on its own so consumers can attach the method to its type
+    // without re-parsing the param list.
+    if let (Some(signature), Some((receiver_type, _))) = (symbol.signature.as_mut(), receiver_info)
+    {
+        signature.receiver = Some(receiver_type);
+    }
     symbol.exported = is_exported_name(&name);
     Some(symbol)
 }
 
+/// Emit one symbol per `type_spec` / `type_alias` in a declaration, so both
+/// `type Foo struct{}` and grouped `type ( A int; B string )` are covered.
+/// Structs map to "class" and interfaces to "interface"; every other named
+/// type (aliases, func types, maps, ...) stays "type".
 fn process_type_declaration(
     node: Node<'_>,
     source: &[u8],
     repo_id: &str,
     rel_path: &str,
-) -> Option<NativeParsedSymbol> {
-    let type_spec = find_child_node(node, "type_spec")?;
-    let name_node = find_child_node(type_spec, "type_identifier")?;
-    let name = node_text(name_node, source).to_string();
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let mut cursor = node.walk();
+    let specs: Vec<Node<'_>> = node
+        .named_children(&mut cursor)
+        .filter(|child| matches!(child.kind(), "type_spec" | "type_alias"))
+        .collect();
+    let grouped = specs.len() > 1;
+
+    for spec in specs {
+        let Some(name_node) = spec.child_by_field_name("name") else {
+            continue;
+        };
+        let name = node_text(name_node, source).to_string();
+        if name.is_empty() {
+            continue;
+        }
 
-    let mut symbol = make_symbol(
-        &name,
-        "type",
-        node,
-        source,
-        repo_id,
-        rel_path,
-        &[],
-        None,
-        &[],
-        "",
-        &[],
-    );
-    symbol.exported = is_exported_name(&name);
-    Some(symbol)
+        let kind = match spec.child_by_field_name("type").map(|n| n.kind()) {
+            Some("struct_type") => "class",
+            Some("interface_type") => "interface",
+            _ => "type",
+        };
+        let generics = extract_type_parameters(spec, source);
+
+        let mut symbol = make_symbol(
+            &name,
+            kind,
+            if grouped { spec } else { node },
+            source,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+            &generics,
+            "",
+            &[],
+        );
+        symbol.exported = is_exported_name(&name);
+        apply_type_parameters(&mut symbol, extract_type_parameter_details(spec, source));
+        symbols.push(symbol);
+    }
+}
+
+fn extract_type_parameters(spec: Node<'_>, source: &[u8]) -> Vec<String> {
+    let Some(type_params) = spec.child_by_field_name("type_parameters") else {
+        return vec![];
+    };
+    let mut cursor = type_params.walk();
+    let generics = type_params
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "type_parameter_declaration")
+        .map(|child| node_text(child, source).to_string())
+        .filter(|text| !text.is_empty())
+        .collect();
+    generics
+}
+
+/// `[K comparable, V any]` -> one entry per name, each carrying its
+/// declaration's constraint (`[A, B Number]` gives both `A` and `B` the
+/// constraint `Number`). Go type parameters have no defaults.
+fn extract_type_parameter_details(node: Node<'_>, source: &[u8]) -> Vec<NativeTypeParameter> {
+    let Some(type_params) = node.child_by_field_name("type_parameters") else {
+        return vec![];
+    };
+    let mut parameters = Vec::new();
+    let mut cursor = type_params.walk();
+    for declaration in type_params
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "type_parameter_declaration")
+    {
+        let constraint = declaration
+            .child_by_field_name("type")
+            .map(|constraint| node_text(constraint, source));
+        let mut name_cursor = declaration.walk();
+        for name in declaration.children_by_field_name("name", &mut name_cursor) {
+            parameters.push(type_parameter(node_text(name, source), constraint, None));
+        }
+    }
+    parameters
 }
 
 fn process_const_or_var_declaration(
@@ -258,6 +331,12 @@ fn process_const_or_var_declaration(
                 &[],
             );
             symbol.exported = is_exported_name(&name);
+            if spec_kind == "const_spec" {
+                symbol.attributes.push(NativeSymbolAttribute {
+                    key: "declaration".to_string(),
+                    value: "const".to_string(),
+                });
+            }
             symbols.push(symbol);
         }
     }
@@ -281,11 +360,6 @@ fn extract_params_for_method(node: Node<'_>, source: &[u8]) -> Vec<ParamInfo> {
     extract_param_infos(param_lists[1], source)
 }
 
-#[allow(dead_code)]
-fn extract_receiver_type(node: Node<'_>, source: &[u8]) -> Option<String> {
-    extract_receiver_info(node, source).map(|(ty, _)| ty)
-}
-
 fn extract_receiver_info(node: Node<'_>, source: &[u8]) -> Option<(String, Option<String>)> {
     let param_lists = collect_parameter_lists(node);
     let receiver_list = param_lists.first().copied()?;
@@ -461,3 +535,53 @@ fn is_exported_name(name: &str) -> bool {
         .map(|c| c.is_uppercase())
         .unwrap_or(false)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn go_parser_records_method_receiver_and_type_kinds() {
+        let source = r#"package server
+
+const DefaultPort = 8080
+
+type Server struct{ port int }
+
+type Handler interface{ Serve() }
+
+func (s *Server) Start() error { return nil }
+
+func (s *Server) stop() {}
+"#;
+
+        let parsed = parse_source("go", "server/server.go", source);
+
+        let find = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .unwrap_or_else(|| panic!("expected {name} symbol, got {:?}", parsed.symbols))
+        };
+
+        assert_eq!(find("Server").kind, "class");
+        assert_eq!(find("Handler").kind, "interface");
+        assert!(find("DefaultPort")
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "declaration" && attr.value == "const"));
+
+        let start = find("Start");
+        assert_eq!(start.kind, "method");
+        assert!(start.exported);
+        assert_eq!(
+            start
+                .signature
+                .as_ref()
+                .and_then(|signature| signature.receiver.as_deref()),
+            Some("*Server")
+        );
+        assert!(!find("stop").exported);
+    }
+}
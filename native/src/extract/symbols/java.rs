@@ -1,8 +1,11 @@
 use tree_sitter::Node;
 
-use crate::types::NativeParsedSymbol;
+use crate::types::{NativeParsedSymbol, NativeSymbolAttribute, NativeTypeParameter};
 
-use super::common::{find_child_node, make_symbol, node_text, ParamInfo};
+use super::common::{
+    apply_modifiers, apply_type_parameters, find_child_node, make_symbol, node_text,
+    type_parameter, ParamInfo,
+};
 
 pub fn extract_symbols_java(
     root: Node<'_>,
@@ -11,6 +14,7 @@ pub fn extract_symbols_java(
     rel_path: &str,
 ) -> Vec<NativeParsedSymbol> {
     let mut symbols = Vec::new();
+    let package = find_package_name(root, source);
     let mut stack = vec![root];
 
     while let Some(node) = stack.pop() {
@@ -21,26 +25,40 @@ pub fn extract_symbols_java(
                 }
             }
             "class_declaration" => {
-                if let Some(symbol) = process_type_like(node, source, repo_id, rel_path, "class") {
+                if let Some(symbol) =
+                    process_type_like(node, source, repo_id, rel_path, "class", package.as_deref())
+                {
                     symbols.push(symbol);
                 }
             }
             "interface_declaration" => {
-                if let Some(symbol) =
-                    process_type_like(node, source, repo_id, rel_path, "interface")
-                {
+                if let Some(symbol) = process_type_like(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    "interface",
+                    package.as_deref(),
+                ) {
                     symbols.push(symbol);
                 }
             }
             "enum_declaration" | "record_declaration" => {
-                if let Some(symbol) = process_type_like(node, source, repo_id, rel_path, "class") {
+                if let Some(symbol) =
+                    process_type_like(node, source, repo_id, rel_path, "class", package.as_deref())
+                {
                     symbols.push(symbol);
                 }
             }
             "annotation_type_declaration" => {
-                if let Some(symbol) =
-                    process_type_like(node, source, repo_id, rel_path, "interface")
-                {
+                if let Some(symbol) = process_type_like(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    "interface",
+                    package.as_deref(),
+                ) {
                     symbols.push(symbol);
                 }
             }
@@ -108,10 +126,19 @@ fn process_type_like(
     repo_id: &str,
     rel_path: &str,
     kind: &str,
+    package: Option<&str>,
 ) -> Option<NativeParsedSymbol> {
     let name = extract_identifier(node, source)?;
     let generics = extract_generics(node, source);
     let visibility = extract_visibility(node, source);
+    let decorators = extract_decorators(node, source);
+    // Record components (`record Point(int x, int y)`) are the record's
+    // canonical constructor parameters.
+    let params = if node.kind() == "record_declaration" {
+        extract_parameters(node, source)
+    } else {
+        Vec::new()
+    };
 
     // TS java.ts emits a signature for class, interface, and record declarations,
     // but not enum or annotation declarations.
@@ -128,11 +155,11 @@ fn process_type_like(
             source,
             repo_id,
             rel_path,
-            &[],
+            &params,
             None,
             &generics,
             &visibility,
-            &[],
+            &decorators,
         )
     } else {
         make_symbol(
@@ -142,14 +169,34 @@ fn process_type_like(
             source,
             repo_id,
             rel_path,
-            &[],
+            &params,
             None,
             &generics,
             &visibility,
-            &[],
+            &decorators,
         )
     };
     symbol.exported = is_public(node);
+    apply_modifiers(&mut symbol, &extract_modifiers(node, source));
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
+
+    // Nested types keep their simple name (matching TS java.ts) and carry
+    // the enclosing chain as a fully qualified `pkg.Outer.Inner` name.
+    let owners = enclosing_type_names(node, source);
+    let qualified_name = package
+        .into_iter()
+        .map(str::to_string)
+        .chain(owners.iter().cloned())
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join(".");
+    if let Some(owner) = owners.last() {
+        symbol.attributes.push(attribute("owner", owner));
+    }
+    symbol
+        .attributes
+        .push(attribute("qualifiedName", &qualified_name));
+    push_annotation_attributes(node, source, &mut symbol);
     Some(symbol)
 }
 
@@ -178,6 +225,10 @@ fn process_method_declaration(
         &extract_decorators(node, source),
     );
     symbol.exported = is_public(node);
+    apply_modifiers(&mut symbol, &extract_modifiers(node, source));
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
+    push_owner_attribute(node, source, &mut symbol);
+    push_annotation_attributes(node, source, &mut symbol);
     Some(symbol)
 }
 
@@ -205,6 +256,8 @@ fn process_constructor_declaration(
         &extract_decorators(node, source),
     );
     symbol.exported = is_public(node);
+    push_owner_attribute(node, source, &mut symbol);
+    push_annotation_attributes(node, source, &mut symbol);
     Some(symbol)
 }
 
@@ -216,6 +269,10 @@ fn process_field_declaration(
     symbols: &mut Vec<NativeParsedSymbol>,
 ) {
     let visibility = extract_visibility(node, source);
+    // Annotations and modifiers sit on the declaration and apply to every
+    // declarator.
+    let decorators = extract_decorators(node, source);
+    let modifiers = extract_modifiers(node, source);
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -242,9 +299,12 @@ fn process_field_declaration(
             None,
             &[],
             &visibility,
-            &extract_decorators(child, source),
+            &decorators,
         );
         symbol.exported = false;
+        apply_modifiers(&mut symbol, &modifiers);
+        push_owner_attribute(node, source, &mut symbol);
+        push_annotation_attributes(node, source, &mut symbol);
         symbols.push(symbol);
     }
 }
@@ -311,6 +371,29 @@ fn extract_generics(node: Node<'_>, source: &[u8]) -> Vec<String> {
     generics
 }
 
+/// `<T extends Comparable<T> & Serializable>` -> name `T`, constraint
+/// `Comparable<T> & Serializable`. Java type parameters have no defaults.
+fn extract_type_parameters(node: Node<'_>, source: &[u8]) -> Vec<NativeTypeParameter> {
+    let Some(type_params) = find_child_node(node, "type_parameters") else {
+        return Vec::new();
+    };
+
+    let mut cursor = type_params.walk();
+    let parameters = type_params
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "type_parameter")
+        .filter_map(|child| {
+            let name = find_child_node(child, "type_identifier")?;
+            let bound = find_child_node(child, "type_bound").map(|bound| {
+                let text = node_text(bound, source).trim();
+                text.strip_prefix("extends").unwrap_or(text)
+            });
+            Some(type_parameter(node_text(name, source), bound, None))
+        })
+        .collect();
+    parameters
+}
+
 fn extract_parameters(node: Node<'_>, source: &[u8]) -> Vec<ParamInfo> {
     let Some(formal_params) = find_child_node(node, "formal_parameters") else {
         return Vec::new();
@@ -403,7 +486,198 @@ fn is_java_type_node(kind: &str) -> bool {
     )
 }
 
-fn extract_decorators(_node: Node<'_>, _source: &[u8]) -> Vec<String> {
-    // TS java.ts does NOT emit decorators for Java declarations.
-    Vec::new()
+/// Annotations on a declaration, as written (`@Override`,
+/// `@GetMapping("/users")`), in source order.
+fn extract_decorators(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    annotation_nodes(node)
+        .into_iter()
+        .map(|annotation| node_text(annotation, source).to_string())
+        .filter(|text| !text.is_empty())
+        .collect()
+}
+
+/// Structured form of the annotations: one `annotation` attribute per
+/// annotation holding its name, plus `annotation.<Name>` with the raw
+/// argument list (`value = "/users", method = GET`) when it has arguments.
+fn push_annotation_attributes(node: Node<'_>, source: &[u8], symbol: &mut NativeParsedSymbol) {
+    for annotation in annotation_nodes(node) {
+        let Some(name_node) = annotation.child_by_field_name("name") else {
+            continue;
+        };
+        let name = node_text(name_node, source);
+        if name.is_empty() {
+            continue;
+        }
+        symbol.attributes.push(attribute("annotation", name));
+
+        if let Some(arguments) = annotation.child_by_field_name("arguments") {
+            let text = node_text(arguments, source);
+            let inner = text
+                .strip_prefix('(')
+                .and_then(|rest| rest.strip_suffix(')'))
+                .unwrap_or(text)
+                .trim();
+            if !inner.is_empty() {
+                symbol
+                    .attributes
+                    .push(attribute(&format!("annotation.{name}"), inner));
+            }
+        }
+    }
+}
+
+/// `abstract` / `static` keywords, `final` on a field as `readonly`, and an
+/// `@Override` annotation as `override`.
+fn extract_modifiers(node: Node<'_>, source: &[u8]) -> Vec<&'static str> {
+    let Some(modifiers) = find_child_node(node, "modifiers") else {
+        return Vec::new();
+    };
+    let is_field = node.kind() == "field_declaration";
+    let mut found = Vec::new();
+    let mut cursor = modifiers.walk();
+    for child in modifiers.children(&mut cursor) {
+        match child.kind() {
+            "abstract" => found.push("abstract"),
+            "static" => found.push("static"),
+            "final" if is_field => found.push("readonly"),
+            "marker_annotation" | "annotation" => {
+                let is_override = child
+                    .child_by_field_name("name")
+                    .is_some_and(|name| node_text(name, source) == "Override");
+                if is_override {
+                    found.push("override");
+                }
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+fn annotation_nodes(node: Node<'_>) -> Vec<Node<'_>> {
+    let Some(modifiers) = find_child_node(node, "modifiers") else {
+        return Vec::new();
+    };
+    let mut cursor = modifiers.walk();
+    let annotations = modifiers
+        .children(&mut cursor)
+        .filter(|child| matches!(child.kind(), "marker_annotation" | "annotation"))
+        .collect();
+    annotations
+}
+
+const TYPE_DECLARATION_KINDS: &[&str] = &[
+    "class_declaration",
+    "interface_declaration",
+    "enum_declaration",
+    "record_declaration",
+    "annotation_type_declaration",
+];
+
+/// Names of the named types enclosing `node`, outermost first. Anonymous
+/// class bodies have no name and are skipped.
+fn enclosing_type_names(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if TYPE_DECLARATION_KINDS.contains(&parent.kind()) {
+            if let Some(name) = extract_identifier(parent, source) {
+                names.push(name);
+            }
+        }
+        current = parent.parent();
+    }
+    names.reverse();
+    names
+}
+
+fn push_owner_attribute(node: Node<'_>, source: &[u8], symbol: &mut NativeParsedSymbol) {
+    if let Some(owner) = enclosing_type_names(node, source).pop() {
+        symbol.attributes.push(attribute("owner", &owner));
+    }
+}
+
+fn find_package_name(root: Node<'_>, source: &[u8]) -> Option<String> {
+    let package = find_child_node(root, "package_declaration")?;
+    let name_node = find_child_node(package, "scoped_identifier")
+        .or_else(|| find_child_node(package, "identifier"))?;
+    let name = node_text(name_node, source).to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+fn attribute(key: &str, value: &str) -> NativeSymbolAttribute {
+    NativeSymbolAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+    use crate::types::NativeParsedSymbol;
+
+    #[test]
+    fn java_parser_qualifies_nested_types_and_records_annotations() {
+        let source = r#"package com.acme.api;
+
+import java.util.List;
+
+public class UserController {
+    public static class Page {}
+
+    public record Query(String name, int limit) {}
+
+    @GetMapping("/users")
+    @Deprecated
+    public List<String> list() { return null; }
+}
+"#;
+
+        let parsed = parse_source(
+            "java",
+            "src/main/java/com/acme/api/UserController.java",
+            source,
+        );
+
+        let find = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .unwrap_or_else(|| panic!("expected {name} symbol, got {:?}", parsed.symbols))
+        };
+        let attr = |symbol: &NativeParsedSymbol, key: &str| {
+            symbol
+                .attributes
+                .iter()
+                .filter(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let page = find("Page");
+        assert_eq!(
+            attr(page, "qualifiedName"),
+            vec!["com.acme.api.UserController.Page"]
+        );
+        assert_eq!(attr(page, "owner"), vec!["UserController"]);
+
+        let query = find("Query");
+        let params = query
+            .signature
+            .as_ref()
+            .and_then(|signature| signature.params.as_ref())
+            .expect("expected record components as params");
+        assert_eq!(params.len(), 2);
+
+        let list = find("list");
+        assert_eq!(
+            list.decorators,
+            vec!["@GetMapping(\"/users\")", "@Deprecated"]
+        );
+        assert_eq!(attr(list, "annotation"), vec!["GetMapping", "Deprecated"]);
+        assert_eq!(attr(list, "annotation.GetMapping"), vec!["\"/users\""]);
+        assert_eq!(attr(list, "owner"), vec!["UserController"]);
+    }
 }
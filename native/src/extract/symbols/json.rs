@@ -0,0 +1,370 @@
+use tree_sitter::Node;
+
+use crate::types::{NativeParsedSymbol, NativeSymbolAttribute};
+
+use super::common::{make_symbol, node_text};
+
+const DEPENDENCY_SECTIONS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// Extract structured config from well-known JSON files. Other JSON files
+/// yield no symbols.
+///
+/// - `package.json`: the package itself (module), each `scripts` entry
+///   (function, `scripts.<name>`, with the command as an attribute), and each
+///   dependency (variable, `<section>.<package>`, with the version range).
+/// - `tsconfig*.json` / `jsconfig*.json`: each `compilerOptions.paths` alias
+///   (variable, named by the alias pattern) with its `baseUrl` and target
+///   patterns as attributes.
+pub fn extract_symbols_json(
+    root: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Vec<NativeParsedSymbol> {
+    let file_name = rel_path.rsplit('/').next().unwrap_or(rel_path);
+    let Some(object) = root_object(root) else {
+        return Vec::new();
+    };
+
+    if file_name == "package.json" {
+        extract_package_json(object, source, repo_id, rel_path)
+    } else if is_indexed_json_file(file_name) {
+        extract_tsconfig(object, source, repo_id, rel_path)
+    } else {
+        Vec::new()
+    }
+}
+
+/// Whether `file_name` is one of the JSON files `extract_symbols_json`
+/// understands. The scanner skips every other JSON file: lockfiles, fixtures
+/// and generated data carry no symbols.
+pub fn is_indexed_json_file(file_name: &str) -> bool {
+    file_name == "package.json"
+        || ((file_name.starts_with("tsconfig") || file_name.starts_with("jsconfig"))
+            && file_name.ends_with(".json"))
+}
+
+fn extract_package_json(
+    object: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Vec<NativeParsedSymbol> {
+    let mut symbols = Vec::new();
+
+    for (key, value, pair) in pairs(object, source) {
+        if key == "name" {
+            let name = string_value(value, source);
+            if name.is_empty() {
+                continue;
+            }
+            let mut symbol = config_symbol(&name, "module", pair, source, repo_id, rel_path);
+            if let Some(version) = member_string(object, "version", source) {
+                symbol.attributes.push(attribute("version", &version));
+            }
+            symbols.push(symbol);
+        } else if key == "scripts" && value.kind() == "object" {
+            for (script, command, script_pair) in pairs(value, source) {
+                let mut symbol = config_symbol(
+                    &format!("scripts.{script}"),
+                    "function",
+                    script_pair,
+                    source,
+                    repo_id,
+                    rel_path,
+                );
+                symbol
+                    .attributes
+                    .push(attribute("command", &string_value(command, source)));
+                symbols.push(symbol);
+            }
+        } else if DEPENDENCY_SECTIONS.contains(&key.as_str()) && value.kind() == "object" {
+            for (package, version, dep_pair) in pairs(value, source) {
+                let mut symbol = config_symbol(
+                    &format!("{key}.{package}"),
+                    "variable",
+                    dep_pair,
+                    source,
+                    repo_id,
+                    rel_path,
+                );
+                symbol.attributes.push(attribute("dependencyType", &key));
+                symbol.attributes.push(attribute("package", &package));
+                symbol
+                    .attributes
+                    .push(attribute("version", &string_value(version, source)));
+                symbols.push(symbol);
+            }
+        }
+    }
+
+    symbols
+}
+
+fn extract_tsconfig(
+    object: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Vec<NativeParsedSymbol> {
+    let mut symbols = Vec::new();
+    let Some(compiler_options) = member(object, "compilerOptions", source) else {
+        return symbols;
+    };
+    let base_url = member_string(compiler_options, "baseUrl", source);
+    let Some(paths) = member(compiler_options, "paths", source) else {
+        return symbols;
+    };
+
+    for (alias, targets, pair) in pairs(paths, source) {
+        let mut symbol = config_symbol(&alias, "variable", pair, source, repo_id, rel_path);
+        if let Some(base_url) = &base_url {
+            symbol.attributes.push(attribute("baseUrl", base_url));
+        }
+        let mut cursor = targets.walk();
+        for target in targets.named_children(&mut cursor) {
+            if target.kind() == "string" {
+                symbol
+                    .attributes
+                    .push(attribute("target", &string_value(target, source)));
+            }
+        }
+        symbols.push(symbol);
+    }
+
+    symbols
+}
+
+fn config_symbol(
+    name: &str,
+    kind: &str,
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> NativeParsedSymbol {
+    let mut symbol = make_symbol(
+        name,
+        kind,
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &[],
+        None,
+        &[],
+        "public",
+        &[],
+    );
+    symbol.exported = true;
+    symbol
+}
+
+fn attribute(key: &str, value: &str) -> NativeSymbolAttribute {
+    NativeSymbolAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+fn root_object(root: Node<'_>) -> Option<Node<'_>> {
+    let mut cursor = root.walk();
+    let found = root
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "object");
+    found
+}
+
+/// `(key, value, pair)` for every member of a JSON object.
+fn pairs<'a>(object: Node<'a>, source: &[u8]) -> Vec<(String, Node<'a>, Node<'a>)> {
+    let mut result = Vec::new();
+    let mut cursor = object.walk();
+    for pair in object.named_children(&mut cursor) {
+        if pair.kind() != "pair" {
+            continue;
+        }
+        let (Some(key), Some(value)) = (
+            pair.child_by_field_name("key"),
+            pair.child_by_field_name("value"),
+        ) else {
+            continue;
+        };
+        let key = string_value(key, source);
+        if !key.is_empty() {
+            result.push((key, value, pair));
+        }
+    }
+    result
+}
+
+fn member<'a>(object: Node<'a>, name: &str, source: &[u8]) -> Option<Node<'a>> {
+    pairs(object, source)
+        .into_iter()
+        .find(|(key, value, _)| key == name && value.kind() == "object")
+        .map(|(_, value, _)| value)
+}
+
+fn member_string(object: Node<'_>, name: &str, source: &[u8]) -> Option<String> {
+    pairs(object, source)
+        .into_iter()
+        .find(|(key, value, _)| key == name && value.kind() == "string")
+        .map(|(_, value, _)| string_value(value, source))
+}
+
+fn string_value(node: Node<'_>, source: &[u8]) -> String {
+    let text = node_text(node, source);
+    if node.kind() != "string" {
+        return text.to_string();
+    }
+    let inner = text.strip_prefix('"').unwrap_or(text);
+    unescape(inner.strip_suffix('"').unwrap_or(inner))
+}
+
+/// Decode JSON escape sequences (`\"`, `\n`, `\u00e9`, surrogate pairs).
+/// Malformed escapes are kept as written.
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('b') => out.push('\u{8}'),
+            Some('f') => out.push('\u{c}'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('u') => {
+                let rest = chars.as_str();
+                match decode_unicode_escape(rest) {
+                    Some((decoded, consumed)) => {
+                        out.push(decoded);
+                        chars = rest[consumed..].chars();
+                    }
+                    None => out.push_str("\\u"),
+                }
+            }
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// The character for the hex digits after a `\u` at the start of `rest`,
+/// joining a UTF-16 surrogate pair, and how many bytes of `rest` it used.
+fn decode_unicode_escape(rest: &str) -> Option<(char, usize)> {
+    let unit = |at: usize| {
+        rest.get(at..at + 4)
+            .and_then(|hex| u16::from_str_radix(hex, 16).ok())
+    };
+    let high = unit(0)?;
+    if (0xD800..0xDC00).contains(&high) && rest.get(4..6) == Some("\\u") {
+        if let Some(low) = unit(6).filter(|low| (0xDC00..0xE000).contains(low)) {
+            let code = 0x10000 + ((u32::from(high) - 0xD800) << 10) + (u32::from(low) - 0xDC00);
+            return char::from_u32(code).map(|c| (c, 10));
+        }
+    }
+    char::from_u32(u32::from(high)).map(|c| (c, 4))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn only_known_manifests_are_indexed() {
+        assert!(is_indexed_json_file("package.json"));
+        assert!(is_indexed_json_file("tsconfig.build.json"));
+        assert!(is_indexed_json_file("jsconfig.json"));
+        assert!(!is_indexed_json_file("package-lock.json"));
+        assert!(!is_indexed_json_file("fixture.json"));
+    }
+
+    #[test]
+    fn escapes_are_decoded() {
+        assert_eq!(unescape(r#"echo \"hi\" \\ \n"#), "echo \"hi\" \\ \n");
+        assert_eq!(unescape(r"caf\u00e9 \ud83d\ude00"), "caf\u{e9} \u{1f600}");
+        assert_eq!(unescape(r"bad \uZZ"), r"bad \uZZ");
+    }
+
+    #[test]
+    fn json_parser_indexes_package_scripts_dependencies_and_tsconfig_paths() {
+        let cases = [
+            (
+                "package.json",
+                r#"{
+  "name": "@acme/web",
+  "version": "1.2.0",
+  "scripts": { "build": "tsc -p .", "greet": "echo \"caf\u00e9\"" },
+  "devDependencies": { "typescript": "^5.4.0" }
+}
+"#,
+                vec![
+                    ("@acme/web", "module", vec![("version", "1.2.0")]),
+                    ("scripts.build", "function", vec![("command", "tsc -p .")]),
+                    (
+                        "scripts.greet",
+                        "function",
+                        vec![("command", "echo \"caf\u{e9}\"")],
+                    ),
+                    (
+                        "devDependencies.typescript",
+                        "variable",
+                        vec![
+                            ("dependencyType", "devDependencies"),
+                            ("package", "typescript"),
+                            ("version", "^5.4.0"),
+                        ],
+                    ),
+                ],
+            ),
+            (
+                "tsconfig.json",
+                r#"{
+  "compilerOptions": {
+    "baseUrl": ".",
+    "paths": { "@app/*": ["src/app/*"] }
+  }
+}
+"#,
+                vec![(
+                    "@app/*",
+                    "variable",
+                    vec![("baseUrl", "."), ("target", "src/app/*")],
+                )],
+            ),
+            ("data.json", r#"{ "name": "fixture" }"#, vec![]),
+        ];
+
+        for (file_name, source, expected) in cases {
+            let parsed = parse_source("json", &format!("packages/web/{file_name}"), source);
+
+            let symbols: Vec<_> = parsed
+                .symbols
+                .iter()
+                .map(|symbol| {
+                    (
+                        symbol.name.as_str(),
+                        symbol.kind.as_str(),
+                        symbol
+                            .attributes
+                            .iter()
+                            .map(|attribute| (attribute.key.as_str(), attribute.value.as_str()))
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect();
+            assert_eq!(symbols, expected, "{file_name}");
+            assert!(parsed.symbols.iter().all(|symbol| symbol.exported));
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use tree_sitter::Node;
+
+use crate::types::NativeParsedSymbol;
+
+use super::common::{make_symbol, make_symbol_with_forced_signature, node_text, ParamInfo};
+
+/// Extract Lua symbols: global/local functions, `M.fn` / `M:method` table
+/// functions, top-level variables, and named fields of top-level table
+/// constructors (`local M = { run = function() end }`).
+///
+/// Lua has no export syntax; a module "exports" whatever is reachable from the
+/// table it returns. We approximate that by treating global functions and
+/// fields of the returned module table as exported, and `local` bindings as
+/// private.
+pub fn extract_symbols_lua(
+    root: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Vec<NativeParsedSymbol> {
+    let mut symbols = Vec::new();
+    let module_table = find_returned_table(root, source);
+    let mut stack: Vec<(Node<'_>, usize)> = vec![(root, 0)];
+
+    while let Some((node, depth)) = stack.pop() {
+        let mut child_depth = depth;
+
+        match node.kind() {
+            "function_declaration" | "local_function_declaration" => {
+                if let Some(symbol) = process_function_declaration(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    module_table.as_deref(),
+                ) {
+                    symbols.push(symbol);
+                }
+                child_depth += 1;
+            }
+            "function_definition" => {
+                child_depth += 1;
+            }
+            "variable_declaration" | "assignment_statement" if depth == 0 => {
+                process_assignment(
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    module_table.as_deref(),
+                    &mut symbols,
+                );
+            }
+            _ => {}
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push((child, child_depth));
+            }
+        }
+    }
+
+    symbols
+}
+
+fn process_function_declaration(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    module_table: Option<&str>,
+) -> Option<NativeParsedSymbol> {
+    let name_node = node.child_by_field_name("name")?;
+    let is_local = node.kind() == "local_function_declaration" || has_local_keyword(node);
+
+    let (name, kind, table) = match name_node.kind() {
+        "dot_index_expression" => {
+            let (table, field) = split_index_expression(name_node, source)?;
+            (format!("{table}.{field}"), "function", Some(table))
+        }
+        "method_index_expression" => {
+            let table = name_node
+                .child_by_field_name("table")
+                .map(|n| node_text(n, source).to_string())?;
+            let method = name_node
+                .child_by_field_name("method")
+                .map(|n| node_text(n, source).to_string())?;
+            (format!("{table}.{method}"), "method", Some(table))
+        }
+        _ => (node_text(name_node, source).to_string(), "function", None),
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    let params = extract_parameters(node, source);
+    let mut symbol = make_symbol_with_forced_signature(
+        &name,
+        kind,
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &params,
+        None,
+        &[],
+        if is_local { "private" } else { "public" },
+        &[],
+    );
+    symbol.exported = is_exported(is_local, table.as_deref(), module_table);
+    Some(symbol)
+}
+
+/// Handle `local x = ...`, `x = ...`, and `M.x = ...` at chunk level. Each
+/// target/value pair becomes a variable (or function, when the value is a
+/// `function ... end` literal). Named fields of table constructors assigned
+/// to a simple identifier are emitted as `Table.field` symbols.
+fn process_assignment(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    module_table: Option<&str>,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let is_local = node.kind() == "variable_declaration";
+    let assignment = if is_local {
+        match find_child(node, "assignment_statement") {
+            Some(inner) => inner,
+            None => {
+                // `local a, b` without initialisers.
+                for name_node in named_field_children(node, "name") {
+                    push_local_variable(name_node, node, source, repo_id, rel_path, symbols);
+                }
+                return;
+            }
+        }
+    } else {
+        node
+    };
+
+    let targets = find_child(assignment, "variable_list")
+        .map(|list| named_field_children(list, "name"))
+        .unwrap_or_default();
+    let values = find_child(assignment, "expression_list")
+        .map(|list| named_field_children(list, "value"))
+        .unwrap_or_default();
+
+    for (index, target) in targets.into_iter().enumerate() {
+        let value = values.get(index).copied();
+
+        let (name, table) = match target.kind() {
+            "identifier" => (node_text(target, source).to_string(), None),
+            "dot_index_expression" => match split_index_expression(target, source) {
+                Some((table, field)) => (format!("{table}.{field}"), Some(table)),
+                None => continue,
+            },
+            _ => continue,
+        };
+        if name.is_empty() {
+            continue;
+        }
+
+        let exported = is_exported(is_local, table.as_deref(), module_table);
+
+        match value {
+            Some(value) if value.kind() == "function_definition" => {
+                let params = extract_parameters(value, source);
+                let mut symbol = make_symbol_with_forced_signature(
+                    &name,
+                    "function",
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    &params,
+                    None,
+                    &[],
+                    if is_local { "private" } else { "public" },
+                    &[],
+                );
+                symbol.exported = exported;
+                symbols.push(symbol);
+            }
+            Some(value) if value.kind() == "table_constructor" && table.is_none() => {
+                let mut symbol = make_symbol(
+                    &name,
+                    "variable",
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    &[],
+                    None,
+                    &[],
+                    if is_local { "private" } else { "public" },
+                    &[],
+                );
+                symbol.exported = exported || module_table == Some(name.as_str());
+                symbols.push(symbol);
+
+                let fields_exported = !is_local || module_table == Some(name.as_str());
+                process_table_fields(
+                    &name,
+                    value,
+                    source,
+                    repo_id,
+                    rel_path,
+                    fields_exported,
+                    symbols,
+                );
+            }
+            _ => {
+                let mut symbol = make_symbol(
+                    &name,
+                    "variable",
+                    node,
+                    source,
+                    repo_id,
+                    rel_path,
+                    &[],
+                    None,
+                    &[],
+                    if is_local { "private" } else { "public" },
+                    &[],
+                );
+                symbol.exported = exported;
+                symbols.push(symbol);
+            }
+        }
+    }
+}
+
+fn process_table_fields(
+    table_name: &str,
+    table: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    exported: bool,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let mut cursor = table.walk();
+    for field in table.named_children(&mut cursor) {
+        if field.kind() != "field" {
+            continue;
+        }
+        // Only `key = value` fields carry a usable name; positional entries
+        // and `[expr] = value` keys are skipped.
+        let Some(key) = field.child_by_field_name("name") else {
+            continue;
+        };
+        if key.kind() != "identifier" {
+            continue;
+        }
+        let key_text = node_text(key, source);
+        if key_text.is_empty() {
+            continue;
+        }
+
+        let name = format!("{table_name}.{key_text}");
+        let value = field.child_by_field_name("value");
+        let mut symbol = match value {
+            Some(value) if value.kind() == "function_definition" => {
+                let params = extract_parameters(value, source);
+                make_symbol_with_forced_signature(
+                    &name,
+                    "function",
+                    field,
+                    source,
+                    repo_id,
+                    rel_path,
+                    &params,
+                    None,
+                    &[],
+                    "public",
+                    &[],
+                )
+            }
+            _ => make_symbol(
+                &name,
+                "variable",
+                field,
+                source,
+                repo_id,
+                rel_path,
+                &[],
+                None,
+                &[],
+                "public",
+                &[],
+            ),
+        };
+        symbol.exported = exported;
+        symbols.push(symbol);
+    }
+}
+
+fn push_local_variable(
+    name_node: Node<'_>,
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let name = node_text(name_node, source);
+    if name.is_empty() {
+        return;
+    }
+    let symbol = make_symbol(
+        name,
+        "variable",
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &[],
+        None,
+        &[],
+        "private",
+        &[],
+    );
+    symbols.push(symbol);
+}
+
+fn extract_parameters(node: Node<'_>, source: &[u8]) -> Vec<ParamInfo> {
+    let mut params = Vec::new();
+    let Some(params_node) = node.child_by_field_name("parameters") else {
+        return params;
+    };
+
+    let mut cursor = params_node.walk();
+    for child in params_node.named_children(&mut cursor) {
+        match child.kind() {
+            "identifier" => params.push(ParamInfo {
+                name: node_text(child, source).to_string(),
+                type_annotation: None,
+            }),
+            "vararg_expression" => params.push(ParamInfo {
+                name: "...".to_string(),
+                type_annotation: None,
+            }),
+            _ => {}
+        }
+    }
+
+    params
+}
+
+/// Find the identifier returned at chunk level (`return M`), which is the
+/// conventional module table in Lua libraries and Neovim plugins.
+fn find_returned_table(root: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut cursor = root.walk();
+    let ret = root
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "return_statement")
+        .last()?;
+
+    let mut ret_cursor = ret.walk();
+    let expr_list = ret
+        .named_children(&mut ret_cursor)
+        .find(|child| child.kind() == "expression_list")?;
+    let mut list_cursor = expr_list.walk();
+    let first = expr_list.named_children(&mut list_cursor).next()?;
+    if first.kind() != "identifier" {
+        return None;
+    }
+    Some(node_text(first, source).to_string())
+}
+
+fn is_exported(is_local: bool, table: Option<&str>, module_table: Option<&str>) -> bool {
+    if is_local {
+        return false;
+    }
+    match (table, module_table) {
+        (Some(table), Some(module)) => table == module,
+        // Without a recognisable `return M`, any non-local binding is global
+        // and therefore visible to other chunks.
+        _ => true,
+    }
+}
+
+fn split_index_expression(node: Node<'_>, source: &[u8]) -> Option<(String, String)> {
+    let table = node.child_by_field_name("table")?;
+    let field = node.child_by_field_name("field")?;
+    Some((
+        node_text(table, source).to_string(),
+        node_text(field, source).to_string(),
+    ))
+}
+
+fn has_local_keyword(node: Node<'_>) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .any(|child| child.kind() == "local");
+    found
+}
+
+fn find_child<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    let found = node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == kind);
+    found
+}
+
+fn named_field_children<'a>(node: Node<'a>, field: &str) -> Vec<Node<'a>> {
+    let mut cursor = node.walk();
+    node.children_by_field_name(field, &mut cursor).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn lua_parser_emits_module_functions_locals_and_requires() {
+        let source = r#"local util = require("game.util")
+local json = require "dkjson"
+
+local M = {}
+
+local function clamp(value, min, max)
+  return util.clamp(value, min, max)
+end
+
+function M.update(dt)
+  return clamp(dt, 0, 1)
+end
+
+function M:draw()
+end
+
+return M
+"#;
+
+        let parsed = parse_source("lua", "src/game/player.lua", source);
+
+        assert!(parsed.parse_error.is_none());
+        let symbols: Vec<(&str, &str, bool)> = parsed
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind.as_str(), symbol.exported))
+            .collect();
+        assert!(symbols.contains(&("clamp", "function", false)));
+        assert!(symbols.contains(&("M.update", "function", true)));
+        assert!(symbols.contains(&("M.draw", "method", true)));
+
+        let util = parsed
+            .imports
+            .iter()
+            .find(|import| import.specifier == "game.util")
+            .expect("require(\"game.util\") should be an import");
+        assert!(!util.is_relative);
+        assert_eq!(util.namespace_import.as_deref(), Some("util"));
+        assert!(parsed
+            .imports
+            .iter()
+            .any(|import| import.specifier == "dkjson"));
+    }
+}
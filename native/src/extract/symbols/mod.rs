@@ -1,39 +1,76 @@
-use tree_sitter::Node;
+pub(crate) mod c_lang;
+pub mod common;
+pub(crate) mod cpp;
+pub(crate) mod csharp;
+pub(crate) mod css;
+mod decorators;
+pub(crate) mod go;
+pub(crate) mod java;
+pub(crate) mod json;
+pub(crate) mod lua;
+pub(crate) mod php;
+pub(crate) mod python;
+pub(crate) mod react;
+pub(crate) mod rust_lang;
+pub(crate) mod shell;
+pub(crate) mod typescript;
 
-use crate::types::NativeParsedSymbol;
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
 
-mod c_lang;
-pub mod common;
-mod cpp;
-mod csharp;
-mod go;
-mod java;
-mod php;
-mod python;
-mod rust_lang;
-mod shell;
-mod typescript;
+    #[test]
+    fn namespaced_symbols_carry_qualified_names() {
+        let cases = [
+            (
+                "ts",
+                "src/models.ts",
+                r#"export namespace Billing.Models {
+  export class Invoice {
+    total(): number { return 0; }
+  }
+  export function create() {}
+}
+"#,
+                vec![
+                    ("Billing.Models", None),
+                    ("Invoice", Some("Billing.Models.Invoice")),
+                    ("total", Some("Billing.Models.Invoice.total")),
+                    ("create", Some("Billing.Models.create")),
+                ],
+            ),
+            (
+                "cs",
+                "src/Invoice.cs",
+                r#"namespace Billing.Models {
+    public class Invoice {
+        public decimal Total() { return 0; }
+    }
+}
+"#,
+                vec![
+                    ("Invoice", Some("Billing.Models.Invoice")),
+                    ("Total", Some("Billing.Models.Invoice.Total")),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
 
-pub fn extract_symbols(
-    root: Node<'_>,
-    source: &[u8],
-    repo_id: &str,
-    rel_path: &str,
-    language: &str,
-) -> Vec<NativeParsedSymbol> {
-    match language {
-        "c" => c_lang::extract_symbols_c(root, source, repo_id, rel_path),
-        "cpp" => cpp::extract_symbols_cpp(root, source, repo_id, rel_path),
-        "go" => go::extract_symbols_go(root, source, repo_id, rel_path),
-        "java" => java::extract_symbols_java(root, source, repo_id, rel_path),
-        "php" => php::extract_symbols_php(root, source, repo_id, rel_path),
-        "py" => python::extract_symbols_python(root, source, repo_id, rel_path),
-        "rs" => rust_lang::extract_symbols_rust(root, source, repo_id, rel_path),
-        "sh" => shell::extract_symbols_shell(root, source, repo_id, rel_path),
-        "cs" => csharp::extract_symbols_csharp(root, source, repo_id, rel_path),
-        "ts" | "tsx" | "js" | "jsx" => {
-            typescript::extract_symbols_ts(root, source, repo_id, rel_path)
+            for (name, qualified_name) in expected {
+                let symbol = parsed
+                    .symbols
+                    .iter()
+                    .find(|symbol| symbol.name == name)
+                    .unwrap_or_else(|| panic!("{language}: missing symbol {name}"));
+                let actual = symbol
+                    .attributes
+                    .iter()
+                    .find(|attr| attr.key == "qualifiedName")
+                    .map(|attr| attr.value.as_str());
+                assert_eq!(actual, qualified_name, "{language}: {name}");
+            }
         }
-        _ => vec![],
     }
 }
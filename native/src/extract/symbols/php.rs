@@ -2,7 +2,9 @@ use tree_sitter::Node;
 
 use crate::types::NativeParsedSymbol;
 
-use super::common::{find_child_by_kind, find_child_node, make_symbol, node_text, ParamInfo};
+use super::common::{
+    apply_modifiers, find_child_by_kind, find_child_node, make_symbol, node_text, ParamInfo,
+};
 
 pub fn extract_symbols_php(
     root: Node<'_>,
@@ -165,6 +167,7 @@ fn process_type_like(
         &[],
     );
     symbol.exported = true;
+    apply_modifiers(&mut symbol, &extract_modifiers(node));
 
     if is_trait {
         symbol.role_tags.push("trait".to_string());
@@ -198,6 +201,7 @@ fn process_method_declaration(
         &[],
     );
     symbol.exported = visibility != "private";
+    apply_modifiers(&mut symbol, &extract_modifiers(node));
     Some(symbol)
 }
 
@@ -249,6 +253,7 @@ fn process_property_declaration(
 ) {
     let properties = extract_property_names(node, source);
     let visibility = extract_visibility(node, source).unwrap_or_else(|| "public".to_string());
+    let modifiers = extract_modifiers(node);
 
     for property in properties {
         let normalized: String = property.trim_start_matches('$').to_string();
@@ -273,6 +278,7 @@ fn process_property_declaration(
             &[],
         );
         symbol.exported = property_visibility != "private";
+        apply_modifiers(&mut symbol, &modifiers);
         symbols.push(symbol);
     }
 }
@@ -417,6 +423,20 @@ fn extract_visibility(node: Node<'_>, source: &[u8]) -> Option<String> {
     None
 }
 
+fn extract_modifiers(node: Node<'_>) -> Vec<&'static str> {
+    let mut modifiers = Vec::new();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "abstract_modifier" => modifiers.push("abstract"),
+            "static_modifier" => modifiers.push("static"),
+            "readonly_modifier" => modifiers.push("readonly"),
+            _ => {}
+        }
+    }
+    modifiers
+}
+
 fn extract_property_names(node: Node<'_>, source: &[u8]) -> Vec<String> {
     let mut names = Vec::new();
     let mut cursor = node.walk();
@@ -3,7 +3,8 @@ use tree_sitter::Node;
 use crate::types::NativeParsedSymbol;
 
 use super::common::{
-    find_child_node, make_symbol, make_symbol_with_forced_signature, node_text, ParamInfo,
+    apply_modifiers, find_child_node, make_symbol, make_symbol_with_forced_signature, node_text,
+    ParamInfo,
 };
 
 pub fn extract_symbols_python(
@@ -121,9 +122,68 @@ fn process_function_definition(
         &decorators,
     );
     symbol.exported = !name.starts_with('_');
+    apply_modifiers(&mut symbol, &extract_modifiers(node, source));
     Some(symbol)
 }
 
+/// `async def`, a `yield` in the body, and the `@staticmethod`,
+/// `@abstractmethod`, and `@override` decorators (optionally qualified, e.g.
+/// `@abc.abstractmethod`).
+fn extract_modifiers(node: Node<'_>, source: &[u8]) -> Vec<&'static str> {
+    let mut modifiers = Vec::new();
+
+    if let Some(parent) = node
+        .parent()
+        .filter(|parent| parent.kind() == "decorated_definition")
+    {
+        let mut cursor = parent.walk();
+        for decorator in parent
+            .children(&mut cursor)
+            .filter(|child| child.kind() == "decorator")
+        {
+            let text = node_text(decorator, source).trim_start_matches('@').trim();
+            let name = text.split('(').next().unwrap_or(text);
+            match name.rsplit('.').next().unwrap_or(name) {
+                "staticmethod" => modifiers.push("static"),
+                "abstractmethod" => modifiers.push("abstract"),
+                "override" => modifiers.push("override"),
+                _ => {}
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    if node
+        .children(&mut cursor)
+        .any(|child| child.kind() == "async")
+    {
+        modifiers.push("async");
+    }
+    if node.child_by_field_name("body").is_some_and(contains_yield) {
+        modifiers.push("generator");
+    }
+    modifiers
+}
+
+/// Whether `body` yields, ignoring nested functions, lambdas, and classes.
+fn contains_yield(body: Node<'_>) -> bool {
+    let mut stack = vec![body];
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "yield" => return true,
+            "function_definition" | "lambda" | "class_definition" => continue,
+            _ => {}
+        }
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+    false
+}
+
 fn process_class_definition(
     node: Node<'_>,
     source: &[u8],
@@ -231,14 +291,19 @@ fn extract_parameters(node: Node<'_>, source: &[u8]) -> Vec<ParamInfo> {
     params
 }
 
+/// Decorators are children of the wrapping `decorated_definition`, whose
+/// `definition` field is the function or class itself.
 fn extract_decorators(node: Node<'_>, source: &[u8]) -> Vec<String> {
     let mut decorators = Vec::new();
-    let Some(decorators_node) = node.child_by_field_name("decorators") else {
+    let Some(decorated) = node
+        .parent()
+        .filter(|parent| parent.kind() == "decorated_definition")
+    else {
         return decorators;
     };
 
-    let mut cursor = decorators_node.walk();
-    for child in decorators_node.children(&mut cursor) {
+    let mut cursor = decorated.walk();
+    for child in decorated.children(&mut cursor) {
         if child.kind() == "decorator" {
             decorators.push(node_text(child, source).to_string());
         }
@@ -0,0 +1,323 @@
+use tree_sitter::Node;
+
+use crate::types::{NativeParsedSymbol, NativeSymbolAttribute, NativeSymbolSignature};
+
+use super::common::node_text;
+
+const JSX_KINDS: &[&str] = &["jsx_element", "jsx_self_closing_element", "jsx_fragment"];
+
+/// Nodes that start a new function or class scope; JSX returned from a
+/// nested callback (`items.map(i => <li/>)`) does not make the outer
+/// function a component on its own.
+const NESTED_SCOPE_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "function",
+    "arrow_function",
+    "generator_function",
+    "generator_function_declaration",
+    "method_definition",
+    "class_declaration",
+    "class",
+];
+
+/// Tag a function symbol as a React function component when its name is
+/// PascalCase and its body renders JSX. Records `component=function`, the
+/// props type (from the first parameter's annotation, or `type_hint` taken
+/// from `React.FC<Props>` / `forwardRef<Ref, Props>`; see `record_props`),
+/// and `hooks=true` when the body calls a hook.
+pub(super) fn annotate_function_component(
+    symbol: &mut NativeParsedSymbol,
+    function: Node<'_>,
+    source: &[u8],
+    type_hint: Option<String>,
+) {
+    if !is_component_name(&symbol.name) || !renders_jsx(function) {
+        return;
+    }
+
+    symbol.attributes.push(attribute("component", "function"));
+    if let Some(props) = type_hint.or_else(|| props_type_from_params(function, source)) {
+        record_props(symbol, &props);
+    }
+    if calls_hook(function, source) {
+        symbol.attributes.push(attribute("hooks", "true"));
+    }
+}
+
+/// Tag a class symbol extending `Component` / `PureComponent` (optionally
+/// `React.`-qualified) as `component=class`, with the first type argument
+/// as its props type.
+pub(super) fn annotate_class_component(
+    symbol: &mut NativeParsedSymbol,
+    class: Node<'_>,
+    source: &[u8],
+) {
+    let mut cursor = class.walk();
+    let Some(heritage) = class
+        .children(&mut cursor)
+        .find(|child| child.kind() == "class_heritage")
+    else {
+        return;
+    };
+    let text = node_text(heritage, source);
+    let Some(extends) = text.trim().strip_prefix("extends") else {
+        return;
+    };
+    let extends = extends
+        .split(" implements ")
+        .next()
+        .unwrap_or(extends)
+        .trim();
+    let base = extends.split('<').next().unwrap_or(extends).trim();
+    let base_name = base.rsplit('.').next().unwrap_or(base);
+    if !matches!(base_name, "Component" | "PureComponent") {
+        return;
+    }
+
+    symbol.attributes.push(attribute("component", "class"));
+    if let Some(props) = type_arguments(extends).into_iter().next() {
+        record_props(symbol, &props);
+    }
+}
+
+/// Store the props contract on the signature, whitespace-collapsed. Named
+/// types are also indexed as a `propsType` attribute; inline object types
+/// (`{ label: string }`) have no name worth indexing.
+fn record_props(symbol: &mut NativeParsedSymbol, props: &str) {
+    let props = props.split_whitespace().collect::<Vec<_>>().join(" ");
+    if props.is_empty() {
+        return;
+    }
+    if !props.starts_with('{') {
+        symbol.attributes.push(attribute("propsType", &props));
+    }
+    symbol
+        .signature
+        .get_or_insert_with(NativeSymbolSignature::default)
+        .props_type = Some(props);
+}
+
+/// Props type named by a component's declared type or wrapper call:
+/// `React.FC<Props>` -> `Props`, `forwardRef<HTMLButtonElement, Props>` ->
+/// `Props`, `memo<Props>` -> `Props`.
+pub(super) fn props_type_from_wrapper(type_text: &str, wrapper: Option<&str>) -> Option<String> {
+    let args = type_arguments(type_text);
+    match wrapper {
+        Some("forwardRef") => args.get(1).cloned(),
+        _ => args.into_iter().next(),
+    }
+}
+
+/// `memo(...)` / `React.forwardRef(...)` -> the wrapper's bare name.
+pub(super) fn component_wrapper_name(callee: &str) -> Option<&'static str> {
+    match callee.strip_prefix("React.").unwrap_or(callee) {
+        "memo" => Some("memo"),
+        "forwardRef" => Some("forwardRef"),
+        _ => None,
+    }
+}
+
+fn is_component_name(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn renders_jsx(function: Node<'_>) -> bool {
+    let mut stack = Vec::new();
+    push_children(function, &mut stack);
+
+    while let Some(node) = stack.pop() {
+        if JSX_KINDS.contains(&node.kind()) {
+            return true;
+        }
+        if NESTED_SCOPE_KINDS.contains(&node.kind()) {
+            continue;
+        }
+        push_children(node, &mut stack);
+    }
+    false
+}
+
+fn calls_hook(function: Node<'_>, source: &[u8]) -> bool {
+    let mut stack = Vec::new();
+    push_children(function, &mut stack);
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression" {
+            if let Some(callee) = node.child_by_field_name("function") {
+                if is_hook_name(node_text(callee, source)) {
+                    return true;
+                }
+            }
+        }
+        if NESTED_SCOPE_KINDS.contains(&node.kind()) {
+            continue;
+        }
+        push_children(node, &mut stack);
+    }
+    false
+}
+
+/// `useState`, `useAuth`, `React.useEffect`: `use` followed by an uppercase
+/// letter, per the rules-of-hooks naming convention.
+pub(crate) fn is_hook_name(callee: &str) -> bool {
+    let name = callee.strip_prefix("React.").unwrap_or(callee);
+    name.strip_prefix("use")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn props_type_from_params(function: Node<'_>, source: &[u8]) -> Option<String> {
+    let params = function.child_by_field_name("parameters").or_else(|| {
+        let mut cursor = function.walk();
+        let found = function
+            .children(&mut cursor)
+            .find(|child| child.kind() == "formal_parameters");
+        found
+    })?;
+    let mut cursor = params.walk();
+    let first = params.named_children(&mut cursor).next()?;
+    let annotation = first.child_by_field_name("type").or_else(|| {
+        let mut inner = first.walk();
+        let found = first
+            .children(&mut inner)
+            .find(|child| child.kind() == "type_annotation");
+        found
+    })?;
+
+    let text = node_text(annotation, source);
+    let text = text.strip_prefix(':').unwrap_or(text).trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Top-level type arguments of `Base<A, B<C>>` -> `["A", "B<C>"]`.
+fn type_arguments(text: &str) -> Vec<String> {
+    let Some(open) = text.find('<') else {
+        return Vec::new();
+    };
+    let Some(close) = text.rfind('>') else {
+        return Vec::new();
+    };
+    if close <= open {
+        return Vec::new();
+    }
+
+    let mut args = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in text[open + 1..close].chars() {
+        match c {
+            '<' | '{' | '(' | '[' => depth += 1,
+            '>' | '}' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        args.push(current.trim().to_string());
+    }
+    args
+}
+
+fn push_children<'a>(node: Node<'a>, stack: &mut Vec<Node<'a>>) {
+    let child_count = node.child_count();
+    for i in (0..child_count).rev() {
+        if let Some(child) = node.child(i) {
+            stack.push(child);
+        }
+    }
+}
+
+fn attribute(key: &str, value: &str) -> NativeSymbolAttribute {
+    NativeSymbolAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn tsx_components_are_classified_with_props_and_hooks() {
+        let source = r#"export function UserCard({ user }: UserCardProps) {
+  const [open, setOpen] = useState(false);
+  return <div onClick={() => setOpen(!open)}>{user.name}</div>;
+}
+
+export const Badge: React.FC<BadgeProps> = ({ label }) => <span>{label}</span>;
+
+export const Input = React.forwardRef<HTMLInputElement, InputProps>((props, ref) => (
+  <input ref={ref} {...props} />
+));
+
+export class Legacy extends React.Component<LegacyProps, LegacyState> {
+  render() {
+    return <p />;
+  }
+}
+
+export function Chip({ text }: { text: string;
+  tone?: Tone }) {
+  return <b>{text}</b>;
+}
+
+export function formatName(user: User) {
+  return user.name;
+}
+"#;
+
+        let parsed = parse_source("tsx", "src/components.tsx", source);
+
+        let attr = |name: &str, key: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.attributes.iter().find(|attr| attr.key == key))
+                .map(|attr| attr.value.clone())
+        };
+
+        assert_eq!(attr("UserCard", "component").as_deref(), Some("function"));
+        assert_eq!(
+            attr("UserCard", "propsType").as_deref(),
+            Some("UserCardProps")
+        );
+        assert_eq!(attr("UserCard", "hooks").as_deref(), Some("true"));
+
+        assert_eq!(attr("Badge", "component").as_deref(), Some("function"));
+        assert_eq!(attr("Badge", "propsType").as_deref(), Some("BadgeProps"));
+        assert_eq!(attr("Badge", "hooks"), None);
+
+        assert_eq!(attr("Input", "component").as_deref(), Some("function"));
+        assert_eq!(attr("Input", "wrapper").as_deref(), Some("forwardRef"));
+        assert_eq!(attr("Input", "propsType").as_deref(), Some("InputProps"));
+
+        assert_eq!(attr("Legacy", "component").as_deref(), Some("class"));
+        assert_eq!(attr("Legacy", "propsType").as_deref(), Some("LegacyProps"));
+
+        let props_type = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.signature.as_ref())
+                .and_then(|signature| signature.props_type.clone())
+        };
+        assert_eq!(props_type("UserCard").as_deref(), Some("UserCardProps"));
+        assert_eq!(
+            props_type("Chip").as_deref(),
+            Some("{ text: string; tone?: Tone }")
+        );
+        assert_eq!(attr("Chip", "propsType"), None);
+
+        assert_eq!(attr("formatName", "component"), None);
+        assert_eq!(props_type("formatName"), None);
+    }
+}
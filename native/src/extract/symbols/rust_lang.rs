@@ -1,8 +1,11 @@
 use tree_sitter::Node;
 
-use crate::types::NativeParsedSymbol;
+use crate::types::{NativeParsedSymbol, NativeSymbolAttribute, NativeTypeParameter};
 
-use super::common::{find_child_node, make_symbol, node_text, ParamInfo};
+use super::common::{
+    apply_modifiers, apply_type_parameters, find_child_node, make_symbol, node_text,
+    type_parameter, ParamInfo,
+};
 
 pub fn extract_symbols_rust(
     root: Node<'_>,
@@ -17,7 +20,8 @@ pub fn extract_symbols_rust(
         let mut skip_children = false;
 
         match node.kind() {
-            "function_item" => {
+            // Trait methods are emitted by `process_trait_methods`.
+            "function_item" if !is_trait_member(node) => {
                 if let Some(symbol) = process_function_item(node, source, repo_id, rel_path) {
                     symbols.push(symbol);
                 }
@@ -29,11 +33,33 @@ pub fn extract_symbols_rust(
             }
             "enum_item" => {
                 if let Some(symbol) = process_enum_item(node, source, repo_id, rel_path) {
+                    let (name, visibility, exported) = (
+                        symbol.name.clone(),
+                        symbol.visibility.clone(),
+                        symbol.exported,
+                    );
                     symbols.push(symbol);
+                    process_enum_variants(
+                        node,
+                        &name,
+                        &visibility,
+                        exported,
+                        source,
+                        repo_id,
+                        rel_path,
+                        &mut symbols,
+                    );
                 }
             }
             "trait_item" => {
                 if let Some(symbol) = process_trait_item(node, source, repo_id, rel_path) {
+                    let name = symbol.name.clone();
+                    symbols.push(symbol);
+                    process_trait_methods(node, &name, source, repo_id, rel_path, &mut symbols);
+                }
+            }
+            "macro_definition" => {
+                if let Some(symbol) = process_macro_definition(node, source, repo_id, rel_path) {
                     symbols.push(symbol);
                 }
             }
@@ -107,12 +133,31 @@ fn process_function_item(
         returns.as_deref(),
         &generics,
         &visibility,
-        &[],
+        &extract_attributes(node, source),
     );
     symbol.exported = visibility == "public";
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
+    apply_modifiers(&mut symbol, &extract_modifiers(node, source));
     Some(symbol)
 }
 
+/// `async fn`, and `abstract` for a trait method without a default body.
+fn extract_modifiers(node: Node<'_>, source: &[u8]) -> Vec<&'static str> {
+    let mut modifiers = Vec::new();
+    if node.kind() == "function_signature_item" {
+        modifiers.push("abstract");
+    }
+    if let Some(function_modifiers) = find_child_node(node, "function_modifiers") {
+        let is_async = node_text(function_modifiers, source)
+            .split_whitespace()
+            .any(|word| word == "async");
+        if is_async {
+            modifiers.push("async");
+        }
+    }
+    modifiers
+}
+
 fn process_struct_item(
     node: Node<'_>,
     source: &[u8],
@@ -140,9 +185,10 @@ fn process_struct_item(
         None,
         &generics,
         &visibility,
-        &[],
+        &extract_attributes(node, source),
     );
     symbol.exported = visibility == "public";
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
     Some(symbol)
 }
 
@@ -173,9 +219,10 @@ fn process_enum_item(
         None,
         &generics,
         &visibility,
-        &[],
+        &extract_attributes(node, source),
     );
     symbol.exported = visibility == "public";
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
     Some(symbol)
 }
 
@@ -205,9 +252,10 @@ fn process_trait_item(
         None,
         &generics,
         &visibility,
-        &[],
+        &extract_attributes(node, source),
     );
     symbol.exported = visibility == "public";
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
     Some(symbol)
 }
 
@@ -248,9 +296,10 @@ fn process_type_item(
         None,
         &generics,
         &visibility,
-        &[],
+        &extract_attributes(node, source),
     );
     symbol.exported = visibility == "public";
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
     Some(symbol)
 }
 
@@ -279,7 +328,7 @@ fn process_variable_item(
         None,
         &[],
         &visibility,
-        &[],
+        &extract_attributes(node, source),
     );
     symbol.exported = visibility == "public";
     Some(symbol)
@@ -310,7 +359,7 @@ fn process_module_item(
         None,
         &[],
         &visibility,
-        &[],
+        &extract_attributes(node, source),
     );
     symbol.exported = visibility == "public";
     Some(symbol)
@@ -332,6 +381,11 @@ fn process_impl_item(
         return;
     }
 
+    let trait_name = node
+        .child_by_field_name("trait")
+        .map(|trait_node| extract_impl_type_name(trait_node, source))
+        .filter(|name| !name.is_empty());
+
     let Some(body_node) = node.child_by_field_name("body") else {
         return;
     };
@@ -350,8 +404,8 @@ fn process_impl_item(
             continue;
         }
 
-        // TS rust.ts uses the bare method name (not Type::method_name).
-        let _ = &type_name;
+        // TS rust.ts uses the bare method name (not Type::method_name); the
+        // owning type (and implemented trait) are recorded as attributes.
         let symbol_name = method_name.clone();
         let params = extract_function_parameters(child, source);
         let returns = extract_function_return_type(child, source);
@@ -369,13 +423,195 @@ fn process_impl_item(
             returns.as_deref(),
             &generics,
             &visibility,
-            &[],
+            &extract_attributes(child, source),
         );
         symbol.exported = visibility == "public";
+        apply_type_parameters(&mut symbol, extract_type_parameters(child, source));
+        apply_modifiers(&mut symbol, &extract_modifiers(child, source));
+        symbol.attributes.push(attribute("owner", &type_name));
+        if let Some(trait_name) = &trait_name {
+            symbol.attributes.push(attribute("trait", trait_name));
+        }
+        symbols.push(symbol);
+    }
+}
+
+/// Emit the methods declared in a trait body, both required
+/// (`fn area(&self) -> f64;`) and provided (default bodies), owned by the
+/// trait. Trait items share the trait's visibility.
+fn process_trait_methods(
+    node: Node<'_>,
+    trait_name: &str,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let Some(body_node) = node.child_by_field_name("body") else {
+        return;
+    };
+    let visibility = extract_visibility(node, source);
+
+    let mut cursor = body_node.walk();
+    for child in body_node.children(&mut cursor) {
+        if !matches!(child.kind(), "function_item" | "function_signature_item") {
+            continue;
+        }
+
+        let Some(name_node) = child.child_by_field_name("name") else {
+            continue;
+        };
+        let method_name = node_text(name_node, source).to_string();
+        if method_name.is_empty() {
+            continue;
+        }
+
+        let params = extract_function_parameters(child, source);
+        let returns = extract_function_return_type(child, source);
+        let generics = extract_generics(child, source);
+
+        let mut symbol = super::common::make_symbol_with_forced_signature(
+            &method_name,
+            "method",
+            child,
+            source,
+            repo_id,
+            rel_path,
+            &params,
+            returns.as_deref(),
+            &generics,
+            &visibility,
+            &extract_attributes(child, source),
+        );
+        symbol.exported = visibility == "public";
+        apply_type_parameters(&mut symbol, extract_type_parameters(child, source));
+        apply_modifiers(&mut symbol, &extract_modifiers(child, source));
+        symbol.attributes.push(attribute("owner", trait_name));
+        symbols.push(symbol);
+    }
+}
+
+fn is_trait_member(node: Node<'_>) -> bool {
+    node.parent()
+        .filter(|parent| parent.kind() == "declaration_list")
+        .and_then(|parent| parent.parent())
+        .is_some_and(|owner| owner.kind() == "trait_item")
+}
+
+/// Emit one `Enum::Variant` symbol per variant, sharing the enum's
+/// visibility. The variants are also kept as params on the enum symbol.
+#[allow(clippy::too_many_arguments)]
+fn process_enum_variants(
+    node: Node<'_>,
+    enum_name: &str,
+    visibility: &str,
+    exported: bool,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let Some(body_node) = node.child_by_field_name("body") else {
+        return;
+    };
+
+    let mut cursor = body_node.walk();
+    for child in body_node.children(&mut cursor) {
+        if child.kind() != "enum_variant" {
+            continue;
+        }
+        let Some(name_node) = child.child_by_field_name("name") else {
+            continue;
+        };
+        let variant_name = node_text(name_node, source);
+        if variant_name.is_empty() {
+            continue;
+        }
+
+        let mut symbol = make_symbol(
+            &format!("{enum_name}::{variant_name}"),
+            "variable",
+            child,
+            source,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+            &[],
+            visibility,
+            &extract_attributes(child, source),
+        );
+        symbol.exported = exported;
+        symbol.attributes.push(attribute("owner", enum_name));
         symbols.push(symbol);
     }
 }
 
+/// `macro_rules! name { ... }`. Macros are textually scoped unless marked
+/// `#[macro_export]`, which makes them public at the crate root.
+fn process_macro_definition(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Option<NativeParsedSymbol> {
+    let name_node = node.child_by_field_name("name")?;
+    let name = node_text(name_node, source).to_string();
+    if name.is_empty() {
+        return None;
+    }
+
+    let exported = has_macro_export_attribute(node, source);
+    let visibility = if exported { "public" } else { "private" };
+
+    let mut symbol = make_symbol(
+        &name,
+        "function",
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &[],
+        None,
+        &[],
+        visibility,
+        &extract_attributes(node, source),
+    );
+    symbol.exported = exported;
+    symbol.attributes.push(attribute("macro", "macro_rules"));
+    Some(symbol)
+}
+
+fn has_macro_export_attribute(node: Node<'_>, source: &[u8]) -> bool {
+    extract_attributes(node, source)
+        .iter()
+        .any(|attribute| attribute.contains("macro_export"))
+}
+
+/// Outer attributes (`#[deprecated(note = "...")]`) are `attribute_item`
+/// siblings preceding the item, possibly interleaved with doc comments.
+fn extract_attributes(node: Node<'_>, source: &[u8]) -> Vec<String> {
+    let mut attributes = Vec::new();
+    let mut sibling = node.prev_named_sibling();
+    while let Some(prev) = sibling {
+        match prev.kind() {
+            "attribute_item" => attributes.push(node_text(prev, source).to_string()),
+            "line_comment" | "block_comment" => {}
+            _ => break,
+        }
+        sibling = prev.prev_named_sibling();
+    }
+    attributes.reverse();
+    attributes
+}
+
+fn attribute(key: &str, value: &str) -> NativeSymbolAttribute {
+    NativeSymbolAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
 fn extract_impl_type_name(type_node: Node<'_>, source: &[u8]) -> String {
     if type_node.kind() == "generic_type" {
         if let Some(inner_type) = type_node.child_by_field_name("type") {
@@ -520,6 +756,96 @@ fn extract_generics(node: Node<'_>, source: &[u8]) -> Vec<String> {
     generics
 }
 
+/// Type and lifetime parameters with their bounds and defaults
+/// (`<'a, T: Clone + 'a = Self>`), plus any bounds from a `where` clause
+/// (`where T: Send`), joined to the inline ones with `+`.
+fn extract_type_parameters(node: Node<'_>, source: &[u8]) -> Vec<NativeTypeParameter> {
+    let Some(type_parameters) = node.child_by_field_name("type_parameters") else {
+        return Vec::new();
+    };
+
+    let mut cursor = type_parameters.walk();
+    let mut parameters: Vec<NativeTypeParameter> = type_parameters
+        .named_children(&mut cursor)
+        .filter_map(|child| rust_type_parameter(child, source))
+        .collect();
+
+    if let Some(where_clause) = find_child_node(node, "where_clause") {
+        let mut where_cursor = where_clause.walk();
+        for predicate in where_clause
+            .named_children(&mut where_cursor)
+            .filter(|child| child.kind() == "where_predicate")
+        {
+            let (Some(left), Some(bounds)) = (
+                predicate.child_by_field_name("left"),
+                predicate.child_by_field_name("bounds"),
+            ) else {
+                continue;
+            };
+            let name = node_text(left, source);
+            let bounds = strip_bounds_colon(node_text(bounds, source));
+            if let Some(parameter) = parameters.iter_mut().find(|p| p.name == name) {
+                parameter.constraint = Some(match parameter.constraint.take() {
+                    Some(existing) => format!("{existing} + {bounds}"),
+                    None => bounds.to_string(),
+                });
+            }
+        }
+    }
+
+    parameters
+}
+
+fn rust_type_parameter(node: Node<'_>, source: &[u8]) -> Option<NativeTypeParameter> {
+    match node.kind() {
+        "type_identifier" | "lifetime" => Some(type_parameter(node_text(node, source), None, None)),
+        "constrained_type_parameter" => {
+            let name = node.child_by_field_name("left")?;
+            let bounds = node
+                .child_by_field_name("bounds")
+                .map(|bounds| strip_bounds_colon(node_text(bounds, source)));
+            Some(type_parameter(node_text(name, source), bounds, None))
+        }
+        // tree-sitter-rust 0.23 wraps every parameter: `'a: 'b` is a
+        // `lifetime_parameter`, `T: Clone = u8` a `type_parameter` carrying
+        // its own `bounds` and `default_type`.
+        "lifetime_parameter" | "type_parameter" => {
+            let name = node.child_by_field_name("name")?;
+            let bounds = node
+                .child_by_field_name("bounds")
+                .map(|bounds| strip_bounds_colon(node_text(bounds, source)));
+            let default = node
+                .child_by_field_name("default_type")
+                .map(|default| node_text(default, source));
+            Some(type_parameter(node_text(name, source), bounds, default))
+        }
+        "optional_type_parameter" => {
+            let mut parameter = rust_type_parameter(node.child_by_field_name("name")?, source)?;
+            parameter.default = node
+                .child_by_field_name("default_type")
+                .map(|default| node_text(default, source).to_string());
+            Some(parameter)
+        }
+        // `const N: usize = 4` -> name `N`, constraint `usize`, default `4`.
+        "const_parameter" => {
+            let name = node.child_by_field_name("name")?;
+            let value_type = node
+                .child_by_field_name("type")
+                .map(|value_type| node_text(value_type, source));
+            let default = node
+                .child_by_field_name("value")
+                .map(|value| node_text(value, source));
+            Some(type_parameter(node_text(name, source), value_type, default))
+        }
+        _ => None,
+    }
+}
+
+fn strip_bounds_colon(text: &str) -> &str {
+    let text = text.trim();
+    text.strip_prefix(':').unwrap_or(text).trim()
+}
+
 fn extract_visibility(node: Node<'_>, source: &[u8]) -> String {
     let visibility_node = find_child_node(node, "visibility_modifier");
     let Some(visibility_node) = visibility_node else {
@@ -530,7 +856,7 @@ fn extract_visibility(node: Node<'_>, source: &[u8]) -> String {
     if text == "pub" {
         return "public".to_string();
     }
-    if text.contains("pub(crate)") {
+    if text.contains("pub(crate)") || text.contains("pub(in") {
         return "internal".to_string();
     }
     if text.contains("pub(super)") {
@@ -539,3 +865,77 @@ fn extract_visibility(node: Node<'_>, source: &[u8]) -> String {
 
     "private".to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+    use crate::types::NativeParsedSymbol;
+
+    #[test]
+    fn rust_parser_records_owners_variants_and_macros() {
+        let source = r#"pub enum Shape { Circle(f64), Square }
+
+pub trait Area {
+    fn area(&self) -> f64;
+}
+
+impl Area for Shape {
+    fn area(&self) -> f64 { 0.0 }
+}
+
+#[macro_export]
+macro_rules! square {
+    ($x:expr) => { $x * $x };
+}
+
+pub(crate) fn helper() {}
+"#;
+
+        let parsed = parse_source("rs", "src/shape.rs", source);
+
+        let attr = |symbol: &NativeParsedSymbol, key: &str| {
+            symbol
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+
+        let circle = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "Shape::Circle")
+            .expect("expected enum variant symbol");
+        assert!(circle.exported);
+
+        let methods: Vec<_> = parsed
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.name == "area")
+            .collect();
+        assert_eq!(methods.len(), 2);
+        assert!(methods.iter().all(|symbol| symbol.kind == "method"));
+        assert!(methods
+            .iter()
+            .any(|symbol| attr(symbol, "owner").as_deref() == Some("Area")));
+        assert!(methods.iter().any(|symbol| {
+            attr(symbol, "owner").as_deref() == Some("Shape")
+                && attr(symbol, "trait").as_deref() == Some("Area")
+        }));
+
+        let square = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "square")
+            .expect("expected macro_rules symbol");
+        assert!(square.exported);
+
+        let helper = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "helper")
+            .expect("expected helper symbol");
+        assert_eq!(helper.visibility, "internal");
+        assert!(!helper.exported);
+    }
+}
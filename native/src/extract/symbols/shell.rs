@@ -213,3 +213,46 @@ fn has_export_keyword(node: Node<'_>, source: &[u8]) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn shell_parser_emits_functions_sources_and_external_commands() {
+        let source = r#"#!/usr/bin/env bash
+source "$(dirname "$0")/lib/common.sh"
+
+deploy() {
+  echo "deploying"
+  build_assets
+  exec kubectl apply -f k8s/
+}
+
+build_assets() {
+  npm run build
+}
+"#;
+
+        let parsed = parse_source("sh", "scripts/deploy.sh", source);
+
+        assert!(parsed
+            .symbols
+            .iter()
+            .any(|symbol| symbol.name == "deploy" && symbol.kind == "function"));
+        assert!(parsed
+            .imports
+            .iter()
+            .any(|import| import.specifier == "./lib/common.sh" && import.is_relative));
+
+        let callees: Vec<(&str, &str)> = parsed
+            .calls
+            .iter()
+            .map(|call| (call.callee_identifier.as_str(), call.call_type.as_str()))
+            .collect();
+        assert!(callees.contains(&("build_assets", "function")));
+        assert!(callees.contains(&("kubectl", "dynamic")));
+        assert!(callees.contains(&("npm", "dynamic")));
+        assert!(!callees.iter().any(|(callee, _)| *callee == "echo"));
+    }
+}
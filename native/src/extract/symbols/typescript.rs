@@ -1,11 +1,14 @@
 use tree_sitter::Node;
 
-use crate::types::NativeParsedSymbol;
+use crate::types::{
+    NativeParsedSymbol, NativeSymbolAttribute, NativeSymbolSignature, NativeTypeParameter,
+};
 
 use super::common::{
-    extract_range, find_child_by_kind, make_symbol, make_symbol_with_forced_signature, node_text,
-    ParamInfo,
+    apply_modifiers, apply_type_parameters, extract_range, find_child_by_kind, find_child_node,
+    make_symbol, make_symbol_with_forced_signature, node_text, type_parameter, ParamInfo,
 };
+use super::react;
 
 pub fn extract_symbols_ts(
     root: Node<'_>,
@@ -15,6 +18,7 @@ pub fn extract_symbols_ts(
 ) -> Vec<NativeParsedSymbol> {
     let mut symbols = Vec::new();
     traverse_ast(root, source, repo_id, rel_path, &mut symbols);
+    mark_commonjs_exports(root, source, &mut symbols);
     symbols
 }
 
@@ -28,18 +32,37 @@ fn traverse_ast(
     let mut stack: Vec<(Node, u32)> = vec![(root, 0)];
 
     while let Some((node, scope_depth)) = stack.pop() {
+        let declared_from = symbols.len();
         match node.kind() {
             "function_declaration" | "generator_function_declaration" => {
                 if let Some(sym) = process_function_declaration(node, source, repo_id, rel_path) {
                     symbols.push(sym);
                 }
             }
-            "method_definition" => {
+            "method_definition" | "abstract_method_signature" => {
+                if let Some(sym) = process_method_definition(node, source, repo_id, rel_path) {
+                    symbols.push(sym);
+                }
+            }
+            // Bodiless declarations only stand for a symbol in ambient
+            // contexts; elsewhere they are overload signatures of a
+            // declaration that is extracted on its own.
+            "function_signature" if is_ambient(node, rel_path) => {
+                if let Some(sym) = process_function_declaration(node, source, repo_id, rel_path) {
+                    symbols.push(sym);
+                }
+            }
+            "method_signature"
+                if node
+                    .parent()
+                    .is_some_and(|parent| parent.kind() == "class_body")
+                    && is_ambient(node, rel_path) =>
+            {
                 if let Some(sym) = process_method_definition(node, source, repo_id, rel_path) {
                     symbols.push(sym);
                 }
             }
-            "class_declaration" => {
+            "class_declaration" | "abstract_class_declaration" => {
                 if let Some(sym) = process_class_declaration(node, source, repo_id, rel_path) {
                     symbols.push(sym);
                 }
@@ -54,6 +77,22 @@ fn traverse_ast(
                     symbols.push(sym);
                 }
             }
+            "pair" if scope_depth == 0 => {
+                if let Some(sym) = process_object_pair_function(node, source, repo_id, rel_path) {
+                    symbols.push(sym);
+                }
+            }
+            "public_field_definition" | "field_definition" => {
+                if let Some(sym) = process_field_definition(node, source, repo_id, rel_path) {
+                    symbols.push(sym);
+                }
+            }
+            "export_statement" => {
+                process_default_export(node, source, repo_id, rel_path, symbols);
+            }
+            "enum_declaration" => {
+                process_enum_declaration(node, source, repo_id, rel_path, symbols);
+            }
             "lexical_declaration" | "variable_declaration" => {
                 if scope_depth == 0 {
                     let mut cursor = node.walk();
@@ -67,7 +106,7 @@ fn traverse_ast(
                     }
                 }
             }
-            "module" => {
+            "module" | "internal_module" => {
                 if let Some(sym) = process_module(node, source, repo_id, rel_path) {
                     symbols.push(sym);
                 }
@@ -78,6 +117,13 @@ fn traverse_ast(
             _ => {}
         }
 
+        if symbols.len() > declared_from {
+            push_namespace_attributes(node, source, &mut symbols[declared_from..]);
+            if is_ambient(node, rel_path) {
+                mark_ambient(&mut symbols[declared_from..]);
+            }
+        }
+
         // Increment scope depth when entering function/method/arrow bodies
         let enters_function_scope = matches!(
             node.kind(),
@@ -144,6 +190,32 @@ fn extract_generics(node: Node<'_>, source: &[u8]) -> Vec<String> {
     generics
 }
 
+/// `<T extends Foo = Bar>` -> name `T`, constraint `Foo`, default `Bar`.
+fn extract_type_parameters(node: Node<'_>, source: &[u8]) -> Vec<NativeTypeParameter> {
+    let Some(type_params) = find_child_node(node, "type_parameters") else {
+        return Vec::new();
+    };
+
+    let mut cursor = type_params.walk();
+    let parameters = type_params
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "type_parameter")
+        .filter_map(|child| {
+            let name = child.child_by_field_name("name")?;
+            let constraint = child.child_by_field_name("constraint").map(|constraint| {
+                let text = node_text(constraint, source).trim();
+                text.strip_prefix("extends").unwrap_or(text)
+            });
+            let default = child.child_by_field_name("value").map(|value| {
+                let text = node_text(value, source).trim();
+                text.strip_prefix('=').unwrap_or(text)
+            });
+            Some(type_parameter(node_text(name, source), constraint, default))
+        })
+        .collect();
+    parameters
+}
+
 fn extract_parameters(node: Node<'_>, source: &[u8]) -> Vec<ParamInfo> {
     let mut params = Vec::new();
 
@@ -222,12 +294,30 @@ fn extract_return_type(node: Node<'_>, source: &[u8]) -> Option<String> {
     None
 }
 
+/// Decorators on the declaration itself, plus those written before `export`
+/// (`@Injectable() export class ...`), which the grammar attaches to the
+/// enclosing `export_statement`.
 fn extract_decorators(node: Node<'_>, source: &[u8]) -> Vec<String> {
     let mut decorators = Vec::new();
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "decorator" {
-            decorators.push(node_text(child, source).to_string());
+    // Member decorators are preceding siblings inside `class_body`.
+    let mut sibling = node
+        .parent()
+        .filter(|parent| parent.kind() == "class_body")
+        .and_then(|_| node.prev_named_sibling());
+    while let Some(decorator) = sibling.filter(|sibling| sibling.kind() == "decorator") {
+        decorators.push(node_text(decorator, source).to_string());
+        sibling = decorator.prev_named_sibling();
+    }
+    decorators.reverse();
+    let export = node
+        .parent()
+        .filter(|parent| parent.kind() == "export_statement");
+    for owner in export.into_iter().chain(std::iter::once(node)) {
+        let mut cursor = owner.walk();
+        for child in owner.children(&mut cursor) {
+            if child.kind() == "decorator" {
+                decorators.push(node_text(child, source).to_string());
+            }
         }
     }
     decorators
@@ -310,6 +400,15 @@ fn process_function_declaration(
         &decorators,
     );
     symbol.exported = is_exported(node);
+    apply_modifiers(&mut symbol, &extract_modifiers(node));
+    apply_type_parameters(
+        &mut symbol,
+        extract_type_parameters(sig_node.unwrap_or(node), source),
+    );
+    if is_default_export(node) {
+        symbol.attributes.push(attribute("export", "default"));
+    }
+    react::annotate_function_component(&mut symbol, node, source, None);
     Some(symbol)
 }
 
@@ -319,7 +418,16 @@ fn process_method_definition(
     repo_id: &str,
     rel_path: &str,
 ) -> Option<NativeParsedSymbol> {
-    let name = extract_identifier(node, source)?;
+    let mut name = extract_identifier(node, source)?;
+    // Object-literal methods (`export default { async handler() {} }`) are
+    // qualified by the object they belong to.
+    let object_owner = node
+        .parent()
+        .filter(|parent| parent.kind() == "object")
+        .and_then(|object| object_qualifier(object, source));
+    if let Some(owner) = &object_owner {
+        name = format!("{owner}.{name}");
+    }
 
     let params = extract_parameters(node, source);
     let returns = extract_return_type(node, source);
@@ -362,9 +470,89 @@ fn process_method_definition(
         &decorators,
     );
     symbol.exported = is_exported(node);
+    apply_modifiers(&mut symbol, &extract_modifiers(node));
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
+    if let Some(owner) = object_owner {
+        symbol.attributes.push(attribute("owner", &owner));
+    }
+    Some(symbol)
+}
+
+/// `save: async (id) => {...}` / `save: function () {...}` inside a
+/// module-level object literal, emitted like an object-literal method.
+fn process_object_pair_function(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Option<NativeParsedSymbol> {
+    let object = node.parent().filter(|parent| parent.kind() == "object")?;
+    let value = node
+        .child_by_field_name("value")
+        .filter(|value| is_function_expression(*value))?;
+    let key = node.child_by_field_name("key")?;
+    if key.kind() == "computed_property_name" {
+        return None;
+    }
+    let key_text = node_text(key, source).trim_matches(|c| c == '"' || c == '\'');
+    if key_text.is_empty() {
+        return None;
+    }
+
+    let owner = object_qualifier(object, source)?;
+    let name = format!("{owner}.{key_text}");
+    let mut symbol = process_function_initializer(&name, node, value, source, repo_id, rel_path);
+    symbol.kind = "method".to_string();
+    symbol.exported = is_exported(node);
+    symbol.attributes.push(attribute("owner", &owner));
     Some(symbol)
 }
 
+/// Name an object literal by where it is bound: `const api = {...}` ->
+/// `api`, `export default {...}` -> `default`, `module.exports = {...}` ->
+/// `module.exports`, and nested `methods: {...}` -> `<outer>.methods`.
+/// Wrapping calls such as `defineComponent({...})` or `new Vuex.Store({...})`
+/// take the name of the call's own binding.
+fn object_qualifier(object: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut current = object;
+    loop {
+        let parent = current.parent()?;
+        match parent.kind() {
+            "variable_declarator" => {
+                let name = parent.child_by_field_name("name")?;
+                if name.kind() != "identifier" {
+                    return None;
+                }
+                return Some(node_text(name, source).to_string());
+            }
+            "export_statement" => return Some("default".to_string()),
+            "assignment_expression" => {
+                let left = parent.child_by_field_name("left")?;
+                return Some(node_text(left, source).to_string());
+            }
+            "pair" => {
+                let key = parent.child_by_field_name("key")?;
+                let key_text = node_text(key, source).trim_matches(|c| c == '"' || c == '\'');
+                let outer = parent.parent().filter(|p| p.kind() == "object")?;
+                let outer_name = object_qualifier(outer, source)?;
+                return Some(format!("{outer_name}.{key_text}"));
+            }
+            "arguments" => {
+                // `defineComponent({...})`: name it after the call's binding.
+                let call = parent.parent()?;
+                if !matches!(call.kind(), "call_expression" | "new_expression") {
+                    return None;
+                }
+                current = call;
+            }
+            "parenthesized_expression" | "as_expression" | "satisfies_expression" => {
+                current = parent;
+            }
+            _ => return None,
+        }
+    }
+}
+
 fn process_class_declaration(
     node: Node<'_>,
     source: &[u8],
@@ -389,6 +577,12 @@ fn process_class_declaration(
         &decorators,
     );
     symbol.exported = is_exported(node);
+    apply_modifiers(&mut symbol, &extract_modifiers(node));
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
+    if is_default_export(node) {
+        symbol.attributes.push(attribute("export", "default"));
+    }
+    react::annotate_class_component(&mut symbol, node, source);
     Some(symbol)
 }
 
@@ -415,6 +609,7 @@ fn process_interface_declaration(
         &extract_decorators(node, source),
     );
     symbol.exported = is_exported(node);
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
     Some(symbol)
 }
 
@@ -441,9 +636,212 @@ fn process_type_alias_declaration(
         &extract_decorators(node, source),
     );
     symbol.exported = is_exported(node);
+    apply_type_parameters(&mut symbol, extract_type_parameters(node, source));
+    Some(symbol)
+}
+
+/// Emit a class field (`apiKey: string = ""`, `#cache = new Map()`) as a
+/// `variable` tagged `member=property`, carrying its owning class, declared
+/// type, and the kind of its initializer.
+fn process_field_definition(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> Option<NativeParsedSymbol> {
+    // TS grammar: `name`; JS grammar: `property`.
+    let name_node = node
+        .child_by_field_name("name")
+        .or_else(|| node.child_by_field_name("property"))?;
+    let name = node_text(name_node, source)
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string();
+    if name.is_empty() {
+        return None;
+    }
+
+    let visibility = if name_node.kind() == "private_property_identifier" {
+        "private".to_string()
+    } else {
+        extract_visibility(node, source)
+    };
+
+    let mut symbol = make_symbol(
+        &name,
+        "variable",
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &[],
+        None,
+        &[],
+        &visibility,
+        &extract_decorators(node, source),
+    );
+    symbol.exported = is_exported(node);
+    apply_modifiers(&mut symbol, &extract_modifiers(node));
+    symbol.attributes.push(attribute("member", "property"));
+    if let Some(owner) = enclosing_class_name(node, source) {
+        symbol.attributes.push(attribute("owner", &owner));
+    }
+    if let Some(type_node) = node.child_by_field_name("type") {
+        let type_text = normalize_type_annotation(node_text(type_node, source).to_string());
+        symbol.attributes.push(attribute("type", &type_text));
+    }
+    if let Some(value) = node.child_by_field_name("value") {
+        symbol
+            .attributes
+            .push(attribute("initializer", initializer_kind(value)));
+    }
     Some(symbol)
 }
 
+/// Coarse classification of an initializer expression.
+fn initializer_kind(value: Node<'_>) -> &'static str {
+    match value.kind() {
+        "arrow_function" | "function_expression" | "function" => "function",
+        "object" => "object",
+        "array" => "array",
+        "string" | "template_string" => "string",
+        "number" => "number",
+        "true" | "false" => "boolean",
+        "null" | "undefined" => "null",
+        "new_expression" => "new",
+        "call_expression" | "await_expression" => "call",
+        "class" => "class",
+        _ => "expression",
+    }
+}
+
+fn enclosing_class_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if matches!(
+            parent.kind(),
+            "class_declaration" | "abstract_class_declaration" | "class"
+        ) {
+            return parent
+                .child_by_field_name("name")
+                .map(|name| node_text(name, source).to_string());
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+/// Emit an enum as a `type` symbol followed by one `Enum.Member` variable
+/// per member. Each member's value is recorded in its signature: the
+/// initializer as written, or the implicit number TypeScript assigns
+/// (previous numeric value + 1, starting at 0).
+fn process_enum_declaration(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let Some(name) = node
+        .child_by_field_name("name")
+        .map(|n| node_text(n, source).to_string())
+    else {
+        return;
+    };
+    let exported = is_exported(node);
+
+    let mut symbol = make_symbol_with_forced_signature(
+        &name,
+        "type",
+        node,
+        source,
+        repo_id,
+        rel_path,
+        &[],
+        None,
+        &[],
+        "",
+        &[],
+    );
+    symbol.exported = exported;
+    symbol.attributes.push(attribute("declaration", "enum"));
+    let mut cursor = node.walk();
+    if node.children(&mut cursor).any(|c| c.kind() == "const") {
+        symbol.attributes.push(attribute("const", "true"));
+    }
+    symbols.push(symbol);
+
+    let Some(body) = node.child_by_field_name("body") else {
+        return;
+    };
+
+    let mut next_auto_value: Option<i64> = Some(0);
+    let mut body_cursor = body.walk();
+    for member in body.named_children(&mut body_cursor) {
+        let (name_node, value_node) = match member.kind() {
+            "enum_assignment" => (
+                member
+                    .child_by_field_name("name")
+                    .or_else(|| member.named_child(0)),
+                member.child_by_field_name("value"),
+            ),
+            "property_identifier" | "string" | "number" | "computed_property_name" => {
+                (Some(member), None)
+            }
+            _ => continue,
+        };
+        let Some(name_node) = name_node else {
+            continue;
+        };
+        let member_name = node_text(name_node, source)
+            .trim_matches(|c| c == '"' || c == '\'')
+            .to_string();
+        if member_name.is_empty() {
+            continue;
+        }
+
+        let value = match value_node {
+            Some(value_node) => {
+                let text = node_text(value_node, source).to_string();
+                next_auto_value = text.parse::<i64>().ok().map(|v| v + 1);
+                Some(text)
+            }
+            None => {
+                let value = next_auto_value.map(|v| v.to_string());
+                next_auto_value = next_auto_value.map(|v| v + 1);
+                value
+            }
+        };
+
+        let mut member_symbol = make_symbol(
+            &format!("{name}.{member_name}"),
+            "variable",
+            member,
+            source,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+            &[],
+            "",
+            &[],
+        );
+        member_symbol.exported = exported;
+        member_symbol.signature = Some(NativeSymbolSignature {
+            value,
+            ..Default::default()
+        });
+        member_symbol.attributes.push(attribute("owner", &name));
+        symbols.push(member_symbol);
+    }
+}
+
+fn attribute(key: &str, value: &str) -> NativeSymbolAttribute {
+    NativeSymbolAttribute {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
 fn process_variable_declaration(
     declarator: Node<'_>,
     source: &[u8],
@@ -500,6 +898,40 @@ fn process_variable_declaration(
         None => return vec![],
     };
 
+    if let Some(value) = declarator.child_by_field_name("value") {
+        let declared_type = declarator
+            .child_by_field_name("type")
+            .map(|type_node| node_text(type_node, source));
+
+        if is_function_expression(value) {
+            let mut symbol =
+                process_function_initializer(&name, declarator, value, source, repo_id, rel_path);
+            symbol.exported = is_exported(parent_node);
+            let props_hint =
+                declared_type.and_then(|text| react::props_type_from_wrapper(text, None));
+            react::annotate_function_component(&mut symbol, value, source, props_hint);
+            return vec![symbol];
+        }
+
+        // `const Button = React.memo((props) => ...)` / `forwardRef(...)`.
+        if let Some((wrapper, inner)) = unwrap_component_wrapper(value, source) {
+            let mut symbol =
+                process_function_initializer(&name, declarator, inner, source, repo_id, rel_path);
+            symbol.exported = is_exported(parent_node);
+            symbol.attributes.push(attribute("wrapper", wrapper));
+            let props_hint = value
+                .child_by_field_name("type_arguments")
+                .and_then(|args| {
+                    react::props_type_from_wrapper(node_text(args, source), Some(wrapper))
+                })
+                .or_else(|| {
+                    declared_type.and_then(|text| react::props_type_from_wrapper(text, None))
+                });
+            react::annotate_function_component(&mut symbol, inner, source, props_hint);
+            return vec![symbol];
+        }
+    }
+
     let mut symbol = make_symbol(
         &name,
         "variable",
@@ -517,13 +949,169 @@ fn process_variable_declaration(
     vec![symbol]
 }
 
+/// `export default function () {}` / `export default class {}` have no
+/// name to extract; index them as `default` so the file's main export is
+/// still a symbol. Named default exports keep their own name and are tagged
+/// `export=default` by their own processors (see `is_default_export`).
+fn process_default_export(
+    node: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    let mut cursor = node.walk();
+    let is_default = node.children(&mut cursor).any(|c| c.kind() == "default");
+    if !is_default {
+        return;
+    }
+
+    let Some(value) = node.child_by_field_name("value") else {
+        return;
+    };
+
+    let mut symbol = if is_function_expression(value) {
+        if value.child_by_field_name("name").is_some() {
+            return;
+        }
+        process_function_initializer("default", value, value, source, repo_id, rel_path)
+    } else if value.kind() == "class" {
+        if value.child_by_field_name("name").is_some() {
+            return;
+        }
+        let mut class = make_symbol_with_forced_signature(
+            "default",
+            "class",
+            value,
+            source,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+            &extract_generics(value, source),
+            "",
+            &extract_decorators(value, source),
+        );
+        apply_type_parameters(&mut class, extract_type_parameters(value, source));
+        class
+    } else {
+        return;
+    };
+    symbol.exported = true;
+    symbol.attributes.push(attribute("export", "default"));
+    symbols.push(symbol);
+}
+
+/// Whether `declaration` is the `declaration` of an `export default ...`.
+fn is_default_export(declaration: Node<'_>) -> bool {
+    let Some(parent) = declaration.parent() else {
+        return false;
+    };
+    if parent.kind() != "export_statement" {
+        return false;
+    }
+    let mut cursor = parent.walk();
+    let found = parent.children(&mut cursor).any(|c| c.kind() == "default");
+    found
+}
+
+/// `memo(fn)` / `React.forwardRef(fn)` -> (`"memo"` | `"forwardRef"`, `fn`).
+fn unwrap_component_wrapper<'a>(
+    value: Node<'a>,
+    source: &[u8],
+) -> Option<(&'static str, Node<'a>)> {
+    if value.kind() != "call_expression" {
+        return None;
+    }
+    let callee = value.child_by_field_name("function")?;
+    let wrapper = react::component_wrapper_name(node_text(callee, source))?;
+    let arguments = value.child_by_field_name("arguments")?;
+    let mut cursor = arguments.walk();
+    let inner = arguments.named_children(&mut cursor).next()?;
+    is_function_expression(inner).then_some((wrapper, inner))
+}
+
+fn is_function_expression(node: Node<'_>) -> bool {
+    matches!(
+        node.kind(),
+        "arrow_function" | "function_expression" | "function" | "generator_function"
+    )
+}
+
+/// `const load = async <T>(id: string): Promise<T> => {...}` becomes a
+/// `function` symbol spanning the declarator, flagged `async` / `generator`.
+fn process_function_initializer(
+    name: &str,
+    declarator: Node<'_>,
+    value: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+) -> NativeParsedSymbol {
+    let params = extract_parameters(value, source);
+    let returns = extract_return_type(value, source);
+    let generics = extract_generics(value, source);
+
+    let mut symbol = make_symbol_with_forced_signature(
+        name,
+        "function",
+        declarator,
+        source,
+        repo_id,
+        rel_path,
+        &params,
+        returns.as_deref(),
+        &generics,
+        "",
+        &[],
+    );
+    apply_type_parameters(&mut symbol, extract_type_parameters(value, source));
+
+    let modifiers = extract_modifiers(value);
+    if modifiers.contains(&"async") {
+        symbol.attributes.push(attribute("async", "true"));
+    }
+    if modifiers.contains(&"generator") {
+        symbol.attributes.push(attribute("generator", "true"));
+    }
+    apply_modifiers(&mut symbol, &modifiers);
+    symbol
+}
+
+/// Modifier keywords written on a declaration (`static`, `abstract`,
+/// `override`, `readonly`, `async`, and `*` for generators).
+fn extract_modifiers(node: Node<'_>) -> Vec<&'static str> {
+    let mut modifiers = Vec::new();
+    match node.kind() {
+        "abstract_class_declaration" | "abstract_method_signature" => modifiers.push("abstract"),
+        "generator_function_declaration" | "generator_function" => modifiers.push("generator"),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "abstract" => modifiers.push("abstract"),
+            "static" => modifiers.push("static"),
+            "override_modifier" | "override" => modifiers.push("override"),
+            "readonly" => modifiers.push("readonly"),
+            "async" => modifiers.push("async"),
+            "*" => modifiers.push("generator"),
+            _ => {}
+        }
+    }
+    modifiers
+}
+
+/// `module Foo {}` / `namespace A.B {}`, or an ambient `declare module
+/// "pkg" {}` named after its specifier.
 fn process_module(
     node: Node<'_>,
     source: &[u8],
     repo_id: &str,
     rel_path: &str,
 ) -> Option<NativeParsedSymbol> {
-    let name = extract_identifier(node, source)?;
+    let name = module_name(node, source).or_else(|| ambient_module_specifier(node, source))?;
     let mut symbol = make_symbol(
         &name,
         "module",
@@ -541,6 +1129,144 @@ fn process_module(
     Some(symbol)
 }
 
+fn module_name(node: Node<'_>, source: &[u8]) -> Option<String> {
+    node.child_by_field_name("name")
+        .filter(|name| matches!(name.kind(), "identifier" | "nested_identifier"))
+        .map(|name| node_text(name, source).to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// `"pkg"` in `declare module "pkg" {}`, without quotes.
+fn ambient_module_specifier(node: Node<'_>, source: &[u8]) -> Option<String> {
+    node.child_by_field_name("name")
+        .filter(|name| name.kind() == "string")
+        .map(|name| {
+            node_text(name, source)
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string()
+        })
+        .filter(|name| !name.is_empty())
+}
+
+/// Specifier of the `declare module "pkg" {}` enclosing `node`, or
+/// `"global"` inside `declare global {}`.
+fn enclosing_ambient_module(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        match parent.kind() {
+            "module" => {
+                if let Some(specifier) = ambient_module_specifier(parent, source) {
+                    return Some(specifier);
+                }
+            }
+            "ambient_declaration" => {
+                let mut cursor = parent.walk();
+                let is_global = parent
+                    .children(&mut cursor)
+                    .any(|child| child.kind() == "global");
+                if is_global {
+                    return Some("global".to_string());
+                }
+            }
+            _ => {}
+        }
+        current = parent.parent();
+    }
+    None
+}
+
+/// Declaration files, and anything under `declare ...`, describe code that
+/// exists elsewhere.
+fn is_ambient(node: Node<'_>, rel_path: &str) -> bool {
+    if [".d.ts", ".d.mts", ".d.cts"]
+        .iter()
+        .any(|suffix| rel_path.ends_with(suffix))
+    {
+        return true;
+    }
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == "ambient_declaration" {
+            return true;
+        }
+        current = parent.parent();
+    }
+    false
+}
+
+fn mark_ambient(declared: &mut [NativeParsedSymbol]) {
+    for symbol in declared {
+        if !symbol
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "declaration")
+        {
+            symbol.attributes.push(attribute("declaration", "ambient"));
+        }
+    }
+}
+
+/// Dotted path of the namespaces / modules enclosing `node`, outermost first
+/// (`namespace A { namespace B.C { ... } }` -> `A.B.C`).
+fn enclosing_namespace(node: Node<'_>, source: &[u8]) -> Option<String> {
+    let mut names = Vec::new();
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if matches!(parent.kind(), "module" | "internal_module") {
+            if let Some(name) = module_name(parent, source) {
+                names.push(name);
+            }
+        }
+        current = parent.parent();
+    }
+    if names.is_empty() {
+        return None;
+    }
+    names.reverse();
+    Some(names.join("."))
+}
+
+/// Symbols inside a namespace keep their bare name (matching the other
+/// extractors) and gain a `namespace` attribute plus a `qualifiedName`
+/// (`A.B.User`, `A.B.User.save`) so same-named declarations in different
+/// namespaces stay distinguishable. Inside `declare module "pkg" {}` the
+/// specifier leads the qualified name (`pkg.Options`), is recorded as the
+/// `module` attribute, and the declarations count as exported by it.
+fn push_namespace_attributes(node: Node<'_>, source: &[u8], declared: &mut [NativeParsedSymbol]) {
+    let ambient_module = enclosing_ambient_module(node, source);
+    let namespace = enclosing_namespace(node, source);
+    let qualifier = match (&ambient_module, &namespace) {
+        (Some(module), Some(namespace)) => format!("{module}.{namespace}"),
+        (Some(qualifier), None) | (None, Some(qualifier)) => qualifier.clone(),
+        (None, None) => return,
+    };
+    let class_owner = enclosing_class_name(node, source);
+    for symbol in declared {
+        let owner = symbol
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "owner")
+            .map(|attr| attr.value.clone())
+            .or_else(|| class_owner.clone());
+        let qualified_name = match owner {
+            Some(owner) if !symbol.name.starts_with(&format!("{owner}.")) => {
+                format!("{qualifier}.{owner}.{}", symbol.name)
+            }
+            _ => format!("{qualifier}.{}", symbol.name),
+        };
+        if let Some(module) = &ambient_module {
+            symbol.attributes.push(attribute("module", module));
+            symbol.exported = true;
+        }
+        if let Some(namespace) = &namespace {
+            symbol.attributes.push(attribute("namespace", namespace));
+        }
+        symbol
+            .attributes
+            .push(attribute("qualifiedName", &qualified_name));
+    }
+}
+
 fn process_assignment_expression(
     node: Node<'_>,
     source: &[u8],
@@ -560,6 +1286,12 @@ fn process_assignment_expression(
 
     let left = match node.child(0) {
         Some(n) if n.kind() == "identifier" => n,
+        Some(n) if n.kind() == "member_expression" => {
+            if let (Some(target), Some(value)) = (commonjs_target(n, source), node.child(2)) {
+                process_commonjs_export(node, target, value, source, repo_id, rel_path, symbols);
+            }
+            return;
+        }
         _ => return,
     };
 
@@ -589,3 +1321,543 @@ fn process_assignment_expression(
     symbol.exported = is_exported(right);
     symbols.push(symbol);
 }
+
+/// Left-hand side of a CommonJS export assignment.
+enum CommonJsTarget {
+    /// `module.exports = ...`
+    Module,
+    /// `exports.name = ...` / `module.exports.name = ...`
+    Named(String),
+}
+
+fn commonjs_target(left: Node<'_>, source: &[u8]) -> Option<CommonJsTarget> {
+    let text: String = node_text(left, source)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+    if text == "module.exports" {
+        return Some(CommonJsTarget::Module);
+    }
+    let name = text
+        .strip_prefix("module.exports.")
+        .or_else(|| text.strip_prefix("exports."))?;
+    let is_identifier = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+    is_identifier.then(|| CommonJsTarget::Named(name.to_string()))
+}
+
+/// `exports.load = async () => {}` -> exported function `load`;
+/// `exports.VERSION = "1"` -> exported variable `VERSION`;
+/// `module.exports = function () {}` / `= class Store {}` -> an exported
+/// `default` (or the expression's own name). Values that are plain
+/// identifiers or object literals re-export existing declarations and are
+/// handled by [`mark_commonjs_exports`].
+fn process_commonjs_export(
+    node: Node<'_>,
+    target: CommonJsTarget,
+    value: Node<'_>,
+    source: &[u8],
+    repo_id: &str,
+    rel_path: &str,
+    symbols: &mut Vec<NativeParsedSymbol>,
+) {
+    if matches!(value.kind(), "identifier" | "object") {
+        return;
+    }
+
+    let (name, export) = match target {
+        CommonJsTarget::Named(name) => (name, "commonjs"),
+        CommonJsTarget::Module => {
+            if !is_function_expression(value) && value.kind() != "class" {
+                return;
+            }
+            let name = value
+                .child_by_field_name("name")
+                .map(|name| node_text(name, source).to_string())
+                .unwrap_or_else(|| "default".to_string());
+            (name, "default")
+        }
+    };
+
+    let mut symbol = if is_function_expression(value) {
+        process_function_initializer(&name, node, value, source, repo_id, rel_path)
+    } else if value.kind() == "class" {
+        let mut class = make_symbol_with_forced_signature(
+            &name,
+            "class",
+            value,
+            source,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+            &extract_generics(value, source),
+            "",
+            &extract_decorators(value, source),
+        );
+        apply_type_parameters(&mut class, extract_type_parameters(value, source));
+        class
+    } else {
+        let mut variable = make_symbol(
+            &name,
+            "variable",
+            node,
+            source,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+            &[],
+            "",
+            &[],
+        );
+        variable
+            .attributes
+            .push(attribute("initializer", initializer_kind(value)));
+        variable
+    };
+    symbol.exported = true;
+    symbol.attributes.push(attribute("export", export));
+    symbols.push(symbol);
+}
+
+/// Mark declarations re-exported through top-level CommonJS assignments as
+/// exported: `module.exports = Store`, `exports.load = load`, and the
+/// shorthand / identifier members of `module.exports = { load, save: saveImpl }`.
+/// Methods declared inside that object literal (`module.exports.save`) are
+/// exported too.
+fn mark_commonjs_exports(root: Node<'_>, source: &[u8], symbols: &mut [NativeParsedSymbol]) {
+    let mut names = Vec::new();
+    let mut has_module_object = false;
+
+    let mut cursor = root.walk();
+    for statement in root.named_children(&mut cursor) {
+        if statement.kind() != "expression_statement" {
+            continue;
+        }
+        let Some(assignment) = statement
+            .named_child(0)
+            .filter(|child| child.kind() == "assignment_expression")
+        else {
+            continue;
+        };
+        let (Some(left), Some(right)) = (
+            assignment.child_by_field_name("left"),
+            assignment.child_by_field_name("right"),
+        ) else {
+            continue;
+        };
+        if left.kind() != "member_expression" || commonjs_target(left, source).is_none() {
+            continue;
+        }
+
+        match right.kind() {
+            "identifier" => names.push(node_text(right, source).to_string()),
+            "object" => {
+                has_module_object = true;
+                let mut inner = right.walk();
+                for member in right.named_children(&mut inner) {
+                    match member.kind() {
+                        "shorthand_property_identifier" => {
+                            names.push(node_text(member, source).to_string());
+                        }
+                        "pair" => {
+                            if let Some(value) = member
+                                .child_by_field_name("value")
+                                .filter(|value| value.kind() == "identifier")
+                            {
+                                names.push(node_text(value, source).to_string());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if names.is_empty() && !has_module_object {
+        return;
+    }
+    for symbol in symbols.iter_mut() {
+        let is_member = symbol.attributes.iter().any(|attr| attr.key == "owner");
+        let reexported = !is_member && names.contains(&symbol.name);
+        let object_member = symbol.name.starts_with("module.exports.");
+        if reexported || object_member {
+            symbol.exported = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+    use crate::types::NativeParsedSymbol;
+
+    #[test]
+    fn typescript_enums_emit_members_with_values() {
+        let source = r#"export enum Status {
+  Idle,
+  Running = 5,
+  Done,
+  Failed = "failed",
+}
+
+const enum Flag { A }
+"#;
+
+        let parsed = parse_source("ts", "src/status.ts", source);
+
+        let status = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "Status")
+            .expect("enum symbol");
+        assert_eq!(status.kind, "type");
+        assert!(status.exported);
+
+        let value_of = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.signature.as_ref())
+                .and_then(|signature| signature.value.clone())
+        };
+        assert_eq!(value_of("Status.Idle").as_deref(), Some("0"));
+        assert_eq!(value_of("Status.Running").as_deref(), Some("5"));
+        assert_eq!(value_of("Status.Done").as_deref(), Some("6"));
+        assert_eq!(value_of("Status.Failed").as_deref(), Some("\"failed\""));
+
+        let flag = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "Flag")
+            .expect("const enum symbol");
+        assert!(flag
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "const" && attr.value == "true"));
+        assert!(parsed.symbols.iter().any(|symbol| symbol.name == "Flag.A"
+            && symbol
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "owner" && attr.value == "Flag")));
+    }
+
+    #[test]
+    fn typescript_class_fields_emit_property_symbols() {
+        let source = r#"export class Config {
+  apiKey: string = "";
+  private retries = 3;
+  #cache = new Map<string, string>();
+  handler = () => {};
+}
+"#;
+
+        let parsed = parse_source("ts", "src/config.ts", source);
+
+        let attr = |name: &str, key: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.attributes.iter().find(|attr| attr.key == key))
+                .map(|attr| attr.value.clone())
+        };
+
+        let api_key = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "apiKey")
+            .expect("apiKey property");
+        assert_eq!(api_key.kind, "variable");
+        assert!(api_key.exported);
+        assert_eq!(attr("apiKey", "member").as_deref(), Some("property"));
+        assert_eq!(attr("apiKey", "owner").as_deref(), Some("Config"));
+        assert_eq!(attr("apiKey", "type").as_deref(), Some("string"));
+        assert_eq!(attr("apiKey", "initializer").as_deref(), Some("string"));
+
+        let retries = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "retries")
+            .expect("retries property");
+        assert_eq!(retries.visibility, "private");
+        assert_eq!(attr("retries", "initializer").as_deref(), Some("number"));
+
+        let cache = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "#cache")
+            .expect("#cache property");
+        assert_eq!(cache.visibility, "private");
+        assert_eq!(attr("#cache", "initializer").as_deref(), Some("new"));
+        assert_eq!(attr("handler", "initializer").as_deref(), Some("function"));
+    }
+
+    #[test]
+    fn typescript_function_valued_consts_emit_function_symbols() {
+        let source = r#"export const fetchUser = async (id: string): Promise<User> => {
+  return load(id);
+};
+const ids = function* () { yield 1; };
+const limit = 10;
+"#;
+
+        let parsed = parse_source("ts", "src/users.ts", source);
+
+        let has_attr = |symbol: &NativeParsedSymbol, key: &str| {
+            symbol
+                .attributes
+                .iter()
+                .any(|attr| attr.key == key && attr.value == "true")
+        };
+
+        let fetch_user = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "fetchUser")
+            .expect("fetchUser symbol");
+        assert_eq!(fetch_user.kind, "function");
+        assert!(fetch_user.exported);
+        assert!(has_attr(fetch_user, "async"));
+        let signature = fetch_user.signature.as_ref().expect("signature");
+        assert_eq!(signature.params.as_ref().map(Vec::len), Some(1));
+
+        let ids = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "ids")
+            .expect("ids symbol");
+        assert_eq!(ids.kind, "function");
+        assert!(has_attr(ids, "generator"));
+
+        let limit = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "limit")
+            .expect("limit symbol");
+        assert_eq!(limit.kind, "variable");
+    }
+
+    #[test]
+    fn typescript_anonymous_default_exports_are_indexed() {
+        let cases = [
+            (
+                "export default async function (req: Request) {}\n",
+                "function",
+            ),
+            ("export default class extends Base {}\n", "class"),
+            ("export default () => 42;\n", "function"),
+        ];
+
+        for (source, kind) in cases {
+            let parsed = parse_source("ts", "src/handler.ts", source);
+
+            let symbol = parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == "default")
+                .unwrap_or_else(|| panic!("no default symbol for {source:?}"));
+            assert_eq!(symbol.kind, kind);
+            assert!(symbol.exported);
+            assert!(symbol
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "export" && attr.value == "default"));
+        }
+    }
+
+    #[test]
+    fn object_literal_methods_are_qualified_by_their_object() {
+        let source = r#"const api = {
+  async fetchUser(id) {},
+  remove: (id) => del(id),
+};
+
+export default defineComponent({
+  methods: {
+    save() {},
+  },
+});
+"#;
+
+        let parsed = parse_source("js", "src/api.js", source);
+
+        let names: Vec<(&str, &str)> = parsed
+            .symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind.as_str()))
+            .collect();
+        assert!(names.contains(&("api.fetchUser", "method")));
+        assert!(names.contains(&("api.remove", "method")));
+        assert!(names.contains(&("default.methods.save", "method")));
+
+        let save = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "default.methods.save")
+            .expect("save method");
+        assert!(save.exported);
+        assert!(save
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "owner" && attr.value == "default.methods"));
+    }
+
+    #[test]
+    fn typescript_modifiers_are_recorded_in_the_signature() {
+        let source = r#"export abstract class Repo<T> {
+  static readonly table: string = "repos";
+  abstract find(id: string): Promise<T>;
+  override async save(item: T): Promise<void> {}
+  static *ids(): Generator<string> {}
+  plain(): void {}
+}
+"#;
+
+        let parsed = parse_source("ts", "src/repo.ts", source);
+
+        let modifiers = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .expect("symbol present")
+                .signature
+                .as_ref()
+                .and_then(|signature| signature.modifiers.clone())
+                .unwrap_or_default()
+        };
+
+        assert_eq!(modifiers("Repo"), vec!["abstract"]);
+        assert_eq!(modifiers("table"), vec!["static", "readonly"]);
+        assert_eq!(modifiers("find"), vec!["abstract"]);
+        assert_eq!(modifiers("save"), vec!["override", "async"]);
+        assert_eq!(modifiers("ids"), vec!["static", "generator"]);
+        assert!(modifiers("plain").is_empty());
+    }
+
+    #[test]
+    fn commonjs_exports_produce_exported_symbols() {
+        let source = r#"function connect(url) {}
+function internal() {}
+class Pool {}
+
+exports.query = async function (sql) {};
+exports.VERSION = "1.0";
+module.exports.Pool = Pool;
+module.exports = {
+  connect,
+  close() {},
+};
+"#;
+
+        let parsed = parse_source("js", "lib/db.js", source);
+
+        let symbol = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .unwrap_or_else(|| panic!("missing symbol {name}"))
+        };
+
+        let query = symbol("query");
+        assert_eq!(query.kind, "function");
+        assert!(query.exported);
+        assert!(query
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "export" && attr.value == "commonjs"));
+
+        assert_eq!(symbol("VERSION").kind, "variable");
+        assert!(symbol("VERSION").exported);
+        assert!(symbol("connect").exported);
+        assert!(symbol("Pool").exported);
+        assert!(symbol("module.exports.close").exported);
+        assert!(!symbol("internal").exported);
+    }
+
+    #[test]
+    fn ambient_module_declarations_are_qualified_by_specifier() {
+        let source = r#"declare module "fastify-plugin" {
+  interface PluginOptions {
+    name?: string;
+  }
+  function fp(fn: unknown, options?: PluginOptions): unknown;
+  export default fp;
+}
+
+declare global {
+  interface Window {
+    analytics: unknown;
+  }
+}
+
+export declare function configure(level: number): void;
+
+export declare class Client {
+  connect(url: string): Promise<void>;
+}
+
+export function overloaded(value: string): string;
+export function overloaded(value: unknown): unknown {
+  return value;
+}
+"#;
+
+        let parsed = parse_source("ts", "types/vendor.ts", source);
+
+        let symbol = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .unwrap_or_else(|| panic!("missing symbol {name}"))
+        };
+        let attr = |name: &str, key: &str| {
+            symbol(name)
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+
+        assert_eq!(symbol("fastify-plugin").kind, "module");
+
+        let fp = symbol("fp");
+        assert_eq!(fp.kind, "function");
+        assert!(fp.exported);
+        assert_eq!(attr("fp", "module").as_deref(), Some("fastify-plugin"));
+        assert_eq!(
+            attr("fp", "qualifiedName").as_deref(),
+            Some("fastify-plugin.fp")
+        );
+        assert_eq!(attr("fp", "declaration").as_deref(), Some("ambient"));
+        assert_eq!(
+            attr("PluginOptions", "qualifiedName").as_deref(),
+            Some("fastify-plugin.PluginOptions")
+        );
+
+        assert_eq!(
+            attr("Window", "qualifiedName").as_deref(),
+            Some("global.Window")
+        );
+
+        assert_eq!(symbol("configure").kind, "function");
+        assert_eq!(attr("configure", "declaration").as_deref(), Some("ambient"));
+        assert_eq!(symbol("connect").kind, "method");
+
+        let overloads = parsed
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.name == "overloaded")
+            .count();
+        assert_eq!(overloads, 1);
+    }
+}
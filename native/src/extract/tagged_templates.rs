@@ -0,0 +1,150 @@
+//! Tagged template literals (TS/JS family).
+//!
+//! `` gql`query { ... }` ``, `` sql`SELECT ...` `` and
+//! `` styled.div`color: red;` `` embed another language in a template
+//! string. Each tagged template is recorded with its tag, the embedded
+//! language when the tag is a known one, and the raw content between the
+//! backticks (substitutions kept as `${...}`).
+
+use tree_sitter::Node;
+
+use crate::types::{NativeParsedSymbol, NativeTaggedTemplate};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+
+/// Tag roots per embedded language. The root is the tag up to the first
+/// `.` or `(` (`styled.div`, `styled(Button)`, `sql.unsafe` -> `styled`,
+/// `styled`, `sql`).
+const TAG_LANGUAGES: &[(&str, &[&str])] = &[
+    ("graphql", &["gql", "graphql"]),
+    ("sql", &["sql", "SQL"]),
+    (
+        "css",
+        &[
+            "styled",
+            "css",
+            "keyframes",
+            "createGlobalStyle",
+            "injectGlobal",
+        ],
+    ),
+    ("html", &["html", "svg"]),
+];
+
+pub fn extract_tagged_templates(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeTaggedTemplate> {
+    let mut templates = Vec::new();
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "call_expression" {
+            if let (Some(function), Some(template)) = (
+                node.child_by_field_name("function"),
+                node.child_by_field_name("arguments")
+                    .filter(|arguments| arguments.kind() == "template_string"),
+            ) {
+                let tag: String = node_text(function, source)
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect();
+                let raw = node_text(template, source);
+                let content = raw
+                    .strip_prefix('`')
+                    .and_then(|rest| rest.strip_suffix('`'))
+                    .unwrap_or(raw);
+                templates.push(NativeTaggedTemplate {
+                    language: tag_language(&tag).map(str::to_string),
+                    tag,
+                    content: content.to_string(),
+                    caller_node_id: find_enclosing_symbol(node, symbols),
+                    range: extract_range(node),
+                });
+            }
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    templates
+}
+
+fn tag_language(tag: &str) -> Option<&'static str> {
+    let root = tag.split(['.', '(', '<']).next().unwrap_or(tag);
+    TAG_LANGUAGES
+        .iter()
+        .find(|(_, tags)| tags.contains(&root))
+        .map(|&(language, _)| language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn tag_language_uses_the_tag_root() {
+        assert_eq!(tag_language("gql"), Some("graphql"));
+        assert_eq!(tag_language("styled.div"), Some("css"));
+        assert_eq!(tag_language("styled(Button).attrs({})"), Some("css"));
+        assert_eq!(tag_language("sql.unsafe"), Some("sql"));
+        assert_eq!(tag_language("String.raw"), None);
+    }
+
+    #[test]
+    fn tagged_templates_record_tag_language_and_content() {
+        let source = r#"const GET_USER = gql`query GetUser($id: ID!) { user(id: $id) { name } }`;
+
+export function findUser(id: string) {
+  return db.query(sql`SELECT * FROM users WHERE id = ${id}`);
+}
+
+const Title = styled.h1`
+  color: red;
+`;
+
+const plain = `not tagged`;
+const raw = String.raw`C:\temp`;
+"#;
+
+        let parsed = parse_source("ts", "tagged.ts", source);
+
+        let templates: Vec<(&str, Option<&str>, &str)> = parsed
+            .tagged_templates
+            .iter()
+            .map(|template| {
+                (
+                    template.tag.as_str(),
+                    template.language.as_deref(),
+                    template.content.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            templates,
+            vec![
+                (
+                    "gql",
+                    Some("graphql"),
+                    "query GetUser($id: ID!) { user(id: $id) { name } }"
+                ),
+                ("sql", Some("sql"), "SELECT * FROM users WHERE id = ${id}"),
+                ("styled.h1", Some("css"), "\n  color: red;\n"),
+                ("String.raw", None, "C:\\temp"),
+            ]
+        );
+        let find_user = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "findUser")
+            .expect("missing findUser");
+        assert_eq!(parsed.tagged_templates[1].caller_node_id, find_user.node_id);
+    }
+}
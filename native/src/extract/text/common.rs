@@ -0,0 +1,142 @@
+use crate::parse::content_hash::hash_content;
+use crate::types::{NativeParsedSymbol, NativeRange};
+
+use crate::extract::symbols::common::{make_symbol_from_parts, ParamInfo};
+
+/// Maps byte offsets in a source string to 1-indexed lines and 0-indexed
+/// byte columns, matching the positions tree-sitter reports.
+pub struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(text: &str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(
+            text.bytes()
+                .enumerate()
+                .filter(|(_, byte)| *byte == b'\n')
+                .map(|(index, _)| index + 1),
+        );
+        Self { starts }
+    }
+
+    pub fn position(&self, offset: usize) -> (u32, u32) {
+        let line = match self.starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+        ((line + 1) as u32, (offset - self.starts[line]) as u32)
+    }
+
+    pub fn range(&self, start: usize, end: usize) -> NativeRange {
+        let (start_line, start_col) = self.position(start);
+        let (end_line, end_col) = self.position(end);
+        NativeRange {
+            start_line,
+            start_col,
+            end_line,
+            end_col,
+        }
+    }
+}
+
+/// Build a symbol for a text-extracted declaration. The fingerprint hashes
+/// the kind, name, and whitespace-normalised source of the declaration, so it
+/// changes when the body changes but not when it is merely re-indented.
+#[allow(clippy::too_many_arguments)]
+pub fn make_text_symbol(
+    name: &str,
+    kind: &str,
+    range: NativeRange,
+    snippet: &str,
+    repo_id: &str,
+    rel_path: &str,
+    params: &[ParamInfo],
+    returns: Option<&str>,
+) -> NativeParsedSymbol {
+    let normalized = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+    let fingerprint = hash_content(&format!("type:{kind}|name:{name}|text:{normalized}"));
+
+    let mut symbol = make_symbol_from_parts(
+        name,
+        kind,
+        fingerprint,
+        range,
+        repo_id,
+        rel_path,
+        params,
+        returns,
+        &[],
+        "public",
+        &[],
+    );
+    symbol.exported = true;
+    symbol
+}
+
+/// Replace comment text with spaces (newlines are kept) so that offsets in
+/// the masked string still line up with the original. Quoted strings are
+/// left untouched so `"http://..."` isn't mistaken for a line comment.
+pub fn mask_comments(
+    text: &str,
+    line_comment: &str,
+    block_comment: Option<(&str, &str)>,
+) -> String {
+    let bytes = text.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+    let mut quote: Option<u8> = None;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+
+        if let Some(q) = quote {
+            if byte == b'\\' {
+                i += 2;
+                continue;
+            }
+            if byte == q || byte == b'\n' {
+                quote = None;
+            }
+            i += 1;
+            continue;
+        }
+
+        if byte == b'"' || byte == b'\'' {
+            quote = Some(byte);
+            i += 1;
+            continue;
+        }
+
+        if !line_comment.is_empty() && bytes[i..].starts_with(line_comment.as_bytes()) {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                out[i] = b' ';
+                i += 1;
+            }
+            continue;
+        }
+
+        if let Some((open, close)) = block_comment {
+            if bytes[i..].starts_with(open.as_bytes()) {
+                let end = text[i + open.len()..]
+                    .find(close)
+                    .map(|pos| i + open.len() + pos + close.len())
+                    .unwrap_or(bytes.len());
+                for slot in &mut out[i..end] {
+                    if *slot != b'\n' {
+                        *slot = b' ';
+                    }
+                }
+                i = end;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+
+    // Only whole comments (ASCII delimiters through end of comment) are
+    // replaced, so every multi-byte sequence is either intact or fully blanked.
+    String::from_utf8(out).unwrap_or_else(|_| text.to_string())
+}
@@ -0,0 +1,417 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::extract::symbols::common::ParamInfo;
+use crate::types::{NativeParsedImport, NativeSymbolAttribute};
+
+use super::common::{make_text_symbol, mask_comments, LineIndex};
+use super::TextExtraction;
+
+static IMPORT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^[ \t]*import\s+(static\s+)?([\w.]+(?:\.\*)?)(?:\s+as\s+(\w+))?").unwrap()
+});
+static TYPE_DECL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^[ \t]*(?:(?:public|private|protected|abstract|final|static)\s+)*(class|interface|trait|enum)\s+(\w+)").unwrap()
+});
+static METHOD_DECL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^[ \t]*(?:(?:public|private|protected|static|final|abstract|synchronized)\s+)*(?:def|void|[A-Z][\w.<>,\[\] ]*?)\s+(\w+)\s*\(([^)]*)\)\s*(?:throws\s+[\w., ]+)?\{").unwrap()
+});
+static BLOCK_START: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]*(dependencies|plugins)\s*\{").unwrap());
+static DEPENDENCY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?m)^[ \t]*(\w+)\s*\(?\s*(?:(?:platform|enforcedPlatform)\s*\(\s*)?(?:['"]([^'"$]+)['"]|project\s*\(\s*(?:path\s*:\s*)?['"]([^'"]+)['"]\s*\))"#).unwrap()
+});
+static PLUGIN_ID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?m)^[ \t]*(?:id[ \t]*\(?[ \t]*['"]([^'"]+)['"][ \t]*\)?|(kotlin|java|application|`[\w-]+`|[a-z][\w-]*)[ \t]*$)(?:[ \t]*version[ \t]*\(?[ \t]*['"]([^'"]+)['"])?"#).unwrap()
+});
+static APPLY_PLUGIN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\bapply\s+plugin\s*:\s*['"]([^'"]+)['"]"#).unwrap());
+static TASK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?m)^[ \t]*(?:task\s+(\w+)(?:\s*\(\s*type\s*:\s*([\w.]+))?|tasks\.(?:register|create)\s*\(\s*['"](\w+)['"](?:\s*,\s*([\w.]+))?)"#).unwrap()
+});
+
+/// Extract Groovy sources and Gradle build scripts.
+///
+/// Every file gets `import` edges plus class/interface/trait/enum and method
+/// symbols. `*.gradle` files additionally get the build structure:
+///
+/// - each dependency declaration as a `variable` named
+///   `<configuration>.<group:artifact>` (attributes: `configuration`,
+///   `group`, `artifact`, `version`), with `project(':x')` dependencies also
+///   emitted as imports of the project path;
+/// - each plugin (`plugins { id ... }`, `apply plugin:`) as a `variable`
+///   named `plugins.<id>`;
+/// - each custom task (`task x`, `tasks.register("x")`) as a `function`
+///   named `tasks.<x>`, with its `type` when declared.
+pub fn extract_groovy(content: &str, repo_id: &str, rel_path: &str) -> TextExtraction {
+    let masked = mask_comments(content, "//", Some(("/*", "*/")));
+    let lines = LineIndex::new(content);
+    let mut out = TextExtraction::default();
+
+    for caps in IMPORT.captures_iter(&masked) {
+        let whole = caps.get(0).expect("capture 0 always present");
+        let raw = &caps[2];
+        let specifier = raw.trim_end_matches(".*").to_string();
+        // Mirrors the Java extractor: wildcard imports name `*`, otherwise the
+        // last segment (or the `as` alias) is the imported name.
+        let named = if raw.ends_with(".*") {
+            "*".to_string()
+        } else {
+            caps.get(3)
+                .map(|m| m.as_str())
+                .unwrap_or_else(|| specifier.rsplit('.').next().unwrap_or(""))
+                .to_string()
+        };
+        out.imports.push(NativeParsedImport {
+            is_relative: false,
+            is_external: !["java.", "javax.", "jdk.", "groovy."]
+                .iter()
+                .any(|prefix| specifier.starts_with(prefix)),
+            named_imports: vec![named],
+            default_import: None,
+            namespace_import: None,
+            is_re_export: false,
+            range: lines.range(whole.start() + leading_ws(whole.as_str()), whole.end()),
+            specifier,
+            ..Default::default()
+        });
+    }
+
+    for caps in TYPE_DECL.captures_iter(&masked) {
+        let whole = caps.get(0).expect("capture 0 always present");
+        let kind = match &caps[1] {
+            "interface" | "trait" => "interface",
+            "enum" => "type",
+            _ => "class",
+        };
+        let end = block_end(&masked, whole.end());
+        out.symbols.push(make_text_symbol(
+            &caps[2],
+            kind,
+            lines.range(whole.start() + leading_ws(whole.as_str()), end),
+            &content[whole.start()..end],
+            repo_id,
+            rel_path,
+            &[],
+            None,
+        ));
+    }
+
+    for caps in METHOD_DECL.captures_iter(&masked) {
+        let whole = caps.get(0).expect("capture 0 always present");
+        let name = &caps[1];
+        if matches!(name, "if" | "for" | "while" | "switch" | "catch") {
+            continue;
+        }
+        let end = block_end(&masked, whole.end() - 1);
+        let params = caps[2]
+            .split(',')
+            .filter_map(|param| {
+                let mut parts = param.split('=').next()?.split_whitespace().rev();
+                let name = parts.next()?.to_string();
+                let type_annotation = parts.next().map(str::to_string);
+                Some(ParamInfo {
+                    name,
+                    type_annotation,
+                })
+            })
+            .collect::<Vec<_>>();
+        out.symbols.push(make_text_symbol(
+            name,
+            "function",
+            lines.range(whole.start() + leading_ws(whole.as_str()), end),
+            &content[whole.start()..end],
+            repo_id,
+            rel_path,
+            &params,
+            None,
+        ));
+    }
+
+    if rel_path.ends_with(".gradle") {
+        extract_gradle(content, &masked, &lines, repo_id, rel_path, &mut out);
+    }
+
+    out.symbols
+        .sort_by_key(|s| (s.range.start_line, s.range.start_col));
+    out
+}
+
+fn extract_gradle(
+    content: &str,
+    masked: &str,
+    lines: &LineIndex,
+    repo_id: &str,
+    rel_path: &str,
+    out: &mut TextExtraction,
+) {
+    for block in BLOCK_START.captures_iter(masked) {
+        let whole = block.get(0).expect("capture 0 always present");
+        let body_start = whole.end();
+        let body_end = closing_brace(masked, whole.end() - 1)
+            .unwrap_or(masked.len())
+            .max(body_start);
+        let body = &masked[body_start..body_end];
+
+        if &block[1] == "dependencies" {
+            for caps in DEPENDENCY.captures_iter(body) {
+                let m = caps.get(0).expect("capture 0 always present");
+                let start = body_start + m.start() + leading_ws(m.as_str());
+                let end = body_start + m.end();
+                let configuration = &caps[1];
+
+                if let Some(project) = caps.get(3) {
+                    out.imports.push(NativeParsedImport {
+                        specifier: project.as_str().to_string(),
+                        is_relative: false,
+                        is_external: false,
+                        named_imports: Vec::new(),
+                        default_import: None,
+                        namespace_import: None,
+                        is_re_export: false,
+                        range: lines.range(start, end),
+                        ..Default::default()
+                    });
+                    continue;
+                }
+
+                let Some(coordinate) = caps.get(2).map(|m| m.as_str()) else {
+                    continue;
+                };
+                let mut parts = coordinate.splitn(3, ':');
+                let group = parts.next().unwrap_or("");
+                let artifact = parts.next().unwrap_or("");
+                let version = parts.next().unwrap_or("");
+                let name = if artifact.is_empty() {
+                    format!("{configuration}.{group}")
+                } else {
+                    format!("{configuration}.{group}:{artifact}")
+                };
+
+                let mut symbol = make_text_symbol(
+                    &name,
+                    "variable",
+                    lines.range(start, end),
+                    &content[start..end],
+                    repo_id,
+                    rel_path,
+                    &[],
+                    None,
+                );
+                let mut attrs = vec![
+                    ("configuration", configuration),
+                    ("group", group),
+                    ("artifact", artifact),
+                ];
+                if !version.is_empty() {
+                    attrs.push(("version", version));
+                }
+                symbol.attributes = to_attributes(&attrs);
+                out.symbols.push(symbol);
+            }
+        } else {
+            for caps in PLUGIN_ID.captures_iter(body) {
+                let m = caps.get(0).expect("capture 0 always present");
+                let Some(id) = caps.get(1).or(caps.get(2)) else {
+                    continue;
+                };
+                let id = id.as_str().trim_matches('`');
+                let start = body_start + m.start() + leading_ws(m.as_str());
+                let end = body_start + m.end();
+                push_plugin(
+                    id,
+                    caps.get(3).map(|v| v.as_str()),
+                    start,
+                    end,
+                    content,
+                    lines,
+                    repo_id,
+                    rel_path,
+                    out,
+                );
+            }
+        }
+    }
+
+    for caps in APPLY_PLUGIN.captures_iter(masked) {
+        let m = caps.get(0).expect("capture 0 always present");
+        push_plugin(
+            &caps[1],
+            None,
+            m.start(),
+            m.end(),
+            content,
+            lines,
+            repo_id,
+            rel_path,
+            out,
+        );
+    }
+
+    for caps in TASK.captures_iter(masked) {
+        let m = caps.get(0).expect("capture 0 always present");
+        let Some(name) = caps.get(1).or(caps.get(3)) else {
+            continue;
+        };
+        let start = m.start() + leading_ws(m.as_str());
+        let end = match masked[m.end()..].find(|c: char| !c.is_whitespace()) {
+            Some(rel) if masked[m.end() + rel..].starts_with('{') => {
+                block_end(masked, m.end() + rel)
+            }
+            _ => m.end(),
+        };
+        let mut symbol = make_text_symbol(
+            &format!("tasks.{}", name.as_str()),
+            "function",
+            lines.range(start, end),
+            &content[start..end],
+            repo_id,
+            rel_path,
+            &[],
+            None,
+        );
+        if let Some(task_type) = caps.get(2).or(caps.get(4)) {
+            symbol.attributes = to_attributes(&[("type", task_type.as_str())]);
+        }
+        out.symbols.push(symbol);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn push_plugin(
+    id: &str,
+    version: Option<&str>,
+    start: usize,
+    end: usize,
+    content: &str,
+    lines: &LineIndex,
+    repo_id: &str,
+    rel_path: &str,
+    out: &mut TextExtraction,
+) {
+    let mut symbol = make_text_symbol(
+        &format!("plugins.{id}"),
+        "variable",
+        lines.range(start, end),
+        &content[start..end],
+        repo_id,
+        rel_path,
+        &[],
+        None,
+    );
+    let mut attrs = vec![("id", id)];
+    if let Some(version) = version {
+        attrs.push(("version", version));
+    }
+    symbol.attributes = to_attributes(&attrs);
+    out.symbols.push(symbol);
+}
+
+fn to_attributes(pairs: &[(&str, &str)]) -> Vec<NativeSymbolAttribute> {
+    pairs
+        .iter()
+        .map(|(key, value)| NativeSymbolAttribute {
+            key: key.to_string(),
+            value: value.to_string(),
+        })
+        .collect()
+}
+
+/// Offset just past the `}` matching the `{` at or after `from`, or the end of
+/// the text when braces are unbalanced.
+fn block_end(masked: &str, from: usize) -> usize {
+    closing_brace(masked, from).map_or(masked.len(), |close| close + 1)
+}
+
+/// Index of the `}` matching the `{` at or after `from`, or `None` when
+/// braces are unbalanced. Braces in masked comments are already gone; braces
+/// in strings are rare enough in build scripts to ignore.
+fn closing_brace(masked: &str, from: usize) -> Option<usize> {
+    let open = from + masked[from..].find('{')?;
+    let mut depth = 0usize;
+    for (index, byte) in masked.bytes().enumerate().skip(open) {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn leading_ws(text: &str) -> usize {
+    text.len() - text.trim_start().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_gradle_dependencies_plugins_and_tasks() {
+        let source = r#"plugins {
+    id 'java-library'
+    id "org.springframework.boot" version "3.2.0"
+}
+
+apply plugin: 'jacoco'
+
+dependencies {
+    implementation 'com.google.guava:guava:33.0-jre'
+    testImplementation("org.junit.jupiter:junit-jupiter:5.10.1") // tests
+    api project(':core')
+}
+
+tasks.register('generateDocs', Javadoc) {
+    source = sourceSets.main.allJava
+}
+
+task cleanCache {
+    doLast { delete 'cache' }
+}
+"#;
+        let out = extract_groovy(source, "repo", "app/build.gradle");
+        let names: Vec<&str> = out.symbols.iter().map(|s| s.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "plugins.java-library",
+                "plugins.org.springframework.boot",
+                "plugins.jacoco",
+                "implementation.com.google.guava:guava",
+                "testImplementation.org.junit.jupiter:junit-jupiter",
+                "tasks.generateDocs",
+                "tasks.cleanCache",
+            ]
+        );
+
+        let boot = &out.symbols[1];
+        assert!(boot
+            .attributes
+            .iter()
+            .any(|a| a.key == "version" && a.value == "3.2.0"));
+
+        let guava = &out.symbols[3];
+        assert!(guava
+            .attributes
+            .iter()
+            .any(|a| a.key == "version" && a.value == "33.0-jre"));
+        assert_eq!(out.imports.len(), 1);
+        assert_eq!(out.imports[0].specifier, ":core");
+    }
+
+    #[test]
+    fn unterminated_block_ending_in_multibyte_text_does_not_panic() {
+        let source = "dependencies {\n    implementation 'org.slf4j:slf4j-api:2.0.9'\n    @import\t\u{1F642}";
+        let out = extract_groovy(source, "repo", "build.gradle");
+        let names: Vec<&str> = out.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["implementation.org.slf4j:slf4j-api"]);
+    }
+}
@@ -0,0 +1,237 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::types::{NativeParsedCall, NativeParsedImport, NativeSymbolAttribute};
+
+use super::common::{make_text_symbol, LineIndex};
+use super::TextExtraction;
+
+static HEADING: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^ {0,3}(#{1,6})\s+(.+?)\s*#*\s*$").unwrap());
+static FENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^ {0,3}(`{3,}|~{3,})\s*([\w+#.-]*)").unwrap());
+static LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"!?\[[^\]]*\]\(\s*<?([^)\s>]+)>?(?:\s+"[^"]*")?\s*\)"#).unwrap());
+static INLINE_CODE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"`([^`\n]+)`").unwrap());
+static CODE_PATH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\.{0,2}/?(?:[\w.@-]+/)*[\w.@-]+\.(?:ts|tsx|js|jsx|mjs|cjs|py|go|rs|java|cs|c|h|cpp|hpp|php|sh|lua|vue|proto|json|ya?ml|md|toml|gradle|nix)$").unwrap()
+});
+static CODE_SYMBOL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([A-Za-z_$][\w$]*(?:(?:\.|::)[A-Za-z_$][\w$]*)*)(\(\))?$").unwrap()
+});
+
+struct Section {
+    symbol_index: usize,
+    level: usize,
+}
+
+/// Extract Markdown structure so docs can be linked to the code they
+/// describe:
+///
+/// - ATX headings become `module` symbols spanning their section, with the
+///   heading `level` and the languages of fenced code blocks inside the
+///   section (`codeLanguage`) as attributes;
+/// - relative links (`[guide](../docs/setup.md)`) and inline-code file
+///   paths (`` `src/foo.ts` ``) become imports;
+/// - inline code naming a call or qualified member (`` `parseFiles()` ``,
+///   `` `Indexer.run` ``) becomes a call from the enclosing section.
+///
+/// Headings, links, and code spans inside fenced code blocks are ignored.
+pub fn extract_markdown(content: &str, repo_id: &str, rel_path: &str) -> TextExtraction {
+    let lines = LineIndex::new(content);
+    let mut out = TextExtraction::default();
+    let mut sections: Vec<Section> = Vec::new();
+    let mut section_bounds: Vec<(usize, usize)> = Vec::new();
+    let mut fence: Option<String> = None;
+    let mut offset = 0;
+
+    for line in content.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let text = line.trim_end_matches(['\n', '\r']);
+
+        if let Some(caps) = FENCE.captures(text) {
+            let marker = &caps[1];
+            match &fence {
+                Some(open) if marker.starts_with(open.as_str()) && caps[2].is_empty() => {
+                    fence = None;
+                }
+                Some(_) => {}
+                None => {
+                    fence = Some(marker.to_string());
+                    let language = caps[2].to_ascii_lowercase();
+                    if !language.is_empty() {
+                        if let Some(section) = sections.last() {
+                            let symbol = &mut out.symbols[section.symbol_index];
+                            if !symbol
+                                .attributes
+                                .iter()
+                                .any(|a| a.key == "codeLanguage" && a.value == language)
+                            {
+                                symbol.attributes.push(NativeSymbolAttribute {
+                                    key: "codeLanguage".to_string(),
+                                    value: language,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+            continue;
+        }
+        if fence.is_some() {
+            continue;
+        }
+
+        if let Some(caps) = HEADING.captures(text) {
+            let level = caps[1].len();
+            let title = caps[2].trim().to_string();
+
+            while sections.last().is_some_and(|s| s.level >= level) {
+                let closed = sections.pop().expect("checked non-empty");
+                section_bounds[closed.symbol_index].1 = line_start;
+            }
+
+            let mut symbol = make_text_symbol(
+                &title,
+                "module",
+                lines.range(line_start, line_start + text.len()),
+                text,
+                repo_id,
+                rel_path,
+                &[],
+                None,
+            );
+            symbol.attributes.push(NativeSymbolAttribute {
+                key: "level".to_string(),
+                value: level.to_string(),
+            });
+            sections.push(Section {
+                symbol_index: out.symbols.len(),
+                level,
+            });
+            section_bounds.push((line_start, content.len()));
+            out.symbols.push(symbol);
+            continue;
+        }
+
+        let caller_node_id = sections
+            .last()
+            .map(|s| out.symbols[s.symbol_index].node_id.clone())
+            .unwrap_or_else(|| "<module>".to_string());
+
+        for caps in LINK.captures_iter(text) {
+            let target = caps.get(1).expect("link target group");
+            if let Some(import) = link_import(target.as_str(), &lines, line_start, text) {
+                out.imports.push(import);
+            }
+        }
+
+        for caps in INLINE_CODE.captures_iter(text) {
+            let whole = caps.get(0).expect("capture 0 always present");
+            let code = caps[1].trim();
+            let range = lines.range(line_start + whole.start(), line_start + whole.end());
+
+            if CODE_PATH.is_match(code) {
+                out.imports.push(NativeParsedImport {
+                    specifier: code.to_string(),
+                    is_relative: code.starts_with('.'),
+                    is_external: false,
+                    named_imports: Vec::new(),
+                    default_import: None,
+                    namespace_import: None,
+                    is_re_export: false,
+                    range,
+                    ..Default::default()
+                });
+            } else if let Some(symbol) = CODE_SYMBOL.captures(code) {
+                let name = &symbol[1];
+                let is_call = symbol.get(2).is_some();
+                let is_member = name.contains('.') || name.contains("::");
+                if !is_call && !is_member {
+                    continue;
+                }
+                out.calls.push(NativeParsedCall {
+                    caller_node_id: caller_node_id.clone(),
+                    callee_identifier: name.to_string(),
+                    call_type: if is_member { "method" } else { "function" }.to_string(),
+                    range,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    // Stretch each heading symbol over its whole section now that the next
+    // heading of the same or higher level is known. The start position (and
+    // therefore the node id) is unchanged.
+    for (symbol, (start, end)) in out.symbols.iter_mut().zip(section_bounds) {
+        let end = start + content[start..end].trim_end().len();
+        symbol.range = lines.range(start, end);
+    }
+
+    out
+}
+
+fn link_import(
+    target: &str,
+    lines: &LineIndex,
+    line_start: usize,
+    text: &str,
+) -> Option<NativeParsedImport> {
+    if target.starts_with('#')
+        || target.contains("://")
+        || target.starts_with("//")
+        || target.starts_with("mailto:")
+    {
+        return None;
+    }
+
+    let path = target.split(['#', '?']).next().unwrap_or(target);
+    if path.is_empty() {
+        return None;
+    }
+
+    Some(NativeParsedImport {
+        specifier: path.to_string(),
+        // Leading `/` is repo-root relative on every major Git host.
+        is_relative: !path.starts_with('/'),
+        is_external: false,
+        named_imports: Vec::new(),
+        default_import: None,
+        namespace_import: None,
+        is_re_export: false,
+        range: lines.range(line_start, line_start + text.len()),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_sections_links_and_code_references() {
+        let source = "# Indexer\n\nSee [setup](./docs/setup.md#install) and [site](https://example.com).\n\n## Parsing\n\nCall `parseFiles()` from `src/indexer/parser.ts`.\n\n```ts\n# not a heading\n```\n\n# API\n";
+        let out = extract_markdown(source, "repo", "README.md");
+
+        let names: Vec<&str> = out.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Indexer", "Parsing", "API"]);
+
+        let indexer = &out.symbols[0];
+        assert_eq!(indexer.range.start_line, 1);
+        assert_eq!(indexer.range.end_line, 11);
+        assert!(out.symbols[1]
+            .attributes
+            .iter()
+            .any(|a| a.key == "codeLanguage" && a.value == "ts"));
+
+        let specifiers: Vec<&str> = out.imports.iter().map(|i| i.specifier.as_str()).collect();
+        assert_eq!(specifiers, vec!["./docs/setup.md", "src/indexer/parser.ts"]);
+
+        assert_eq!(out.calls.len(), 1);
+        assert_eq!(out.calls[0].callee_identifier, "parseFiles");
+        assert_eq!(out.calls[0].caller_node_id, out.symbols[1].node_id);
+    }
+}
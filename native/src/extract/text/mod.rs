@@ -0,0 +1,41 @@
+//! Line/regex-based extractors for formats that have no tree-sitter grammar
+//! wired into the native addon. They produce the same symbol/import/call
+//! records as the AST extractors so downstream indexing is format-agnostic.
+
+use crate::types::{NativeParsedCall, NativeParsedImport, NativeParsedSymbol};
+
+pub mod common;
+mod groovy;
+mod markdown;
+mod nix;
+mod proto;
+mod yaml;
+
+/// Output of a text extractor for a single file.
+#[derive(Default)]
+pub struct TextExtraction {
+    pub symbols: Vec<NativeParsedSymbol>,
+    pub imports: Vec<NativeParsedImport>,
+    pub calls: Vec<NativeParsedCall>,
+}
+
+/// Whether `language` is handled by a text extractor rather than tree-sitter.
+pub fn is_text_language(language: &str) -> bool {
+    matches!(language, "groovy" | "markdown" | "nix" | "proto" | "yaml")
+}
+
+pub fn extract_text(
+    content: &str,
+    repo_id: &str,
+    rel_path: &str,
+    language: &str,
+) -> TextExtraction {
+    match language {
+        "groovy" => groovy::extract_groovy(content, repo_id, rel_path),
+        "markdown" => markdown::extract_markdown(content, repo_id, rel_path),
+        "nix" => nix::extract_nix(content, repo_id, rel_path),
+        "proto" => proto::extract_proto(content, repo_id, rel_path),
+        "yaml" => yaml::extract_yaml(content, repo_id, rel_path),
+        _ => TextExtraction::default(),
+    }
+}
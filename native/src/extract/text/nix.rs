@@ -0,0 +1,251 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::extract::symbols::common::ParamInfo;
+use crate::types::NativeParsedImport;
+
+use super::common::{make_text_symbol, LineIndex};
+use super::TextExtraction;
+
+static BINDING: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]*([A-Za-z_][\w'.-]*)\s*=[^=>]").unwrap());
+static SIMPLE_LAMBDA: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([A-Za-z_][\w'-]*)\s*:(?:\s|$)").unwrap());
+static FORMALS_LAMBDA: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\{([^{}]*)\}\s*(?:@\s*[A-Za-z_][\w'-]*\s*)?:").unwrap());
+static IMPORT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(import|callPackage)\s+(\.{0,2}/[\w./+-]+|<[\w./+-]+>)").unwrap()
+});
+
+/// Extract Nix expressions:
+///
+/// - top-level bindings (`let` bindings and attributes of the outermost
+///   attribute set, but not attributes nested in their values) as symbols;
+///   bindings whose value is a lambda (`x: ...` or `{ a, b ? 1, ... }: ...`)
+///   are `function`s with the lambda arguments as params, everything else
+///   is a `variable`;
+/// - `import ./path.nix`, `import <nixpkgs>`, and `callPackage ./pkg { }`
+///   as imports, so package graphs built from `callPackage` can be traced.
+pub fn extract_nix(content: &str, repo_id: &str, rel_path: &str) -> TextExtraction {
+    let masked = mask_nix(content);
+    let lines = LineIndex::new(content);
+    let depths = brace_depths(&masked);
+    let mut out = TextExtraction::default();
+    let mut last_end = 0;
+
+    for caps in BINDING.captures_iter(&masked) {
+        let name = caps.get(1).expect("binding name group");
+        // Skip bindings nested inside the value of an earlier binding, and
+        // anything below the outermost attribute set.
+        if name.start() < last_end || depths[name.start()] > 1 {
+            continue;
+        }
+        let eq = masked[name.end()..]
+            .find('=')
+            .map(|i| name.end() + i + 1)
+            .unwrap_or(name.end());
+        let end = binding_end(&masked, eq);
+        last_end = end;
+        let value = masked[eq..end].trim_start();
+
+        let (kind, params) = if let Some(lambda) = FORMALS_LAMBDA.captures(value) {
+            ("function", formal_params(&lambda[1]))
+        } else if let Some(lambda) = SIMPLE_LAMBDA.captures(value) {
+            (
+                "function",
+                vec![ParamInfo {
+                    name: lambda[1].to_string(),
+                    type_annotation: None,
+                }],
+            )
+        } else {
+            ("variable", Vec::new())
+        };
+
+        out.symbols.push(make_text_symbol(
+            name.as_str(),
+            kind,
+            lines.range(name.start(), end),
+            &content[name.start()..end],
+            repo_id,
+            rel_path,
+            &params,
+            None,
+        ));
+    }
+
+    for caps in IMPORT.captures_iter(&masked) {
+        let whole = caps.get(0).expect("capture 0 always present");
+        let target = &caps[2];
+        let is_search_path = target.starts_with('<');
+        out.imports.push(NativeParsedImport {
+            specifier: target.trim_matches(['<', '>']).to_string(),
+            is_relative: target.starts_with('.'),
+            is_external: is_search_path,
+            named_imports: Vec::new(),
+            default_import: None,
+            namespace_import: None,
+            is_re_export: false,
+            range: lines.range(whole.start(), whole.end()),
+            ..Default::default()
+        });
+    }
+
+    out
+}
+
+fn formal_params(formals: &str) -> Vec<ParamInfo> {
+    formals
+        .split(',')
+        .filter_map(|formal| {
+            let name = formal.split('?').next()?.trim();
+            if name.is_empty() || name == "..." {
+                return None;
+            }
+            Some(ParamInfo {
+                name: name.to_string(),
+                type_annotation: None,
+            })
+        })
+        .collect()
+}
+
+/// Offset just past the `;` that terminates the binding whose value starts
+/// at `from`, skipping over nested braces, brackets, and parens.
+fn binding_end(masked: &str, from: usize) -> usize {
+    let mut depth = 0i32;
+    for (index, byte) in masked.bytes().enumerate().skip(from) {
+        match byte {
+            b'{' | b'[' | b'(' => depth += 1,
+            b'}' | b']' | b')' => {
+                depth -= 1;
+                if depth < 0 {
+                    return index;
+                }
+            }
+            b';' if depth == 0 => return index + 1,
+            _ => {}
+        }
+    }
+    masked.len()
+}
+
+/// Brace depth at every byte offset (`{` increments after itself).
+fn brace_depths(masked: &str) -> Vec<usize> {
+    let mut depths = Vec::with_capacity(masked.len() + 1);
+    let mut depth = 0usize;
+    for byte in masked.bytes() {
+        depths.push(depth);
+        match byte {
+            b'{' => depth += 1,
+            b'}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    depths.push(depth);
+    depths
+}
+
+/// Blank out comments (`#`, `/* */`) and the contents of strings (`"..."`
+/// and `''...''`) so braces and `=` inside them don't affect structure.
+/// Newlines are kept so offsets and line numbers are unchanged.
+fn mask_nix(content: &str) -> String {
+    let bytes = content.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+
+    let blank = |out: &mut Vec<u8>, from: usize, to: usize| {
+        for slot in &mut out[from..to] {
+            if *slot != b'\n' {
+                *slot = b' ';
+            }
+        }
+    };
+
+    while i < bytes.len() {
+        if bytes[i] == b'#' {
+            let end = content[i..].find('\n').map_or(bytes.len(), |p| i + p);
+            blank(&mut out, i, end);
+            i = end;
+        } else if bytes[i..].starts_with(b"/*") {
+            let end = content[i + 2..]
+                .find("*/")
+                .map_or(bytes.len(), |p| i + 2 + p + 2);
+            blank(&mut out, i, end);
+            i = end;
+        } else if bytes[i] == b'"' {
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j] != b'"' {
+                j += if bytes[j] == b'\\' { 2 } else { 1 };
+            }
+            let end = j.min(bytes.len());
+            blank(&mut out, i + 1, end);
+            i = end + 1;
+        } else if bytes[i..].starts_with(b"''") {
+            let mut j = i + 2;
+            while j < bytes.len() {
+                if bytes[j..].starts_with(b"'''") {
+                    j += 3;
+                } else if bytes[j..].starts_with(b"''") {
+                    break;
+                } else {
+                    j += 1;
+                }
+            }
+            let end = j.min(bytes.len());
+            blank(&mut out, i + 2, end);
+            i = end + 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    String::from_utf8(out).unwrap_or_else(|_| content.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_bindings_and_package_edges() {
+        let source = r#"{ pkgs ? import <nixpkgs> { } }:
+let
+  # helper
+  mkTool = { name, version ? "1.0", ... }: pkgs.stdenv.mkDerivation {
+    inherit name version;
+    buildPhase = ''
+      echo "{ not = a binding; }"
+    '';
+  };
+in {
+  cli = pkgs.callPackage ./pkgs/cli { };
+  lib = import ./lib/default.nix { inherit pkgs; };
+  greet = who: "hello ${who}";
+}
+"#;
+        let out = extract_nix(source, "repo", "default.nix");
+        let names: Vec<(&str, &str)> = out
+            .symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.kind.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("mkTool", "function"),
+                ("cli", "variable"),
+                ("lib", "variable"),
+                ("greet", "function"),
+            ]
+        );
+
+        let specifiers: Vec<&str> = out.imports.iter().map(|i| i.specifier.as_str()).collect();
+        assert_eq!(
+            specifiers,
+            vec!["nixpkgs", "./pkgs/cli", "./lib/default.nix"]
+        );
+        assert!(out.imports[0].is_external);
+    }
+}
@@ -0,0 +1,217 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::extract::symbols::common::ParamInfo;
+use crate::types::NativeParsedImport;
+
+use super::common::{make_text_symbol, mask_comments, LineIndex};
+use super::TextExtraction;
+
+static TOKEN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?x)
+        \b(?P<kw>message|enum|service|oneof|extend)\s+(?P<name>[A-Za-z_][\w.]*)\s*\{
+        | \brpc\s+(?P<rpc>\w+)\s*\(\s*(?P<req>(?:stream\s+)?[\w.]+)\s*\)
+          \s*returns\s*\(\s*(?P<res>(?:stream\s+)?[\w.]+)\s*\)
+        | \bpackage\s+(?P<pkg>[\w.]+)\s*;
+        | \bimport\s+(?P<imp_mod>public\s+|weak\s+)?"(?P<imp>[^"]+)"\s*;
+        | "(?:[^"\\\n]|\\.)*"
+        | '(?:[^'\\\n]|\\.)*'
+        | (?P<open>\{)
+        | (?P<close>\})
+        "#,
+    )
+    .unwrap()
+});
+
+struct Scope {
+    name: Option<String>,
+    kind: &'static str,
+    start: usize,
+}
+
+/// Extract protobuf IDL: `package` (module), messages (class), enums (type),
+/// services (interface), and RPCs (method, `Service.Rpc`, with the request
+/// type as the single param and the response type as the return). Nested
+/// declarations are qualified with their parents (`Outer.Inner`). `import`
+/// statements become imports; `import public` is a re-export.
+pub fn extract_proto(content: &str, repo_id: &str, rel_path: &str) -> TextExtraction {
+    let masked = mask_comments(content, "//", Some(("/*", "*/")));
+    let lines = LineIndex::new(content);
+    let mut out = TextExtraction::default();
+    let mut scopes: Vec<Scope> = Vec::new();
+
+    for caps in TOKEN.captures_iter(&masked) {
+        let whole = caps.get(0).expect("capture 0 always present");
+
+        if let (Some(kw), Some(name)) = (caps.name("kw"), caps.name("name")) {
+            let kind = match kw.as_str() {
+                "message" => "class",
+                "enum" => "type",
+                "service" => "interface",
+                // `oneof` groups fields and `extend` targets another type;
+                // neither declares a new named type.
+                _ => "",
+            };
+            scopes.push(Scope {
+                name: (!kind.is_empty()).then(|| name.as_str().to_string()),
+                kind,
+                start: whole.start(),
+            });
+        } else if caps.name("open").is_some() {
+            scopes.push(Scope {
+                name: None,
+                kind: "",
+                start: whole.start(),
+            });
+        } else if caps.name("close").is_some() {
+            let Some(scope) = scopes.pop() else {
+                continue;
+            };
+            let Some(name) = scope.name else {
+                continue;
+            };
+            let qualified = qualify(&scopes, &name);
+            let end = whole.end();
+            out.symbols.push(make_text_symbol(
+                &qualified,
+                scope.kind,
+                lines.range(scope.start, end),
+                &content[scope.start..end],
+                repo_id,
+                rel_path,
+                &[],
+                None,
+            ));
+        } else if let Some(rpc) = caps.name("rpc") {
+            let service = qualify(&scopes, "");
+            let name = format!("{}{}", service, rpc.as_str());
+            let params = [ParamInfo {
+                name: "request".to_string(),
+                type_annotation: caps.name("req").map(|m| normalize_type(m.as_str())),
+            }];
+            let returns = caps.name("res").map(|m| normalize_type(m.as_str()));
+            out.symbols.push(make_text_symbol(
+                &name,
+                "method",
+                lines.range(whole.start(), whole.end()),
+                whole.as_str(),
+                repo_id,
+                rel_path,
+                &params,
+                returns.as_deref(),
+            ));
+        } else if let Some(pkg) = caps.name("pkg") {
+            out.symbols.push(make_text_symbol(
+                pkg.as_str(),
+                "module",
+                lines.range(whole.start(), whole.end()),
+                whole.as_str(),
+                repo_id,
+                rel_path,
+                &[],
+                None,
+            ));
+        } else if let Some(spec) = caps.name("imp") {
+            let specifier = spec.as_str().to_string();
+            out.imports.push(NativeParsedImport {
+                // Proto imports are resolved against include roots, never the
+                // importing file's directory.
+                is_relative: false,
+                is_external: specifier.starts_with("google/protobuf/"),
+                named_imports: Vec::new(),
+                default_import: None,
+                namespace_import: None,
+                is_re_export: caps
+                    .name("imp_mod")
+                    .is_some_and(|m| m.as_str().trim() == "public"),
+                range: lines.range(whole.start(), whole.end()),
+                specifier,
+                ..Default::default()
+            });
+        }
+    }
+
+    // Declarations are closed innermost-first; restore source order.
+    out.symbols
+        .sort_by_key(|symbol| (symbol.range.start_line, symbol.range.start_col));
+    out
+}
+
+/// Join the names of enclosing named scopes with `.` and append `name`. An
+/// empty `name` yields the parent prefix with a trailing dot (or "").
+fn qualify(scopes: &[Scope], name: &str) -> String {
+    let mut parts: Vec<&str> = scopes.iter().filter_map(|s| s.name.as_deref()).collect();
+    if name.is_empty() {
+        return parts.iter().map(|p| format!("{p}.")).collect();
+    }
+    parts.push(name);
+    parts.join(".")
+}
+
+fn normalize_type(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_nested_messages_services_and_imports() {
+        let source = r#"syntax = "proto3";
+package acme.users.v1;
+
+import "google/protobuf/timestamp.proto";
+import public "acme/common.proto";
+
+// A user account.
+message User {
+  string id = 1;
+  message Address { string city = 1; }
+  oneof contact { string email = 2; }
+}
+
+enum Role { ROLE_UNSPECIFIED = 0; }
+
+service UserService {
+  rpc GetUser(GetUserRequest) returns (User) {
+    option (google.api.http) = { get: "/v1/{id=users/*}" };
+  }
+  rpc Watch(stream WatchRequest) returns (stream User);
+}
+"#;
+        let out = extract_proto(source, "repo", "proto/users.proto");
+        let names: Vec<(&str, &str)> = out
+            .symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.kind.as_str()))
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                ("acme.users.v1", "module"),
+                ("User", "class"),
+                ("User.Address", "class"),
+                ("Role", "type"),
+                ("UserService", "interface"),
+                ("UserService.GetUser", "method"),
+                ("UserService.Watch", "method"),
+            ]
+        );
+
+        let watch = out
+            .symbols
+            .iter()
+            .find(|s| s.name == "UserService.Watch")
+            .unwrap();
+        let signature = watch.signature.as_ref().unwrap();
+        assert_eq!(signature.returns.as_deref(), Some("stream User"));
+
+        assert_eq!(out.imports.len(), 2);
+        assert!(out.imports[0].is_external);
+        assert!(out.imports[1].is_re_export);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::types::NativeSymbolAttribute;
+
+use super::common::{make_text_symbol, LineIndex};
+use super::TextExtraction;
+
+/// Fields collected from one YAML document.
+#[derive(Default)]
+struct Manifest {
+    api_version: Option<String>,
+    kind: Option<String>,
+    name: Option<String>,
+    namespace: Option<String>,
+    images: Vec<String>,
+}
+
+/// Extract Kubernetes-style resources (anything with top-level `kind` and
+/// `metadata.name`) from YAML, one symbol per `---`-separated document.
+///
+/// The symbol is named `Kind/name` (e.g. `Deployment/api`) and carries
+/// `apiVersion`, `kind`, `name`, `namespace`, and every container `image` as
+/// attributes, so "what deploys service X" can be answered from the index.
+/// Helm templates are handled as plain text: `{{ ... }}` values are kept
+/// verbatim. YAML without a `kind`/`metadata.name` pair yields nothing.
+pub fn extract_yaml(content: &str, repo_id: &str, rel_path: &str) -> TextExtraction {
+    let lines = LineIndex::new(content);
+    let mut out = TextExtraction::default();
+
+    for (start, end) in split_documents(content) {
+        let doc = &content[start..end];
+        let manifest = parse_manifest(doc);
+        let (Some(kind), Some(name)) = (&manifest.kind, &manifest.name) else {
+            continue;
+        };
+
+        let trimmed_end = start + doc.trim_end().len();
+        let mut symbol = make_text_symbol(
+            &format!("{kind}/{name}"),
+            "module",
+            lines.range(start, trimmed_end),
+            doc,
+            repo_id,
+            rel_path,
+            &[],
+            None,
+        );
+
+        let mut attributes = Vec::new();
+        let mut push = |key: &str, value: &str| {
+            attributes.push(NativeSymbolAttribute {
+                key: key.to_string(),
+                value: value.to_string(),
+            })
+        };
+        if let Some(api_version) = &manifest.api_version {
+            push("apiVersion", api_version);
+        }
+        push("kind", kind);
+        push("name", name);
+        if let Some(namespace) = &manifest.namespace {
+            push("namespace", namespace);
+        }
+        for image in &manifest.images {
+            push("image", image);
+        }
+        symbol.attributes = attributes;
+
+        out.symbols.push(symbol);
+    }
+
+    out
+}
+
+/// Byte ranges of each `---`-separated document, excluding the separators.
+fn split_documents(content: &str) -> Vec<(usize, usize)> {
+    let mut docs = Vec::new();
+    let mut doc_start = 0;
+    let mut offset = 0;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed == "---" || trimmed.starts_with("--- ") || trimmed == "..." {
+            docs.push((doc_start, offset));
+            doc_start = offset + line.len();
+        }
+        offset += line.len();
+    }
+    docs.push((doc_start, content.len()));
+
+    docs.into_iter()
+        .filter(|(start, end)| !content[*start..*end].trim().is_empty())
+        .collect()
+}
+
+fn parse_manifest(doc: &str) -> Manifest {
+    let mut manifest = Manifest::default();
+    let mut in_metadata = false;
+    let mut metadata_child_indent: Option<usize> = None;
+
+    for line in doc.lines() {
+        let without_comment = strip_comment(line);
+        if without_comment.trim().is_empty() {
+            continue;
+        }
+        let indent = without_comment.len() - without_comment.trim_start().len();
+        let body = without_comment.trim();
+
+        if indent == 0 {
+            in_metadata = false;
+            match split_key_value(body) {
+                Some(("kind", value)) => manifest.kind = non_empty(value),
+                Some(("apiVersion", value)) => manifest.api_version = non_empty(value),
+                Some(("metadata", _)) => {
+                    in_metadata = true;
+                    metadata_child_indent = None;
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        if in_metadata {
+            let child_indent = *metadata_child_indent.get_or_insert(indent);
+            if indent == child_indent {
+                match split_key_value(body) {
+                    Some(("name", value)) => manifest.name = non_empty(value),
+                    Some(("namespace", value)) => manifest.namespace = non_empty(value),
+                    _ => {}
+                }
+            }
+        }
+
+        let item = body.strip_prefix("- ").unwrap_or(body).trim_start();
+        if let Some(("image", value)) = split_key_value(item) {
+            if let Some(image) = non_empty(value) {
+                if !manifest.images.contains(&image) {
+                    manifest.images.push(image);
+                }
+            }
+        }
+    }
+
+    manifest
+}
+
+fn split_key_value(body: &str) -> Option<(&str, &str)> {
+    let (key, value) = body.split_once(':')?;
+    let key = key.trim().trim_matches(['"', '\'']);
+    if key.is_empty() || key.contains(' ') {
+        return None;
+    }
+    Some((key, value.trim()))
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim().trim_matches(['"', '\'']).trim();
+    if value.is_empty() || value == "|" || value == ">" {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+/// Drop a trailing `# comment` that isn't inside quotes or a `{{ }}` block.
+fn strip_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    let mut prev = ' ';
+    for (index, ch) in line.char_indices() {
+        match quote {
+            Some(q) if ch == q => quote = None,
+            Some(_) => {}
+            None if ch == '"' || ch == '\'' => quote = Some(ch),
+            None if ch == '#' && prev.is_whitespace() => return &line[..index],
+            None => {}
+        }
+        prev = ch;
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_resources_with_namespace_and_images() {
+        let source = r#"apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: api
+  namespace: prod
+  labels:
+    name: not-the-name
+spec:
+  template:
+    spec:
+      containers:
+        - name: api
+          image: "ghcr.io/acme/api:1.4.2" # pinned
+---
+apiVersion: v1
+kind: Service
+metadata:
+  name: api
+spec:
+  selector:
+    app: api
+"#;
+        let out = extract_yaml(source, "repo", "deploy/api.yaml");
+        let names: Vec<&str> = out.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Deployment/api", "Service/api"]);
+
+        let deployment = &out.symbols[0];
+        let get = |key: &str| {
+            deployment
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.as_str())
+        };
+        assert_eq!(get("namespace"), Some("prod"));
+        assert_eq!(get("image"), Some("ghcr.io/acme/api:1.4.2"));
+        assert_eq!(out.symbols[1].range.start_line, 15);
+    }
+}
@@ -0,0 +1,69 @@
+//! Trigram postings for code search.
+//!
+//! The file's distinct byte trigrams, ASCII case folded, each packed into
+//! the low 24 bits of a `u32` (`b0 << 16 | b1 << 8 | b2`) and sorted. A
+//! search engine intersects the trigrams of a query literal with these
+//! sets to pick candidate files before matching their contents. Trigrams
+//! spanning a line break are skipped since no single-line query can
+//! contain them.
+
+/// Sorted, deduplicated packed trigrams of `content`.
+pub fn trigram_postings(content: &str) -> Vec<u32> {
+    let mut trigrams: Vec<u32> = content
+        .as_bytes()
+        .windows(3)
+        .filter(|window| !window.iter().any(|&byte| byte == b'\n' || byte == b'\r'))
+        .map(|window| {
+            let [a, b, c] = [window[0], window[1], window[2]].map(|byte| byte.to_ascii_lowercase());
+            u32::from(a) << 16 | u32::from(b) << 8 | u32::from(c)
+        })
+        .collect();
+    trigrams.sort_unstable();
+    trigrams.dedup();
+    trigrams
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::test_support::{parse_source, parse_source_with_options};
+    use crate::types::NativeParseOptions;
+
+    fn pack(trigram: &[u8; 3]) -> u32 {
+        u32::from(trigram[0]) << 16 | u32::from(trigram[1]) << 8 | u32::from(trigram[2])
+    }
+
+    #[test]
+    fn postings_are_folded_sorted_and_line_local() {
+        let postings = trigram_postings("Abc\nabcd");
+        assert_eq!(postings, vec![pack(b"abc"), pack(b"bcd")]);
+        assert!(trigram_postings("ab").is_empty());
+    }
+
+    #[test]
+    fn trigram_postings_are_returned_when_requested() {
+        let cases = [
+            ("ts", "search.ts", "export const Needle = 1;\n"),
+            ("markdown", "README.md", "# Needle\n"),
+        ];
+
+        for (language, rel_path, source) in cases {
+            let options = NativeParseOptions {
+                trigrams: Some(true),
+                ..Default::default()
+            };
+
+            let parsed = parse_source_with_options(language, rel_path, source, &options);
+            let plain = parse_source(language, rel_path, source);
+
+            let trigrams = parsed.trigrams.expect("missing trigrams");
+            let needle = u32::from(b'n') << 16 | u32::from(b'e') << 8 | u32::from(b'e');
+            assert!(trigrams.binary_search(&needle).is_ok(), "{language}");
+            assert!(
+                trigrams.windows(2).all(|pair| pair[0] < pair[1]),
+                "{language}"
+            );
+            assert!(plain.trigrams.is_none(), "{language}");
+        }
+    }
+}
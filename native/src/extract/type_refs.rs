@@ -0,0 +1,341 @@
+//! Named types referenced by parameter, return and field annotations.
+//!
+//! `function save(user: User): Promise<Receipt>` uses `User`, `Promise`
+//! and `Receipt`; the TS side turns each record into a USES_TYPE edge from
+//! the enclosing symbol to the type's declaration. The pass is
+//! grammar-agnostic: it finds the annotation through the field names every
+//! supported grammar shares and collects the type names inside it.
+//! Primitives and built-in scalars are left out; generic parameters (`T`)
+//! are kept and simply never resolve.
+
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::types::{NativeParsedSymbol, NativeTypeReference};
+
+use super::calls::common::{extract_range, find_enclosing_symbol, node_text};
+
+/// Parameter nodes; their type is the `type` field.
+const PARAMETER_KINDS: &[&str] = &[
+    "required_parameter",
+    "optional_parameter",
+    "formal_parameter",
+    "spread_parameter",
+    "parameter",
+    "parameter_declaration",
+    "variadic_parameter_declaration",
+    "typed_parameter",
+    "typed_default_parameter",
+    "simple_parameter",
+    "variadic_parameter",
+    "property_promotion_parameter",
+];
+
+/// Function fields holding the return type.
+const RETURN_FIELDS: &[&str] = &["return_type", "result", "returns"];
+
+/// Function *types* (`(u: User) => Receipt`) also have a return type; they
+/// are part of some other annotation and already collected with it.
+const FUNCTION_TYPES: &[&str] = &["function_type", "constructor_type"];
+
+/// Declarations whose `type` field is the return type (Java / C# methods,
+/// C / C++ functions).
+const TYPED_FUNCTIONS: &[&str] = &["method_declaration", "function_definition"];
+
+/// Field / property declarations; their type is the `type` field (or that
+/// of a C# `variable_declaration` child).
+const FIELD_KINDS: &[&str] = &[
+    "public_field_definition",
+    "property_signature",
+    "field_declaration",
+    "property_declaration",
+    "field_definition",
+];
+
+/// Qualified names taken whole (`pkg.Type`, `crate::a::B`, `ns.Type`,
+/// Python's `models.User`).
+const QUALIFIED_TYPES: &[&str] = &[
+    "scoped_type_identifier",
+    "nested_type_identifier",
+    "qualified_type",
+    "qualified_name",
+    "attribute",
+];
+
+/// Names inside a type annotation that are types. `identifier` / `name`
+/// cover Python, C# and PHP, whose grammars have no dedicated type node.
+const TYPE_NAMES: &[&str] = &["type_identifier", "identifier", "name"];
+
+/// Type subtrees that name values or lifetimes, not types.
+const NON_TYPES: &[&str] = &["type_query", "lifetime", "typeof_expression"];
+
+/// Built-in scalar and container names that grammars parse as plain
+/// identifiers (Go's `string`, Python's `int`).
+const BUILTIN_TYPES: &[&str] = &[
+    "any",
+    "bool",
+    "byte",
+    "bytes",
+    "complex",
+    "complex64",
+    "complex128",
+    "dict",
+    "error",
+    "float",
+    "float32",
+    "float64",
+    "frozenset",
+    "int",
+    "int8",
+    "int16",
+    "int32",
+    "int64",
+    "list",
+    "None",
+    "object",
+    "rune",
+    "set",
+    "str",
+    "string",
+    "tuple",
+    "type",
+    "uint",
+    "uint8",
+    "uint16",
+    "uint32",
+    "uint64",
+    "uintptr",
+];
+
+pub fn extract_type_references(
+    root: Node<'_>,
+    source: &[u8],
+    symbols: &[NativeParsedSymbol],
+) -> Vec<NativeTypeReference> {
+    let mut collector = Collector {
+        source,
+        symbols,
+        seen: HashSet::new(),
+        references: Vec::new(),
+    };
+    let mut stack = vec![root];
+
+    while let Some(node) = stack.pop() {
+        let kind = node.kind();
+        if PARAMETER_KINDS.contains(&kind) {
+            // Go's `(Receipt, error)` result list is collected as the return.
+            if let Some(annotation) = node
+                .child_by_field_name("type")
+                .filter(|_| !is_result_parameter(node))
+            {
+                collector.collect(annotation, "parameter");
+            }
+        } else if FIELD_KINDS.contains(&kind) {
+            if let Some(annotation) = field_type(node) {
+                collector.collect(annotation, "field");
+            }
+        } else if kind == "assignment" && is_class_attribute(node) {
+            // Python `name: Type = ...` in a class body.
+            if let Some(annotation) = node.child_by_field_name("type") {
+                collector.collect(annotation, "field");
+            }
+        }
+
+        let return_type = RETURN_FIELDS
+            .iter()
+            .find_map(|field| node.child_by_field_name(field))
+            .or_else(|| {
+                TYPED_FUNCTIONS
+                    .contains(&kind)
+                    .then(|| node.child_by_field_name("type"))
+                    .flatten()
+            })
+            .filter(|_| !FUNCTION_TYPES.contains(&kind));
+        if let Some(annotation) = return_type {
+            collector.collect(annotation, "return");
+        }
+
+        let child_count = node.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = node.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+
+    collector.references
+}
+
+fn field_type(node: Node<'_>) -> Option<Node<'_>> {
+    node.child_by_field_name("type").or_else(|| {
+        let mut cursor = node.walk();
+        let declaration = node
+            .named_children(&mut cursor)
+            .find(|child| child.kind() == "variable_declaration");
+        declaration.and_then(|declaration| declaration.child_by_field_name("type"))
+    })
+}
+
+/// A parameter of a function's return list (Go `func f() (a A, err error)`).
+fn is_result_parameter(node: Node<'_>) -> bool {
+    node.parent().is_some_and(|list| {
+        list.parent()
+            .and_then(|function| function.child_by_field_name("result"))
+            == Some(list)
+    })
+}
+
+fn is_class_attribute(node: Node<'_>) -> bool {
+    node.parent()
+        .filter(|parent| parent.kind() == "expression_statement")
+        .and_then(|statement| statement.parent())
+        .filter(|block| block.kind() == "block")
+        .and_then(|block| block.parent())
+        .is_some_and(|owner| owner.kind() == "class_definition")
+}
+
+struct Collector<'a> {
+    source: &'a [u8],
+    symbols: &'a [NativeParsedSymbol],
+    seen: HashSet<(String, String, String)>,
+    references: Vec<NativeTypeReference>,
+}
+
+impl Collector<'_> {
+    /// Push one record per named type in `annotation`, once per enclosing
+    /// symbol, type and role.
+    fn collect(&mut self, annotation: Node<'_>, role: &str) {
+        let mut stack = vec![annotation];
+        while let Some(node) = stack.pop() {
+            let kind = node.kind();
+            if NON_TYPES.contains(&kind) {
+                continue;
+            }
+            // `x is User`: only the asserted type is a type.
+            if kind == "type_predicate" {
+                stack.extend(node.child_by_field_name("type"));
+                continue;
+            }
+            // `(r: Receipt) => void`: the parameter name is a pattern, not a
+            // type; only its annotation is.
+            if PARAMETER_KINDS.contains(&kind) {
+                stack.extend(node.child_by_field_name("type"));
+                continue;
+            }
+            if QUALIFIED_TYPES.contains(&kind) || TYPE_NAMES.contains(&kind) {
+                let type_name = node_text(node, self.source);
+                if !type_name.is_empty() && !BUILTIN_TYPES.contains(&type_name) {
+                    let caller_node_id = find_enclosing_symbol(annotation, self.symbols);
+                    let key = (
+                        caller_node_id.clone(),
+                        type_name.to_string(),
+                        role.to_string(),
+                    );
+                    if self.seen.insert(key) {
+                        self.references.push(NativeTypeReference {
+                            caller_node_id,
+                            type_name: type_name.to_string(),
+                            role: role.to_string(),
+                            range: extract_range(node),
+                        });
+                    }
+                }
+                continue;
+            }
+
+            let child_count = node.child_count();
+            for i in (0..child_count).rev() {
+                if let Some(child) = node.child(i) {
+                    stack.push(child);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn annotations_record_named_type_references() {
+        let cases = [
+            (
+                "ts",
+                "service.ts",
+                r#"export class Service {
+  private repo: UserRepo;
+
+  save(user: User, onDone: (r: Receipt) => void): Promise<Receipt> {
+    return this.repo.save(user);
+  }
+
+  isAdmin(x: unknown): x is Admin {
+    return typeof x === "object";
+  }
+}
+"#,
+                vec![
+                    ("Service", "UserRepo", "field"),
+                    ("save", "Promise", "return"),
+                    ("save", "Receipt", "return"),
+                    ("save", "User", "parameter"),
+                    ("save", "Receipt", "parameter"),
+                    ("isAdmin", "Admin", "return"),
+                ],
+            ),
+            (
+                "py",
+                "service.py",
+                r#"class Service:
+    repo: UserRepo
+
+    def save(self, user: models.User, tags: list[str]) -> Optional[Receipt]:
+        pass
+"#,
+                vec![
+                    ("Service", "UserRepo", "field"),
+                    ("Service.save", "Optional", "return"),
+                    ("Service.save", "Receipt", "return"),
+                    ("Service.save", "models.User", "parameter"),
+                ],
+            ),
+            (
+                "go",
+                "service.go",
+                r#"package service
+
+func Save(u *models.User, name string) (Receipt, error) {
+	return Receipt{}, nil
+}
+"#,
+                vec![
+                    ("Save", "Receipt", "return"),
+                    ("Save", "models.User", "parameter"),
+                ],
+            ),
+        ];
+
+        for (language, rel_path, source, expected) in cases {
+            let parsed = parse_source(language, rel_path, source);
+
+            let references: Vec<(&str, &str, &str)> = parsed
+                .type_references
+                .iter()
+                .map(|reference| {
+                    (
+                        reference
+                            .caller_node_id
+                            .split(':')
+                            .next()
+                            .unwrap_or_default(),
+                        reference.type_name.as_str(),
+                        reference.role.as_str(),
+                    )
+                })
+                .collect();
+            assert_eq!(references, expected, "{language}");
+        }
+    }
+}
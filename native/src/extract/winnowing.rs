@@ -0,0 +1,209 @@
+//! Winnowed k-gram fingerprints for near-duplicate detection.
+//!
+//! Each function body becomes a stream of normalized tokens (identifiers
+//! become `ID`, literals `LIT`, comments vanish, everything else is its
+//! node kind), so renaming variables or changing constants does not change
+//! it. Every `K` consecutive tokens are hashed, and in each window of `W`
+//! consecutive hashes the smallest is kept (Schleimer et al., "Winnowing",
+//! 2003). Two functions sharing a copied run of at least `K + W - 1` tokens
+//! are guaranteed to share a fingerprint, so the TS side can cluster
+//! near-duplicates by counting shared fingerprints.
+
+use tree_sitter::Node;
+
+use crate::types::NativeParsedSymbol;
+
+use super::calls::common::{node_at_range, node_text};
+use super::string_literals::STRING_KINDS;
+
+/// Tokens per k-gram.
+pub const K: usize = 5;
+
+/// K-gram hashes per winnowing window.
+const W: usize = 4;
+
+const CALLABLE_KINDS: &[&str] = &["function", "method", "constructor"];
+
+/// Leaf kinds that name something, besides the `*identifier` family.
+const NAME_KINDS: &[&str] = &["name", "constant", "word", "variable_name"];
+
+/// Fill `winnow_fingerprints` on every function, method and constructor
+/// with at least `K` tokens.
+pub fn annotate_winnow_fingerprints(
+    symbols: &mut [NativeParsedSymbol],
+    root: Node<'_>,
+    source: &[u8],
+) {
+    for symbol in symbols
+        .iter_mut()
+        .filter(|symbol| CALLABLE_KINDS.contains(&symbol.kind.as_str()))
+    {
+        let Some(node) = node_at_range(root, &symbol.range) else {
+            continue;
+        };
+        let hashes = kgram_hashes(&normalized_tokens(node, source));
+        let fingerprints = winnow(&hashes);
+        symbol.winnow_fingerprints = (!fingerprints.is_empty()).then_some(fingerprints);
+    }
+}
+
+/// Normalized token stream of `node`, in source order.
+pub fn normalized_tokens<'a>(node: Node<'a>, source: &'a [u8]) -> Vec<&'a str> {
+    let mut tokens = Vec::new();
+    let mut stack = vec![node];
+    while let Some(current) = stack.pop() {
+        let kind = current.kind();
+        if kind.contains("comment") {
+            continue;
+        }
+        if STRING_KINDS.contains(&kind) || is_literal(kind) {
+            tokens.push("LIT");
+            continue;
+        }
+        if current.child_count() == 0 {
+            if current.is_named() && (kind.ends_with("identifier") || NAME_KINDS.contains(&kind)) {
+                tokens.push("ID");
+            } else if !current.is_named() {
+                tokens.push(node_text(current, source));
+            } else {
+                tokens.push(kind);
+            }
+            continue;
+        }
+        let child_count = current.child_count();
+        for i in (0..child_count).rev() {
+            if let Some(child) = current.child(i) {
+                stack.push(child);
+            }
+        }
+    }
+    tokens
+}
+
+fn is_literal(kind: &str) -> bool {
+    kind.contains("number")
+        || kind.contains("integer")
+        || kind.contains("float")
+        || kind.ends_with("_literal")
+}
+
+/// Hash of every `K` consecutive tokens; empty when there are fewer.
+pub fn kgram_hashes(tokens: &[&str]) -> Vec<u32> {
+    tokens
+        .windows(K)
+        .map(|kgram| {
+            let mut hash = FNV_OFFSET;
+            for token in kgram {
+                hash = fnv1a(hash, token.as_bytes());
+                hash = fnv1a(hash, &[0]);
+            }
+            fold(hash)
+        })
+        .collect()
+}
+
+/// The minimum hash of each window of `W` (the rightmost on ties), each
+/// kept once, in order of first selection. Fewer than `W` hashes form a
+/// single window.
+fn winnow(hashes: &[u32]) -> Vec<u32> {
+    let mut selected: Vec<u32> = Vec::new();
+    let mut last = None;
+    for (offset, window) in hashes.windows(W.min(hashes.len()).max(1)).enumerate() {
+        let Some((index, &hash)) = window
+            .iter()
+            .enumerate()
+            .rev()
+            .min_by_key(|&(_, &hash)| hash)
+        else {
+            continue;
+        };
+        let position = offset + index;
+        if last != Some(position) {
+            last = Some(position);
+            if !selected.contains(&hash) {
+                selected.push(hash);
+            }
+        }
+    }
+    selected
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a; stable across builds and platforms, unlike `std`'s hasher.
+pub fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Fold to 32 bits so the value survives as a JS number.
+fn fold(hash: u64) -> u32 {
+    ((hash >> 32) ^ hash) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn winnowing_keeps_one_minimum_per_window() {
+        assert_eq!(winnow(&[5, 3, 8, 3, 9, 1, 7]), vec![3, 1]);
+        assert_eq!(winnow(&[4, 2]), vec![2]);
+        assert!(winnow(&[]).is_empty());
+    }
+
+    #[test]
+    fn kgrams_need_k_tokens() {
+        assert!(kgram_hashes(&["a"; K - 1]).is_empty());
+        assert_eq!(kgram_hashes(&["a"; K + 1]).len(), 2);
+    }
+
+    #[test]
+    fn renamed_copies_share_winnow_fingerprints() {
+        let source = r#"export function totalPrice(items: Item[]) {
+  let sum = 0;
+  for (const item of items) {
+    if (item.active) {
+      sum += item.price * 2;
+    }
+  }
+  return sum;
+}
+
+export function totalWeight(parcels: Parcel[]) {
+  let acc = 0;
+  // same loop, different names
+  for (const parcel of parcels) {
+    if (parcel.active) {
+      acc += parcel.weight * 3;
+    }
+  }
+  return acc;
+}
+
+export async function notify(user: User) {
+  await mailer.send(user.email, "Welcome");
+  log.info("sent");
+}
+"#;
+
+        let parsed = parse_source("ts", "dupes.ts", source);
+
+        let fingerprints = |name: &str| {
+            parsed
+                .symbols
+                .iter()
+                .find(|symbol| symbol.name == name)
+                .and_then(|symbol| symbol.winnow_fingerprints.clone())
+                .expect("missing winnow fingerprints")
+        };
+        let price = fingerprints("totalPrice");
+        assert_eq!(price, fingerprints("totalWeight"));
+        assert_ne!(price, fingerprints("notify"));
+    }
+}
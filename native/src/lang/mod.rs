@@ -3,7 +3,8 @@ use tree_sitter::{Language, Parser};
 /// Get the tree-sitter Language for a given language identifier.
 ///
 /// Language identifiers match the config schema: "ts", "tsx", "js", "jsx",
-/// "py", "go", "java", "cs", "c", "cpp", "php", "rs", "kt", "sh".
+/// "py", "go", "java", "cs", "c", "cpp", "php", "rs", "kt", "sh", "lua",
+/// "html", "css", "scss", "json".
 pub fn get_language(lang_id: &str) -> Option<Language> {
     match lang_id {
         "ts" => Some(tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()),
@@ -18,6 +19,12 @@ pub fn get_language(lang_id: &str) -> Option<Language> {
         "php" => Some(tree_sitter_php::LANGUAGE_PHP.into()),
         "rs" => Some(tree_sitter_rust::LANGUAGE.into()),
         "sh" => Some(tree_sitter_bash::LANGUAGE.into()),
+        "lua" => Some(tree_sitter_lua::LANGUAGE.into()),
+        "html" => Some(tree_sitter_html::LANGUAGE.into()),
+        // There is no maintained SCSS grammar for tree-sitter 0.25; the CSS
+        // grammar recovers well enough to find selectors and `@use` rules.
+        "css" | "scss" => Some(tree_sitter_css::LANGUAGE.into()),
+        "json" => Some(tree_sitter_json::LANGUAGE.into()),
         // Kotlin doesn't have an official tree-sitter-kotlin Rust crate yet
         "kt" => None,
         _ => None,
@@ -50,6 +57,21 @@ pub fn extension_to_language(ext: &str) -> Option<&'static str> {
         "rs" => Some("rs"),
         "kt" | "kts" => Some("kt"),
         "sh" | "bash" | "zsh" => Some("sh"),
+        "lua" => Some("lua"),
+        "html" | "htm" => Some("html"),
+        "css" => Some("css"),
+        "scss" => Some("scss"),
+        // Only the manifests `extract::symbols::json` understands are
+        // scanned; see `is_indexed_json_file`.
+        "json" => Some("json"),
+        // Parsed by the SFC front-end in `parse::vue`, not a grammar of its own.
+        "vue" => Some("vue"),
+        // Handled by the line-based extractors in `extract::text`.
+        "proto" => Some("proto"),
+        "md" | "markdown" => Some("markdown"),
+        "groovy" | "gradle" => Some("groovy"),
+        "nix" => Some("nix"),
+        "yaml" | "yml" => Some("yaml"),
         _ => None,
     }
 }
@@ -10,6 +10,7 @@ pub mod error;
 pub mod extract;
 pub mod lang;
 pub mod layout;
+pub mod merkle;
 pub mod pagerank;
 pub mod parse;
 pub mod process;
@@ -31,24 +32,31 @@ pub fn release_windows_library(token: u32) -> napi::Result<()> {
 }
 
 use types::{
-    NativeClusterAssignment, NativeClusterEdge, NativeClusterSymbol, NativeFileInput,
-    NativeParsedFile, NativeProcess, NativeProcessCallEdge, NativeProcessStep, NativeProcessSymbol,
+    NativeClusterAssignment, NativeClusterEdge, NativeClusterSymbol, NativeDirectoryHash,
+    NativeFileInput, NativeManifestFile, NativeParseOptions, NativeParsedFile, NativeProcess,
+    NativeProcessCallEdge, NativeProcessStep, NativeProcessSymbol, NativeScanOptions,
+    NativeScanResult,
 };
 
 #[napi]
-pub fn parse_files(files: Vec<NativeFileInput>, thread_count: u32) -> Vec<NativeParsedFile> {
+pub fn parse_files(
+    files: Vec<NativeFileInput>,
+    thread_count: u32,
+    options: Option<NativeParseOptions>,
+) -> Vec<NativeParsedFile> {
     let count = if thread_count == 0 {
         num_cpus()
     } else {
         thread_count as usize
     };
 
-    parse::parse_files_parallel(&files, count)
+    parse::parse_files_parallel(&files, count, &options.unwrap_or_default())
 }
 
 pub struct ParseFilesTask {
     files: Vec<NativeFileInput>,
     thread_count: usize,
+    options: NativeParseOptions,
 }
 
 impl napi::Task for ParseFilesTask {
@@ -56,7 +64,11 @@ impl napi::Task for ParseFilesTask {
     type JsValue = Vec<NativeParsedFile>;
 
     fn compute(&mut self) -> napi::Result<Self::Output> {
-        Ok(parse::parse_files_parallel(&self.files, self.thread_count))
+        Ok(parse::parse_files_parallel(
+            &self.files,
+            self.thread_count,
+            &self.options,
+        ))
     }
 
     fn resolve(&mut self, _env: napi::Env, output: Self::Output) -> napi::Result<Self::JsValue> {
@@ -68,6 +80,7 @@ impl napi::Task for ParseFilesTask {
 pub fn parse_files_async(
     files: Vec<NativeFileInput>,
     thread_count: u32,
+    options: Option<NativeParseOptions>,
 ) -> napi::bindgen_prelude::AsyncTask<ParseFilesTask> {
     let count = if thread_count == 0 {
         num_cpus()
@@ -78,12 +91,45 @@ pub fn parse_files_async(
     napi::bindgen_prelude::AsyncTask::new(ParseFilesTask {
         files,
         thread_count: count,
+        options: options.unwrap_or_default(),
     })
 }
 
+/// Walk `root_path` for source files (respecting `.gitignore`) and return
+/// them ready for `parse_files`.
+#[napi]
+pub fn scan_directory(
+    root_path: String,
+    repo_id: String,
+    options: Option<NativeScanOptions>,
+) -> napi::Result<Vec<NativeFileInput>> {
+    Ok(scan_directory_with_report(root_path, repo_id, options)?.files)
+}
+
+/// `scan_directory`, also reporting the symlinks that were skipped.
+#[napi]
+pub fn scan_directory_with_report(
+    root_path: String,
+    repo_id: String,
+    options: Option<NativeScanOptions>,
+) -> napi::Result<NativeScanResult> {
+    if !std::path::Path::new(&root_path).is_dir() {
+        return Err(napi::Error::from_reason(format!(
+            "Scan root is not a directory: {root_path}"
+        )));
+    }
+    Ok(scanner::scan_directory(
+        &root_path,
+        &repo_id,
+        &options.unwrap_or_default(),
+    ))
+}
+
+/// Hash content with the algorithm named by `kind` ("sha256", the default,
+/// or "xxh3"), matching `NativeParsedFile::content_hash`.
 #[napi]
-pub fn hash_content_native(content: String) -> String {
-    parse::content_hash::hash_content(&content)
+pub fn hash_content_native(content: String, kind: Option<String>) -> String {
+    parse::content_hash::ContentHashKind::from_option(kind.as_deref()).hash(&content)
 }
 
 #[napi]
@@ -97,6 +143,41 @@ pub fn generate_symbol_id_native(
     extract::symbol_id::generate_symbol_id(&repo_id, &rel_path, &kind, &name, &fingerprint)
 }
 
+/// Path-independent symbol ID, as produced by the "qualified"
+/// `symbol_id_scheme`.
+#[napi]
+pub fn generate_qualified_symbol_id_native(
+    repo_id: String,
+    kind: String,
+    qualified_name: String,
+    fingerprint: String,
+) -> String {
+    extract::symbol_id::generate_qualified_symbol_id(&repo_id, &kind, &qualified_name, &fingerprint)
+}
+
+/// Version of the symbol fingerprint algorithms. Symbols stored with an
+/// older `fingerprint_version` need re-fingerprinting.
+#[napi]
+pub fn get_fingerprint_version() -> u32 {
+    extract::fingerprint::FINGERPRINT_VERSION
+}
+
+/// Merkle manifest of directory hashes built from per-file content hashes.
+#[napi]
+pub fn build_directory_manifest(files: Vec<NativeManifestFile>) -> Vec<NativeDirectoryHash> {
+    merkle::build_manifest(&files)
+}
+
+/// Top-most directories whose manifest hash did not change; no file in
+/// their subtree needs re-processing by an incremental run.
+#[napi]
+pub fn find_unchanged_directories(
+    previous: Vec<NativeDirectoryHash>,
+    current: Vec<NativeDirectoryHash>,
+) -> Vec<String> {
+    merkle::unchanged_subtrees(&previous, &current)
+}
+
 #[napi]
 pub fn compute_clusters(
     symbols: Vec<NativeClusterSymbol>,
@@ -0,0 +1,208 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::parse::content_hash::hash_content;
+use crate::types::{NativeDirectoryHash, NativeManifestFile};
+
+/// Build a Merkle manifest: one hash per directory, computed from the
+/// sorted names and hashes of its files and subdirectories. A directory's
+/// hash changes iff some file beneath it was added, removed or changed, so
+/// an incremental run can compare against the previous manifest and skip
+/// re-parsing and re-summarizing whole unchanged subtrees.
+///
+/// The leaf hashes come from the caller, so building a manifest still
+/// needs every file's hash; the walk itself is not pruned.
+///
+/// The root directory is reported with an empty `path`. Results are sorted
+/// by path.
+pub fn build_manifest(files: &[NativeManifestFile]) -> Vec<NativeDirectoryHash> {
+    // dir path -> (child name -> (is_dir, hash)); BTreeMap keeps children
+    // sorted so the hash is independent of input order.
+    let mut children: BTreeMap<String, BTreeMap<String, (bool, String)>> = BTreeMap::new();
+    let mut file_counts: HashMap<String, u32> = HashMap::new();
+
+    children.entry(String::new()).or_default();
+    for file in files {
+        let rel_path = file.rel_path.trim_start_matches('/');
+        let (dir, name) = split_parent(rel_path);
+        children
+            .entry(dir.to_string())
+            .or_default()
+            .insert(name.to_string(), (false, file.content_hash.clone()));
+
+        // Register every ancestor so empty-of-files intermediate
+        // directories still get an entry, and count the file towards each.
+        let mut ancestor = dir;
+        loop {
+            *file_counts.entry(ancestor.to_string()).or_default() += 1;
+            if ancestor.is_empty() {
+                break;
+            }
+            let (parent, child) = split_parent(ancestor);
+            children
+                .entry(parent.to_string())
+                .or_default()
+                .entry(child.to_string())
+                .or_insert_with(|| (true, String::new()));
+            ancestor = parent;
+        }
+    }
+
+    // Deepest directories first, so every subdirectory is hashed before
+    // its parent reads it.
+    let mut dirs: Vec<String> = children.keys().cloned().collect();
+    dirs.sort_by_key(|d| std::cmp::Reverse(depth(d)));
+
+    let mut hashes: HashMap<String, String> = HashMap::with_capacity(dirs.len());
+    for dir in &dirs {
+        let mut buf = String::new();
+        for (name, (is_dir, hash)) in &children[dir] {
+            let hash = if *is_dir {
+                hashes
+                    .get(&join(dir, name))
+                    .map(String::as_str)
+                    .unwrap_or_default()
+            } else {
+                hash.as_str()
+            };
+            buf.push_str(if *is_dir { "d\0" } else { "f\0" });
+            buf.push_str(name);
+            buf.push('\0');
+            buf.push_str(hash);
+            buf.push('\n');
+        }
+        hashes.insert(dir.clone(), hash_content(&buf));
+    }
+
+    let mut manifest: Vec<NativeDirectoryHash> = hashes
+        .into_iter()
+        .map(|(path, hash)| NativeDirectoryHash {
+            file_count: file_counts.get(&path).copied().unwrap_or(0),
+            path,
+            hash,
+        })
+        .collect();
+    manifest.sort_by(|a, b| a.path.cmp(&b.path));
+    manifest
+}
+
+/// Top-most directories whose hash is the same in both manifests: the
+/// subtrees whose files an incremental run need not re-process. A directory
+/// is reported only when its parent changed (or it is the root), so no
+/// reported path lies beneath another.
+pub fn unchanged_subtrees(
+    previous: &[NativeDirectoryHash],
+    current: &[NativeDirectoryHash],
+) -> Vec<String> {
+    let before: HashMap<&str, &str> = previous
+        .iter()
+        .map(|d| (d.path.as_str(), d.hash.as_str()))
+        .collect();
+    let after: HashMap<&str, &str> = current
+        .iter()
+        .map(|d| (d.path.as_str(), d.hash.as_str()))
+        .collect();
+    let unchanged = |path: &str| after.contains_key(path) && before.get(path) == after.get(path);
+
+    current
+        .iter()
+        .filter(|d| unchanged(&d.path))
+        .filter(|d| d.path.is_empty() || !unchanged(split_parent(&d.path).0))
+        .map(|d| d.path.clone())
+        .collect()
+}
+
+fn split_parent(path: &str) -> (&str, &str) {
+    match path.rfind('/') {
+        Some(i) => (&path[..i], &path[i + 1..]),
+        None => ("", path),
+    }
+}
+
+fn join(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        name.to_string()
+    } else {
+        format!("{dir}/{name}")
+    }
+}
+
+fn depth(dir: &str) -> usize {
+    if dir.is_empty() {
+        0
+    } else {
+        dir.matches('/').count() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(rel_path: &str, hash: &str) -> NativeManifestFile {
+        NativeManifestFile {
+            rel_path: rel_path.to_string(),
+            content_hash: hash.to_string(),
+        }
+    }
+
+    fn hash_of<'a>(manifest: &'a [NativeDirectoryHash], path: &str) -> &'a str {
+        &manifest.iter().find(|d| d.path == path).unwrap().hash
+    }
+
+    #[test]
+    fn test_manifest_covers_every_directory() {
+        let manifest = build_manifest(&[
+            file("README.md", "r"),
+            file("src/a.ts", "a"),
+            file("src/util/b.ts", "b"),
+        ]);
+        let paths: Vec<&str> = manifest.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(paths, vec!["", "src", "src/util"]);
+        let counts: Vec<u32> = manifest.iter().map(|d| d.file_count).collect();
+        assert_eq!(counts, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_manifest_is_order_independent() {
+        let a = build_manifest(&[file("src/a.ts", "a"), file("lib/b.ts", "b")]);
+        let b = build_manifest(&[file("lib/b.ts", "b"), file("src/a.ts", "a")]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_change_propagates_to_ancestors_only() {
+        let before = build_manifest(&[
+            file("src/a.ts", "a"),
+            file("src/util/b.ts", "b"),
+            file("lib/c.ts", "c"),
+        ]);
+        let after = build_manifest(&[
+            file("src/a.ts", "a"),
+            file("src/util/b.ts", "b2"),
+            file("lib/c.ts", "c"),
+        ]);
+        assert_ne!(hash_of(&before, ""), hash_of(&after, ""));
+        assert_ne!(hash_of(&before, "src"), hash_of(&after, "src"));
+        assert_ne!(hash_of(&before, "src/util"), hash_of(&after, "src/util"));
+        assert_eq!(hash_of(&before, "lib"), hash_of(&after, "lib"));
+    }
+
+    #[test]
+    fn test_unchanged_subtrees_reports_top_most() {
+        let before = build_manifest(&[
+            file("src/util/b.ts", "b"),
+            file("src/core/d.ts", "d"),
+            file("lib/x/c.ts", "c"),
+        ]);
+        let after = build_manifest(&[
+            file("src/util/b.ts", "b2"),
+            file("src/core/d.ts", "d"),
+            file("lib/x/c.ts", "c"),
+        ]);
+        assert_eq!(
+            unchanged_subtrees(&before, &after),
+            vec!["lib".to_string(), "src/core".to_string()]
+        );
+        assert_eq!(unchanged_subtrees(&after, &after), vec![String::new()]);
+    }
+}
@@ -1,4 +1,8 @@
+use std::borrow::Cow;
+
 use sha2::{Digest, Sha256};
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+use xxhash_rust::xxh3::xxh3_128;
 
 /// SHA-256 hash of content, returned as lowercase hex.
 /// Exact parity with TypeScript `hashContent(content: string): string`.
@@ -14,6 +18,63 @@ pub fn hash_content(content: &str) -> String {
     hex::encode(hasher.finalize())
 }
 
+/// `text` in Unicode Normalization Form C. macOS file systems and editors
+/// can produce decomposed (NFD) paths and names; normalizing them before
+/// hashing gives a macOS and a Linux checkout of the same repo the same
+/// symbol IDs and fingerprints. File content hashes stay byte-exact.
+/// Borrows when the text is already NFC, which is the common case.
+pub fn to_nfc(text: &str) -> Cow<'_, str> {
+    match is_nfc_quick(text.chars()) {
+        IsNormalized::Yes => Cow::Borrowed(text),
+        _ => Cow::Owned(text.nfc().collect()),
+    }
+}
+
+/// Algorithm used for `NativeParsedFile::content_hash`.
+///
+/// SHA-256 stays the default and is always used for symbol IDs and
+/// fingerprints, which must match the TypeScript side. `Xxh3` is an opt-in
+/// mode for content-change detection only, where collision resistance
+/// against adversarial input does not matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentHashKind {
+    #[default]
+    Sha256,
+    Xxh3,
+}
+
+impl ContentHashKind {
+    /// Parse a `NativeParseOptions::content_hash` value. Unknown or missing
+    /// values fall back to SHA-256.
+    pub fn from_option(value: Option<&str>) -> Self {
+        match value {
+            Some("xxh3") => ContentHashKind::Xxh3,
+            _ => ContentHashKind::Sha256,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ContentHashKind::Sha256 => "sha256",
+            ContentHashKind::Xxh3 => "xxh3",
+        }
+    }
+
+    /// Hash `content` with this algorithm, returned as lowercase hex.
+    pub fn hash(self, content: &str) -> String {
+        match self {
+            ContentHashKind::Sha256 => hash_content(content),
+            ContentHashKind::Xxh3 => hash_content_fast(content),
+        }
+    }
+}
+
+/// 128-bit XXH3 hash of content, returned as 32 lowercase hex chars.
+/// Several times faster than SHA-256 on large files; not cryptographic.
+pub fn hash_content_fast(content: &str) -> String {
+    format!("{:032x}", xxh3_128(content.as_bytes()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +104,41 @@ mod tests {
         let hash = hash_content(content);
         assert_eq!(hash.len(), 64); // SHA-256 hex is always 64 chars
     }
+
+    #[test]
+    fn test_fast_hash_is_stable_and_distinct() {
+        let a = hash_content_fast("hello world");
+        assert_eq!(a.len(), 32);
+        assert_eq!(a, hash_content_fast("hello world"));
+        assert_ne!(a, hash_content_fast("hello world!"));
+    }
+
+    #[test]
+    fn test_kind_from_option() {
+        assert_eq!(ContentHashKind::from_option(None), ContentHashKind::Sha256);
+        assert_eq!(
+            ContentHashKind::from_option(Some("xxh3")),
+            ContentHashKind::Xxh3
+        );
+        assert_eq!(
+            ContentHashKind::from_option(Some("md5")),
+            ContentHashKind::Sha256
+        );
+        assert_eq!(
+            ContentHashKind::Sha256.hash("hello world"),
+            hash_content("hello world")
+        );
+    }
+
+    #[test]
+    fn test_nfc_normalizes_decomposed_text() {
+        let composed = "caf\u{e9}";
+        let decomposed = "cafe\u{301}";
+        assert!(matches!(to_nfc(composed), Cow::Borrowed(_)));
+        assert_eq!(to_nfc(decomposed), composed);
+        assert_eq!(
+            hash_content(&to_nfc(decomposed)),
+            hash_content(&to_nfc(composed))
+        );
+    }
 }
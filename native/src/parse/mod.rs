@@ -1,13 +1,20 @@
 pub mod content_hash;
 pub mod file_reader;
+pub mod resolve;
+pub mod vue;
+#[cfg(test)]
+pub(crate) mod test_support;
 
 use std::panic;
 
 use rayon::prelude::*;
 
 use crate::extract;
+use crate::extract::language::ExtractContext;
 use crate::lang;
-use crate::types::{NativeFileInput, NativeParsedFile};
+use crate::types::{
+    NativeFileInput, NativeParseOptions, NativeParsedFile, NativeParsedSymbol, NativeRange,
+};
 
 /// Stack size per Rayon worker thread (64 MiB). Tree-sitter's C-based parser
 /// can recurse deeply on complex/generated files (e.g. LLVM's deeply-nested
@@ -30,9 +37,25 @@ const MAX_PARSE_FILE_BYTES: usize = 1_500_000; // 1.5 MB
 /// Individual file panics (e.g. tree-sitter C-level crashes) are caught via
 /// `catch_unwind` so they produce a per-file `parse_error` instead of
 /// bringing down the entire Node.js process.
+///
+/// Under the "qualified" `symbol_id_scheme`, symbol IDs that collide across
+/// files of the batch are disambiguated afterwards.
 pub fn parse_files_parallel(
     files: &[NativeFileInput],
     thread_count: usize,
+    options: &NativeParseOptions,
+) -> Vec<NativeParsedFile> {
+    let mut parsed = parse_batch(files, thread_count, options);
+    if options.symbol_id_scheme.as_deref() == Some(extract::symbol_id::QUALIFIED_SCHEME) {
+        extract::symbol_id::disambiguate_qualified_symbol_ids(files, &mut parsed);
+    }
+    parsed
+}
+
+fn parse_batch(
+    files: &[NativeFileInput],
+    thread_count: usize,
+    options: &NativeParseOptions,
 ) -> Vec<NativeParsedFile> {
     // Build a custom thread pool with large stacks. If both the custom pool
     // and global pool fail to build (e.g. OOM under heavy load), we fall back
@@ -53,7 +76,10 @@ pub fn parse_files_parallel(
                     "sdl-mcp-native: all Rayon pools failed ({e1}, {e2}), parsing sequentially"
                 );
                 // Sequential fallback — no parallelism but no crash
-                return files.iter().map(|f| parse_single_file_safe(f)).collect();
+                return files
+                    .iter()
+                    .map(|f| parse_and_resolve(f, options))
+                    .collect();
             }
         },
     };
@@ -61,16 +87,33 @@ pub fn parse_files_parallel(
     pool.install(|| {
         files
             .par_iter()
-            .map(|file| parse_single_file_safe(file))
+            .map(|file| parse_and_resolve(file, options))
             .collect()
     })
 }
 
-/// Wrapper around `parse_single_file` that catches panics from tree-sitter's
-/// C code (or any other unexpected panic) and converts them to a parse error.
-fn parse_single_file_safe(input: &NativeFileInput) -> NativeParsedFile {
+fn parse_and_resolve(input: &NativeFileInput, options: &NativeParseOptions) -> NativeParsedFile {
+    let mut parsed = parse_single_file_safe(input, options);
+    if !parsed.content_hash.is_empty() {
+        let kind = content_hash::ContentHashKind::from_option(options.content_hash.as_deref());
+        parsed.content_hash_kind = Some(kind.as_str().to_string());
+    }
+    resolve::resolve_imports(&mut parsed.imports, input, options);
+    resolve::classify_workspace_imports(&mut parsed.imports, &input.language, options);
+    parsed
+}
+
+/// Wrapper around `parse_single_file_with_options` that catches panics from
+/// tree-sitter's C code (or any other unexpected panic) and converts them to
+/// a parse error.
+fn parse_single_file_safe(
+    input: &NativeFileInput,
+    options: &NativeParseOptions,
+) -> NativeParsedFile {
     let rel_path = input.rel_path.clone();
-    match panic::catch_unwind(panic::AssertUnwindSafe(|| parse_single_file(input))) {
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        parse_single_file_with_options(input, options)
+    })) {
         Ok(result) => result,
         Err(payload) => {
             let msg = if let Some(s) = payload.downcast_ref::<&str>() {
@@ -84,17 +127,24 @@ fn parse_single_file_safe(input: &NativeFileInput) -> NativeParsedFile {
                 rel_path,
                 content_hash: String::new(),
                 content: None,
-                symbols: vec![],
-                imports: vec![],
-                calls: vec![],
                 parse_error: Some(msg),
+                ..Default::default()
             }
         }
     }
 }
 
-/// Parse a single file: read content, compute hash, parse AST, extract all.
+/// Parse a single file with default options.
+#[cfg(test)]
 fn parse_single_file(input: &NativeFileInput) -> NativeParsedFile {
+    parse_single_file_with_options(input, &NativeParseOptions::default())
+}
+
+/// Parse a single file: read content, compute hash, parse AST, extract all.
+fn parse_single_file_with_options(
+    input: &NativeFileInput,
+    options: &NativeParseOptions,
+) -> NativeParsedFile {
     let content = match file_reader::read_file(&input.absolute_path) {
         Ok(c) => c,
         Err(e) => {
@@ -102,28 +152,43 @@ fn parse_single_file(input: &NativeFileInput) -> NativeParsedFile {
                 rel_path: input.rel_path.clone(),
                 content_hash: String::new(),
                 content: None,
-                symbols: vec![],
-                imports: vec![],
-                calls: vec![],
                 parse_error: Some(format!("{e}")),
+                ..Default::default()
             };
         }
     };
 
-    let content_hash = content_hash::hash_content(&content);
+    let content_hash =
+        content_hash::ContentHashKind::from_option(options.content_hash.as_deref()).hash(&content);
 
-    if lang::get_language(&input.language).is_none() {
-        return NativeParsedFile {
-            rel_path: input.rel_path.clone(),
-            content_hash,
-            content: None,
-            symbols: vec![],
-            imports: vec![],
-            calls: vec![],
-            parse_error: Some(format!("Unsupported language: {}", input.language)),
-        };
+    // Vue single-file components are parsed with the TS/JS grammar after
+    // blanking out everything outside their `<script>` blocks.
+    let sfc = (input.language == "vue").then(|| vue::split_sfc(&content));
+    let (language, source) = match &sfc {
+        Some(sfc) => (sfc.language, sfc.source.as_str()),
+        None => (input.language.as_str(), content.as_str()),
+    };
+
+    if extract::text::is_text_language(language) {
+        return parse_text_file(input, options, content, content_hash);
     }
 
+    let extractor = match (
+        lang::get_language(language),
+        extract::language::extractor_for(language),
+    ) {
+        (Some(_), Some(extractor)) => extractor,
+        _ => {
+            return NativeParsedFile {
+                rel_path: input.rel_path.clone(),
+                content_hash,
+                content: None,
+                parse_error: Some(format!("Unsupported language: {}", input.language)),
+                ..Default::default()
+            }
+        }
+    };
+
     // Skip files that are too large — they cause excessive memory usage and
     // risk stack overflows in tree-sitter's C parser on deeply-nested ASTs.
     if content.len() > MAX_PARSE_FILE_BYTES {
@@ -131,53 +196,265 @@ fn parse_single_file(input: &NativeFileInput) -> NativeParsedFile {
             rel_path: input.rel_path.clone(),
             content_hash,
             content: None,
-            symbols: vec![],
-            imports: vec![],
-            calls: vec![],
             parse_error: Some(format!(
                 "File too large for native parser ({} bytes, limit {})",
                 content.len(),
                 MAX_PARSE_FILE_BYTES
             )),
+            ..Default::default()
         };
     }
 
-    let mut parser = lang::create_parser(&input.language);
-    let tree = match parser.as_mut().and_then(|p| p.parse(&content, None)) {
+    let mut parser = lang::create_parser(language);
+    let tree = match parser.as_mut().and_then(|p| p.parse(source, None)) {
         Some(t) => t,
         None => {
             return NativeParsedFile {
                 rel_path: input.rel_path.clone(),
                 content_hash,
                 content: None,
-                symbols: vec![],
-                imports: vec![],
-                calls: vec![],
                 parse_error: Some("tree-sitter parse returned None".into()),
+                ..Default::default()
             };
         }
     };
 
     let root = tree.root_node();
 
+    let ctx = ExtractContext {
+        source: source.as_bytes(),
+        repo_id: &input.repo_id,
+        rel_path: &input.rel_path,
+        module_path: input.module_path.as_deref(),
+    };
+
     // Extract symbols
-    let mut symbols = extract::symbols::extract_symbols(
+    let mut symbols = extractor.symbols(root, &ctx);
+
+    if sfc.is_some() {
+        symbols.push(make_vue_component_symbol(root, source, input));
+    }
+
+    extract::nesting::assign_parent_symbols(&mut symbols);
+    if options.symbol_id_scheme.as_deref() == Some(extract::symbol_id::QUALIFIED_SCHEME) {
+        extract::symbol_id::apply_qualified_symbol_ids(&mut symbols, &input.repo_id);
+    }
+
+    enrich_symbols(&mut symbols, source, language, &input.rel_path);
+    extract::metrics::annotate_symbol_metrics(
+        &mut symbols,
         root,
-        content.as_bytes(),
-        &input.repo_id,
+        source.as_bytes(),
+        options.halstead_metrics.unwrap_or(false),
+    );
+    extract::mutations::annotate_param_mutations(&mut symbols, root, source.as_bytes());
+    extract::invariants::annotate_code_invariants(&mut symbols, root, source.as_bytes(), language);
+    extract::invariants::annotate_test_expectations(
+        &mut symbols,
+        root,
+        source.as_bytes(),
         &input.rel_path,
+    );
+    extract::dead_code::flag_unreferenced_symbols(&mut symbols, root, source.as_bytes());
+    extract::winnowing::annotate_winnow_fingerprints(&mut symbols, root, source.as_bytes());
+    if options.minhash_signatures.unwrap_or(false) {
+        extract::minhash::annotate_minhash_signatures(&mut symbols, root, source.as_bytes());
+    }
+
+    // Extract imports
+    let imports = extractor.imports(root, &ctx);
+
+    // Extract calls
+    let mut calls = extractor.calls(root, &ctx, &symbols);
+    extract::calls::type_positions::drop_type_position_calls(&mut calls, root);
+    extract::calls::control_flow::annotate_control_flow(&mut calls, root, source.as_bytes());
+    extract::calls::import_hints::annotate_import_hints(&mut calls, &imports, source);
+    extract::calls::receivers::annotate_self_receivers(&mut calls, &symbols, source);
+    extract::calls::receivers::annotate_declared_receivers(&mut calls, root, source);
+    extract::calls::chains::decompose_call_chains(&mut calls, source);
+    extract::calls::sql::annotate_sql_queries(&mut calls, root, source.as_bytes());
+    extract::purity::classify_purity(&mut symbols, &calls, source);
+    let callback_references = extract::calls::callbacks::extract_callback_references(
+        root,
+        source.as_bytes(),
+        &symbols,
+        &imports,
+    );
+
+    // Extract React hook usages
+    let hooks = extractor.hooks(root, &ctx, &symbols);
+
+    // Extract event-emitter listeners and emissions
+    let events = extractor.events(root, &ctx, &symbols);
+
+    let exceptions =
+        extract::exceptions::extract_exception_sites(root, source.as_bytes(), &symbols);
+
+    let type_references =
+        extract::type_refs::extract_type_references(root, source.as_bytes(), &symbols);
+
+    let mut routes = extractor.routes(root, &ctx, &symbols);
+    if let Some(framework) = options.route_framework.as_deref() {
+        if matches!(language, "ts" | "tsx" | "js" | "jsx") {
+            routes.extend(extract::routes::file_conventions::extract_file_routes(
+                framework,
+                &input.rel_path,
+                root,
+                source.as_bytes(),
+                &symbols,
+            ));
+        }
+    }
+
+    let orm_entities = extractor.orm_entities(root, &ctx, &symbols);
+
+    let graphql_resolvers = extractor.graphql_resolvers(root, &ctx, &symbols);
+
+    let grpc_bindings = extractor.grpc_bindings(root, &ctx, &symbols);
+
+    let env_vars = extract::env_vars::extract_env_vars(root, source.as_bytes(), &symbols);
+
+    let log_calls = extract::logging::extract_log_calls(root, source.as_bytes(), &symbols);
+
+    let tagged_templates =
+        extract::tagged_templates::extract_tagged_templates(root, source.as_bytes(), &symbols);
+
+    let identifiers = extract::identifiers::extract_identifiers(root, source.as_bytes());
+
+    let string_literals = if options.string_literals.unwrap_or(false) {
+        extract::string_literals::extract_string_literals(root, source.as_bytes(), &symbols)
+    } else {
+        Vec::new()
+    };
+
+    let secrets = if options.secret_scan.unwrap_or(false) {
+        extract::secrets::extract_secrets(root, source, &symbols)
+    } else {
+        Vec::new()
+    };
+
+    let comments = if options.comments.unwrap_or(false) {
+        extract::comments::extract_comments(root, source.as_bytes(), &symbols)
+    } else {
+        Vec::new()
+    };
+
+    let trigrams = options
+        .trigrams
+        .unwrap_or(false)
+        .then(|| extract::trigrams::trigram_postings(&content));
+
+    // Module-level flags
+    let flags = extractor.file_flags(root, &ctx);
+
+    let line_counts = extract::line_counts::count_lines(root, &content);
+
+    let license = extract::license::detect_license(&content);
+
+    NativeParsedFile {
+        rel_path: input.rel_path.clone(),
+        content_hash,
+        content_hash_kind: None,
+        content: Some(content),
+        symbols,
+        imports,
+        calls,
+        hooks,
+        callback_references,
+        events,
+        exceptions,
+        type_references,
+        routes,
+        orm_entities,
+        graphql_resolvers,
+        grpc_bindings,
+        env_vars,
+        log_calls,
+        tagged_templates,
+        identifiers,
+        string_literals,
+        secrets,
+        comments,
+        trigrams,
+        flags,
+        line_counts: Some(line_counts),
+        license,
+        parse_error: None,
+    }
+}
+
+/// Parse a file handled by one of the line-based extractors in
+/// `extract::text` (formats without a tree-sitter grammar).
+fn parse_text_file(
+    input: &NativeFileInput,
+    options: &NativeParseOptions,
+    content: String,
+    content_hash: String,
+) -> NativeParsedFile {
+    if content.len() > MAX_PARSE_FILE_BYTES {
+        return NativeParsedFile {
+            rel_path: input.rel_path.clone(),
+            content_hash,
+            content: None,
+            parse_error: Some(format!(
+                "File too large for native parser ({} bytes, limit {})",
+                content.len(),
+                MAX_PARSE_FILE_BYTES
+            )),
+            ..Default::default()
+        };
+    }
+
+    let mut extracted =
+        extract::text::extract_text(&content, &input.repo_id, &input.rel_path, &input.language);
+    extract::nesting::assign_parent_symbols(&mut extracted.symbols);
+    if options.symbol_id_scheme.as_deref() == Some(extract::symbol_id::QUALIFIED_SCHEME) {
+        extract::symbol_id::apply_qualified_symbol_ids(&mut extracted.symbols, &input.repo_id);
+    }
+    enrich_symbols(
+        &mut extracted.symbols,
+        &content,
         &input.language,
+        &input.rel_path,
     );
 
-    for symbol in &mut symbols {
-        symbol.summary = extract::summary::generate_summary(symbol, &content, &input.language);
+    let license = extract::license::detect_license(&content);
+
+    let trigrams = options
+        .trigrams
+        .unwrap_or(false)
+        .then(|| extract::trigrams::trigram_postings(&content));
+
+    NativeParsedFile {
+        rel_path: input.rel_path.clone(),
+        content_hash,
+        content: Some(content),
+        symbols: extracted.symbols,
+        imports: extracted.imports,
+        calls: extracted.calls,
+        trigrams,
+        license,
+        parse_error: None,
+        ..Default::default()
+    }
+}
+
+/// Fill in the per-symbol derived fields (summary, quality, invariants,
+/// side effects, role tags, search text) shared by every extractor.
+fn enrich_symbols(
+    symbols: &mut [NativeParsedSymbol],
+    source: &str,
+    language: &str,
+    rel_path: &str,
+) {
+    for symbol in symbols {
+        symbol.summary = extract::summary::generate_summary(symbol, source, language);
 
         // Compute summary quality score
         symbol.summary_quality = if !symbol.summary.is_empty() {
             // Check if summary came from a doc comment by re-extracting
             // (doc comment summaries tend to be longer and don't match auto-gen patterns)
-            let has_doc_comment =
-                extract::summary::has_doc_comment(symbol, &content, &input.language);
+            let has_doc_comment = extract::summary::has_doc_comment(symbol, source, language);
             if has_doc_comment {
                 Some(1.0)
             } else if matches!(symbol.kind.as_str(), "function" | "method" | "constructor") {
@@ -189,48 +466,70 @@ fn parse_single_file(input: &NativeFileInput) -> NativeParsedFile {
             Some(0.0)
         };
 
-        let invariants = extract::invariants::extract_invariants(symbol, &content);
-        symbol.invariants = invariants;
-
-        let side_effects = extract::side_effects::extract_side_effects(symbol, &content);
-        symbol.side_effects = side_effects;
-
-        let role_tags = extract::roles::extract_role_tags(symbol, &input.rel_path);
+        let deprecation = extract::deprecation::detect_deprecation(symbol, source, language);
+        symbol.deprecated = deprecation.is_some();
+        symbol.deprecation_message = deprecation.filter(|message| !message.is_empty());
+
+        let invariants = extract::invariants::extract_invariants(symbol, source);
+        symbol.invariants = invariants
+            .iter()
+            .map(|record| record.text.clone())
+            .collect();
+        symbol.invariant_records = invariants;
+
+        let side_effects = extract::side_effects::extract_side_effects(symbol, source, language);
+        symbol.side_effects = side_effects
+            .iter()
+            .map(|record| record.text.clone())
+            .collect();
+        symbol.side_effect_records = side_effects;
+
+        let role_tags = extract::roles::extract_role_tags(symbol, rel_path);
         symbol.role_tags = role_tags.clone();
-        symbol.search_text =
-            extract::search_text::build_search_text(symbol, &input.rel_path, &role_tags);
+        symbol.search_text = extract::search_text::build_search_text(symbol, rel_path, &role_tags);
     }
+}
 
-    // Extract imports
-    let imports = extract::imports::extract_imports(root, content.as_bytes(), &input.language);
-
-    // Extract calls
-    let calls = extract::calls::extract_calls(root, content.as_bytes(), &symbols, &input.language);
+/// Build the exported component symbol for a `.vue` file, named after the
+/// file (`user-card.vue` -> `UserCard`) and spanning the whole component.
+fn make_vue_component_symbol(
+    root: tree_sitter::Node<'_>,
+    source: &str,
+    input: &NativeFileInput,
+) -> NativeParsedSymbol {
+    let name = vue::component_name(&input.rel_path);
+    let mut symbol = extract::symbols::common::make_symbol(
+        &name,
+        "class",
+        root,
+        source.as_bytes(),
+        &input.repo_id,
+        &input.rel_path,
+        &[],
+        None,
+        &[],
+        "public",
+        &[],
+    );
 
-    NativeParsedFile {
-        rel_path: input.rel_path.clone(),
-        content_hash,
-        content: Some(content),
-        symbols,
-        imports,
-        calls,
-        parse_error: None,
-    }
+    symbol.range = NativeRange {
+        start_line: 1,
+        start_col: 0,
+        end_line: source.lines().count().max(1) as u32,
+        end_col: source.lines().last().map(str::len).unwrap_or(0) as u32,
+    };
+    symbol.node_id = format!("{}:1:0", name);
+    symbol.exported = true;
+    symbol
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
-    use std::time::{SystemTime, UNIX_EPOCH};
+    use crate::parse::test_support::parse_source;
 
     #[test]
     fn go_parser_emits_symbols_for_valid_file() {
-        let unique = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("system clock before UNIX_EPOCH")
-            .as_nanos();
-        let file_path = std::env::temp_dir().join(format!("sdl_mcp_parse_{unique}.go"));
         let source = r#"package main
 
 import "fmt"
@@ -240,17 +539,7 @@ func main() { fmt.Println(add(1, 2)) }
 func add(a int, b int) int { return a + b }
 "#;
 
-        fs::write(&file_path, source).expect("failed to write temporary Go file");
-
-        let input = NativeFileInput {
-            rel_path: "tmp/smoke.go".to_string(),
-            absolute_path: file_path.to_string_lossy().into_owned(),
-            repo_id: "test-repo".to_string(),
-            language: "go".to_string(),
-        };
-
-        let parsed = parse_single_file(&input);
-        let _ = fs::remove_file(file_path);
+        let parsed = parse_source("go", "tmp/smoke.go", source);
 
         assert_eq!(parsed.parse_error.as_deref(), None);
         assert!(
@@ -267,24 +556,13 @@ func add(a int, b int) int { return a + b }
 
     #[test]
     fn oversized_unsupported_language_reports_unsupported_language() {
-        let unique = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .expect("system clock before UNIX_EPOCH")
-            .as_nanos();
-        let file_path = std::env::temp_dir().join(format!("sdl_mcp_parse_{unique}.txt"));
         let large_content = vec![b'x'; MAX_PARSE_FILE_BYTES + 1];
 
-        fs::write(&file_path, large_content).expect("failed to write temporary file");
-
-        let input = NativeFileInput {
-            rel_path: "tmp/oversized.unsupported".to_string(),
-            absolute_path: file_path.to_string_lossy().into_owned(),
-            repo_id: "test-repo".to_string(),
-            language: "unsupported-language".to_string(),
-        };
-
-        let parsed = parse_single_file(&input);
-        let _ = fs::remove_file(file_path);
+        let parsed = parse_source(
+            "unsupported-language",
+            "tmp/oversized.unsupported",
+            large_content,
+        );
 
         assert_eq!(
             parsed.parse_error.as_deref(),
@@ -0,0 +1,311 @@
+//! Import specifier resolution against per-repo options.
+//!
+//! Relative specifiers and tsconfig `paths` aliases are mapped to the
+//! repo-relative file they point at, and imports of sibling monorepo
+//! packages are told apart from third-party ones, so pass-2 can link import
+//! edges without re-resolving every specifier in JS.
+
+use std::path::{Path, PathBuf};
+
+use crate::types::{NativeFileInput, NativeParseOptions, NativeParsedImport, NativePathAlias};
+
+/// Extensions probed for an extensionless specifier, in TypeScript's
+/// resolution order.
+const EXTENSIONS: &[&str] = &[
+    ".ts", ".tsx", ".d.ts", ".js", ".jsx", ".mts", ".cts", ".mjs", ".cjs",
+];
+
+/// ESM TypeScript imports name the emitted file (`./x.js` for `x.ts`).
+const EMITTED_EXTENSIONS: &[(&str, &[&str])] = &[
+    (".js", &[".ts", ".tsx"]),
+    (".jsx", &[".tsx"]),
+    (".mjs", &[".mts"]),
+    (".cjs", &[".cts"]),
+];
+
+/// Set `resolved_rel_path` on each import of a TS/JS file whose specifier is
+/// relative or matches one of `options.path_aliases`, when the target exists
+/// on disk.
+pub fn resolve_imports(
+    imports: &mut [NativeParsedImport],
+    input: &NativeFileInput,
+    options: &NativeParseOptions,
+) {
+    if !matches!(input.language.as_str(), "ts" | "tsx" | "js" | "jsx") || imports.is_empty() {
+        return;
+    }
+    let Some(root) = repo_root(input) else {
+        return;
+    };
+    let aliases = options.path_aliases.as_deref().unwrap_or(&[]);
+
+    for import in imports {
+        import.resolved_rel_path = candidate_bases(&import.specifier, &input.rel_path, aliases)
+            .into_iter()
+            .find_map(|base| probe(&root, &base));
+    }
+}
+
+/// Reclassify external imports of `options.workspace_packages` as
+/// "workspace" (not external).
+pub fn classify_workspace_imports(
+    imports: &mut [NativeParsedImport],
+    language: &str,
+    options: &NativeParseOptions,
+) {
+    let Some(packages) = options.workspace_packages.as_deref() else {
+        return;
+    };
+    if packages.is_empty() {
+        return;
+    }
+
+    for import in imports.iter_mut().filter(|import| import.is_external) {
+        if packages
+            .iter()
+            .any(|package| imports_package(language, &import.specifier, package))
+        {
+            import.is_external = false;
+            import.origin = Some("workspace".to_string());
+        }
+    }
+}
+
+/// Whether `specifier` names `package` or a path inside it, using each
+/// ecosystem's naming rules.
+fn imports_package(language: &str, specifier: &str, package: &str) -> bool {
+    match language {
+        "ts" | "tsx" | "js" | "jsx" => npm_package_name(specifier) == package,
+        // Cargo turns `my-crate` into the `my_crate` path root.
+        "rs" => {
+            let root = specifier.trim_start_matches("::").split("::").next();
+            root == Some(package.replace('-', "_").as_str())
+        }
+        "go" => {
+            specifier == package
+                || specifier
+                    .strip_prefix(package)
+                    .is_some_and(|rest| rest.starts_with('/'))
+        }
+        // Distribution names are case-insensitive and use `-` where the
+        // import name uses `_`.
+        "py" => {
+            let root = specifier.split('.').next().unwrap_or(specifier);
+            root.eq_ignore_ascii_case(&package.replace('-', "_"))
+        }
+        _ => false,
+    }
+}
+
+/// `@scope/pkg/sub/path` -> `@scope/pkg`, `pkg/sub` -> `pkg`,
+/// `npm:pkg@^2/sub` -> `pkg`.
+fn npm_package_name(specifier: &str) -> &str {
+    let specifier = specifier.strip_prefix("npm:").unwrap_or(specifier);
+    let segments = if specifier.starts_with('@') { 2 } else { 1 };
+    let name = match specifier.match_indices('/').nth(segments - 1) {
+        Some((index, _)) => &specifier[..index],
+        None => specifier,
+    };
+    // A version range follows the last `@` that is not the scope marker.
+    match name.rfind('@') {
+        Some(index) if index > 0 => &name[..index],
+        _ => name,
+    }
+}
+
+/// The repo root is the absolute path with the relative path's segments
+/// removed.
+fn repo_root(input: &NativeFileInput) -> Option<PathBuf> {
+    let depth = input.rel_path.split('/').filter(|s| !s.is_empty()).count();
+    Path::new(&input.absolute_path)
+        .ancestors()
+        .nth(depth)
+        .map(Path::to_path_buf)
+}
+
+/// Repo-relative paths the specifier may refer to, before probing
+/// extensions.
+fn candidate_bases(specifier: &str, rel_path: &str, aliases: &[NativePathAlias]) -> Vec<String> {
+    if specifier.starts_with("./") || specifier.starts_with("../") {
+        let dir = rel_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+        return normalize(&format!("{dir}/{specifier}"))
+            .into_iter()
+            .collect();
+    }
+
+    let Some((alias, wildcard)) = match_alias(specifier, aliases) else {
+        return Vec::new();
+    };
+    alias
+        .targets
+        .iter()
+        .filter_map(|target| normalize(&target.replacen('*', wildcard, 1)))
+        .collect()
+}
+
+/// The alias whose pattern matches `specifier` with the longest literal
+/// prefix (tsconfig semantics), and the text matched by its `*`.
+fn match_alias<'a, 'b>(
+    specifier: &'b str,
+    aliases: &'a [NativePathAlias],
+) -> Option<(&'a NativePathAlias, &'b str)> {
+    let mut best: Option<(&NativePathAlias, &str, usize)> = None;
+    for alias in aliases {
+        let matched = match alias.pattern.split_once('*') {
+            Some((prefix, suffix)) => specifier
+                .strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(suffix))
+                .map(|wildcard| (wildcard, prefix.len())),
+            None if alias.pattern == specifier => Some(("", usize::MAX)),
+            None => None,
+        };
+        if let Some((wildcard, rank)) = matched {
+            if best.is_none_or(|(_, _, best_rank)| rank > best_rank) {
+                best = Some((alias, wildcard, rank));
+            }
+        }
+    }
+    best.map(|(alias, wildcard, _)| (alias, wildcard))
+}
+
+/// Collapse `.` / `..` segments. `None` when the path climbs above the repo
+/// root.
+fn normalize(path: &str) -> Option<String> {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            _ => segments.push(segment),
+        }
+    }
+    Some(segments.join("/"))
+}
+
+/// First existing file among `base`, its TypeScript source for an emitted
+/// `.js` name, `base` + extension, and `base/index` + extension.
+fn probe(root: &Path, base: &str) -> Option<String> {
+    let mut probes = vec![base.to_string()];
+    for (emitted, sources) in EMITTED_EXTENSIONS {
+        if let Some(stem) = base.strip_suffix(emitted) {
+            probes.extend(sources.iter().map(|ext| format!("{stem}{ext}")));
+        }
+    }
+    probes.extend(EXTENSIONS.iter().map(|ext| format!("{base}{ext}")));
+    probes.extend(EXTENSIONS.iter().map(|ext| format!("{base}/index{ext}")));
+
+    probes
+        .into_iter()
+        .filter(|candidate| !candidate.is_empty())
+        .find(|candidate| root.join(candidate).is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alias(pattern: &str, targets: &[&str]) -> NativePathAlias {
+        NativePathAlias {
+            pattern: pattern.to_string(),
+            targets: targets.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn relative_specifiers_are_joined_with_the_importing_directory() {
+        assert_eq!(
+            candidate_bases("../lib/util", "src/app/main.ts", &[]),
+            vec!["src/lib/util"]
+        );
+        assert_eq!(candidate_bases("./x", "main.ts", &[]), vec!["x"]);
+        assert!(candidate_bases("../../x", "src/main.ts", &[]).is_empty());
+    }
+
+    #[test]
+    fn longest_alias_prefix_wins() {
+        let aliases = [
+            alias("@app/*", &["src/*"]),
+            alias("@app/ui/*", &["packages/ui/src/*", "vendor/ui/*"]),
+            alias("config", &["config/index.ts"]),
+        ];
+        assert_eq!(
+            candidate_bases("@app/ui/button", "src/main.ts", &aliases),
+            vec!["packages/ui/src/button", "vendor/ui/button"]
+        );
+        assert_eq!(
+            candidate_bases("@app/models/user", "src/main.ts", &aliases),
+            vec!["src/models/user"]
+        );
+        assert_eq!(
+            candidate_bases("config", "src/main.ts", &aliases),
+            vec!["config/index.ts"]
+        );
+        assert!(candidate_bases("react", "src/main.ts", &aliases).is_empty());
+    }
+
+    #[test]
+    fn workspace_packages_are_matched_per_ecosystem() {
+        let cases = [
+            ("ts", "@acme/ui/button", "@acme/ui", true),
+            ("ts", "@acme/ui-kit", "@acme/ui", false),
+            ("js", "shared/utils", "shared", true),
+            ("ts", "npm:@acme/ui@^2/button", "@acme/ui", true),
+            ("rs", "acme_core::config", "acme-core", true),
+            (
+                "go",
+                "github.com/acme/app/pkg/db",
+                "github.com/acme/app",
+                true,
+            ),
+            (
+                "go",
+                "github.com/acme/application",
+                "github.com/acme/app",
+                false,
+            ),
+            ("py", "acme_models.user", "acme-models", true),
+            ("java", "com.acme.Model", "com.acme", false),
+        ];
+        for (language, specifier, package, expected) in cases {
+            assert_eq!(
+                imports_package(language, specifier, package),
+                expected,
+                "{language} {specifier}"
+            );
+        }
+    }
+
+    #[test]
+    fn probing_finds_sources_behind_extensionless_and_emitted_names() {
+        let root = std::env::temp_dir().join(format!(
+            "sdl_mcp_resolve_{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("system clock before UNIX_EPOCH")
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(root.join("src/models")).expect("failed to create temp dirs");
+        std::fs::write(root.join("src/util.ts"), "").expect("failed to write util.ts");
+        std::fs::write(root.join("src/models/index.ts"), "").expect("failed to write index.ts");
+
+        let resolved = [
+            probe(&root, "src/util"),
+            probe(&root, "src/util.js"),
+            probe(&root, "src/models"),
+            probe(&root, "src/missing"),
+        ];
+        let _ = std::fs::remove_dir_all(&root);
+
+        assert_eq!(
+            resolved,
+            [
+                Some("src/util.ts".to_string()),
+                Some("src/util.ts".to_string()),
+                Some("src/models/index.ts".to_string()),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers for tests that run a source snippet through `parse_single_file`.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::types::{NativeFileInput, NativeParseOptions, NativeParsedFile};
+
+use super::{parse_single_file, parse_single_file_with_options};
+
+static NEXT_FILE: AtomicUsize = AtomicUsize::new(0);
+
+/// Parses `source` as the file `rel_path` written in `language`.
+pub(crate) fn parse_source(
+    language: &str,
+    rel_path: &str,
+    source: impl AsRef<[u8]>,
+) -> NativeParsedFile {
+    parse_temp_file(language, rel_path, None, source, parse_single_file)
+}
+
+/// `parse_source` for a file of the Go module `module_path`.
+pub(crate) fn parse_source_in_module(
+    language: &str,
+    rel_path: &str,
+    module_path: &str,
+    source: impl AsRef<[u8]>,
+) -> NativeParsedFile {
+    parse_temp_file(
+        language,
+        rel_path,
+        Some(module_path),
+        source,
+        parse_single_file,
+    )
+}
+
+/// `parse_source` with per-repo parse options.
+pub(crate) fn parse_source_with_options(
+    language: &str,
+    rel_path: &str,
+    source: impl AsRef<[u8]>,
+    options: &NativeParseOptions,
+) -> NativeParsedFile {
+    parse_temp_file(language, rel_path, None, source, |input| {
+        parse_single_file_with_options(input, options)
+    })
+}
+
+/// Writes `source` to a temporary file, parses it and removes the file.
+fn parse_temp_file(
+    language: &str,
+    rel_path: &str,
+    module_path: Option<&str>,
+    source: impl AsRef<[u8]>,
+    parse: impl FnOnce(&NativeFileInput) -> NativeParsedFile,
+) -> NativeParsedFile {
+    let file_path = temp_path(rel_path);
+    fs::write(&file_path, source).expect("failed to write temporary source file");
+
+    let input = NativeFileInput {
+        rel_path: rel_path.to_string(),
+        absolute_path: file_path.to_string_lossy().into_owned(),
+        repo_id: "test-repo".to_string(),
+        language: language.to_string(),
+        module_path: module_path.map(str::to_string),
+    };
+    let parsed = parse(&input);
+    let _ = fs::remove_file(file_path);
+    parsed
+}
+
+/// A temporary path unique across the tests of every running test binary:
+/// the process id separates binaries and the counter separates tests within
+/// one. The file name is kept so the extension still matches the language.
+fn temp_path(rel_path: &str) -> PathBuf {
+    let file_name = rel_path.rsplit('/').next().unwrap_or(rel_path);
+    std::env::temp_dir().join(format!(
+        "sdl_mcp_parse_{}_{}_{file_name}",
+        std::process::id(),
+        NEXT_FILE.fetch_add(1, Ordering::Relaxed)
+    ))
+}
@@ -0,0 +1,180 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static SCRIPT_OPEN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)<script\b([^>]*)>").unwrap());
+static SCRIPT_CLOSE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)</script\s*>").unwrap());
+static LANG_ATTR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?i)\blang\s*=\s*["']?([a-z]+)"#).unwrap());
+
+/// Script portion of a Vue single-file component, ready for the TS/JS grammar.
+pub struct SfcScript {
+    /// Grammar to parse `source` with ("ts", "tsx", "js", or "jsx").
+    pub language: &'static str,
+    /// Original file content with everything outside `<script>` blocks
+    /// replaced by spaces. Newlines are kept and byte lengths are unchanged,
+    /// so tree-sitter positions line up with the `.vue` file on disk.
+    pub source: String,
+}
+
+/// Split a `.vue` file into its script blocks.
+///
+/// `<template>` and `<style>` sections are blanked out rather than removed so
+/// that every symbol, import, and call range still points at the right
+/// line/column of the original component file. Both a classic `<script>` and
+/// a `<script setup>` block may be present; they are parsed together as one
+/// module, which matches how the Vue compiler merges them.
+pub fn split_sfc(content: &str) -> SfcScript {
+    let bytes = content.as_bytes();
+    let mut keep = vec![false; bytes.len()];
+    let mut language = "js";
+    let mut offset = 0;
+
+    while let Some(open) = SCRIPT_OPEN.captures_at(content, offset) {
+        let whole = open.get(0).expect("capture 0 always present");
+        let attrs = open.get(1).map(|m| m.as_str()).unwrap_or("");
+        let body_start = whole.end();
+        let body_end = SCRIPT_CLOSE
+            .find_at(content, body_start)
+            .map(|m| m.start())
+            .unwrap_or(content.len());
+
+        if !attrs.trim_end().ends_with('/') {
+            for flag in &mut keep[body_start..body_end] {
+                *flag = true;
+            }
+            language = merge_language(language, script_language(attrs));
+        }
+
+        offset = body_end;
+        if offset >= content.len() {
+            break;
+        }
+    }
+
+    let masked: Vec<u8> = bytes
+        .iter()
+        .zip(keep)
+        .map(|(&byte, kept)| match (kept, byte) {
+            (true, _) | (_, b'\n') | (_, b'\r') => byte,
+            _ => b' ',
+        })
+        .collect();
+
+    SfcScript {
+        language,
+        // Script bodies are copied byte-for-byte and everything else becomes
+        // ASCII, so the result is still valid UTF-8.
+        source: String::from_utf8(masked).unwrap_or_default(),
+    }
+}
+
+/// Derive the component name from the file name (`user-card.vue` -> `UserCard`).
+pub fn component_name(rel_path: &str) -> String {
+    let file_name = rel_path.rsplit('/').next().unwrap_or(rel_path);
+    let stem = file_name.strip_suffix(".vue").unwrap_or(file_name);
+
+    stem.split(['-', '_', '.', ' '])
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn script_language(attrs: &str) -> &'static str {
+    match LANG_ATTR
+        .captures(attrs)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("ts") | Some("typescript") => "ts",
+        Some("tsx") => "tsx",
+        Some("jsx") => "jsx",
+        _ => "js",
+    }
+}
+
+/// Pick a single grammar when blocks disagree: TypeScript supersedes JS, and
+/// the JSX-capable variant wins within each family.
+fn merge_language(current: &'static str, next: &'static str) -> &'static str {
+    fn rank(lang: &str) -> u8 {
+        match lang {
+            "tsx" => 3,
+            "ts" => 2,
+            "jsx" => 1,
+            _ => 0,
+        }
+    }
+    if current == "ts" && next == "jsx" {
+        return "tsx";
+    }
+    if rank(next) > rank(current) {
+        next
+    } else {
+        current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::test_support::parse_source;
+
+    #[test]
+    fn masks_template_and_keeps_script_positions() {
+        let content = "<template>\n  <div>{{ msg }}</div>\n</template>\n<script setup lang=\"ts\">\nimport { ref } from 'vue'\n</script>\n";
+        let sfc = split_sfc(content);
+
+        assert_eq!(sfc.language, "ts");
+        assert_eq!(sfc.source.len(), content.len());
+        assert_eq!(sfc.source.lines().count(), content.lines().count());
+        assert!(!sfc.source.contains("<div>"));
+        assert_eq!(sfc.source.lines().nth(4), Some("import { ref } from 'vue'"));
+    }
+
+    #[test]
+    fn derives_pascal_case_component_name() {
+        assert_eq!(component_name("src/components/user-card.vue"), "UserCard");
+        assert_eq!(component_name("App.vue"), "App");
+    }
+
+    #[test]
+    fn vue_sfc_emits_component_and_script_imports() {
+        let source = r#"<template>
+  <button @click="increment">{{ count }}</button>
+</template>
+
+<script setup lang="ts">
+import { ref } from "vue";
+
+const count = ref(0);
+function increment(): void { count.value++; }
+</script>
+"#;
+
+        let parsed = parse_source("vue", "src/components/counter-button.vue", source);
+
+        assert_eq!(parsed.parse_error.as_deref(), None);
+        assert!(parsed
+            .symbols
+            .iter()
+            .any(|symbol| symbol.name == "CounterButton" && symbol.exported));
+        let increment = parsed
+            .symbols
+            .iter()
+            .find(|symbol| symbol.name == "increment")
+            .expect("expected increment symbol");
+        assert_eq!(increment.range.start_line, 9);
+        assert!(parsed
+            .imports
+            .iter()
+            .any(|import| import.specifier == "vue"));
+    }
+}
@@ -1,32 +1,59 @@
+use ignore::overrides::{Override, OverrideBuilder};
 use ignore::WalkBuilder;
 use std::path::Path;
 
+use crate::extract::symbols::json::is_indexed_json_file;
 use crate::lang::extension_to_language;
-use crate::types::NativeFileInput;
+use crate::types::{NativeFileInput, NativeScanOptions, NativeScanResult, NativeSkippedSymlink};
 
-/// Scan a directory for source files, respecting .gitignore and ignore patterns.
+/// Gitignore-syntax file honored in any directory of the scanned tree, for
+/// excluding files from indexing without touching `.gitignore`.
+pub const SDL_IGNORE_FILENAME: &str = ".sdlmcpignore";
+
+/// Scan a directory for source files, respecting .gitignore, `.sdlmcpignore`
+/// files and ignore patterns.
+///
+/// Returns NativeFileInput entries ready for parse_files_parallel, plus the
+/// symlinks that were not scanned through.
 ///
-/// Returns NativeFileInput entries ready for parse_files_parallel.
+/// Symlinked files are scanned like regular files. Symlinked directories are
+/// skipped unless `NativeScanOptions::follow_symlinks` is set.
+/// When following, a link back to one of its own ancestor directories is
+/// reported as a cycle instead of being walked again.
 pub fn scan_directory(
     root_path: &str,
     repo_id: &str,
-    ignore_patterns: &[String],
-    languages: &[String],
-    max_file_bytes: u64,
-) -> Vec<NativeFileInput> {
+    options: &NativeScanOptions,
+) -> NativeScanResult {
     let root = Path::new(root_path);
     let mut files = Vec::new();
+    let mut skipped_symlinks = Vec::new();
+    let follow_symlinks = options.follow_symlinks.unwrap_or(false);
+
+    let ignore_patterns = options.ignore_patterns.as_deref().unwrap_or_default();
+    let languages = options.languages.as_deref().unwrap_or_default();
+    let max_file_bytes = options.max_file_bytes.map_or(u64::MAX, u64::from);
+    let include = build_include_matcher(root, options.include_globs.as_deref());
 
     let mut builder = WalkBuilder::new(root);
-    builder.hidden(false).git_ignore(true).git_global(false);
-
-    // Add custom ignore patterns
-    for pattern in ignore_patterns {
-        let mut override_builder = ignore::overrides::OverrideBuilder::new(root);
-        if let Ok(_) = override_builder.add(&format!("!{pattern}")) {
-            if let Ok(overrides) = override_builder.build() {
-                builder.overrides(overrides);
-            }
+    builder
+        .hidden(false)
+        .git_ignore(true)
+        .git_global(false)
+        .follow_links(follow_symlinks);
+
+    builder.add_custom_ignore_filename(SDL_IGNORE_FILENAME);
+
+    // Add custom ignore patterns. `overrides` replaces any previous matcher,
+    // so every pattern must go into the same builder. Invalid globs are
+    // skipped.
+    if !ignore_patterns.is_empty() {
+        let mut override_builder = OverrideBuilder::new(root);
+        for pattern in ignore_patterns {
+            let _ = override_builder.add(&format!("!{pattern}"));
+        }
+        if let Ok(overrides) = override_builder.build() {
+            builder.overrides(overrides);
         }
     }
 
@@ -35,9 +62,25 @@ pub fn scan_directory(
     for entry in walker {
         let entry = match entry {
             Ok(e) => e,
-            Err(_) => continue,
+            Err(err) => {
+                if let Some(skipped) = skipped_symlink_for_error(&err, root) {
+                    skipped_symlinks.push(skipped);
+                }
+                continue;
+            }
         };
 
+        // `is_dir` follows the link, so only links to directories are skipped.
+        if entry.depth() > 0 && entry.path_is_symlink() && !follow_symlinks && entry.path().is_dir()
+        {
+            skipped_symlinks.push(NativeSkippedSymlink {
+                path: relative_path(entry.path(), root),
+                target: link_target(entry.path()),
+                reason: "not_followed".to_string(),
+            });
+            continue;
+        }
+
         // Skip directories
         if entry.file_type().map(|ft| ft.is_dir()).unwrap_or(true) {
             continue;
@@ -60,16 +103,33 @@ pub fn scan_directory(
             None => continue,
         };
 
+        // JSON files other than the manifests we extract from are data
+        if lang == "json"
+            && !path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(is_indexed_json_file)
+        {
+            continue;
+        }
+
         // Filter by configured languages
         if !languages.is_empty() && !languages.iter().any(|l| l == lang) {
             continue;
         }
 
+        // Filter by include globs
+        if let Some(include) = &include {
+            if !include.matched(path, false).is_whitelist() {
+                continue;
+            }
+        }
+
         // Compute relative path
-        let rel_path = match path.strip_prefix(root) {
-            Ok(rel) => rel.to_string_lossy().replace('\\', "/"),
-            Err(_) => continue,
-        };
+        if path.strip_prefix(root).is_err() {
+            continue;
+        }
+        let rel_path = relative_path(path, root);
 
         let absolute_path = path.to_string_lossy().to_string();
 
@@ -78,8 +138,204 @@ pub fn scan_directory(
             absolute_path,
             repo_id: repo_id.to_string(),
             language: lang.to_string(),
+            module_path: None,
         });
     }
 
-    files
+    NativeScanResult {
+        files,
+        skipped_symlinks,
+    }
+}
+
+/// Report a walk error caused by a symlink: a cycle back to an ancestor
+/// directory, or a followed link whose target does not exist.
+fn skipped_symlink_for_error(err: &ignore::Error, root: &Path) -> Option<NativeSkippedSymlink> {
+    match err {
+        ignore::Error::Loop { ancestor, child } => Some(NativeSkippedSymlink {
+            path: relative_path(child, root),
+            target: ancestor.to_string_lossy().to_string(),
+            reason: "cycle".to_string(),
+        }),
+        ignore::Error::WithPath { path, err } => {
+            skipped_symlink_for_error(err, root).or_else(|| {
+                let is_link = path
+                    .symlink_metadata()
+                    .map(|m| m.file_type().is_symlink())
+                    .unwrap_or(false);
+                (is_link && !path.exists()).then(|| NativeSkippedSymlink {
+                    path: relative_path(path, root),
+                    target: link_target(path),
+                    reason: "broken".to_string(),
+                })
+            })
+        }
+        ignore::Error::WithDepth { err, .. } | ignore::Error::WithLineNumber { err, .. } => {
+            skipped_symlink_for_error(err, root)
+        }
+        _ => None,
+    }
+}
+
+fn relative_path(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn link_target(path: &Path) -> String {
+    std::fs::read_link(path)
+        .map(|target| target.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Matcher for `NativeScanOptions::include_globs`, or `None` when no globs
+/// are configured (every file is included). Invalid globs are skipped.
+fn build_include_matcher(root: &Path, globs: Option<&[String]>) -> Option<Override> {
+    let globs = globs.filter(|globs| !globs.is_empty())?;
+    let mut builder = OverrideBuilder::new(root);
+    for glob in globs {
+        let _ = builder.add(glob);
+    }
+    builder.build().ok().filter(|include| !include.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_repo() -> std::path::PathBuf {
+        let unique = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock before UNIX_EPOCH")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("sdl_mcp_scan_{unique}"));
+        fs::create_dir_all(root.join("src/nested")).expect("failed to create temp dirs");
+        fs::write(root.join("src/a.ts"), "export const a = 1;").expect("failed to write a.ts");
+        fs::write(root.join("src/nested/b.py"), "b = 2").expect("failed to write b.py");
+        fs::write(root.join("notes.unknownext"), "").expect("failed to write notes");
+        root
+    }
+
+    fn rel_paths(files: &[NativeFileInput]) -> Vec<String> {
+        let mut paths: Vec<String> = files.iter().map(|f| f.rel_path.clone()).collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_scan_filters_by_language_and_glob() {
+        let root = temp_repo();
+        let root_str = root.to_string_lossy().to_string();
+
+        let all = scan_directory(&root_str, "repo", &NativeScanOptions::default()).files;
+        assert_eq!(rel_paths(&all), vec!["src/a.ts", "src/nested/b.py"]);
+        assert!(all.iter().all(|f| f.repo_id == "repo"));
+
+        let ts_only = NativeScanOptions {
+            languages: Some(vec!["ts".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            rel_paths(&scan_directory(&root_str, "repo", &ts_only).files),
+            vec!["src/a.ts"]
+        );
+
+        let nested_only = NativeScanOptions {
+            include_globs: Some(vec!["src/nested/**".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            rel_paths(&scan_directory(&root_str, "repo", &nested_only).files),
+            vec!["src/nested/b.py"]
+        );
+
+        let tiny = NativeScanOptions {
+            max_file_bytes: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            rel_paths(&scan_directory(&root_str, "repo", &tiny).files),
+            vec!["src/nested/b.py"]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_every_ignore_pattern_and_sdlmcpignore_apply() {
+        let root = temp_repo();
+        let root_str = root.to_string_lossy().to_string();
+        fs::create_dir_all(root.join("lib/gen")).expect("failed to create lib/gen");
+        fs::write(root.join("lib/keep.ts"), "").expect("failed to write keep.ts");
+        fs::write(root.join("lib/gen/out.ts"), "").expect("failed to write out.ts");
+        fs::write(root.join("lib/skip.ts"), "").expect("failed to write skip.ts");
+        fs::write(
+            root.join("lib").join(SDL_IGNORE_FILENAME),
+            "gen/\nskip.ts\n",
+        )
+        .expect("failed to write .sdlmcpignore");
+
+        let options = NativeScanOptions {
+            ignore_patterns: Some(vec!["src/a.ts".to_string(), "**/*.py".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            rel_paths(&scan_directory(&root_str, "repo", &options).files),
+            vec!["lib/keep.ts"]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlinks_are_skipped_or_followed_without_cycles() {
+        let root = temp_repo();
+        let root_str = root.to_string_lossy().to_string();
+        std::os::unix::fs::symlink(root.join("src/nested"), root.join("linked"))
+            .expect("failed to create dir symlink");
+        std::os::unix::fs::symlink(&root, root.join("src/loop"))
+            .expect("failed to create cyclic symlink");
+        std::os::unix::fs::symlink(root.join("src/a.ts"), root.join("src/alias.ts"))
+            .expect("failed to create file symlink");
+
+        let skipped = scan_directory(&root_str, "repo", &NativeScanOptions::default());
+        assert_eq!(
+            rel_paths(&skipped.files),
+            vec!["src/a.ts", "src/alias.ts", "src/nested/b.py"]
+        );
+        let mut reasons: Vec<(String, String)> = skipped
+            .skipped_symlinks
+            .iter()
+            .map(|s| (s.path.clone(), s.reason.clone()))
+            .collect();
+        reasons.sort();
+        assert_eq!(
+            reasons,
+            vec![
+                ("linked".to_string(), "not_followed".to_string()),
+                ("src/loop".to_string(), "not_followed".to_string()),
+            ]
+        );
+
+        let follow = NativeScanOptions {
+            follow_symlinks: Some(true),
+            ..Default::default()
+        };
+        let followed = scan_directory(&root_str, "repo", &follow);
+        assert_eq!(
+            rel_paths(&followed.files),
+            vec!["linked/b.py", "src/a.ts", "src/alias.ts", "src/nested/b.py"]
+        );
+        assert!(followed
+            .skipped_symlinks
+            .iter()
+            .any(|s| s.path == "src/loop" && s.reason == "cycle"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
 }
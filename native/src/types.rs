@@ -12,6 +12,110 @@ pub struct NativeFileInput {
     pub repo_id: String,
     /// Language identifier (e.g., "ts", "tsx", "js", "py", "go").
     pub language: String,
+    /// Module path of the enclosing Go module (the `module` line of
+    /// `go.mod`, e.g. "github.com/acme/app"). Used to tell module-internal
+    /// imports apart from third-party ones; ignored for other languages.
+    pub module_path: Option<String>,
+}
+
+/// Options for `scan_directory`.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeScanOptions {
+    /// Gitignore-style patterns to exclude, on top of `.gitignore` and
+    /// `.sdlmcpignore` files.
+    pub ignore_patterns: Option<Vec<String>>,
+    /// Glob patterns relative to the root; when set, only matching files
+    /// are returned.
+    pub include_globs: Option<Vec<String>>,
+    /// Language identifiers to keep (e.g. "ts", "py"); all supported
+    /// languages when unset or empty.
+    pub languages: Option<Vec<String>>,
+    /// Files larger than this many bytes are skipped. No limit when unset.
+    pub max_file_bytes: Option<u32>,
+    /// Walk into symlinked directories. Off by default, in which case they
+    /// are skipped and listed in `NativeScanResult::skipped_symlinks`.
+    /// Symlinked files are scanned either way.
+    pub follow_symlinks: Option<bool>,
+}
+
+/// Result of `scan_directory_with_report`.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeScanResult {
+    /// Files ready for `parse_files`.
+    pub files: Vec<NativeFileInput>,
+    /// Symlinks that were not scanned through.
+    pub skipped_symlinks: Vec<NativeSkippedSymlink>,
+}
+
+/// A symlink `scan_directory` did not follow.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeSkippedSymlink {
+    /// Path of the link relative to the scan root (forward slashes).
+    pub path: String,
+    /// Where the link points: its raw target, or for a cycle the ancestor
+    /// directory it leads back to.
+    pub target: String,
+    /// "not_followed" (following is off), "cycle" (the target contains
+    /// the link) or "broken" (the target does not exist).
+    pub reason: String,
+}
+
+/// Per-repo options for a `parse_files` batch.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeParseOptions {
+    /// tsconfig `compilerOptions.paths` entries with targets already made
+    /// repo-relative (`baseUrl` applied), e.g. `@app/*` -> `["src/*"]`.
+    pub path_aliases: Option<Vec<NativePathAlias>>,
+    /// Names of the packages in this monorepo (pnpm / yarn / npm workspaces,
+    /// Cargo workspace members, Go modules, Python distributions). Imports of
+    /// them get `origin` "workspace" instead of "external".
+    pub workspace_packages: Option<Vec<String>>,
+    /// Add Halstead volume and a maintainability-index estimate to each
+    /// callable's `metrics_json`. Off by default.
+    pub halstead_metrics: Option<bool>,
+    /// Collect string literals into `NativeParsedFile::string_literals`.
+    /// Off by default.
+    pub string_literals: Option<bool>,
+    /// Scan for likely hardcoded secrets into
+    /// `NativeParsedFile::secrets`. Off by default.
+    pub secret_scan: Option<bool>,
+    /// Add a MinHash signature to each callable for similarity search.
+    /// Off by default.
+    pub minhash_signatures: Option<bool>,
+    /// File-system routing convention of the project ("nextjs" or
+    /// "sveltekit"); TS/JS files under its route directories are added to
+    /// `NativeParsedFile::routes`. Unset by default.
+    pub route_framework: Option<String>,
+    /// Collect every comment into `NativeParsedFile::comments`. Off by
+    /// default.
+    pub comments: Option<bool>,
+    /// Compute `NativeParsedFile::trigrams` for code search. Off by
+    /// default.
+    pub trigrams: Option<bool>,
+    /// Algorithm for `NativeParsedFile::content_hash`: "sha256" (default)
+    /// or "xxh3", a much faster non-cryptographic hash for change
+    /// detection. Symbol IDs and fingerprints always use SHA-256.
+    pub content_hash: Option<String>,
+    /// How `NativeParsedSymbol::symbol_id` is derived: "path" (default)
+    /// hashes the file path in, "qualified" uses the enclosing symbols'
+    /// names instead so moving a file keeps its symbol IDs. Qualified IDs
+    /// that collide across files of one batch get a module-path prefix.
+    pub symbol_id_scheme: Option<String>,
+}
+
+/// One tsconfig `paths` entry.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativePathAlias {
+    /// Specifier pattern with at most one `*` (e.g. `"@app/*"`).
+    pub pattern: String,
+    /// Repo-relative targets tried in order; `*` is replaced by the text the
+    /// pattern's `*` matched (e.g. `["src/*"]`).
+    pub targets: Vec<String>,
 }
 
 /// Range within a source file (1-indexed lines, 0-indexed columns).
@@ -38,14 +142,29 @@ pub struct NativeSymbolSignatureParam {
     pub type_name: Option<String>,
 }
 
+/// A generic type parameter: `T extends Foo = Bar` (TS), `T: Clone = Self`
+/// (Rust), `K comparable` (Go), `T extends Comparable<T>` (Java),
+/// `T : class` (C#).
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeTypeParameter {
+    /// Parameter name (e.g. `"T"`, or `"'a"` for a Rust lifetime).
+    pub name: String,
+    /// Constraint as written, without the `extends` / `:` keyword (e.g.
+    /// `"Foo"`, `"Clone + Send"`, `"class, new()"`).
+    pub constraint: Option<String>,
+    /// Default type as written, without the `=` (e.g. `"Bar"`).
+    pub default: Option<String>,
+}
+
 /// Structured representation of a function or method signature.
 ///
 /// `None` fields are omitted rather than serialised as empty arrays/strings,
 /// keeping the napi payload compact. The struct is `None` on the parent symbol
-/// when there are no params, no return type, and no generics (e.g. plain
-/// variables or class declarations).
+/// when there are no params, no return type, no generics, and no modifiers
+/// (e.g. plain variables or class declarations).
 #[napi(object)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct NativeSymbolSignature {
     /// Parameter list. `None` when the symbol has no parameters.
     pub params: Option<Vec<NativeSymbolSignatureParam>>,
@@ -53,11 +172,58 @@ pub struct NativeSymbolSignature {
     pub returns: Option<String>,
     /// Generic type parameters (e.g. `["T", "U extends Serializable"]`).
     pub generics: Option<Vec<String>>,
+    /// Receiver type of a Go method as written (e.g. `"*Server"`).
+    pub receiver: Option<String>,
+    /// Value of an enum member: the initializer as written, or the implicit
+    /// auto-incremented number (e.g. `"\"active\""`, `"2"`).
+    pub value: Option<String>,
+    /// Generic type parameters with their constraint and default, in
+    /// declaration order (e.g. `<T extends Foo = Bar>`).
+    pub type_parameters: Option<Vec<NativeTypeParameter>>,
+    /// Props type of a React component: a referenced type name
+    /// (`"UserCardProps"`) or an inline object type (`"{ label: string }"`).
+    pub props_type: Option<String>,
+    /// Declaration modifiers, in the fixed order `abstract`, `static`,
+    /// `override`, `readonly`, `async`, `generator`. `None` when there are none.
+    pub modifiers: Option<Vec<String>>,
+    /// Parameters the body assigns a new value to (`opts = {...}`), in
+    /// parameter order. `None` when there are none.
+    pub reassigned_params: Option<Vec<String>>,
+    /// Parameters whose properties or elements the body writes, or that it
+    /// calls a mutating method on (`opts.retries = 3`, `items.push(x)`), in
+    /// parameter order. `None` when there are none.
+    pub mutated_params: Option<Vec<String>>,
+}
+
+/// A format-specific key/value fact attached to a symbol, for extractors
+/// whose nodes carry data that has no dedicated field (e.g. the `namespace`
+/// and container `image` of a Kubernetes resource).
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeSymbolAttribute {
+    pub key: String,
+    pub value: String,
+}
+
+/// A decorator / annotation / attribute parsed from its source text.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeDecorator {
+    /// Name without sigil or arguments (e.g. `"Controller"`, `"app.route"`,
+    /// `"derive"`).
+    pub name: String,
+    /// Positional arguments. String literals are unquoted; anything else is
+    /// kept as written (e.g. `@Get(':id')` -> `[":id"]`).
+    pub arguments: Vec<String>,
+    /// Object-literal properties and named arguments (e.g.
+    /// `@Component({ selector: 'app-root' })` -> `selector=app-root`,
+    /// `@RequestMapping(value = "/users")` -> `value=/users`).
+    pub options: Vec<NativeSymbolAttribute>,
 }
 
 /// Extracted symbol from AST analysis.
 #[napi(object)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct NativeParsedSymbol {
     /// Stable per-file nodeId, format `${name}:${startLine}:${startCol}`.
     /// Distinct from `symbol_id` (a cross-repo SHA-256) — `node_id` is the
@@ -94,15 +260,93 @@ pub struct NativeParsedSymbol {
     /// symbol. Includes the leading sigil (e.g. `@Component(...)`, `@override`,
     /// `#[derive(Debug)]`). Empty for languages without a decorator concept.
     pub decorators: Vec<String>,
+    /// Structured form of `decorators`: each decorator's name with its literal
+    /// arguments (e.g. `@Get(':id')`, `@Component({ selector: 'app-root' })`).
+    pub decorator_metadata: Vec<NativeDecorator>,
     /// Search-oriented text including identifier splits, summary, tags, and path hints.
     pub search_text: String,
     /// Summary quality score: 1.0 = doc comment, 0.4 = typed function, 0.3 = heuristic, 0.0 = none.
     pub summary_quality: Option<f64>,
+    /// Language- or format-specific key/value facts (e.g. `owner`, `async`,
+    /// a Kubernetes `namespace`).
+    pub attributes: Vec<NativeSymbolAttribute>,
+    /// `symbol_id` of the innermost symbol containing this one: the class of
+    /// a method, the function around a nested function, the namespace around
+    /// its members. `None` for top-level symbols.
+    pub parent_symbol_id: Option<String>,
+    /// JSON object of code metrics for functions, methods and constructors
+    /// (`{"cyclomatic": 4}`). `None` for other kinds and for files without a
+    /// syntax tree.
+    pub metrics_json: Option<String>,
+    /// Likely dead code: unexported (or `private`) and never mentioned
+    /// elsewhere in its file. Decorated symbols and entry points such as
+    /// `main` are never flagged.
+    pub unreferenced: bool,
+    /// Marked deprecated by an attribute (`#[deprecated]`, `[Obsolete]`,
+    /// `@Deprecated`), its doc comment (`@deprecated`, `Deprecated:`), or a
+    /// Python `DeprecationWarning` in its body.
+    pub deprecated: bool,
+    /// Deprecation note or warning text, when `deprecated` and one is given.
+    pub deprecation_message: Option<String>,
+    /// Winnowed k-gram fingerprints of the body's normalized tokens, for
+    /// near-duplicate detection: functions sharing many of them share code.
+    /// `None` for other kinds and for bodies shorter than one k-gram.
+    pub winnow_fingerprints: Option<Vec<u32>>,
+    /// MinHash signature of the body's k-gram shingles: the share of
+    /// positions two signatures agree on estimates their similarity. Set
+    /// only with `NativeParseOptions::minhash_signatures`, and only for
+    /// functions, methods and constructors.
+    pub minhash_signature: Option<Vec<u32>>,
+    /// Functions and methods only: "pure", "read_only" (reads environment
+    /// or instance state) or "effectful" (I/O, shared-state or parameter
+    /// mutation), including what same-file callees do.
+    pub purity: Option<String>,
+    /// `invariants` with their category, evidence and confidence, in the
+    /// same order.
+    pub invariant_records: Vec<NativeBehaviorRecord>,
+    /// `side_effects` with their category, evidence and confidence, in the
+    /// same order.
+    pub side_effect_records: Vec<NativeBehaviorRecord>,
+    /// Hash of the body alone, without the name or location, so a symbol
+    /// renamed or moved to another file keeps it. Unset for symbols of
+    /// text formats.
+    pub body_fingerprint: Option<String>,
+    /// `get_fingerprint_version()` of the parser that computed
+    /// `ast_fingerprint` and `body_fingerprint`.
+    pub fingerprint_version: u32,
+    /// Hash of the declaration up to the body (the whole symbol if it has
+    /// none), whitespace-insensitive. A change means the symbol's
+    /// interface changed. Unset for symbols of text formats.
+    pub signature_hash: Option<String>,
+    /// Hash of the body text, whitespace-insensitive. A change with an
+    /// unchanged `signature_hash` means only the implementation changed.
+    pub body_hash: Option<String>,
+}
+
+/// An invariant or side effect of a symbol and where it was detected.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeBehaviorRecord {
+    /// The entry as listed in `invariants` / `side_effects`.
+    pub text: String,
+    /// Invariants: "requires", "asserts", "validates", "ensures",
+    /// "expects", "panics", "raises", "propagates" or "throws". Side
+    /// effects: "network", "filesystem", "database", "global_state",
+    /// "dom", "environment" or "process".
+    pub category: String,
+    /// The source text the entry was read from, whitespace-collapsed and
+    /// cut to 160 characters.
+    pub evidence: String,
+    pub range: NativeRange,
+    /// 0-1: how reliably the detector identifies this kind of entry. Line
+    /// patterns that also match unrelated code (`open(`, `.query(`) score
+    /// low; syntax-tree matches and specific APIs score high.
+    pub confidence: f64,
 }
 
 /// Extracted import statement.
 #[napi(object)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct NativeParsedImport {
     /// Module specifier (e.g., "./utils.js", "lodash").
     pub specifier: String,
@@ -121,11 +365,69 @@ pub struct NativeParsedImport {
     pub is_re_export: bool,
     /// Source range.
     pub range: NativeRange,
+    /// Number of leading dots on a Python relative import (`from . import x`
+    /// is 1, `from ..pkg import y` is 2), or of leading `super::` segments on
+    /// a Rust path. `None` for absolute imports and for languages without
+    /// package-relative imports.
+    pub relative_level: Option<u32>,
+    /// Where the imported module lives: "stdlib", "internal" (same module or
+    /// crate as the importing file), "workspace" (another package of the same
+    /// monorepo, see `NativeParseOptions::workspace_packages`), or
+    /// "external". `None` when the extractor does not classify imports
+    /// beyond `is_external`.
+    pub origin: Option<String>,
+    /// Renamed specifiers, original name first (e.g. `export { a as b } from
+    /// './x'` -> `a` / `b`), so re-export chains through barrel files can be
+    /// followed back to the declaring module. `named_imports` keeps only the
+    /// local / exported name.
+    pub aliases: Vec<NativeImportAlias>,
+    /// Whether the module is loaded lazily through `import('./x')` rather
+    /// than a static import statement. Such imports carry no bindings.
+    pub is_dynamic: bool,
+    /// Import attributes (`with { type: 'json' }`, legacy `assert { ... }`,
+    /// or the `with` option of `import('./x.json', { with: { ... } })`).
+    pub attributes: Vec<NativeSymbolAttribute>,
+    /// Repo-relative path of the file the specifier resolves to (relative
+    /// specifiers and `NativeParseOptions::path_aliases`, TS/JS family
+    /// only). `None` for bare package specifiers and unresolvable targets.
+    pub resolved_rel_path: Option<String>,
+    /// How often each imported binding is referenced in the rest of the file
+    /// (TS/JS family and Python). Empty for re-exports and other languages.
+    pub binding_usages: Vec<NativeBindingUsage>,
+    /// Whether this re-exports everything the target exports without naming
+    /// it (`export * from './mod'`, Rust `pub use mod::*`), so barrel files
+    /// can be flattened. `export * as ns` binds a namespace instead and is
+    /// not flagged.
+    pub is_star_re_export: bool,
+    /// URL-like scheme of the specifier, lowercased (`https`, `npm`, `jsr`,
+    /// `node`), TS/JS family only. `None` for bare and relative specifiers.
+    pub scheme: Option<String>,
+}
+
+/// References to one imported binding.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeBindingUsage {
+    /// Local name of the binding.
+    pub name: String,
+    /// Identifier references outside the import itself, matched by name
+    /// (shadowing locals are not told apart). 0 means the import is unused.
+    pub references: u32,
+}
+
+/// A renamed import or re-export specifier.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeImportAlias {
+    /// Name as declared by the source module (`a` in `a as b`).
+    pub imported: String,
+    /// Name bound or re-exported by this file (`b` in `a as b`).
+    pub local: String,
 }
 
 /// Extracted call site.
 #[napi(object)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct NativeParsedCall {
     /// Stable nodeId of the caller (enclosing symbol), format
     /// `${name}:${startLine}:${startCol}`. Must match the `node_id` of a
@@ -139,16 +441,429 @@ pub struct NativeParsedCall {
     pub call_type: String,
     /// Source range.
     pub range: NativeRange,
+    /// Specifier of the import that binds the callee's base identifier
+    /// (`api` in `api.save()`), if any.
+    pub import_specifier: Option<String>,
+    /// Name the callee refers to in that module: the original name of a
+    /// named import, `"default"` for a default import, or the member called
+    /// on a namespace import (`save` in `api.save()`).
+    pub imported_name: Option<String>,
+    /// Type the callee is a member of, when known: the enclosing class for
+    /// `this.save()` / `self.save()`.
+    pub receiver_type: Option<String>,
+    /// Position of this call in a fluent chain (`a.b().c().d()`: `b` is 0,
+    /// `c` is 1, `d` is 2). Later links carry only their own method name as
+    /// `callee_identifier`. `None` when the call is not part of a chain.
+    pub chain_index: Option<u32>,
+    /// Whether the call's result is awaited directly (`await f()`,
+    /// `f().await`); `false` for fire-and-forget or stored promises.
+    pub is_awaited: bool,
+    /// Inside a branch of an `if` / `switch` / `match` / ternary (not its
+    /// condition).
+    pub in_conditional: bool,
+    /// Inside a loop body or comprehension.
+    pub in_loop: bool,
+    /// Inside the body of a `try` block.
+    pub in_try: bool,
+    /// Inside error handling: a `catch` / `except` clause, Go's
+    /// `if err != nil`, a Rust `Err(..)` match arm, or a promise `.catch`
+    /// callback.
+    pub in_error_handler: bool,
+    /// Statement type ("select", "insert", "update", "delete", ...) of the
+    /// SQL literal passed to a query function (`db.query("SELECT ...")`).
+    pub sql_statement: Option<String>,
+    /// Tables that SQL references (schema-qualified as written); empty
+    /// unless `sql_statement` is set.
+    pub sql_tables: Vec<String>,
 }
 
-/// Complete parse result for a single file.
+/// React hook call (`useState`, `useEffect`, custom `useX`) attributed to
+/// the component or hook that makes it.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeHookUsage {
+    /// Hook name without any `React.` prefix (e.g., "useState", "useAuth").
+    pub hook: String,
+    /// nodeId of the enclosing component or custom hook (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Whether the hook is one of React's built-in hooks.
+    pub is_builtin: bool,
+    /// Dependency array entries (`useEffect(fn, [a, b.c])` -> `["a", "b.c"]`).
+    /// `None` when no dependency array is passed.
+    pub dependencies: Option<Vec<String>>,
+    /// Source range of the hook call.
+    pub range: NativeRange,
+}
+
+/// One side of an event-emitter edge: a listener registration
+/// (`emitter.on('ready', onReady)`) or an emission (`emitter.emit('ready')`).
+/// Listeners and emissions of the same `event` pair up into a flow.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeEventUsage {
+    /// Event name string literal.
+    pub event: String,
+    /// "listen" or "emit".
+    pub kind: String,
+    /// Method called ("on", "once", "addListener", "emit", ...).
+    pub method: String,
+    /// Receiver expression as written (e.g., "emitter", "this.bus").
+    pub emitter: String,
+    /// Listener passed by name (`onReady`, `this.handle`); `None` for inline
+    /// functions and emissions.
+    pub handler: Option<String>,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the call.
+    pub range: NativeRange,
+}
+
+/// Where a symbol raises or handles an error (`throw new NotFound()`,
+/// `raise ValueError`, `Err(..)`, `panic!`, `catch (IOException e)`).
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeExceptionSite {
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// "throw", "panic", "error_return" or "catch".
+    pub kind: String,
+    /// Exception / error type as written, when the source names one.
+    pub exception_type: Option<String>,
+    /// Source range of the throw, macro, call or clause.
+    pub range: NativeRange,
+}
+
+/// A named type used by a parameter, return or field annotation: the source
+/// of a USES_TYPE edge from the enclosing symbol to the type.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeTypeReference {
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Type name as written, qualified if it was (`User`, `models.User`).
+    pub type_name: String,
+    /// "parameter", "return" or "field".
+    pub role: String,
+    /// Source range of the first reference.
+    pub range: NativeRange,
+}
+
+/// An ORM model class and the table it maps to.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeOrmEntity {
+    /// "typeorm", "sequelize", "sqlalchemy" or "entity_framework".
+    pub framework: String,
+    /// Class name as declared.
+    pub class_name: String,
+    /// nodeId of the class symbol.
+    pub class_node_id: Option<String>,
+    /// Table name when declared (`@Entity("users")`, `__tablename__`,
+    /// `[Table("users")]`); `None` when the ORM derives it.
+    pub table: Option<String>,
+    /// Mapped columns, in declaration order.
+    pub columns: Vec<NativeOrmColumn>,
+    /// Relations to other entities, in declaration order.
+    pub relations: Vec<NativeOrmRelation>,
+    /// Source range of the class.
+    pub range: NativeRange,
+}
+
+/// A mapped column of an ORM entity.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeOrmColumn {
+    /// Field or property name.
+    pub name: String,
+    /// Declared column type (`"varchar"`, `String(50)`, `DataType.STRING`),
+    /// else the field's type annotation.
+    pub column_type: Option<String>,
+    /// Part of the primary key.
+    pub primary_key: bool,
+}
+
+/// A relation declared on an ORM entity.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeOrmRelation {
+    /// "one_to_one", "one_to_many", "many_to_one", "many_to_many",
+    /// "relationship" (SQLAlchemy, cardinality not declared) or
+    /// "foreign_key".
+    pub kind: String,
+    /// Field or property holding the relation.
+    pub field: String,
+    /// Related entity class (`Photo`), or the referenced column for
+    /// SQLAlchemy foreign keys (`users.id`).
+    pub target: Option<String>,
+}
+
+/// A GraphQL resolver: the function implementing one field of a schema
+/// type (`Query.user`, `User.posts`).
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeGraphqlResolver {
+    /// Schema type the field belongs to ("Query", "Mutation",
+    /// "Subscription" or an object type).
+    pub parent_type: String,
+    /// Schema field name.
+    pub field: String,
+    /// Handler as written; `None` for inline functions.
+    pub handler: Option<String>,
+    /// nodeId of the handler when it is declared in this file.
+    pub handler_node_id: Option<String>,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the map entry, decorated method or registration
+    /// call.
+    pub range: NativeRange,
+}
+
+/// A gRPC service registration (`rpc` unset) or the method implementing
+/// one of its RPCs.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeGrpcBinding {
+    /// Service name from the generated stub (`Greeter` for
+    /// `Greeter.GreeterBase`, `RegisterGreeterServer`, `GreeterServicer`).
+    pub service: String,
+    /// RPC name as implemented (Java methods are lowerCamelCase); `None`
+    /// for a service registration.
+    pub rpc: Option<String>,
+    /// Implementing class or type as written, when known.
+    pub implementation: Option<String>,
+    /// Handler as written; `None` for registrations and inline functions.
+    pub handler: Option<String>,
+    /// nodeId of the handler when it is declared in this file.
+    pub handler_node_id: Option<String>,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the registration call or implementing method.
+    pub range: NativeRange,
+}
+
+/// An HTTP route registration (`app.get("/users/:id", show)`,
+/// `@app.post("/items")`, `@GetMapping("/{id}")`).
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeHttpRoute {
+    /// Upper-case HTTP method; "ALL" when the registration matches any.
+    pub method: String,
+    /// Path pattern as declared (`/users/:id`, `/items/{id}`), joined with
+    /// any class-level prefix.
+    pub path: String,
+    /// Handler as written; `None` for inline functions.
+    pub handler: Option<String>,
+    /// nodeId of the handler when it is declared in this file.
+    pub handler_node_id: Option<String>,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the registration call or annotation.
+    pub range: NativeRange,
+}
+
+/// A read of a named environment variable (`process.env.PORT`,
+/// `os.getenv("HOME")`, `std::env::var("PATH")`).
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeEnvVarUsage {
+    /// Variable name.
+    pub key: String,
+    /// Environment object or reader function as written, whitespace removed
+    /// (e.g., "process.env", "os.getenv", "env!").
+    pub accessor: String,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the access.
+    pub range: NativeRange,
+}
+
+/// A logging call (`logger.info("...")`, `log.Printf(...)`, `warn!(...)`).
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeLogCall {
+    /// "trace", "debug", "info", "warn", "error" or "fatal".
+    pub level: String,
+    /// Logger as written, whitespace removed (e.g., "console", "this.logger",
+    /// "logging", "tracing").
+    pub logger: String,
+    /// First string argument as written, placeholders included; `None` when
+    /// no argument is a string literal.
+    pub message: Option<String>,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the call.
+    pub range: NativeRange,
+}
+
+/// A string literal, tagged with a guessed role so translation keys,
+/// endpoints and queries can be cross-referenced between files.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeStringLiteral {
+    /// Contents without quotes or prefix; escapes are left as written.
+    pub value: String,
+    /// "i18n", "sql", "url" or "path"; `None` when no heuristic matched.
+    pub role: Option<String>,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the literal, quotes included.
+    pub range: NativeRange,
+}
+
+/// A tagged template literal (`` gql`...` ``, `` sql`...` ``,
+/// `` styled.div`...` ``).
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeTaggedTemplate {
+    /// Tag expression as written, whitespace removed (`gql`, `styled.div`,
+    /// `styled(Button)`).
+    pub tag: String,
+    /// Embedded language for known tags: "graphql", "sql", "css" or
+    /// "html".
+    pub language: Option<String>,
+    /// Raw text between the backticks, `${...}` substitutions included.
+    pub content: String,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the tagged template.
+    pub range: NativeRange,
+}
+
+/// An identifier used in a file and how often it occurs.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeIdentifierCount {
+    /// Identifier text.
+    pub name: String,
+    /// Occurrences in the file, declarations included.
+    pub count: u32,
+}
+
+/// A source comment and the symbol it is attached to.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeComment {
+    /// Comment text as written, delimiters included.
+    pub text: String,
+    /// "line", "block" or "doc" (`/**`, `///`, `//!` and `/*!` comments).
+    pub kind: String,
+    /// nodeId of the symbol declared on the line after the comment (or
+    /// after the run of comments it belongs to), or on the same line for
+    /// a trailing comment.
+    pub attached_node_id: Option<String>,
+    /// Source range of the comment.
+    pub range: NativeRange,
+}
+
+/// A likely hardcoded secret. The value itself is never included.
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NativeSecretFinding {
+    /// "aws_access_key", "private_key", "github_token", "slack_token",
+    /// "stripe_key", "google_api_key", or "high_entropy_assignment" for a
+    /// random-looking literal assigned to a credential-like name.
+    pub kind: String,
+    /// Name the literal is assigned to (`apiToken`); `None` for format
+    /// matches.
+    pub name: Option<String>,
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Source range of the match or the assigned literal.
+    pub range: NativeRange,
+}
+
+/// A function passed by name as an argument (`arr.map(transform)`,
+/// `router.get(path, handler)`): an indirect call edge from the enclosing
+/// symbol to the referenced function.
 #[napi(object)]
 #[derive(Debug, Clone)]
+pub struct NativeCallbackReference {
+    /// nodeId of the enclosing symbol (same format as
+    /// `NativeParsedCall::caller_node_id`).
+    pub caller_node_id: String,
+    /// Name of the referenced function as written at the call site.
+    pub callee_identifier: String,
+    /// Callee of the call the function is passed to (e.g., "arr.map",
+    /// "router.get").
+    pub passed_to: String,
+    /// Zero-based position of the argument.
+    pub argument_index: u32,
+    /// For promise handlers (`.then` / `.catch` / `.finally`), the call or
+    /// value the chain starts from (`load` in `load(id).then(a).catch(b)`).
+    pub chain_root: Option<String>,
+    /// Source range of the argument.
+    pub range: NativeRange,
+}
+
+/// Line counts of a file. Every line is exactly one of code, comment or
+/// blank.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeLineCounts {
+    /// Number of lines.
+    pub total: u32,
+    /// Lines with any non-comment, non-whitespace text.
+    pub code: u32,
+    /// Lines holding only comment text.
+    pub comment: u32,
+    /// Empty or whitespace-only lines.
+    pub blank: u32,
+}
+
+/// License and copyright notices from the top of a file.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeLicenseHeader {
+    /// SPDX license expression (`MIT`, `Apache-2.0 OR MIT`, `GPL-3.0`).
+    /// `None` when the header only carries copyright notices.
+    pub license: Option<String>,
+    /// How `license` was found: `spdx` for an `SPDX-License-Identifier:`
+    /// tag, `header` for recognised license prose.
+    pub source: Option<String>,
+    /// Copyright lines without comment markers
+    /// (`Copyright (c) 2024 Acme Inc.`), in file order.
+    pub copyrights: Vec<String>,
+}
+
+/// What a module does when it is first evaluated.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeFileFlags {
+    /// `await` (or `for await`) outside any function body.
+    pub has_top_level_await: bool,
+    /// A top-level statement that runs code on import: calls, assignments,
+    /// control flow, side-effect-only imports (`import "./polyfill"`), or
+    /// declarations initialized by a call. Pure declarations do not count.
+    pub has_top_level_side_effects: bool,
+    /// Directive prologue entries (e.g. `"use client"`, `"use server"`,
+    /// `"use strict"`), without quotes.
+    pub directives: Vec<String>,
+}
+
+/// Complete parse result for a single file.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
 pub struct NativeParsedFile {
     /// Relative path (matches input).
     pub rel_path: String,
-    /// SHA-256 hex digest of file content.
+    /// Hex digest of file content; SHA-256 unless
+    /// `NativeParseOptions::content_hash` selects another algorithm.
     pub content_hash: String,
+    /// Algorithm that produced `content_hash` ("sha256" or "xxh3"). `None`
+    /// when the file could not be read.
+    pub content_hash_kind: Option<String>,
     /// Raw file content (passed through to avoid double-read on JS side).
     /// `None` for error paths where content was unavailable or irrelevant.
     pub content: Option<String>,
@@ -158,10 +873,85 @@ pub struct NativeParsedFile {
     pub imports: Vec<NativeParsedImport>,
     /// Extracted calls.
     pub calls: Vec<NativeParsedCall>,
+    /// React hook usages (TS/JS family only).
+    pub hooks: Vec<NativeHookUsage>,
+    /// Functions passed by name as call arguments.
+    pub callback_references: Vec<NativeCallbackReference>,
+    /// Event-emitter listener registrations and emissions (TS/JS family
+    /// only).
+    pub events: Vec<NativeEventUsage>,
+    /// Throw, panic and error-return sites and caught exception types.
+    pub exceptions: Vec<NativeExceptionSite>,
+    /// Named types used by parameter, return and field annotations, one per
+    /// enclosing symbol, type and role.
+    pub type_references: Vec<NativeTypeReference>,
+    /// HTTP route registrations, plus file-system routes when
+    /// `NativeParseOptions::route_framework` is set.
+    pub routes: Vec<NativeHttpRoute>,
+    /// ORM entity classes (TypeORM, sequelize-typescript, SQLAlchemy,
+    /// Entity Framework).
+    pub orm_entities: Vec<NativeOrmEntity>,
+    /// GraphQL resolvers bound to schema fields.
+    pub graphql_resolvers: Vec<NativeGraphqlResolver>,
+    /// gRPC service registrations and RPC implementations.
+    pub grpc_bindings: Vec<NativeGrpcBinding>,
+    /// Environment variables read by name.
+    pub env_vars: Vec<NativeEnvVarUsage>,
+    /// Logging calls with their level and message.
+    pub log_calls: Vec<NativeLogCall>,
+    /// Tagged template literals (TS/JS family only).
+    pub tagged_templates: Vec<NativeTaggedTemplate>,
+    /// Every identifier in the file with its occurrence count, sorted by
+    /// name.
+    pub identifiers: Vec<NativeIdentifierCount>,
+    /// String literals; empty unless `NativeParseOptions::string_literals`
+    /// is set.
+    pub string_literals: Vec<NativeStringLiteral>,
+    /// Likely hardcoded secrets; empty unless
+    /// `NativeParseOptions::secret_scan` is set.
+    pub secrets: Vec<NativeSecretFinding>,
+    /// Comments in source order; empty unless
+    /// `NativeParseOptions::comments` is set.
+    pub comments: Vec<NativeComment>,
+    /// Distinct byte trigrams of the content, ASCII case folded, packed
+    /// into the low 24 bits of each value and sorted; `None` unless
+    /// `NativeParseOptions::trigrams` is set.
+    pub trigrams: Option<Vec<u32>>,
+    /// Module-level flags (TS/JS family only).
+    pub flags: Option<NativeFileFlags>,
+    /// Code / comment / blank line counts. `None` for files handled by the
+    /// line-based extractors, which have no syntax tree to find comments in.
+    pub line_counts: Option<NativeLineCounts>,
+    /// SPDX tag or recognised license header and copyright notices at the
+    /// top of the file. `None` when there are neither.
+    pub license: Option<NativeLicenseHeader>,
     /// Parse error message, if any.
     pub parse_error: Option<String>,
 }
 
+/// One file fed into `build_directory_manifest`.
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct NativeManifestFile {
+    /// Relative path from repo root (forward slashes).
+    pub rel_path: String,
+    /// Content hash of the file, e.g. `NativeParsedFile::content_hash`.
+    pub content_hash: String,
+}
+
+/// Merkle hash of one directory in a `build_directory_manifest` result.
+#[napi(object)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NativeDirectoryHash {
+    /// Directory path relative to repo root; empty for the root itself.
+    pub path: String,
+    /// SHA-256 over the sorted names and hashes of the directory's files
+    /// and subdirectories.
+    pub hash: String,
+    /// Number of files anywhere beneath the directory.
+    pub file_count: u32,
+}
+
 // Cluster + process analysis types (see native/src/cluster/types.rs, native/src/process/types.rs)
 pub use crate::cluster::types::{NativeClusterAssignment, NativeClusterEdge, NativeClusterSymbol};
 pub use crate::process::types::{
@@ -192,5 +192,11 @@ export function generateSymbolId(
   name: string,
   astFingerprint: string,
 ): string {
-  return hashContent(`${repoId}:${relPath}:${kind}:${name}:${astFingerprint}`);
+  // NFC so decomposed (macOS) paths and names hash like composed ones;
+  // matches the native generate_symbol_id.
+  const nfcRelPath = relPath.normalize("NFC");
+  const nfcName = name.normalize("NFC");
+  return hashContent(
+    `${repoId}:${nfcRelPath}:${kind}:${nfcName}:${astFingerprint}`,
+  );
 }
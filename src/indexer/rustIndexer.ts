@@ -766,6 +766,9 @@ function mapCallType(rustType: string): ExtractedCall["callType"] {
       return "function";
     case "method":
       return "method";
+    // Rust `Type::function()` calls resolve like static methods.
+    case "associated":
+      return "method";
     case "constructor":
       return "constructor";
     case "dynamic":
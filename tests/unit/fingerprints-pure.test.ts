@@ -52,6 +52,24 @@ describe("generateSymbolId", () => {
     const b = generateSymbolId("repo1", "src/foo.ts", "function", "bar", "fp2");
     assert.notStrictEqual(a, b);
   });
+
+  it("NFD and NFC relPath and name produce the same ID", () => {
+    const a = generateSymbolId(
+      "repo1",
+      "src/caf\u00e9.ts",
+      "function",
+      "caf\u00e9",
+      "fp1",
+    );
+    const b = generateSymbolId(
+      "repo1",
+      "src/cafe\u0301.ts",
+      "function",
+      "cafe\u0301",
+      "fp1",
+    );
+    assert.strictEqual(a, b);
+  });
 });
 
 describe("clearFingerprintCollisionLog", () => {